@@ -1,4 +1,8 @@
-use std::{process::Command, str};
+use {
+  base64::{engine::general_purpose::STANDARD, Engine},
+  sha2::{Digest, Sha384},
+  std::{env, fs, path::Path, process::Command, str},
+};
 
 fn git_branch() -> Option<String> {
   str::from_utf8(
@@ -24,6 +28,29 @@ fn git_commit() -> Option<String> {
   .map(|branch| branch.into())
 }
 
+fn preview_script_integrity() -> String {
+  let mut scripts = fs::read_dir("static")
+    .unwrap()
+    .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+    .filter(|name| name.starts_with("preview-") && name.ends_with(".js"))
+    .collect::<Vec<String>>();
+
+  scripts.sort();
+
+  scripts
+    .iter()
+    .map(|script| {
+      format!(
+        "pub(crate) const {}: &str = \"sha384-{}\";\n",
+        script.replace(['-', '.'], "_").to_uppercase(),
+        STANDARD.encode(Sha384::digest(
+          fs::read(Path::new("static").join(script)).unwrap()
+        )),
+      )
+    })
+    .collect()
+}
+
 fn main() {
   println!(
     "cargo:rustc-env=GIT_BRANCH={}",
@@ -33,4 +60,9 @@ fn main() {
     "cargo:rustc-env=GIT_COMMIT={}",
     git_commit().unwrap_or_default()
   );
+  fs::write(
+    Path::new(&env::var("OUT_DIR").unwrap()).join("integrity.rs"),
+    preview_script_integrity(),
+  )
+  .unwrap();
 }
@@ -17,15 +17,15 @@ use {
     Wtxid,
   },
   bitcoincore_rpc::json::{
-    Bip125Replaceable, CreateRawTransactionInput, Descriptor, EstimateMode, FeeRatePercentiles,
-    FinalizePsbtResult, GetBalancesResult, GetBalancesResultEntry, GetBlockHeaderResult,
-    GetBlockStatsResult, GetBlockchainInfoResult, GetDescriptorInfoResult, GetNetworkInfoResult,
-    GetRawTransactionResult, GetRawTransactionResultVout, GetRawTransactionResultVoutScriptPubKey,
-    GetTransactionResult, GetTransactionResultDetail, GetTransactionResultDetailCategory,
-    GetTxOutResult, GetWalletInfoResult, ImportDescriptors, ImportMultiResult,
-    ListDescriptorsResult, ListTransactionResult, ListUnspentResultEntry, ListWalletDirItem,
-    ListWalletDirResult, LoadWalletResult, SignRawTransactionInput, SignRawTransactionResult,
-    Timestamp, WalletProcessPsbtResult, WalletTxInfo,
+    Bip125Replaceable, CreateRawTransactionInput, Descriptor, EstimateMode, EstimateSmartFeeResult,
+    FeeRatePercentiles, FinalizePsbtResult, GetBalancesResult, GetBalancesResultEntry,
+    GetBlockHeaderResult, GetBlockStatsResult, GetBlockchainInfoResult, GetDescriptorInfoResult,
+    GetNetworkInfoResult, GetRawTransactionResult, GetRawTransactionResultVout,
+    GetRawTransactionResultVoutScriptPubKey, GetTransactionResult, GetTransactionResultDetail,
+    GetTransactionResultDetailCategory, GetTxOutResult, GetWalletInfoResult, ImportDescriptors,
+    ImportMultiResult, ListDescriptorsResult, ListTransactionResult, ListUnspentResultEntry,
+    ListWalletDirItem, ListWalletDirResult, LoadWalletResult, SignRawTransactionInput,
+    SignRawTransactionResult, Timestamp, WalletProcessPsbtResult, WalletTxInfo,
   },
   jsonrpc_core::{IoHandler, Value},
   jsonrpc_http_server::{CloseHandle, ServerBuilder},
@@ -247,6 +247,20 @@ impl Api for Server {
     )
   }
 
+  fn estimate_smart_fee(
+    &self,
+    conf_target: u16,
+    estimate_mode: Option<EstimateMode>,
+  ) -> Result<EstimateSmartFeeResult, jsonrpc_core::Error> {
+    assert_eq!(estimate_mode, None);
+
+    Ok(EstimateSmartFeeResult {
+      fee_rate: Some(Amount::from_sat(2000)),
+      errors: None,
+      blocks: conf_target.into(),
+    })
+  }
+
   fn get_tx_out(
     &self,
     txid: Txid,
@@ -671,13 +685,18 @@ impl Api for Server {
 
     let blockhash = tx_height.map(|tx_height| state.hashes[usize::try_from(*tx_height).unwrap()]);
 
+    let transaction = state
+      .transactions
+      .get(&txid)
+      .or_else(|| state.mempool().iter().find(|tx| tx.txid() == txid));
+
     if verbose.unwrap_or(false) {
-      match state.transactions.get(&txid) {
+      match transaction {
         Some(transaction) => Ok(
           serde_json::to_value(GetRawTransactionResult {
-            in_active_chain: Some(true),
-            hex: Vec::new(),
-            txid: Txid::all_zeros(),
+            in_active_chain: state.transactions.contains_key(&txid).then_some(true),
+            hex: serialize(transaction),
+            txid,
             hash: Wtxid::all_zeros(),
             size: 0,
             vsize: 0,
@@ -711,7 +730,7 @@ impl Api for Server {
         None => Err(Self::not_found()),
       }
     } else {
-      match state.transactions.get(&txid) {
+      match transaction {
         Some(tx) => Ok(Value::String(hex::encode(serialize(tx)))),
         None => Err(Self::not_found()),
       }
@@ -726,7 +745,10 @@ impl Api for Server {
     include_unsafe: Option<bool>,
     query_options: Option<String>,
   ) -> Result<Vec<ListUnspentResultEntry>, jsonrpc_core::Error> {
-    assert_eq!(minconf, None, "minconf param not supported");
+    assert!(
+      matches!(minconf, None | Some(0)),
+      "minconf param not supported"
+    );
     assert_eq!(maxconf, None, "maxconf param not supported");
     assert_eq!(address, None, "address param not supported");
     assert_eq!(include_unsafe, None, "include_unsafe param not supported");
@@ -762,7 +784,7 @@ impl Api for Server {
         witness_script: None,
         script_pub_key: ScriptBuf::new(),
         amount,
-        confirmations: 0,
+        confirmations: state.get_confirmations(tx).try_into().unwrap(),
         spendable: true,
         solvable: true,
         descriptor: None,
@@ -878,8 +900,6 @@ impl Api for Server {
     unlock: bool,
     outputs: Vec<JsonOutPoint>,
   ) -> Result<bool, jsonrpc_core::Error> {
-    assert!(!unlock);
-
     let mut state = self.state();
 
     if state.fail_lock_unspent {
@@ -891,7 +911,12 @@ impl Api for Server {
         vout: output.vout,
         txid: output.txid,
       };
-      assert!(state.locked.insert(output));
+
+      if unlock {
+        assert!(state.locked.remove(&output));
+      } else {
+        assert!(state.locked.insert(output));
+      }
     }
 
     Ok(true)
@@ -1000,18 +1025,19 @@ impl Api for Server {
     psbt: String,
     _extract: Option<bool>,
   ) -> Result<FinalizePsbtResult, jsonrpc_core::Error> {
-    let mut transaction = Psbt::deserialize(
+    let psbt = Psbt::deserialize(
       &base64::engine::general_purpose::STANDARD
         .decode(psbt)
         .unwrap(),
     )
-    .unwrap()
-    .unsigned_tx;
+    .unwrap();
 
-    for input in &mut transaction.input {
-      if input.witness.is_empty() {
-        input.witness = Witness::from_slice(&[&[0; 64]]);
-      }
+    let mut transaction = psbt.unsigned_tx;
+
+    for (input, psbt_input) in transaction.input.iter_mut().zip(psbt.inputs) {
+      input.witness = psbt_input
+        .final_script_witness
+        .unwrap_or_else(|| Witness::from_slice(&[&[0; 64]]));
     }
 
     Ok(FinalizePsbtResult {
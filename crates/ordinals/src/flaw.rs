@@ -12,6 +12,7 @@ pub enum Flaw {
   TruncatedField,
   UnrecognizedEvenTag,
   UnrecognizedFlag,
+  UnrecognizedOddTag,
   Varint,
 }
 
@@ -27,6 +28,7 @@ impl Display for Flaw {
       Self::TruncatedField => write!(f, "field with missing value"),
       Self::UnrecognizedEvenTag => write!(f, "unrecognized even tag"),
       Self::UnrecognizedFlag => write!(f, "unrecognized field"),
+      Self::UnrecognizedOddTag => write!(f, "unrecognized or invalid odd tag"),
       Self::Varint => write!(f, "invalid varint"),
     }
   }
@@ -23,6 +23,16 @@ impl Runestone {
   pub const COMMIT_CONFIRMATIONS: u16 = 6;
 
   pub fn decipher(transaction: &Transaction) -> Option<Artifact> {
+    Self::decipher_with(transaction, false)
+  }
+
+  /// Like `decipher`, but odd tags which are unrecognized or carry invalid
+  /// values produce a cenotaph instead of being ignored.
+  pub fn decipher_strict(transaction: &Transaction) -> Option<Artifact> {
+    Self::decipher_with(transaction, true)
+  }
+
+  fn decipher_with(transaction: &Transaction, strict: bool) -> Option<Artifact> {
     let payload = match Runestone::payload(transaction) {
       Some(Payload::Valid(payload)) => payload,
       Some(Payload::Invalid(flaw)) => {
@@ -110,6 +120,10 @@ impl Runestone {
       flaw.get_or_insert(Flaw::UnrecognizedEvenTag);
     }
 
+    if strict && !fields.is_empty() {
+      flaw.get_or_insert(Flaw::UnrecognizedOddTag);
+    }
+
     if let Some(flaw) = flaw {
       return Some(Artifact::Cenotaph(Cenotaph {
         flaw: Some(flaw),
@@ -262,11 +276,19 @@ mod tests {
   }
 
   fn decipher(integers: &[u128]) -> Artifact {
+    Runestone::decipher(&transaction(integers)).unwrap()
+  }
+
+  fn decipher_strict(integers: &[u128]) -> Artifact {
+    Runestone::decipher_strict(&transaction(integers)).unwrap()
+  }
+
+  fn transaction(integers: &[u128]) -> Transaction {
     let payload = payload(integers);
 
     let payload: &PushBytes = payload.as_slice().try_into().unwrap();
 
-    Runestone::decipher(&Transaction {
+    Transaction {
       input: Vec::new(),
       output: vec![TxOut {
         script_pubkey: script::Builder::new()
@@ -278,8 +300,7 @@ mod tests {
       }],
       lock_time: LockTime::ZERO,
       version: 2,
-    })
-    .unwrap()
+    }
   }
 
   fn payload(integers: &[u128]) -> Vec<u8> {
@@ -843,6 +864,68 @@ mod tests {
     );
   }
 
+  #[test]
+  fn unrecognized_odd_tag_is_cenotaph_in_strict_mode() {
+    assert_eq!(
+      decipher_strict(&[Tag::Nop.into(), 100, Tag::Body.into(), 1, 1, 2, 0]),
+      Artifact::Cenotaph(Cenotaph {
+        flaw: Some(Flaw::UnrecognizedOddTag),
+        ..default()
+      }),
+    );
+  }
+
+  #[test]
+  fn duplicate_odd_tags_are_cenotaph_in_strict_mode() {
+    assert_eq!(
+      decipher_strict(&[
+        Tag::Flags.into(),
+        Flag::Etching.mask(),
+        Tag::Rune.into(),
+        4,
+        Tag::Divisibility.into(),
+        4,
+        Tag::Divisibility.into(),
+        5,
+      ]),
+      Artifact::Cenotaph(Cenotaph {
+        flaw: Some(Flaw::UnrecognizedOddTag),
+        etching: Some(Rune(4)),
+        ..default()
+      }),
+    );
+  }
+
+  #[test]
+  fn invalid_divisibility_is_cenotaph_in_strict_mode() {
+    assert_eq!(
+      decipher_strict(&[Tag::Divisibility.into(), u128::MAX]),
+      Artifact::Cenotaph(Cenotaph {
+        flaw: Some(Flaw::UnrecognizedOddTag),
+        ..default()
+      }),
+    );
+  }
+
+  #[test]
+  fn strict_mode_accepts_valid_runestones() {
+    let integers = [
+      Tag::Flags.into(),
+      Flag::Etching.mask(),
+      Tag::Rune.into(),
+      4,
+      Tag::Divisibility.into(),
+      4,
+      Tag::Body.into(),
+      1,
+      1,
+      2,
+      0,
+    ];
+
+    assert_eq!(decipher_strict(&integers), decipher(&integers));
+  }
+
   #[test]
   fn runestone_with_unrecognized_even_tag_is_cenotaph() {
     assert_eq!(
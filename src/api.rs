@@ -4,7 +4,7 @@ use {
 };
 
 pub use crate::templates::{
-  BlocksHtml as Blocks, RuneHtml as Rune, RunesHtml as Runes, StatusHtml as Status,
+  BlocksHtml as Blocks, RuneHoldersHtml as RuneHolders, RuneHtml as Rune, StatusHtml as Status,
   TransactionHtml as Transaction,
 };
 
@@ -37,6 +37,39 @@ impl Block {
   }
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct BlockHeader {
+  pub bits: u32,
+  #[serde(with = "SerHex::<Strict>")]
+  pub chainwork: [u8; 32],
+  pub confirmations: i32,
+  pub difficulty: f64,
+  pub hash: BlockHash,
+  pub height: u32,
+  pub median_time: Option<u64>,
+  pub merkle_root: TxMerkleNode,
+  pub next_block: Option<BlockHash>,
+  pub nonce: u32,
+  pub previous_block: Option<BlockHash>,
+  pub target: BlockHash,
+  pub timestamp: u64,
+  pub transaction_count: u64,
+  pub version: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RawBlockHeader {
+  pub bits: u32,
+  pub hash: BlockHash,
+  pub height: u32,
+  pub hex: String,
+  pub merkle_root: TxMerkleNode,
+  pub nonce: u32,
+  pub previous_block: BlockHash,
+  pub timestamp: u32,
+  pub version: u32,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct BlockInfo {
   pub average_fee: u64,
@@ -48,6 +81,7 @@ pub struct BlockInfo {
   pub difficulty: f64,
   pub hash: BlockHash,
   pub height: u32,
+  pub inscription_count: Option<u64>,
   pub max_fee: u64,
   pub max_fee_rate: u64,
   pub max_tx_size: u32,
@@ -59,6 +93,7 @@ pub struct BlockInfo {
   pub next_block: Option<BlockHash>,
   pub nonce: u32,
   pub previous_block: Option<BlockHash>,
+  pub rune_etching_count: Option<u64>,
   pub subsidy: u64,
   pub target: BlockHash,
   pub timestamp: u64,
@@ -76,28 +111,66 @@ pub struct Children {
   pub page: usize,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Count {
+  pub count: u64,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct Inscription {
   pub address: Option<String>,
   pub charms: Vec<Charm>,
   pub children: Vec<InscriptionId>,
+  pub cid: Option<Cid>,
   pub content_length: Option<usize>,
   pub content_type: Option<String>,
+  pub delegate: Option<InscriptionId>,
   pub effective_content_type: Option<String>,
   pub fee: u64,
+  pub fee_rate: Option<f64>,
   pub height: u32,
   pub id: InscriptionId,
   pub next: Option<InscriptionId>,
   pub number: i32,
   pub parents: Vec<InscriptionId>,
   pub previous: Option<InscriptionId>,
+  pub rarity: Option<Rarity>,
+  pub recursive: bool,
   pub rune: Option<SpacedRune>,
   pub sat: Option<ordinals::Sat>,
   pub satpoint: SatPoint,
+  pub teleburn: Teleburn,
   pub timestamp: i64,
+  #[serde(default)]
+  pub unbound: bool,
   pub value: Option<u64>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct Teleburn {
+  pub ethereum: String,
+}
+
+impl From<InscriptionId> for Teleburn {
+  fn from(inscription_id: InscriptionId) -> Self {
+    Self {
+      ethereum: teleburn::Ethereum::from(inscription_id).to_string(),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentTypeCount {
+  pub content_type: Option<String>,
+  pub count: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionContentType {
+  pub content_type: Option<String>,
+  pub content_length: Option<usize>,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct InscriptionRecursive {
   pub charms: Vec<Charm>,
@@ -108,12 +181,26 @@ pub struct InscriptionRecursive {
   pub id: InscriptionId,
   pub number: i32,
   pub output: OutPoint,
+  pub rarity: Option<Rarity>,
+  pub rune: Option<SpacedRune>,
   pub sat: Option<ordinals::Sat>,
   pub satpoint: SatPoint,
   pub timestamp: i64,
   pub value: Option<u64>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionRune {
+  pub id: RuneId,
+  pub rune: SpacedRune,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MinimumRune {
+  pub height: u32,
+  pub rune: ordinals::Rune,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Inscriptions {
   pub ids: Vec<InscriptionId>,
@@ -124,8 +211,14 @@ pub struct Inscriptions {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub address: Option<Address<NetworkUnchecked>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub confirmed: Option<bool>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub height: Option<u32>,
   pub indexed: bool,
   pub inscriptions: Vec<InscriptionId>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub rarity_counts: Option<BTreeMap<Rarity, u64>>,
   pub runes: Vec<(SpacedRune, Pile)>,
   pub sat_ranges: Option<Vec<(u64, u64)>>,
   pub script_pubkey: String,
@@ -150,8 +243,11 @@ impl Output {
         .address_from_script(&tx_out.script_pubkey)
         .ok()
         .map(|address| uncheck(&address)),
+      confirmed: None,
+      height: None,
       indexed,
       inscriptions,
+      rarity_counts: sat_ranges.as_deref().map(Self::rarity_counts),
       runes,
       sat_ranges,
       script_pubkey: tx_out.script_pubkey.to_asm_string(),
@@ -160,6 +256,80 @@ impl Output {
       value: tx_out.value,
     }
   }
+
+  // Only the first sat of a block can be rare, and the first sat of a block
+  // always starts a sat range, so only range starts need to be checked.
+  fn rarity_counts(sat_ranges: &[(u64, u64)]) -> BTreeMap<Rarity, u64> {
+    let mut counts = BTreeMap::new();
+
+    for (start, end) in sat_ranges {
+      let mut common = end - start;
+
+      let sat = Sat(*start);
+
+      if !sat.common() {
+        *counts.entry(sat.rarity()).or_default() += 1;
+        common -= 1;
+      }
+
+      if common > 0 {
+        *counts.entry(Rarity::Common).or_default() += common;
+      }
+    }
+
+    counts
+  }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct Reorg {
+  pub depth: u32,
+  pub height: u32,
+  pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct ReorgPurge {
+  pub depth: u32,
+  pub height: u32,
+  pub start_height: u32,
+  pub end_height: u32,
+  pub old_tip: Option<BlockHash>,
+  pub new_tip: Option<BlockHash>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Runes {
+  pub entries: Vec<(RuneId, RunesEntry)>,
+  pub more: bool,
+  pub prev: Option<usize>,
+  pub next: Option<usize>,
+}
+
+/// A rune entry with its derived `supply`, so that listings don't need to
+/// recompute it from `premine`, `mints`, and `terms`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RuneEntry")]
+pub struct RunesEntry {
+  #[serde(flatten)]
+  pub entry: RuneEntry,
+  pub supply: u128,
+}
+
+impl From<RuneEntry> for RunesEntry {
+  fn from(entry: RuneEntry) -> Self {
+    Self {
+      supply: entry.supply(),
+      entry,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunesEtched {
+  pub ids: Vec<RuneId>,
+  pub more: bool,
+  pub page: usize,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -181,11 +351,24 @@ pub struct Sat {
   pub timestamp: i64,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Supply {
+  pub circulating: u64,
+  pub lost: u64,
+  pub mined: u64,
+  pub unmined: u64,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct SatInscription {
   pub id: Option<InscriptionId>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SatLocation {
+  pub satpoint: Option<SatPoint>,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct SatInscriptions {
   pub ids: Vec<InscriptionId>,
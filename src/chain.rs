@@ -17,6 +17,27 @@ impl Chain {
     self.into()
   }
 
+  pub(crate) fn from_network(network: Network) -> Option<Self> {
+    match network {
+      Network::Bitcoin => Some(Self::Mainnet),
+      Network::Testnet => Some(Self::Testnet),
+      Network::Signet => Some(Self::Signet),
+      Network::Regtest => Some(Self::Regtest),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn from_core_chain_str(chain: &str) -> Result<Self> {
+    if chain == "testnet4" {
+      bail!("Bitcoin RPC server on testnet4, which is not supported");
+    }
+
+    Network::from_core_arg(chain)
+      .ok()
+      .and_then(Self::from_network)
+      .ok_or_else(|| anyhow!("Bitcoin RPC server on unknown chain: {chain}"))
+  }
+
   pub(crate) fn default_rpc_port(self) -> u16 {
     match self {
       Self::Mainnet => 8332,
@@ -138,4 +159,37 @@ mod tests {
       "invalid chain `foo`"
     );
   }
+
+  #[test]
+  fn from_network() {
+    for chain in [
+      Chain::Mainnet,
+      Chain::Testnet,
+      Chain::Signet,
+      Chain::Regtest,
+    ] {
+      assert_eq!(Chain::from_network(chain.network()), Some(chain));
+    }
+  }
+
+  #[test]
+  fn from_core_chain_str() {
+    assert_eq!(Chain::from_core_chain_str("main").unwrap(), Chain::Mainnet);
+    assert_eq!(Chain::from_core_chain_str("test").unwrap(), Chain::Testnet);
+    assert_eq!(Chain::from_core_chain_str("signet").unwrap(), Chain::Signet);
+    assert_eq!(
+      Chain::from_core_chain_str("regtest").unwrap(),
+      Chain::Regtest
+    );
+    assert_eq!(
+      Chain::from_core_chain_str("testnet4")
+        .unwrap_err()
+        .to_string(),
+      "Bitcoin RPC server on testnet4, which is not supported"
+    );
+    assert_eq!(
+      Chain::from_core_chain_str("foo").unwrap_err().to_string(),
+      "Bitcoin RPC server on unknown chain: foo"
+    );
+  }
 }
@@ -6,13 +6,18 @@ use {
     },
     event::Event,
     lot::Lot,
+    lru::Lru,
     reorg::Reorg,
     updater::Updater,
   },
   super::*,
   crate::{
     runes::MintError,
-    subcommand::{find::FindRangeOutput, server::query},
+    subcommand::{
+      find::FindRangeOutput,
+      index::{check, info},
+      server::query,
+    },
     templates::StatusHtml,
   },
   bitcoin::block::Header,
@@ -41,6 +46,7 @@ pub(crate) mod entry;
 pub mod event;
 mod fetcher;
 mod lot;
+pub(crate) mod lru;
 mod reorg;
 mod rtx;
 mod updater;
@@ -48,11 +54,14 @@ mod updater;
 #[cfg(test)]
 pub(crate) mod testing;
 
-const SCHEMA_VERSION: u64 = 25;
+const MAX_DELEGATE_DEPTH: usize = 16;
+const SCHEMA_VERSION: u64 = 30;
 
+define_multimap_table! { RUNE_ID_TO_OUTPOINTS, RuneIdValue, &OutPointValue }
 define_multimap_table! { SATPOINT_TO_SEQUENCE_NUMBER, &SatPointValue, u32 }
 define_multimap_table! { SAT_TO_SEQUENCE_NUMBER, u64, u32 }
 define_multimap_table! { SEQUENCE_NUMBER_TO_CHILDREN, u32, u32 }
+define_table! { CONTENT_SHA256_TO_INSCRIPTION_ID, &[u8; 32], InscriptionIdValue }
 define_table! { CONTENT_TYPE_TO_COUNT, Option<&[u8]>, u64 }
 define_table! { HEIGHT_TO_BLOCK_HEADER, u32, &HeaderValue }
 define_table! { HEIGHT_TO_LAST_SEQUENCE_NUMBER, u32, u32 }
@@ -62,9 +71,11 @@ define_table! { INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER, i32, u32 }
 define_table! { OUTPOINT_TO_RUNE_BALANCES, &OutPointValue, &[u8] }
 define_table! { OUTPOINT_TO_SAT_RANGES, &OutPointValue, &[u8] }
 define_table! { OUTPOINT_TO_VALUE, &OutPointValue, u64}
+define_table! { RECURSIVE_INSCRIPTIONS, u32, () }
 define_table! { RUNE_ID_TO_RUNE_ENTRY, RuneIdValue, RuneEntryValue }
 define_table! { RUNE_TO_RUNE_ID, u128, RuneIdValue }
 define_table! { SAT_TO_SATPOINT, u64, &SatPointValue }
+define_table! { SEQUENCE_NUMBER_TO_CONTENT_LENGTH, u32, u64 }
 define_table! { SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY, u32, InscriptionEntryValue }
 define_table! { SEQUENCE_NUMBER_TO_RUNE_ID, u32, RuneIdValue }
 define_table! { SEQUENCE_NUMBER_TO_SATPOINT, u32, &SatPointValue }
@@ -90,6 +101,11 @@ pub(crate) enum Statistic {
   IndexTransactions = 12,
   IndexSpentSats = 13,
   InitialSyncTime = 14,
+  CompressSatRanges = 15,
+  FirstInscriptionHeight = 16,
+  IndexCids = 17,
+  StrictRunestone = 18,
+  JubileeHeight = 19,
 }
 
 impl Statistic {
@@ -151,6 +167,24 @@ impl From<TableStats> for TableInfo {
   }
 }
 
+#[derive(Debug, PartialEq)]
+pub(crate) struct ContentTooLarge {
+  pub(crate) inscription_id: InscriptionId,
+  pub(crate) content_length: u64,
+}
+
+impl Display for ContentTooLarge {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(
+      f,
+      "inscription {} content is {} bytes",
+      self.inscription_id, self.content_length
+    )
+  }
+}
+
+impl std::error::Error for ContentTooLarge {}
+
 #[derive(Serialize)]
 pub(crate) struct TransactionInfo {
   pub(crate) starting_block_count: u32,
@@ -182,6 +216,7 @@ impl<T> BitcoinCoreRpcResultExt<T> for Result<T, bitcoincore_rpc::Error> {
 
 pub struct Index {
   pub(crate) client: Client,
+  compress_sat_ranges: bool,
   database: Database,
   durability: redb::Durability,
   event_sender: Option<tokio::sync::mpsc::Sender<Event>>,
@@ -189,14 +224,20 @@ pub struct Index {
   genesis_block_coinbase_transaction: Transaction,
   genesis_block_coinbase_txid: Txid,
   height_limit: Option<u32>,
+  hidden_patterns: Vec<regex::bytes::Regex>,
+  index_cids: bool,
   index_runes: bool,
   index_sats: bool,
   index_spent_sats: bool,
   index_transactions: bool,
+  inscriptions_on_output_cache: Mutex<Lru<OutPoint, Vec<InscriptionId>>>,
   settings: Settings,
   path: PathBuf,
+  reorgs: Mutex<VecDeque<api::Reorg>>,
+  rpc_latency: AtomicU64,
   started: DateTime<Utc>,
   unrecoverably_reorged: AtomicBool,
+  watched_runes: Mutex<HashSet<RuneId>>,
 }
 
 impl Index {
@@ -210,6 +251,15 @@ impl Index {
   ) -> Result<Self> {
     let client = settings.bitcoin_rpc_client(None)?;
 
+    let hidden_patterns = settings
+      .hidden_patterns()
+      .iter()
+      .map(|pattern| {
+        regex::bytes::Regex::new(pattern)
+          .with_context(|| format!("invalid hidden pattern `{pattern}`"))
+      })
+      .collect::<Result<Vec<regex::bytes::Regex>>>()?;
+
     let path = settings.index().to_owned();
 
     if let Err(err) = fs::create_dir_all(path.parent().unwrap()) {
@@ -233,11 +283,14 @@ impl Index {
     let once = Once::new();
     let progress_bar = Mutex::new(None);
     let integration_test = settings.integration_test();
+    let quiet = settings.quiet();
 
     let repair_callback = move |progress: &mut RepairSession| {
-      once.call_once(|| println!("Index file `{}` needs recovery. This can take a long time, especially for the --index-sats index.", index_path.display()));
+      if !quiet {
+        once.call_once(|| eprintln!("Index file `{}` needs recovery. This can take a long time, especially for the --index-sats index.", index_path.display()));
+      }
 
-      if !(cfg!(test) || log_enabled!(log::Level::Info) || integration_test) {
+      if !(cfg!(test) || log_enabled!(log::Level::Info) || integration_test || quiet) {
         let mut guard = progress_bar.lock().unwrap();
 
         let progress_bar = guard.get_or_insert_with(|| {
@@ -296,9 +349,11 @@ impl Index {
 
         tx.set_durability(durability);
 
+        tx.open_multimap_table(RUNE_ID_TO_OUTPOINTS)?;
         tx.open_multimap_table(SATPOINT_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?;
+        tx.open_table(CONTENT_SHA256_TO_INSCRIPTION_ID)?;
         tx.open_table(CONTENT_TYPE_TO_COUNT)?;
         tx.open_table(HEIGHT_TO_BLOCK_HEADER)?;
         tx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
@@ -307,9 +362,11 @@ impl Index {
         tx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
         tx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
         tx.open_table(OUTPOINT_TO_VALUE)?;
+        tx.open_table(RECURSIVE_INSCRIPTIONS)?;
         tx.open_table(RUNE_ID_TO_RUNE_ENTRY)?;
         tx.open_table(RUNE_TO_RUNE_ID)?;
         tx.open_table(SAT_TO_SATPOINT)?;
+        tx.open_table(SEQUENCE_NUMBER_TO_CONTENT_LENGTH)?;
         tx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
         tx.open_table(SEQUENCE_NUMBER_TO_RUNE_ID)?;
         tx.open_table(SEQUENCE_NUMBER_TO_SATPOINT)?;
@@ -324,6 +381,12 @@ impl Index {
             outpoint_to_sat_ranges.insert(&OutPoint::null().store(), [].as_slice())?;
           }
 
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::IndexCids,
+            u64::from(settings.index_cids()),
+          )?;
+
           Self::set_statistic(
             &mut statistics,
             Statistic::IndexRunes,
@@ -348,6 +411,30 @@ impl Index {
             u64::from(settings.index_transactions()),
           )?;
 
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::CompressSatRanges,
+            u64::from(settings.compress_sat_ranges()),
+          )?;
+
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::FirstInscriptionHeight,
+            settings.first_inscription_height().into(),
+          )?;
+
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::StrictRunestone,
+            u64::from(settings.strict_runestone()),
+          )?;
+
+          Self::set_statistic(
+            &mut statistics,
+            Statistic::JubileeHeight,
+            settings.jubilee_height().into(),
+          )?;
+
           Self::set_statistic(&mut statistics, Statistic::Schema, SCHEMA_VERSION)?;
         }
 
@@ -402,18 +489,92 @@ impl Index {
       Err(error) => bail!("failed to open index: {error}"),
     };
 
+    let compress_sat_ranges;
+    let index_cids;
     let index_runes;
     let index_sats;
     let index_spent_sats;
     let index_transactions;
+    let jubilee_height;
+    let strict_runestone;
 
     {
       let tx = database.begin_read()?;
       let statistics = tx.open_table(STATISTIC_TO_COUNT)?;
+      compress_sat_ranges = Self::is_statistic_set(&statistics, Statistic::CompressSatRanges)?;
+      index_cids = Self::is_statistic_set(&statistics, Statistic::IndexCids)?;
       index_runes = Self::is_statistic_set(&statistics, Statistic::IndexRunes)?;
       index_sats = Self::is_statistic_set(&statistics, Statistic::IndexSats)?;
       index_spent_sats = Self::is_statistic_set(&statistics, Statistic::IndexSpentSats)?;
       index_transactions = Self::is_statistic_set(&statistics, Statistic::IndexTransactions)?;
+      jubilee_height = statistics
+        .get(&Statistic::JubileeHeight.key())?
+        .map(|guard| guard.value());
+      strict_runestone = statistics
+        .get(&Statistic::StrictRunestone.key())?
+        .map(|guard| guard.value() != 0);
+    }
+
+    for (flag, configured, indexed) in [
+      (
+        "--compress-sat-ranges",
+        settings.compress_sat_ranges(),
+        compress_sat_ranges,
+      ),
+      ("--index-cids", settings.index_cids(), index_cids),
+      ("--index-runes", settings.index_runes(), index_runes),
+      (
+        "--index-spent-sats",
+        settings.index_spent_sats(),
+        index_spent_sats,
+      ),
+      ("--index-sats", settings.index_sats(), index_sats),
+      (
+        "--index-transactions",
+        settings.index_transactions(),
+        index_transactions,
+      ),
+    ] {
+      if configured && !indexed {
+        bail!(
+          "index at `{}` was built without `{flag}`, delete and rebuild the index to enable it",
+          path.display()
+        );
+      }
+    }
+
+    // indices built before these settings were recorded are not checked
+    if let Some(strict_runestone) =
+      strict_runestone.filter(|&strict_runestone| strict_runestone != settings.strict_runestone())
+    {
+      bail!(
+        "index at `{}` was built {} `--strict-runestone`, delete and rebuild the index to change it",
+        path.display(),
+        if strict_runestone { "with" } else { "without" },
+      );
+    }
+
+    if let Some(jubilee_height) = jubilee_height
+      .filter(|&jubilee_height| jubilee_height != u64::from(settings.jubilee_height()))
+    {
+      bail!(
+        "index at `{}` was built with jubilee height {jubilee_height}, but jubilee height is {}, delete and rebuild the index to change it",
+        path.display(),
+        settings.jubilee_height(),
+      );
+    }
+
+    let default_first_inscription_height = settings.chain().first_inscription_height();
+
+    if !settings.integration_test()
+      && settings.first_inscription_height() != default_first_inscription_height
+    {
+      log::warn!(
+        "first inscription height is {}, but {} is the default for {}, inscriptions below it will not be indexed",
+        settings.first_inscription_height(),
+        default_first_inscription_height,
+        settings.chain(),
+      );
     }
 
     let genesis_block_coinbase_transaction =
@@ -422,20 +583,29 @@ impl Index {
     Ok(Self {
       genesis_block_coinbase_txid: genesis_block_coinbase_transaction.txid(),
       client,
+      compress_sat_ranges,
       database,
       durability,
       event_sender,
       first_inscription_height: settings.first_inscription_height(),
       genesis_block_coinbase_transaction,
       height_limit: settings.height_limit(),
+      hidden_patterns,
+      index_cids,
       index_runes,
       index_sats,
       index_spent_sats,
       index_transactions,
+      inscriptions_on_output_cache: Mutex::new(Lru::new(
+        settings.inscriptions_on_output_cache_size(),
+      )),
       settings: settings.clone(),
       path,
+      reorgs: Mutex::new(VecDeque::new()),
+      rpc_latency: AtomicU64::new(u64::MAX),
       started: Utc::now(),
       unrecoverably_reorged: AtomicBool::new(false),
+      watched_runes: Mutex::new(HashSet::new()),
     })
   }
 
@@ -455,6 +625,28 @@ impl Index {
     )
   }
 
+  /// Only send rune mint and mint rollback events for watched runes. If no
+  /// runes are watched, events are sent for all runes.
+  pub fn watch_rune(&self, rune_id: RuneId) {
+    self.watched_runes.lock().unwrap().insert(rune_id);
+  }
+
+  pub(crate) fn watched_runes(&self) -> HashSet<RuneId> {
+    self.watched_runes.lock().unwrap().clone()
+  }
+
+  pub(crate) fn has_cid_index(&self) -> bool {
+    self.index_cids
+  }
+
+  pub(crate) fn has_inscription_index(&self) -> bool {
+    self.settings.index_inscriptions()
+  }
+
+  pub(crate) fn hidden_patterns(&self) -> &[regex::bytes::Regex] {
+    &self.hidden_patterns
+  }
+
   pub(crate) fn has_rune_index(&self) -> bool {
     self.index_runes
   }
@@ -463,6 +655,42 @@ impl Index {
     self.index_sats
   }
 
+  /// Returns the first inscription height the index was built with, if it
+  /// differs from the configured first inscription height. Indices built
+  /// before this height was recorded never report a mismatch.
+  pub(crate) fn first_inscription_height_mismatch(&self) -> Result<Option<u32>> {
+    let indexed = self
+      .database
+      .begin_read()?
+      .open_table(STATISTIC_TO_COUNT)?
+      .get(&Statistic::FirstInscriptionHeight.key())?
+      .map(|guard| guard.value());
+
+    Ok(
+      indexed
+        .map(|height| u32::try_from(height).unwrap())
+        .filter(|&height| height != self.first_inscription_height),
+    )
+  }
+
+  pub(crate) fn get_content_type_counts(&self) -> Result<Vec<(Option<Vec<u8>>, u64)>> {
+    Self::content_type_counts(&self.database.begin_read()?)
+  }
+
+  fn content_type_counts(rtx: &redb::ReadTransaction) -> Result<Vec<(Option<Vec<u8>>, u64)>> {
+    let mut content_type_counts = rtx
+      .open_table(CONTENT_TYPE_TO_COUNT)?
+      .iter()?
+      .map(|result| {
+        result.map(|(key, value)| (key.value().map(|slice| slice.into()), value.value()))
+      })
+      .collect::<Result<Vec<(Option<Vec<u8>>, u64)>, StorageError>>()?;
+
+    content_type_counts.sort_by_key(|(_content_type, count)| Reverse(*count));
+
+    Ok(content_type_counts)
+  }
+
   pub(crate) fn status(&self) -> Result<StatusHtml> {
     let rtx = self.database.begin_read()?;
 
@@ -490,39 +718,71 @@ impl Index {
     let cursed_inscriptions = statistic(Statistic::CursedInscriptions)?;
     let initial_sync_time = statistic(Statistic::InitialSyncTime)?;
 
-    let mut content_type_counts = rtx
-      .open_table(CONTENT_TYPE_TO_COUNT)?
-      .iter()?
-      .map(|result| {
-        result.map(|(key, value)| (key.value().map(|slice| slice.into()), value.value()))
-      })
-      .collect::<Result<Vec<(Option<Vec<u8>>, u64)>, StorageError>>()?;
+    let content_type_counts = Self::content_type_counts(&rtx)?;
 
-    content_type_counts.sort_by_key(|(_content_type, count)| Reverse(*count));
+    let lost_sats = statistic(Statistic::LostSats)?;
+
+    let sat_index = statistic(Statistic::IndexSats)? != 0;
+
+    let supply = sat_index.then(|| {
+      let mined = Height(next_height).starting_sat().n();
+
+      api::Supply {
+        circulating: mined.saturating_sub(lost_sats),
+        lost: lost_sats,
+        mined,
+        unmined: Sat::SUPPLY - mined,
+      }
+    });
+
+    let rpc_latency = Some(self.rpc_latency.load(atomic::Ordering::Relaxed))
+      .filter(|&micros| micros != u64::MAX)
+      .map(Duration::from_micros);
 
     Ok(StatusHtml {
       blessed_inscriptions,
       chain: self.settings.chain(),
       content_type_counts,
       cursed_inscriptions,
+      first_inscription_height: self.first_inscription_height,
       height,
       initial_sync_time: Duration::from_micros(initial_sync_time),
       inscriptions: blessed_inscriptions + cursed_inscriptions,
-      lost_sats: statistic(Statistic::LostSats)?,
+      lost_sats,
       minimum_rune_for_next_block: Rune::minimum_at_height(
         self.settings.chain().network(),
         Height(next_height),
       ),
+      reorgs: self.reorgs(),
+      rpc_latency,
       rune_index: statistic(Statistic::IndexRunes)? != 0,
       runes: statistic(Statistic::Runes)?,
-      sat_index: statistic(Statistic::IndexSats)? != 0,
+      sat_index,
       started: self.started,
+      supply,
       transaction_index: statistic(Statistic::IndexTransactions)? != 0,
       unrecoverably_reorged: self.unrecoverably_reorged.load(atomic::Ordering::Relaxed),
       uptime: (Utc::now() - self.started).to_std()?,
+      version: env!("CARGO_PKG_VERSION").into(),
     })
   }
 
+  /// Records the latency of an RPC call the updater made, for `/status`
+  pub(crate) fn record_rpc_latency(&self, latency: Duration) {
+    self.rpc_latency.store(
+      latency
+        .as_micros()
+        .try_into()
+        .unwrap_or(u64::MAX - 1)
+        .min(u64::MAX - 1),
+      atomic::Ordering::Relaxed,
+    );
+  }
+
+  pub(crate) fn reorgs(&self) -> Vec<api::Reorg> {
+    self.reorgs.lock().unwrap().iter().copied().collect()
+  }
+
   pub(crate) fn info(&self) -> Result<Info> {
     let stats = self.database.begin_write()?.stats()?;
 
@@ -609,6 +869,226 @@ impl Index {
     Ok(info)
   }
 
+  pub(crate) fn table_info(&self, name: &str) -> Result<info::TableOutput> {
+    let rtx = self.database.begin_read()?;
+
+    match name {
+      "CONTENT_SHA256_TO_INSCRIPTION_ID" => {
+        Self::sample_table(&rtx, CONTENT_SHA256_TO_INSCRIPTION_ID, |key, value| {
+          Ok((hex::encode(key), InscriptionId::load(value).to_string()))
+        })
+      }
+      "CONTENT_TYPE_TO_COUNT" => Self::sample_table(&rtx, CONTENT_TYPE_TO_COUNT, |key, value| {
+        Ok((
+          key
+            .map(|content_type| String::from_utf8_lossy(content_type).into())
+            .unwrap_or_else(|| "none".into()),
+          value.to_string(),
+        ))
+      }),
+      "HEIGHT_TO_BLOCK_HEADER" => Self::sample_table(&rtx, HEIGHT_TO_BLOCK_HEADER, |key, value| {
+        Ok((
+          key.to_string(),
+          Header::load(*value).block_hash().to_string(),
+        ))
+      }),
+      "HEIGHT_TO_LAST_SEQUENCE_NUMBER" => {
+        Self::sample_table(&rtx, HEIGHT_TO_LAST_SEQUENCE_NUMBER, |key, value| {
+          Ok((key.to_string(), value.to_string()))
+        })
+      }
+      "HOME_INSCRIPTIONS" => Self::sample_table(&rtx, HOME_INSCRIPTIONS, |key, value| {
+        Ok((key.to_string(), InscriptionId::load(value).to_string()))
+      }),
+      "INSCRIPTION_ID_TO_SEQUENCE_NUMBER" => {
+        Self::sample_table(&rtx, INSCRIPTION_ID_TO_SEQUENCE_NUMBER, |key, value| {
+          Ok((InscriptionId::load(key).to_string(), value.to_string()))
+        })
+      }
+      "INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER" => {
+        Self::sample_table(&rtx, INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER, |key, value| {
+          Ok((key.to_string(), value.to_string()))
+        })
+      }
+      "OUTPOINT_TO_RUNE_BALANCES" => {
+        Self::sample_table(&rtx, OUTPOINT_TO_RUNE_BALANCES, |key, value| {
+          let mut balances = Vec::new();
+          let mut i = 0;
+          while i < value.len() {
+            let ((id, amount), length) = Self::decode_rune_balance(&value[i..])?;
+            balances.push(format!("{id}={amount}"));
+            i += length;
+          }
+          Ok((OutPoint::load(*key).to_string(), balances.join(" ")))
+        })
+      }
+      "OUTPOINT_TO_SAT_RANGES" => Self::sample_table(&rtx, OUTPOINT_TO_SAT_RANGES, |key, value| {
+        Ok((
+          OutPoint::load(*key).to_string(),
+          self
+            .load_sat_ranges(value)?
+            .into_iter()
+            .map(|(start, end)| format!("{start}-{end}"))
+            .collect::<Vec<String>>()
+            .join(" "),
+        ))
+      }),
+      "OUTPOINT_TO_VALUE" => Self::sample_table(&rtx, OUTPOINT_TO_VALUE, |key, value| {
+        Ok((OutPoint::load(*key).to_string(), value.to_string()))
+      }),
+      "RECURSIVE_INSCRIPTIONS" => Self::sample_table(&rtx, RECURSIVE_INSCRIPTIONS, |key, ()| {
+        Ok((key.to_string(), String::new()))
+      }),
+      "RUNE_ID_TO_OUTPOINTS" => {
+        Self::sample_multimap_table(&rtx, RUNE_ID_TO_OUTPOINTS, |key, value| {
+          Ok((
+            RuneId::load(key).to_string(),
+            OutPoint::load(*value).to_string(),
+          ))
+        })
+      }
+      "RUNE_ID_TO_RUNE_ENTRY" => Self::sample_table(&rtx, RUNE_ID_TO_RUNE_ENTRY, |key, value| {
+        Ok((
+          RuneId::load(key).to_string(),
+          RuneEntry::load(value).spaced_rune.to_string(),
+        ))
+      }),
+      "RUNE_TO_RUNE_ID" => Self::sample_table(&rtx, RUNE_TO_RUNE_ID, |key, value| {
+        Ok((Rune(key).to_string(), RuneId::load(value).to_string()))
+      }),
+      "SAT_TO_SATPOINT" => Self::sample_table(&rtx, SAT_TO_SATPOINT, |key, value| {
+        Ok((key.to_string(), SatPoint::load(*value).to_string()))
+      }),
+      "SATPOINT_TO_SEQUENCE_NUMBER" => {
+        Self::sample_multimap_table(&rtx, SATPOINT_TO_SEQUENCE_NUMBER, |key, value| {
+          Ok((SatPoint::load(*key).to_string(), value.to_string()))
+        })
+      }
+      "SAT_TO_SEQUENCE_NUMBER" => {
+        Self::sample_multimap_table(&rtx, SAT_TO_SEQUENCE_NUMBER, |key, value| {
+          Ok((key.to_string(), value.to_string()))
+        })
+      }
+      "SEQUENCE_NUMBER_TO_CHILDREN" => {
+        Self::sample_multimap_table(&rtx, SEQUENCE_NUMBER_TO_CHILDREN, |key, value| {
+          Ok((key.to_string(), value.to_string()))
+        })
+      }
+      "SEQUENCE_NUMBER_TO_CONTENT_LENGTH" => {
+        Self::sample_table(&rtx, SEQUENCE_NUMBER_TO_CONTENT_LENGTH, |key, value| {
+          Ok((key.to_string(), value.to_string()))
+        })
+      }
+      "SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY" => {
+        Self::sample_table(&rtx, SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY, |key, value| {
+          let entry = InscriptionEntry::load(value);
+          Ok((
+            key.to_string(),
+            format!("{} #{}", entry.id, entry.inscription_number),
+          ))
+        })
+      }
+      "SEQUENCE_NUMBER_TO_RUNE_ID" => {
+        Self::sample_table(&rtx, SEQUENCE_NUMBER_TO_RUNE_ID, |key, value| {
+          Ok((key.to_string(), RuneId::load(value).to_string()))
+        })
+      }
+      "SEQUENCE_NUMBER_TO_SATPOINT" => {
+        Self::sample_table(&rtx, SEQUENCE_NUMBER_TO_SATPOINT, |key, value| {
+          Ok((key.to_string(), SatPoint::load(*value).to_string()))
+        })
+      }
+      "STATISTIC_TO_COUNT" => Self::sample_table(&rtx, STATISTIC_TO_COUNT, |key, value| {
+        Ok((key.to_string(), value.to_string()))
+      }),
+      "TRANSACTION_ID_TO_RUNE" => Self::sample_table(&rtx, TRANSACTION_ID_TO_RUNE, |key, value| {
+        Ok((Txid::load(*key).to_string(), Rune(value).to_string()))
+      }),
+      "TRANSACTION_ID_TO_TRANSACTION" => {
+        Self::sample_table(&rtx, TRANSACTION_ID_TO_TRANSACTION, |key, value| {
+          Ok((
+            Txid::load(*key).to_string(),
+            format!("{} bytes", value.len()),
+          ))
+        })
+      }
+      "WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP" => Self::sample_table(
+        &rtx,
+        WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP,
+        |key, value| Ok((key.to_string(), value.to_string())),
+      ),
+      _ => {
+        let mut tables = rtx
+          .list_tables()?
+          .map(|handle| handle.name().to_string())
+          .chain(
+            rtx
+              .list_multimap_tables()?
+              .map(|handle| handle.name().to_string()),
+          )
+          .collect::<Vec<String>>();
+
+        tables.sort();
+
+        bail!(
+          "unknown table `{name}`, expected one of: {}",
+          tables.join(", ")
+        );
+      }
+    }
+  }
+
+  fn sample_table<K: redb::Key + 'static, V: redb::Value + 'static>(
+    rtx: &redb::ReadTransaction,
+    definition: TableDefinition<K, V>,
+    format: impl Fn(K::SelfType<'_>, V::SelfType<'_>) -> Result<(String, String)>,
+  ) -> Result<info::TableOutput> {
+    let table = rtx.open_table(definition)?;
+
+    let mut sample = Vec::new();
+
+    for result in table.iter()?.take(info::TABLE_SAMPLE_SIZE) {
+      let (key, value) = result?;
+      let (key, value) = format(key.value(), value.value())?;
+      sample.push(info::TableSample { key, value });
+    }
+
+    Ok(info::TableOutput::new(
+      definition.name(),
+      table.stats()?,
+      table.len()?,
+      sample,
+    ))
+  }
+
+  fn sample_multimap_table<K: redb::Key + 'static, V: redb::Key + 'static>(
+    rtx: &redb::ReadTransaction,
+    definition: MultimapTableDefinition<K, V>,
+    format: impl Fn(K::SelfType<'_>, V::SelfType<'_>) -> Result<(String, String)>,
+  ) -> Result<info::TableOutput> {
+    let table = rtx.open_multimap_table(definition)?;
+
+    let mut sample = Vec::new();
+
+    'outer: for result in table.iter()? {
+      let (key, values) = result?;
+      for value in values {
+        if sample.len() == info::TABLE_SAMPLE_SIZE {
+          break 'outer;
+        }
+        let (key, value) = format(key.value(), value?.value())?;
+        sample.push(info::TableSample { key, value });
+      }
+    }
+
+    Ok(info::TableOutput::new(
+      definition.name(),
+      table.stats()?,
+      table.len()?,
+      sample,
+    ))
+  }
+
   pub fn update(&self) -> Result {
     loop {
       let wtx = self.begin_write()?;
@@ -690,7 +1170,7 @@ impl Index {
       )?;
 
       if include_addresses {
-        let address = if satpoint.outpoint == unbound_outpoint() {
+        let address = if is_unbound(satpoint.outpoint) {
           "unbound".to_string()
         } else {
           let output = self
@@ -719,6 +1199,97 @@ impl Index {
     Ok(())
   }
 
+  pub(crate) fn check(&self, deep: bool) -> Result<check::Output> {
+    let rtx = self.database.begin_read()?;
+
+    let mut output = check::Output::default();
+
+    let sequence_number_to_entry = rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+    let sequence_number_to_satpoint = rtx.open_table(SEQUENCE_NUMBER_TO_SATPOINT)?;
+
+    for result in rtx.open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?.iter()? {
+      let (_id, sequence_number) = result?;
+      let sequence_number = sequence_number.value();
+
+      output.inscriptions += 1;
+
+      if sequence_number_to_entry.get(sequence_number)?.is_none() {
+        output.missing_inscription_entries += 1;
+      }
+
+      if sequence_number_to_satpoint.get(sequence_number)?.is_none() {
+        output.missing_satpoints += 1;
+      }
+    }
+
+    let mut next = 0;
+    for result in sequence_number_to_entry.iter()? {
+      let (sequence_number, _entry) = result?;
+      let sequence_number = sequence_number.value();
+
+      if sequence_number != next {
+        output.sequence_number_gaps += 1;
+      }
+
+      next = sequence_number + 1;
+    }
+
+    for result in rtx.open_table(OUTPOINT_TO_RUNE_BALANCES)?.iter()? {
+      let (_outpoint, balances) = result?;
+      let balances = balances.value();
+
+      output.rune_balance_outputs += 1;
+
+      let mut i = 0;
+      while i < balances.len() {
+        match Self::decode_rune_balance(&balances[i..]) {
+          Ok((_balance, length)) => i += length,
+          Err(_) => {
+            output.undecodable_rune_balances += 1;
+            break;
+          }
+        }
+      }
+    }
+
+    if deep {
+      output.duplicate_satpoints = Some(0);
+
+      let satpoint_to_sequence_number = rtx.open_multimap_table(SATPOINT_TO_SEQUENCE_NUMBER)?;
+
+      for result in satpoint_to_sequence_number.iter()? {
+        let (satpoint, sequence_numbers) = result?;
+
+        if sequence_numbers.len() < 2 {
+          continue;
+        }
+
+        let mut ids = Vec::new();
+        let mut originals = 0;
+
+        for sequence_number in sequence_numbers {
+          let Some(entry) = sequence_number_to_entry.get(sequence_number?.value())? else {
+            continue;
+          };
+
+          let entry = InscriptionEntry::load(entry.value());
+
+          if !Charm::Reinscription.is_set(entry.charms) {
+            originals += 1;
+          }
+
+          ids.push(entry.id);
+        }
+
+        if originals > 1 {
+          output.add_duplicate_satpoint(SatPoint::load(*satpoint.value()), ids);
+        }
+      }
+    }
+
+    Ok(output)
+  }
+
   fn begin_read(&self) -> Result<rtx::Rtx> {
     Ok(rtx::Rtx(self.database.begin_read()?))
   }
@@ -785,6 +1356,10 @@ impl Index {
       .inscription_number
   }
 
+  pub(crate) fn is_caught_up(&self) -> Result<bool> {
+    Ok(u64::from(self.block_count()?) > self.client.get_block_count()?)
+  }
+
   pub(crate) fn block_count(&self) -> Result<u32> {
     self.begin_read()?.block_count()
   }
@@ -844,6 +1419,33 @@ impl Index {
     )
   }
 
+  /// Current location of `sat`, if it is rare or inscribed. Inscriptions move
+  /// with the sat they are on, so the satpoint of a sat's first inscription is
+  /// also the sat's satpoint, and no additional table is needed.
+  pub(crate) fn get_sat_satpoint(&self, sat: Sat) -> Result<Option<SatPoint>> {
+    let rtx = self.database.begin_read()?;
+
+    if let Some(satpoint) = rtx.open_table(SAT_TO_SATPOINT)?.get(&sat.n())? {
+      return Ok(Some(Entry::load(*satpoint.value())));
+    }
+
+    let Some(sequence_number) = rtx
+      .open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?
+      .get(&sat.n())?
+      .next()
+      .transpose()?
+    else {
+      return Ok(None);
+    };
+
+    Ok(
+      rtx
+        .open_table(SEQUENCE_NUMBER_TO_SATPOINT)?
+        .get(sequence_number.value())?
+        .map(|satpoint| Entry::load(*satpoint.value())),
+    )
+  }
+
   pub(crate) fn get_rune_by_id(&self, id: RuneId) -> Result<Option<Rune>> {
     Ok(
       self
@@ -908,6 +1510,39 @@ impl Index {
     Ok(Some((RuneId::load(id), entry, parent)))
   }
 
+  pub(crate) fn get_rune_by_inscription_id(
+    &self,
+    inscription_id: InscriptionId,
+  ) -> Result<Option<(RuneId, SpacedRune)>> {
+    let rtx = self.database.begin_read()?;
+
+    let Some(sequence_number) = rtx
+      .open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?
+      .get(&inscription_id.store())?
+      .map(|guard| guard.value())
+    else {
+      return Ok(None);
+    };
+
+    let Some(id) = rtx
+      .open_table(SEQUENCE_NUMBER_TO_RUNE_ID)?
+      .get(sequence_number)?
+      .map(|guard| guard.value())
+    else {
+      return Ok(None);
+    };
+
+    let entry = RuneEntry::load(
+      rtx
+        .open_table(RUNE_ID_TO_RUNE_ENTRY)?
+        .get(id)?
+        .unwrap()
+        .value(),
+    );
+
+    Ok(Some((RuneId::load(id), entry.spaced_rune)))
+  }
+
   pub(crate) fn runes(&self) -> Result<Vec<(RuneId, RuneEntry)>> {
     let mut entries = Vec::new();
 
@@ -949,6 +1584,42 @@ impl Index {
     Ok((entries, more))
   }
 
+  pub(crate) fn runes_etched_between(
+    &self,
+    start_height: u64,
+    end_height: u64,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<RuneId>, bool)> {
+    let min_id = RuneId {
+      block: start_height,
+      tx: 0,
+    };
+
+    let max_id = RuneId {
+      block: end_height,
+      tx: u32::MAX,
+    };
+
+    let mut ids = self
+      .database
+      .begin_read()?
+      .open_table(RUNE_ID_TO_RUNE_ENTRY)?
+      .range(min_id.store()..=max_id.store())?
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+      .map(|result| result.map(|(id, _)| RuneId::load(id.value())))
+      .collect::<Result<Vec<RuneId>, StorageError>>()?;
+
+    let more = ids.len() > page_size;
+
+    if more {
+      ids.pop();
+    }
+
+    Ok((ids, more))
+  }
+
   pub(crate) fn encode_rune_balance(id: RuneId, balance: u128, buffer: &mut Vec<u8>) {
     varint::encode_to_vec(id.block.into(), buffer);
     varint::encode_to_vec(id.tx.into(), buffer);
@@ -1007,6 +1678,9 @@ impl Index {
     Ok(balances)
   }
 
+  /// Balances are ordered by rune, then by outpoint. `ord balances` and
+  /// `ord index export-runes` use this ordering, as does `/runes/balances`
+  /// unless `?order=amount` is given.
   pub(crate) fn get_rune_balance_map(
     &self,
   ) -> Result<BTreeMap<SpacedRune, BTreeMap<OutPoint, Pile>>> {
@@ -1064,6 +1738,51 @@ impl Index {
     Ok(rune_balances)
   }
 
+  pub(crate) fn get_rune_holders(
+    &self,
+    id: RuneId,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<(OutPoint, u128)>, u64)> {
+    let rtx = self.database.begin_read()?;
+
+    let outpoints = rtx
+      .open_multimap_table(RUNE_ID_TO_OUTPOINTS)?
+      .get(id.store())?;
+
+    let holder_count = outpoints.len();
+
+    let outpoint_to_balances = rtx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
+
+    let mut holders = Vec::new();
+
+    for outpoint in outpoints
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size)
+    {
+      let outpoint = outpoint?;
+
+      let balances = outpoint_to_balances
+        .get(outpoint.value())?
+        .ok_or_else(|| anyhow!("rune holder output has no balances"))?;
+
+      let balances = balances.value();
+
+      let mut i = 0;
+      while i < balances.len() {
+        let ((balance_id, balance), length) = Index::decode_rune_balance(&balances[i..])?;
+        i += length;
+
+        if balance_id == id {
+          holders.push((OutPoint::load(*outpoint.value()), balance));
+          break;
+        }
+      }
+    }
+
+    Ok((holders, holder_count))
+  }
+
   pub(crate) fn get_rune_balances(&self) -> Result<Vec<(OutPoint, Vec<(RuneId, u128)>)>> {
     let mut result = Vec::new();
 
@@ -1095,6 +1814,23 @@ impl Index {
     self.client.get_block_header(&hash).into_option()
   }
 
+  pub(crate) fn block_header_by_height(&self, height: u32) -> Result<Option<Header>> {
+    if let Some(header) = self
+      .database
+      .begin_read()?
+      .open_table(HEIGHT_TO_BLOCK_HEADER)?
+      .get(height)?
+    {
+      return Ok(Some(Header::load(*header.value())));
+    }
+
+    let Some(hash) = self.client.get_block_hash(height.into()).into_option()? else {
+      return Ok(None);
+    };
+
+    self.block_header(hash)
+  }
+
   pub(crate) fn block_header_info(&self, hash: BlockHash) -> Result<Option<GetBlockHeaderResult>> {
     self.client.get_block_header_info(&hash).into_option()
   }
@@ -1216,6 +1952,17 @@ impl Index {
       .collect()
   }
 
+  pub(crate) fn get_children_count_by_sequence_number(&self, sequence_number: u32) -> Result<u64> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?
+        .get(sequence_number)?
+        .len(),
+    )
+  }
+
   pub(crate) fn get_children_by_sequence_number_paginated(
     &self,
     sequence_number: u32,
@@ -1229,7 +1976,7 @@ impl Index {
     let mut children = rtx
       .open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?
       .get(sequence_number)?
-      .skip(page_index * page_size)
+      .skip(page_index.saturating_mul(page_size))
       .take(page_size.saturating_add(1))
       .map(|result| {
         result
@@ -1323,6 +2070,17 @@ impl Index {
     Ok(ids)
   }
 
+  pub(crate) fn get_inscription_count_by_sat(&self, sat: Sat) -> Result<u64> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?
+        .get(&sat.n())?
+        .len(),
+    )
+  }
+
   pub(crate) fn get_inscription_ids_by_sat_paginated(
     &self,
     sat: Sat,
@@ -1394,7 +2152,6 @@ impl Index {
     .transpose()
   }
 
-  #[cfg(test)]
   pub(crate) fn get_inscription_id_by_inscription_number(
     &self,
     inscription_number: i32,
@@ -1455,6 +2212,56 @@ impl Index {
     }))
   }
 
+  /// Follow delegates from `inscription_id`. If `max_content_length` is
+  /// given, fails with `ContentTooLarge` before loading any inscription in
+  /// the chain whose stored content length exceeds it.
+  pub(crate) fn get_inscription_by_id_resolved(
+    &self,
+    inscription_id: InscriptionId,
+    max_content_length: Option<u64>,
+  ) -> Result<Option<(InscriptionId, Inscription)>> {
+    let mut visited = HashSet::new();
+    let mut id = inscription_id;
+
+    while visited.len() <= MAX_DELEGATE_DEPTH && visited.insert(id) {
+      if let Some(max_content_length) = max_content_length {
+        let content_length = self.get_inscription_content_length(id)?.unwrap_or_default();
+
+        if content_length > max_content_length {
+          return Err(
+            ContentTooLarge {
+              inscription_id: id,
+              content_length,
+            }
+            .into(),
+          );
+        }
+      }
+
+      let Some(inscription) = self.get_inscription_by_id(id)? else {
+        return Ok(None);
+      };
+
+      match inscription.delegate() {
+        Some(delegate) => id = delegate,
+        None => return Ok(Some((id, inscription))),
+      }
+    }
+
+    Ok(None)
+  }
+
+  pub(crate) fn get_inscription_id_by_cid(&self, cid: Cid) -> Result<Option<InscriptionId>> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_table(CONTENT_SHA256_TO_INSCRIPTION_ID)?
+        .get(&cid.0)?
+        .map(|id| InscriptionId::load(id.value())),
+    )
+  }
+
   pub(crate) fn inscription_count(&self, txid: Txid) -> Result<u32> {
     let start = InscriptionId { index: 0, txid };
 
@@ -1475,6 +2282,28 @@ impl Index {
     )
   }
 
+  pub(crate) fn get_inscription_content_length(
+    &self,
+    inscription_id: InscriptionId,
+  ) -> Result<Option<u64>> {
+    let rtx = self.database.begin_read()?;
+
+    let Some(sequence_number) = rtx
+      .open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?
+      .get(&inscription_id.store())?
+      .map(|guard| guard.value())
+    else {
+      return Ok(None);
+    };
+
+    Ok(
+      rtx
+        .open_table(SEQUENCE_NUMBER_TO_CONTENT_LENGTH)?
+        .get(sequence_number)?
+        .map(|guard| guard.value()),
+    )
+  }
+
   pub(crate) fn inscription_exists(&self, inscription_id: InscriptionId) -> Result<bool> {
     Ok(
       self
@@ -1506,13 +2335,34 @@ impl Index {
     &self,
     outpoint: OutPoint,
   ) -> Result<Vec<InscriptionId>> {
-    Ok(
-      self
-        .get_inscriptions_on_output_with_satpoints(outpoint)?
-        .iter()
-        .map(|(_satpoint, inscription_id)| *inscription_id)
-        .collect(),
-    )
+    let generation = {
+      let mut cache = self.inscriptions_on_output_cache.lock().unwrap();
+
+      if let Some(inscriptions) = cache.get(&outpoint) {
+        return Ok(inscriptions);
+      }
+
+      cache.generation()
+    };
+
+    let inscriptions = self
+      .get_inscriptions_on_output_with_satpoints(outpoint)?
+      .iter()
+      .map(|(_satpoint, inscription_id)| *inscription_id)
+      .collect::<Vec<InscriptionId>>();
+
+    let mut cache = self.inscriptions_on_output_cache.lock().unwrap();
+
+    // don't cache results read before the index was last modified
+    if cache.generation() == generation {
+      cache.insert(outpoint, inscriptions.clone());
+    }
+
+    Ok(inscriptions)
+  }
+
+  pub(crate) fn invalidate_inscriptions_on_output_cache(&self) {
+    self.inscriptions_on_output_cache.lock().unwrap().clear();
   }
 
   pub(crate) fn get_transaction(&self, txid: Txid) -> Result<Option<Transaction>> {
@@ -1534,6 +2384,70 @@ impl Index {
     self.client.get_raw_transaction(&txid, None).into_option()
   }
 
+  pub(crate) fn get_transaction_with_height(
+    &self,
+    txid: Txid,
+  ) -> Result<Option<(Transaction, Option<u32>)>> {
+    if txid == self.genesis_block_coinbase_txid {
+      return Ok(Some((
+        self.genesis_block_coinbase_transaction.clone(),
+        Some(0),
+      )));
+    }
+
+    if self.index_transactions {
+      if let Some(transaction) = self
+        .database
+        .begin_read()?
+        .open_table(TRANSACTION_ID_TO_TRANSACTION)?
+        .get(&txid.store())?
+      {
+        return Ok(Some((
+          consensus::encode::deserialize(transaction.value())?,
+          self.get_transaction_height(txid)?,
+        )));
+      }
+    }
+
+    let Some(info) = self
+      .client
+      .get_raw_transaction_info(&txid, None)
+      .into_option()?
+    else {
+      return Ok(None);
+    };
+
+    let height = match info.blockhash {
+      Some(blockhash) => self
+        .block_header_info(blockhash)?
+        .map(|info| u32::try_from(info.height).unwrap()),
+      None => None,
+    };
+
+    Ok(Some((info.transaction()?, height)))
+  }
+
+  pub(crate) fn get_transaction_height(&self, txid: Txid) -> Result<Option<u32>> {
+    if txid == self.genesis_block_coinbase_txid {
+      return Ok(Some(0));
+    }
+
+    let Some(blockhash) = self
+      .client
+      .get_raw_transaction_info(&txid, None)
+      .into_option()?
+      .and_then(|info| info.blockhash)
+    else {
+      return Ok(None);
+    };
+
+    Ok(
+      self
+        .block_header_info(blockhash)?
+        .map(|info| u32::try_from(info.height).unwrap()),
+    )
+  }
+
   pub(crate) fn find(&self, sat: Sat) -> Result<Option<SatPoint>> {
     let sat = sat.0;
     let rtx = self.begin_read()?;
@@ -1547,8 +2461,7 @@ impl Index {
     for range in outpoint_to_sat_ranges.range::<&[u8; 36]>(&[0; 36]..)? {
       let (key, value) = range?;
       let mut offset = 0;
-      for chunk in value.value().chunks_exact(11) {
-        let (start, end) = SatRange::load(chunk.try_into().unwrap());
+      for (start, end) in self.load_sat_ranges(value.value())? {
         if start <= sat && sat < end {
           return Ok(Some(SatPoint {
             outpoint: Entry::load(*key.value()),
@@ -1562,6 +2475,44 @@ impl Index {
     Ok(None)
   }
 
+  pub(crate) fn find_many(&self, sats: &[Sat]) -> Result<BTreeMap<Sat, SatPoint>> {
+    let mut needles = sats.to_vec();
+    needles.sort();
+    needles.dedup();
+
+    let rtx = self.begin_read()?;
+
+    let outpoint_to_sat_ranges = rtx.0.open_table(OUTPOINT_TO_SAT_RANGES)?;
+
+    let mut found = BTreeMap::new();
+
+    for range in outpoint_to_sat_ranges.range::<&[u8; 36]>(&[0; 36]..)? {
+      if found.len() == needles.len() {
+        break;
+      }
+
+      let (key, value) = range?;
+      let mut offset = 0;
+      for (start, end) in self.load_sat_ranges(value.value())? {
+        let first = needles.partition_point(|sat| sat.0 < start);
+
+        for sat in needles[first..].iter().take_while(|sat| sat.0 < end) {
+          found.insert(
+            *sat,
+            SatPoint {
+              outpoint: Entry::load(*key.value()),
+              offset: offset + sat.0 - start,
+            },
+          );
+        }
+
+        offset += end - start;
+      }
+    }
+
+    Ok(found)
+  }
+
   pub(crate) fn find_range(
     &self,
     range_start: Sat,
@@ -1586,9 +2537,7 @@ impl Index {
       let (outpoint_entry, sat_ranges_entry) = range?;
 
       let mut offset = 0;
-      for sat_range in sat_ranges_entry.value().chunks_exact(11) {
-        let (start, end) = SatRange::load(sat_range.try_into().unwrap());
-
+      for (start, end) in self.load_sat_ranges(sat_ranges_entry.value())? {
         if end > range_start && start < range_end {
           let overlap_start = start.max(range_start);
           let overlap_end = end.min(range_end);
@@ -1605,7 +2554,7 @@ impl Index {
           remaining_sats -= overlap_end - overlap_start;
 
           if remaining_sats == 0 {
-            break;
+            return Ok(Some(result));
           }
         }
         offset += end - start;
@@ -1616,20 +2565,21 @@ impl Index {
   }
 
   pub(crate) fn list(&self, outpoint: OutPoint) -> Result<Option<Vec<(u64, u64)>>> {
-    Ok(
-      self
-        .database
-        .begin_read()?
-        .open_table(OUTPOINT_TO_SAT_RANGES)?
-        .get(&outpoint.store())?
-        .map(|outpoint| outpoint.value().to_vec())
-        .map(|sat_ranges| {
-          sat_ranges
-            .chunks_exact(11)
-            .map(|chunk| SatRange::load(chunk.try_into().unwrap()))
-            .collect::<Vec<(u64, u64)>>()
-        }),
-    )
+    self
+      .database
+      .begin_read()?
+      .open_table(OUTPOINT_TO_SAT_RANGES)?
+      .get(&outpoint.store())?
+      .map(|sat_ranges| self.load_sat_ranges(sat_ranges.value()))
+      .transpose()
+  }
+
+  fn load_sat_ranges(&self, sat_ranges: &[u8]) -> Result<Vec<SatRange>> {
+    entry::load_sat_ranges(sat_ranges, self.compress_sat_ranges)
+  }
+
+  fn store_sat_ranges(&self, sat_ranges: &[SatRange]) -> Vec<u8> {
+    entry::store_sat_ranges(sat_ranges, self.compress_sat_ranges)
   }
 
   pub(crate) fn is_output_spent(&self, outpoint: OutPoint) -> Result<bool> {
@@ -1671,6 +2621,25 @@ impl Index {
     Ok(true)
   }
 
+  /// Unlike `is_output_in_active_chain`, relies on `blockhash` rather than
+  /// `in_active_chain`, which Bitcoin Core only returns when `getrawtransaction`
+  /// is called with a block hash.
+  pub(crate) fn is_output_confirmed(&self, outpoint: OutPoint) -> Result<bool> {
+    if outpoint == self.settings.chain().genesis_coinbase_outpoint() {
+      return Ok(true);
+    }
+
+    Ok(
+      self
+        .client
+        .get_raw_transaction_info(&outpoint.txid, None)
+        .into_option()?
+        .is_some_and(|info| {
+          info.blockhash.is_some() && outpoint.vout.into_usize() < info.vout.len()
+        }),
+    )
+  }
+
   pub(crate) fn block_time(&self, height: Height) -> Result<Blocktime> {
     let height = height.n();
 
@@ -1705,6 +2674,25 @@ impl Index {
     ))
   }
 
+  pub(crate) fn get_inscription_ids_by_sequence_number_range(
+    &self,
+    start: u32,
+    count: usize,
+  ) -> Result<Vec<InscriptionId>> {
+    self
+      .database
+      .begin_read()?
+      .open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?
+      .range(start..)?
+      .take(count)
+      .map(|result| {
+        result
+          .map(|(_number, entry)| InscriptionEntry::load(entry.value()).id)
+          .map_err(|err| err.into())
+      })
+      .collect()
+  }
+
   pub(crate) fn get_inscriptions_paginated(
     &self,
     page_size: u32,
@@ -1838,7 +2826,7 @@ impl Index {
     )
   }
 
-  pub(crate) fn get_feed_inscriptions(&self, n: usize) -> Result<Vec<(u32, InscriptionId)>> {
+  pub(crate) fn get_feed_inscriptions(&self, n: usize) -> Result<Vec<(u32, InscriptionId, u32)>> {
     Ok(
       self
         .database
@@ -1848,7 +2836,10 @@ impl Index {
         .rev()
         .take(n)
         .flat_map(|result| {
-          result.map(|(number, entry)| (number.value(), InscriptionEntry::load(entry.value()).id))
+          result.map(|(number, entry)| {
+            let entry = InscriptionEntry::load(entry.value());
+            (number.value(), entry.id, entry.timestamp)
+          })
         })
         .collect(),
     )
@@ -1895,7 +2886,15 @@ impl Index {
       return Ok(None);
     };
 
-    let Some(inscription) = ParsedEnvelope::from_transaction(&transaction)
+    let envelopes = ParsedEnvelope::from_transaction(&transaction);
+
+    // reveal fees are split evenly between the inscriptions in a transaction
+    #[allow(clippy::cast_precision_loss)]
+    let fee_rate = Some(
+      (entry.fee * u64::try_from(envelopes.len()).unwrap()) as f64 / transaction.vsize() as f64,
+    );
+
+    let Some(inscription) = envelopes
       .into_iter()
       .nth(entry.id.index as usize)
       .map(|envelope| envelope.payload)
@@ -1911,8 +2910,7 @@ impl Index {
         .value(),
     );
 
-    let output = if satpoint.outpoint == unbound_outpoint() || satpoint.outpoint == OutPoint::null()
-    {
+    let output = if is_unbound(satpoint.outpoint) || satpoint.outpoint == OutPoint::null() {
       None
     } else {
       let Some(transaction) = self.get_transaction(satpoint.outpoint.txid)? else {
@@ -1986,18 +2984,21 @@ impl Index {
       })
       .collect::<Result<Vec<InscriptionId>>>()?;
 
+    let recursive = rtx
+      .open_table(RECURSIVE_INSCRIPTIONS)?
+      .get(sequence_number)?
+      .is_some();
+
     let mut charms = entry.charms;
 
     if satpoint.outpoint == OutPoint::null() {
       Charm::Lost.set(&mut charms);
     }
 
-    let effective_mime_type = if let Some(delegate_id) = inscription.delegate() {
-      let delegate_result = self.get_inscription_by_id(delegate_id);
-      if let Ok(Some(delegate)) = delegate_result {
-        delegate.content_type().map(str::to_string)
-      } else {
-        inscription.content_type().map(str::to_string)
+    let effective_mime_type = if inscription.delegate().is_some() {
+      match self.get_inscription_by_id_resolved(entry.id, None) {
+        Ok(Some((_, delegate))) => delegate.content_type().map(str::to_string),
+        _ => inscription.content_type().map(str::to_string),
       }
     } else {
       inscription.content_type().map(str::to_string)
@@ -2017,20 +3018,27 @@ impl Index {
           .map(|address| address.to_string()),
         charms: Charm::charms(charms),
         children,
+        cid: inscription.cid(),
         content_length: inscription.content_length(),
         content_type: inscription.content_type().map(|s| s.to_string()),
+        delegate: inscription.delegate(),
         effective_content_type: effective_mime_type,
         fee: entry.fee,
+        fee_rate,
         height: entry.height,
         id: entry.id,
         next,
         number: entry.inscription_number,
         parents,
         previous,
+        rarity: entry.sat.map(Sat::rarity),
+        recursive,
         rune,
         sat: entry.sat,
         satpoint,
+        teleburn: entry.id.into(),
         timestamp: timestamp(entry.timestamp.into()).timestamp(),
+        unbound: is_unbound(satpoint.outpoint),
         value: output.as_ref().map(|o| o.value),
       },
       output,
@@ -2191,7 +3199,7 @@ impl Index {
 
     let indexed;
 
-    let txout = if outpoint == OutPoint::null() || outpoint == unbound_outpoint() {
+    let txout = if outpoint == OutPoint::null() || is_unbound(outpoint) {
       let mut value = 0;
 
       if let Some(ranges) = &sat_ranges {
@@ -2262,12 +3270,129 @@ mod tests {
       assert_eq!(context.index.block_count().unwrap(), 1);
     }
 
-    {
-      let context = Context::builder().args(["--height-limit", "2"]).build();
-      context.mine_blocks(2);
-      assert_eq!(context.index.block_height().unwrap(), Some(Height(1)));
-      assert_eq!(context.index.block_count().unwrap(), 2);
-    }
+    {
+      let context = Context::builder().args(["--height-limit", "2"]).build();
+      context.mine_blocks(2);
+      assert_eq!(context.index.block_height().unwrap(), Some(Height(1)));
+      assert_eq!(context.index.block_count().unwrap(), 2);
+    }
+  }
+
+  #[test]
+  fn is_caught_up() {
+    let context = Context::builder().build();
+    assert!(context.index.is_caught_up().unwrap());
+
+    context.mine_blocks_with_update(1, false);
+    assert!(!context.index.is_caught_up().unwrap());
+
+    context.index.update().unwrap();
+    assert!(context.index.is_caught_up().unwrap());
+  }
+
+  #[test]
+  fn check_reports_missing_satpoints() {
+    let context = Context::builder().build();
+    context.mine_blocks(1);
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.check(false).unwrap(),
+      check::Output {
+        inscriptions: 1,
+        ..default()
+      }
+    );
+
+    let wtx = context.index.database.begin_write().unwrap();
+
+    wtx
+      .open_table(SEQUENCE_NUMBER_TO_SATPOINT)
+      .unwrap()
+      .remove(0)
+      .unwrap();
+
+    wtx.commit().unwrap();
+
+    let output = context.index.check(false).unwrap();
+
+    assert_eq!(output.missing_satpoints, 1);
+    assert_eq!(output.inconsistencies(), 1);
+  }
+
+  #[test]
+  fn deep_check_reports_duplicate_satpoints() {
+    let context = Context::builder().build();
+    context.mine_blocks(1);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    let first = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    let second = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    let third = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.check(false).unwrap().duplicate_satpoints,
+      None
+    );
+    assert_eq!(
+      context.index.check(true).unwrap().duplicate_satpoints,
+      Some(0)
+    );
+
+    let satpoint = context
+      .index
+      .get_inscription_satpoint_by_id(second)
+      .unwrap()
+      .unwrap();
+
+    let wtx = context.index.database.begin_write().unwrap();
+
+    wtx
+      .open_multimap_table(SATPOINT_TO_SEQUENCE_NUMBER)
+      .unwrap()
+      .insert(&satpoint.store(), 2)
+      .unwrap();
+
+    wtx.commit().unwrap();
+
+    let output = context.index.check(true).unwrap();
+
+    assert_eq!(output.duplicate_satpoints, Some(1));
+    assert_eq!(
+      output.duplicate_satpoint_examples,
+      [(satpoint, vec![first, second, third])]
+        .into_iter()
+        .collect(),
+    );
+    assert_eq!(output.inconsistencies(), 1);
   }
 
   #[test]
@@ -2397,6 +3522,45 @@ mod tests {
     )
   }
 
+  #[test]
+  fn split_ranges_pass_sat_range_verification() {
+    let context = Context::builder()
+      .args(["--index-sats", "--verify-sat-ranges"])
+      .build();
+
+    context.mine_blocks(1);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      fee: 0,
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let starting_sat = 50 * COIN_VALUE;
+    assert_eq!(
+      context.index.list(OutPoint::new(txid, 1)).unwrap().unwrap(),
+      &[(
+        starting_sat + 25 * COIN_VALUE,
+        starting_sat + 50 * COIN_VALUE
+      )],
+    );
+  }
+
+  #[test]
+  fn sat_range_verification_rejects_mismatched_totals() {
+    let outpoint = OutPoint::null();
+
+    assert_eq!(
+      updater::Updater::verify_sat_ranges(outpoint, &[(0, 10), (20, 25)], 16)
+        .unwrap_err()
+        .to_string(),
+      format!("sat ranges of output {outpoint} sum to 15 but output value is 16"),
+    );
+  }
+
   #[test]
   fn list_split_ranges_are_tracked_correctly() {
     let context = Context::builder().arg("--index-sats").build();
@@ -2514,6 +3678,88 @@ mod tests {
     );
   }
 
+  #[test]
+  fn compressed_sat_ranges_are_tracked_correctly() {
+    let context = Context::builder()
+      .args(["--index-sats", "--compress-sat-ranges"])
+      .build();
+
+    context.mine_blocks(2);
+
+    let merge_coinbase_outputs = TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default()), (2, 0, 0, Default::default())],
+      outputs: 2,
+      fee: 10,
+      ..default()
+    };
+
+    let txid = context.core.broadcast_tx(merge_coinbase_outputs);
+    let coinbase_txid = context.mine_blocks(1)[0].txdata[0].txid();
+
+    assert_eq!(
+      context.index.list(OutPoint::new(txid, 0)).unwrap().unwrap(),
+      &[(50 * COIN_VALUE, 9999999995)],
+    );
+
+    assert_eq!(
+      context.index.list(OutPoint::new(txid, 1)).unwrap().unwrap(),
+      &[
+        (9999999995, 100 * COIN_VALUE),
+        (100 * COIN_VALUE, 14999999990)
+      ],
+    );
+
+    assert_eq!(
+      context
+        .index
+        .list(OutPoint::new(coinbase_txid, 0))
+        .unwrap()
+        .unwrap(),
+      &[
+        (150 * COIN_VALUE, 200 * COIN_VALUE),
+        (14999999990, 150 * COIN_VALUE)
+      ],
+    );
+
+    assert_eq!(
+      context
+        .index
+        .find(Sat(150 * COIN_VALUE - 1))
+        .unwrap()
+        .unwrap(),
+      SatPoint {
+        outpoint: OutPoint::new(coinbase_txid, 0),
+        offset: 50 * COIN_VALUE + 9,
+      },
+    );
+
+    assert_eq!(
+      context
+        .index
+        .find_range(Sat(100 * COIN_VALUE - 1), Sat(100 * COIN_VALUE + 1))
+        .unwrap()
+        .unwrap(),
+      &[
+        FindRangeOutput {
+          start: 100 * COIN_VALUE - 1,
+          size: 1,
+          satpoint: SatPoint {
+            outpoint: OutPoint::new(txid, 1),
+            offset: 4,
+          },
+        },
+        FindRangeOutput {
+          start: 100 * COIN_VALUE,
+          size: 1,
+          satpoint: SatPoint {
+            outpoint: OutPoint::new(txid, 1),
+            offset: 5,
+          },
+        },
+      ],
+    );
+  }
+
   #[test]
   fn list_null_output() {
     let context = Context::builder().arg("--index-sats").build();
@@ -2661,6 +3907,77 @@ mod tests {
     )
   }
 
+  #[test]
+  fn block_header_by_height_falls_back_to_rpc_above_indexed_tip() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    let indexed = context.index.block_header_by_height(1).unwrap().unwrap();
+
+    assert_eq!(
+      Some(indexed.block_hash()),
+      context.index.block_hash(Some(1)).unwrap()
+    );
+
+    context.core.mine_blocks(1);
+
+    assert_eq!(context.index.block_hash(Some(2)).unwrap(), None);
+
+    assert_eq!(
+      context
+        .index
+        .block_header_by_height(2)
+        .unwrap()
+        .unwrap()
+        .prev_blockhash,
+      indexed.block_hash(),
+    );
+
+    assert_eq!(context.index.block_header_by_height(3).unwrap(), None);
+  }
+
+  #[test]
+  fn find_many_sats() {
+    let context = Context::builder().arg("--index-sats").build();
+    context.mine_blocks(1);
+    let tx = context.core.tx(1, 0);
+    assert_eq!(
+      context
+        .index
+        .find_many(&[
+          Sat(50 * COIN_VALUE + 1),
+          Sat(100 * COIN_VALUE),
+          Sat(1),
+          Sat(1)
+        ])
+        .unwrap(),
+      [
+        (
+          Sat(1),
+          SatPoint {
+            outpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0"
+              .parse()
+              .unwrap(),
+            offset: 1,
+          }
+        ),
+        (
+          Sat(50 * COIN_VALUE + 1),
+          SatPoint {
+            outpoint: OutPoint {
+              txid: tx.txid(),
+              vout: 0,
+            },
+            offset: 1,
+          }
+        ),
+      ]
+      .into_iter()
+      .collect()
+    )
+  }
+
   #[test]
   fn inscriptions_are_tracked_correctly() {
     for context in Context::configurations() {
@@ -3357,6 +4674,32 @@ mod tests {
       format!("index at `{}{delimiter}regtest{delimiter}index.redb` appears to have been built with a newer, incompatible version of ord, consider updating ord: index schema {}, ord schema {SCHEMA_VERSION}", path.display(), u64::MAX));
   }
 
+  #[test]
+  fn first_inscription_height_mismatch() {
+    let tempdir = {
+      let context = Context::builder()
+        .arg("--first-inscription-height=3")
+        .build();
+
+      assert_eq!(
+        context.index.first_inscription_height_mismatch().unwrap(),
+        None
+      );
+
+      context.tempdir
+    };
+
+    let context = Context::builder()
+      .arg("--first-inscription-height=5")
+      .tempdir(tempdir)
+      .build();
+
+    assert_eq!(
+      context.index.first_inscription_height_mismatch().unwrap(),
+      Some(3)
+    );
+  }
+
   #[test]
   fn inscriptions_on_output() {
     for context in Context::configurations() {
@@ -3415,6 +4758,88 @@ mod tests {
     }
   }
 
+  #[test]
+  fn content_type_counts_are_normalized() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("Text/Plain", "hello").to_witness())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, inscription("text/plain ", "hello").to_witness())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.status().unwrap().content_type_counts,
+      [(Some(b"text/plain".to_vec()), 2)]
+    );
+  }
+
+  #[test]
+  fn inscriptions_on_output_cache_is_invalidated_by_updates_and_reorgs() {
+    let mut context = Context::builder()
+      .arg("--inscriptions-on-output-cache-size=1")
+      .build();
+
+    context.index.set_durability(redb::Durability::Immediate);
+
+    context.mine_blocks(1);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+    let outpoint = OutPoint { txid, vout: 0 };
+
+    assert_eq!(
+      context.index.get_inscriptions_on_output(outpoint).unwrap(),
+      [inscription_id]
+    );
+
+    assert_eq!(
+      context
+        .index
+        .inscriptions_on_output_cache
+        .lock()
+        .unwrap()
+        .get(&outpoint),
+      Some(vec![inscription_id])
+    );
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Default::default())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.get_inscriptions_on_output(outpoint).unwrap(),
+      []
+    );
+
+    context.core.invalidate_tip();
+    context.mine_blocks(2);
+
+    assert_eq!(
+      context.index.get_inscriptions_on_output(outpoint).unwrap(),
+      [inscription_id]
+    );
+  }
+
   #[test]
   fn inscriptions_on_same_sat_after_the_first_are_not_unbound() {
     for context in Context::configurations() {
@@ -3656,8 +5081,82 @@ mod tests {
 
       assert_eq!(context.core.height(), 110);
 
-      assert_eq!(context.index.inscription_number(inscription_id), 0);
-    }
+      assert_eq!(context.index.inscription_number(inscription_id), 0);
+    }
+  }
+
+  #[test]
+  fn interrupted_update_leaves_index_at_committed_block() {
+    let mut context = Context::builder().arg("--commit-interval=2").build();
+
+    context.index.set_durability(redb::Durability::Immediate);
+
+    context.core.mine_blocks(10);
+
+    updater::INTERRUPT_HEIGHT.set(Some(6));
+    context.index.update().unwrap();
+    updater::INTERRUPT_HEIGHT.set(None);
+
+    assert_eq!(context.index.block_count().unwrap(), 6);
+
+    let settings = context.index.settings.clone();
+
+    drop(context.index);
+
+    let index = Index::open(&settings).unwrap();
+
+    assert_eq!(index.block_count().unwrap(), 6);
+
+    index.update().unwrap();
+
+    assert_eq!(index.block_count().unwrap(), 11);
+  }
+
+  #[test]
+  fn jubilee_height_can_be_overridden() {
+    let context = Context::builder().arg("--jubilee-height=5").build();
+
+    context.mine_blocks(3);
+
+    let witness = envelope(&[
+      b"ord",
+      &[1],
+      b"text/plain;charset=utf-8",
+      &[1],
+      b"text/plain;charset=utf-8",
+    ]);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, witness.clone())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(context.core.height(), 4);
+
+    assert_eq!(
+      context
+        .index
+        .inscription_number(InscriptionId { txid, index: 0 }),
+      -1
+    );
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, witness)],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(context.core.height(), 5);
+
+    assert_eq!(
+      context
+        .index
+        .inscription_number(InscriptionId { txid, index: 0 }),
+      0
+    );
   }
 
   #[test]
@@ -4494,6 +5993,217 @@ mod tests {
     }
   }
 
+  #[test]
+  fn block_hashes_are_updated_by_reorg() {
+    let mut context = Context::builder().build();
+
+    context.index.set_durability(redb::Durability::Immediate);
+
+    context.mine_blocks(7);
+
+    let stale = context.mine_blocks(1)[0].block_hash();
+
+    assert_eq!(context.index.block_hash(Some(8)).unwrap(), Some(stale));
+
+    context.core.invalidate_tip();
+
+    let blocks = context.mine_blocks(2);
+
+    assert_eq!(
+      context.index.block_hash(Some(8)).unwrap(),
+      Some(blocks[0].block_hash())
+    );
+    assert_eq!(
+      context.index.block_hash(Some(9)).unwrap(),
+      Some(blocks[1].block_hash())
+    );
+    assert_eq!(
+      context.index.block_hash(None).unwrap(),
+      Some(blocks[1].block_hash())
+    );
+  }
+
+  #[test]
+  fn reorgs_are_recorded() {
+    let mut context = Context::builder().build();
+
+    context.index.set_durability(redb::Durability::Immediate);
+
+    context.mine_blocks(7);
+
+    assert!(context.index.reorgs().is_empty());
+
+    context.mine_blocks(1);
+
+    context.core.invalidate_tip();
+
+    context.mine_blocks(2);
+
+    let reorgs = context.index.reorgs();
+
+    assert_eq!(reorgs.len(), 1);
+    assert_eq!(reorgs[0].depth, 2);
+    assert_eq!(reorgs[0].height, 9);
+    assert_eq!(context.index.status().unwrap().reorgs, reorgs);
+  }
+
+  #[test]
+  fn reorg_purge_webhook_is_notified() {
+    use std::io::{BufRead, BufReader};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+
+    let mut context = Context::builder()
+      .arg(format!(
+        "--reorg-purge-webhook=http://{}",
+        listener.local_addr().unwrap()
+      ))
+      .build();
+
+    context.index.set_durability(redb::Durability::Immediate);
+
+    context.mine_blocks(7);
+
+    context.mine_blocks(1);
+
+    let old_tip = context.index.block_hash(None).unwrap();
+
+    context.core.invalidate_tip();
+
+    let new_tip = context.mine_blocks(2)[1].block_hash();
+
+    listener.set_nonblocking(true).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    let stream = loop {
+      match listener.accept() {
+        Ok((stream, _)) => break stream,
+        Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+          assert!(
+            Instant::now() < deadline,
+            "reorg purge webhook was not called within 10 seconds",
+          );
+          thread::sleep(Duration::from_millis(10));
+        }
+        Err(err) => panic!("failed to accept reorg purge webhook connection: {err}"),
+      }
+    };
+
+    stream.set_nonblocking(false).unwrap();
+    stream
+      .set_read_timeout(Some(Duration::from_secs(10)))
+      .unwrap();
+
+    let mut reader = BufReader::new(stream);
+
+    let mut content_length = 0;
+
+    loop {
+      let mut line = String::new();
+      reader.read_line(&mut line).unwrap();
+
+      if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+        content_length = value.trim().parse().unwrap();
+      }
+
+      if line == "\r\n" {
+        break;
+      }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    reader
+      .into_inner()
+      .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+      .unwrap();
+
+    assert_eq!(
+      serde_json::from_slice::<api::ReorgPurge>(&body).unwrap(),
+      api::ReorgPurge {
+        depth: 2,
+        height: 9,
+        start_height: 8,
+        end_height: 8,
+        old_tip,
+        new_tip: Some(new_tip),
+      }
+    );
+  }
+
+  #[test]
+  fn runes_etched_between() {
+    const RUNE: u128 = 99246114928149462;
+
+    let context = Context::builder().arg("--index-runes").build();
+
+    let (_txid, first) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    context.mine_blocks(5);
+
+    let (_txid, second) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE + 1)),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    assert_eq!(
+      context
+        .index
+        .runes_etched_between(0, u64::MAX, 10, 0)
+        .unwrap(),
+      (vec![first, second], false)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .runes_etched_between(first.block, second.block - 1, 10, 0)
+        .unwrap(),
+      (vec![first], false)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .runes_etched_between(second.block, second.block, 10, 0)
+        .unwrap(),
+      (vec![second], false)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .runes_etched_between(0, u64::MAX, 1, 0)
+        .unwrap(),
+      (vec![first], true)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .runes_etched_between(0, u64::MAX, 1, 1)
+        .unwrap(),
+      (vec![second], false)
+    );
+  }
+
   #[test]
   fn recover_from_3_block_deep_and_consecutive_reorg() {
     for mut context in Context::configurations() {
@@ -6371,6 +8081,162 @@ mod tests {
     );
   }
 
+  #[test]
+  fn rolled_back_rune_mints_send_event() {
+    const RUNE: u128 = 99246114928149462;
+
+    let (event_sender, mut event_receiver) = tokio::sync::mpsc::channel(1024);
+    let mut context = Context::builder()
+      .arg("--index-runes")
+      .event_sender(event_sender)
+      .build();
+
+    context.index.set_durability(redb::Durability::Immediate);
+
+    let (_txid, id) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          terms: Some(Terms {
+            amount: Some(1000),
+            cap: Some(100),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    context.core.invalidate_tip();
+    context.mine_blocks(2);
+
+    let mut events = Vec::new();
+    while let Ok(event) = event_receiver.try_recv() {
+      events.push(event);
+    }
+
+    assert!(events.contains(&Event::RuneMintsRolledBack {
+      amount: 1000,
+      block_height: context.index.block_height().unwrap().unwrap().0 - 2,
+      mints: 1,
+      rune_id: id,
+    }));
+  }
+
+  #[test]
+  fn only_watched_rune_mints_send_events() {
+    const RUNE: u128 = 99246114928149462;
+
+    let (event_sender, mut event_receiver) = tokio::sync::mpsc::channel(1024);
+    let context = Context::builder()
+      .arg("--index-runes")
+      .event_sender(event_sender)
+      .build();
+
+    context.index.watch_rune(RuneId { block: 1, tx: 1 });
+
+    let (_txid, id) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          terms: Some(Terms {
+            amount: Some(1000),
+            cap: Some(100),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    while let Ok(event) = event_receiver.try_recv() {
+      assert!(!matches!(event, Event::RuneMinted { .. }));
+    }
+
+    context.index.watch_rune(id);
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let mut minted = false;
+    while let Ok(event) = event_receiver.try_recv() {
+      minted |= matches!(event, Event::RuneMinted { rune_id, .. } if rune_id == id);
+    }
+
+    assert!(minted);
+  }
+
+  #[test]
+  fn runestone_transactions_are_stored_with_transaction_index() {
+    const RUNE: u128 = 99246114928149462;
+
+    let context = Context::builder()
+      .arg("--index-runes")
+      .arg("--index-transactions")
+      .build();
+
+    let (txid, _id) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    let coinbase = context.core.tx(1, 0).txid();
+
+    context.core.clear_state();
+
+    assert!(context.index.get_transaction(txid).unwrap().is_some());
+    assert!(context.index.get_transaction(coinbase).unwrap().is_none());
+  }
+
   #[test]
   fn rune_event_sender_channel() {
     const RUNE: u128 = 99246114928149462;
@@ -6480,6 +8346,10 @@ mod tests {
         txid: txid1,
         rune_id: id,
         amount: 1000,
+        outpoint: Some(OutPoint {
+          txid: txid1,
+          vout: 0,
+        }),
       }
     );
 
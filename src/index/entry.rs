@@ -466,6 +466,104 @@ impl Entry for SatRange {
   }
 }
 
+/// Encode sat ranges as a flat list of 11-byte `SatRange` entries, or, if
+/// `compress` is set, as the 7-byte start of the first range followed by
+/// varint sizes and zigzag varint gaps between ranges. Contiguous ranges are
+/// merged when compressing, unless the second range starts with an uncommon
+/// sat, whose location must be kept in `SAT_TO_SATPOINT` when it is spent.
+pub(super) fn store_sat_ranges(ranges: &[SatRange], compress: bool) -> Vec<u8> {
+  let mut buffer = Vec::new();
+
+  if !compress {
+    for range in ranges {
+      buffer.extend_from_slice(&range.store());
+    }
+    return buffer;
+  }
+
+  let mut merged: Vec<SatRange> = Vec::with_capacity(ranges.len());
+
+  for &(start, end) in ranges {
+    match merged.last_mut() {
+      Some(last) if last.1 == start && Sat(start).common() => last.1 = end,
+      _ => merged.push((start, end)),
+    }
+  }
+
+  let mut previous_end = None;
+
+  for (start, end) in merged {
+    match previous_end {
+      None => buffer.extend_from_slice(&start.to_le_bytes()[0..7]),
+      Some(previous_end) => {
+        let gap = if start >= previous_end {
+          (start - previous_end) << 1
+        } else {
+          ((previous_end - start) << 1) - 1
+        };
+        varint::encode_to_vec(gap.into(), &mut buffer);
+      }
+    }
+
+    varint::encode_to_vec((end - start).into(), &mut buffer);
+
+    previous_end = Some(end);
+  }
+
+  buffer
+}
+
+/// Decode sat ranges encoded by `store_sat_ranges`.
+pub(super) fn load_sat_ranges(buffer: &[u8], compressed: bool) -> Result<Vec<SatRange>> {
+  if !compressed {
+    return Ok(
+      buffer
+        .chunks_exact(11)
+        .map(|chunk| SatRange::load(chunk.try_into().unwrap()))
+        .collect(),
+    );
+  }
+
+  let mut ranges = Vec::new();
+
+  let Some(first) = buffer.get(0..7) else {
+    ensure!(buffer.is_empty(), "truncated sat ranges");
+    return Ok(ranges);
+  };
+
+  let mut start = u64::from_le_bytes([
+    first[0], first[1], first[2], first[3], first[4], first[5], first[6], 0,
+  ]);
+
+  let mut i = 7;
+
+  loop {
+    let (size, len) = varint::decode(&buffer[i..])?;
+    i += len;
+
+    let end = start + u64::try_from(size)?;
+
+    ranges.push((start, end));
+
+    if i == buffer.len() {
+      break;
+    }
+
+    let (gap, len) = varint::decode(&buffer[i..])?;
+    i += len;
+
+    let gap = u64::try_from(gap)?;
+
+    start = if gap & 1 == 0 {
+      end + (gap >> 1)
+    } else {
+      end - ((gap + 1) >> 1)
+    };
+  }
+
+  Ok(ranges)
+}
+
 pub(super) type TxidValue = [u8; 32];
 
 impl Entry for Txid {
@@ -484,6 +582,67 @@ impl Entry for Txid {
 mod tests {
   use super::*;
 
+  #[test]
+  fn sat_ranges_round_trip() {
+    let ranges = [
+      (0, 1),
+      (50 * COIN_VALUE, 100 * COIN_VALUE),
+      (10, 20),
+      (2099999997689999, 2099999997690000),
+    ];
+
+    for compress in [false, true] {
+      assert_eq!(
+        load_sat_ranges(&store_sat_ranges(&ranges, compress), compress).unwrap(),
+        ranges,
+      );
+      assert_eq!(
+        load_sat_ranges(&store_sat_ranges(&[], compress), compress).unwrap(),
+        Vec::new(),
+      );
+    }
+  }
+
+  #[test]
+  fn compressed_sat_ranges_are_smaller() {
+    let start = 1_000_000 * COIN_VALUE + 1;
+    assert_eq!(
+      store_sat_ranges(&[(start, start + 10_000)], false).len(),
+      11
+    );
+    assert_eq!(store_sat_ranges(&[(start, start + 10_000)], true).len(), 9);
+
+    let ranges = [
+      (start, start + 100),
+      (start + 100, start + 200),
+      (start + 300, start + 400),
+    ];
+    assert_eq!(store_sat_ranges(&ranges, false).len(), 33);
+    assert_eq!(store_sat_ranges(&ranges, true).len(), 12);
+    assert_eq!(
+      load_sat_ranges(&store_sat_ranges(&ranges, true), true).unwrap(),
+      [(start, start + 200), (start + 300, start + 400)],
+    );
+  }
+
+  #[test]
+  fn compressed_sat_ranges_do_not_merge_uncommon_sats() {
+    let ranges = [(0, 100), (100, 200)];
+    assert_eq!(
+      load_sat_ranges(&store_sat_ranges(&ranges, true), true).unwrap(),
+      [(0, 200)],
+    );
+
+    let ranges = [
+      (50 * COIN_VALUE - 1, 50 * COIN_VALUE),
+      (50 * COIN_VALUE, 50 * COIN_VALUE + 1),
+    ];
+    assert_eq!(
+      load_sat_ranges(&store_sat_ranges(&ranges, true), true).unwrap(),
+      ranges,
+    );
+  }
+
   #[test]
   fn inscription_entry() {
     let id = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdefi0"
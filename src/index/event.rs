@@ -17,6 +17,10 @@ pub enum Event {
     old_location: SatPoint,
     sequence_number: u32,
   },
+  Reorg {
+    depth: u32,
+    height: u32,
+  },
   RuneBurned {
     amount: u128,
     block_height: u32,
@@ -31,9 +35,20 @@ pub enum Event {
   RuneMinted {
     amount: u128,
     block_height: u32,
+    outpoint: Option<OutPoint>,
     rune_id: RuneId,
     txid: Txid,
   },
+  /// Sent per rune after a reorg undoes mints. `mints` and `amount` are
+  /// totals, not individual mints: every `RuneMinted` event for `rune_id`
+  /// with a `block_height` greater than this event's `block_height` was
+  /// undone, and mints that are indexed again are sent again.
+  RuneMintsRolledBack {
+    amount: u128,
+    block_height: u32,
+    mints: u128,
+    rune_id: RuneId,
+  },
   RuneTransferred {
     amount: u128,
     block_height: u32,
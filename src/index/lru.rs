@@ -0,0 +1,116 @@
+use {super::*, std::hash::Hash};
+
+pub(crate) struct Lru<K, V> {
+  capacity: usize,
+  entries: HashMap<K, (u64, V)>,
+  generation: u64,
+  recency: BTreeMap<u64, K>,
+  tick: u64,
+}
+
+impl<K, V> Default for Lru<K, V> {
+  fn default() -> Self {
+    Self {
+      capacity: 0,
+      entries: HashMap::new(),
+      generation: 0,
+      recency: BTreeMap::new(),
+      tick: 0,
+    }
+  }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Lru<K, V> {
+  pub(crate) fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      ..default()
+    }
+  }
+
+  pub(crate) fn get(&mut self, key: &K) -> Option<V> {
+    let (tick, value) = self.entries.get_mut(key)?;
+
+    self.recency.remove(tick);
+    self.tick += 1;
+    *tick = self.tick;
+    self.recency.insert(self.tick, key.clone());
+
+    Some(value.clone())
+  }
+
+  pub(crate) fn insert(&mut self, key: K, value: V) {
+    if self.capacity == 0 {
+      return;
+    }
+
+    if let Some((tick, _)) = self.entries.remove(&key) {
+      self.recency.remove(&tick);
+    }
+
+    while self.entries.len() >= self.capacity {
+      let Some((_, oldest)) = self.recency.pop_first() else {
+        break;
+      };
+      self.entries.remove(&oldest);
+    }
+
+    self.tick += 1;
+    self.recency.insert(self.tick, key.clone());
+    self.entries.insert(key, (self.tick, value));
+  }
+
+  pub(crate) fn generation(&self) -> u64 {
+    self.generation
+  }
+
+  pub(crate) fn clear(&mut self) {
+    self.entries.clear();
+    self.recency.clear();
+    self.generation += 1;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn least_recently_used_entry_is_evicted() {
+    let mut lru = Lru::new(2);
+    lru.insert(1, "a");
+    lru.insert(2, "b");
+    assert_eq!(lru.get(&1), Some("a"));
+    lru.insert(3, "c");
+    assert_eq!(lru.get(&2), None);
+    assert_eq!(lru.get(&1), Some("a"));
+    assert_eq!(lru.get(&3), Some("c"));
+    assert_eq!(lru.entries.len(), 2);
+  }
+
+  #[test]
+  fn reinserting_replaces_value() {
+    let mut lru = Lru::new(2);
+    lru.insert(1, "a");
+    lru.insert(1, "b");
+    assert_eq!(lru.get(&1), Some("b"));
+    assert_eq!(lru.entries.len(), 1);
+  }
+
+  #[test]
+  fn zero_capacity_caches_nothing() {
+    let mut lru = Lru::new(0);
+    lru.insert(1, "a");
+    assert_eq!(lru.get(&1), None);
+    assert_eq!(lru.entries.len(), 0);
+  }
+
+  #[test]
+  fn clear_bumps_generation() {
+    let mut lru = Lru::new(1);
+    lru.insert(1, "a");
+    lru.clear();
+    assert_eq!(lru.get(&1), None);
+    assert_eq!(lru.generation(), 1);
+  }
+}
@@ -22,6 +22,8 @@ impl std::error::Error for Error {}
 const MAX_SAVEPOINTS: u32 = 2;
 const SAVEPOINT_INTERVAL: u32 = 10;
 const CHAIN_TIP_DISTANCE: u32 = 21;
+const MAX_REORG_HISTORY: usize = 16;
+const PURGE_WEBHOOK_ATTEMPTS: u32 = 3;
 
 pub(crate) struct Reorg {}
 
@@ -60,24 +62,145 @@ impl Reorg {
       panic!("set index durability to `Durability::Immediate` to test reorg handling");
     }
 
+    let old_block_count = index.begin_read()?.block_count()?;
+    let old_tip = index.block_hash(None)?;
+
     let mut wtx = index.begin_write()?;
 
     let oldest_savepoint =
       wtx.get_persistent_savepoint(wtx.list_persistent_savepoints()?.min().unwrap())?;
 
+    let mints = if index.event_sender.is_some() && index.index_runes {
+      Some(Self::rune_mints(index, &wtx)?)
+    } else {
+      None
+    };
+
     wtx.restore_savepoint(&oldest_savepoint)?;
 
+    if let (Some(sender), Some(mints)) = (&index.event_sender, mints) {
+      let block_height = wtx
+        .open_table(HEIGHT_TO_BLOCK_HEADER)?
+        .range(0..)?
+        .next_back()
+        .transpose()?
+        .map(|(height, _header)| height.value())
+        .unwrap_or(0);
+
+      let restored = Self::rune_mints(index, &wtx)?;
+
+      for (rune_id, (mints, amount)) in mints {
+        let (restored_mints, _) = restored.get(&rune_id).copied().unwrap_or_default();
+
+        if mints > restored_mints {
+          sender.blocking_send(Event::RuneMintsRolledBack {
+            amount: amount.unwrap_or_default() * (mints - restored_mints),
+            block_height,
+            mints: mints - restored_mints,
+            rune_id,
+          })?;
+        }
+      }
+    }
+
     Index::increment_statistic(&wtx, Statistic::Commits, 1)?;
     wtx.commit()?;
 
-    log::info!(
-      "successfully rolled back database to height {}",
-      index.begin_read()?.block_count()?
-    );
+    index.invalidate_inscriptions_on_output_cache();
+
+    {
+      let mut reorgs = index.reorgs.lock().unwrap();
+
+      if reorgs.len() >= MAX_REORG_HISTORY {
+        reorgs.pop_front();
+      }
+
+      reorgs.push_back(api::Reorg {
+        depth,
+        height,
+        timestamp: Utc::now(),
+      });
+    }
+
+    if let Some(sender) = &index.event_sender {
+      sender.blocking_send(Event::Reorg { depth, height })?;
+    }
+
+    let block_count = index.begin_read()?.block_count()?;
+
+    if let Some(url) = index.settings.reorg_purge_webhook() {
+      if block_count < old_block_count {
+        Self::post_purge(
+          url.clone(),
+          api::ReorgPurge {
+            depth,
+            height,
+            start_height: block_count,
+            end_height: old_block_count - 1,
+            old_tip,
+            new_tip: index.client.get_best_block_hash().ok(),
+          },
+        );
+      }
+    }
+
+    log::info!("successfully rolled back database to height {block_count}");
 
     Ok(())
   }
 
+  fn post_purge(url: Url, purge: api::ReorgPurge) {
+    thread::spawn(move || {
+      let client = reqwest::blocking::Client::new();
+
+      for attempt in 1..=PURGE_WEBHOOK_ATTEMPTS {
+        match client
+          .post(url.clone())
+          .json(&purge)
+          .send()
+          .and_then(|response| response.error_for_status())
+        {
+          Ok(_) => return,
+          Err(err) => {
+            log::warn!(
+              "reorg purge webhook attempt {attempt} of {PURGE_WEBHOOK_ATTEMPTS} failed: {err}"
+            );
+            thread::sleep(Duration::from_secs(attempt.into()));
+          }
+        }
+      }
+    });
+  }
+
+  fn rune_mints(
+    index: &Index,
+    wtx: &WriteTransaction,
+  ) -> Result<HashMap<RuneId, (u128, Option<u128>)>> {
+    let watched_runes = index.watched_runes();
+
+    let mut mints = HashMap::new();
+
+    for result in wtx.open_table(RUNE_ID_TO_RUNE_ENTRY)?.iter()? {
+      let (id, entry) = result?;
+      let id = RuneId::load(id.value());
+
+      if !watched_runes.is_empty() && !watched_runes.contains(&id) {
+        continue;
+      }
+
+      let entry = RuneEntry::load(entry.value());
+
+      if entry.mints > 0 {
+        mints.insert(
+          id,
+          (entry.mints, entry.terms.and_then(|terms| terms.amount)),
+        );
+      }
+    }
+
+    Ok(mints)
+  }
+
   pub(crate) fn update_savepoints(index: &Index, height: u32) -> Result {
     if let redb::Durability::None = index.durability {
       return Ok(());
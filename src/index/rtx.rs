@@ -28,6 +28,8 @@ impl Rtx {
     )
   }
 
+  // Served entirely from the index, so no RPC calls are made. Reorg recovery
+  // restores a savepoint, which rolls back this table along with the others.
   pub(crate) fn block_hash(&self, height: Option<u32>) -> Result<Option<BlockHash>> {
     let height_to_block_header = self.0.open_table(HEIGHT_TO_BLOCK_HEADER)?;
 
@@ -9,6 +9,12 @@ use {
 mod inscription_updater;
 mod rune_updater;
 
+#[cfg(test)]
+thread_local! {
+  /// Height at which to stop updating, as if ord had received a shutdown signal
+  pub(super) static INTERRUPT_HEIGHT: std::cell::Cell<Option<u32>> = const { std::cell::Cell::new(None) };
+}
+
 pub(crate) struct BlockData {
   pub(crate) header: Header,
   pub(crate) txdata: Vec<(Transaction, Txid)>,
@@ -43,7 +49,9 @@ pub(crate) struct Updater<'index> {
 impl<'index> Updater<'index> {
   pub(crate) fn update_index(&mut self, mut wtx: WriteTransaction) -> Result {
     let start = Instant::now();
+    let rpc_start = Instant::now();
     let starting_height = u32::try_from(self.index.client.get_block_count()?).unwrap() + 1;
+    self.index.record_rpc_latency(rpc_start.elapsed());
     let starting_index_height = self.height;
 
     wtx
@@ -60,6 +68,7 @@ impl<'index> Updater<'index> {
       || log_enabled!(log::Level::Info)
       || starting_height <= self.height
       || self.index.settings.integration_test()
+      || self.index.settings.quiet()
     {
       None
     } else {
@@ -130,6 +139,11 @@ impl<'index> Updater<'index> {
       if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
         break;
       }
+
+      #[cfg(test)]
+      if INTERRUPT_HEIGHT.get() == Some(self.height) {
+        break;
+      }
     }
 
     if starting_index_height == 0 && self.height > 0 {
@@ -163,6 +177,8 @@ impl<'index> Updater<'index> {
 
     let first_inscription_height = index.first_inscription_height;
 
+    let retries = index.settings.bitcoin_rpc_retries();
+
     thread::spawn(move || loop {
       if let Some(height_limit) = height_limit {
         if height >= height_limit {
@@ -170,7 +186,13 @@ impl<'index> Updater<'index> {
         }
       }
 
-      match Self::get_block_with_retries(&client, height, index_sats, first_inscription_height) {
+      match Self::get_block_with_retries(
+        &client,
+        height,
+        index_sats,
+        first_inscription_height,
+        retries,
+      ) {
         Ok(Some(block)) => {
           if let Err(err) = tx.send(block.into()) {
             log::info!("Block receiver disconnected: {err}");
@@ -194,6 +216,7 @@ impl<'index> Updater<'index> {
     height: u32,
     index_sats: bool,
     first_inscription_height: u32,
+    retries: u32,
   ) -> Result<Option<Block>> {
     let mut errors = 0;
     loop {
@@ -220,14 +243,15 @@ impl<'index> Updater<'index> {
           }
 
           errors += 1;
-          let seconds = 1 << errors;
-          log::warn!("failed to fetch block {height}, retrying in {seconds}s: {err}");
 
-          if seconds > 120 {
-            log::error!("would sleep for more than 120s, giving up");
+          if errors > retries {
+            log::error!("failed to fetch block {height} after {retries} retries, giving up");
             return Err(err);
           }
 
+          let seconds = 1u64 << errors.min(16);
+          log::warn!("failed to fetch block {height}, retrying in {seconds}s: {err}");
+
           thread::sleep(Duration::from_secs(seconds));
         }
         Ok(result) => return Ok(result),
@@ -238,6 +262,11 @@ impl<'index> Updater<'index> {
   fn spawn_fetcher(settings: &Settings) -> Result<(Sender<OutPoint>, Receiver<u64>)> {
     let fetcher = Fetcher::new(settings)?;
 
+    // Default rpcworkqueue in bitcoind is 16, meaning more than 16 concurrent requests will be rejected.
+    // Since we are already requesting blocks on a separate thread, and we don't want to break if anything
+    // else runs a request, this defaults to 12.
+    let parallel_requests = settings.max_fetch_requests();
+
     // Not sure if any block has more than 20k inputs, but none so far after first inscription block
     const CHANNEL_BUFFER_SIZE: usize = 20_000;
     let (outpoint_sender, mut outpoint_receiver) =
@@ -247,10 +276,6 @@ impl<'index> Updater<'index> {
     // Batch 2048 missing inputs at a time. Arbitrarily chosen for now, maybe higher or lower can be faster?
     // Did rudimentary benchmarks with 1024 and 4096 and time was roughly the same.
     const BATCH_SIZE: usize = 2048;
-    // Default rpcworkqueue in bitcoind is 16, meaning more than 16 concurrent requests will be rejected.
-    // Since we are already requesting blocks on a separate thread, and we don't want to break if anything
-    // else runs a request, we keep this to 12.
-    const PARALLEL_REQUESTS: usize = 12;
 
     thread::spawn(move || {
       let rt = tokio::runtime::Builder::new_multi_thread()
@@ -273,8 +298,8 @@ impl<'index> Updater<'index> {
             outpoints.push(outpoint);
           }
           // Break outpoints into chunks for parallel requests
-          let chunk_size = (outpoints.len() / PARALLEL_REQUESTS) + 1;
-          let mut futs = Vec::with_capacity(PARALLEL_REQUESTS);
+          let chunk_size = (outpoints.len() / parallel_requests) + 1;
+          let mut futs = Vec::with_capacity(parallel_requests);
           for chunk in outpoints.chunks(chunk_size) {
             let txids = chunk.iter().map(|outpoint| outpoint.txid).collect();
             let fut = fetcher.get_transactions(txids);
@@ -370,6 +395,7 @@ impl<'index> Updater<'index> {
       }
     }
 
+    let mut content_sha256_to_inscription_id = wtx.open_table(CONTENT_SHA256_TO_INSCRIPTION_ID)?;
     let mut content_type_to_count = wtx.open_table(CONTENT_TYPE_TO_COUNT)?;
     let mut height_to_block_header = wtx.open_table(HEIGHT_TO_BLOCK_HEADER)?;
     let mut height_to_last_sequence_number = wtx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
@@ -378,9 +404,12 @@ impl<'index> Updater<'index> {
       wtx.open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
     let mut inscription_number_to_sequence_number =
       wtx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
+    let mut recursive_inscriptions = wtx.open_table(RECURSIVE_INSCRIPTIONS)?;
     let mut sat_to_sequence_number = wtx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
     let mut satpoint_to_sequence_number = wtx.open_multimap_table(SATPOINT_TO_SEQUENCE_NUMBER)?;
     let mut sequence_number_to_children = wtx.open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?;
+    let mut sequence_number_to_content_length =
+      wtx.open_table(SEQUENCE_NUMBER_TO_CONTENT_LENGTH)?;
     let mut sequence_number_to_inscription_entry =
       wtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
     let mut sequence_number_to_satpoint = wtx.open_table(SEQUENCE_NUMBER_TO_SATPOINT)?;
@@ -418,7 +447,7 @@ impl<'index> Updater<'index> {
 
     let mut inscription_updater = InscriptionUpdater {
       blessed_inscription_count,
-      chain: self.index.settings.chain(),
+      content_sha256_to_inscription_id: &mut content_sha256_to_inscription_id,
       content_type_to_count: &mut content_type_to_count,
       cursed_inscription_count,
       event_sender: self.index.event_sender.as_ref(),
@@ -427,15 +456,19 @@ impl<'index> Updater<'index> {
       home_inscription_count,
       home_inscriptions: &mut home_inscriptions,
       id_to_sequence_number: &mut inscription_id_to_sequence_number,
+      index_cids: self.index.index_cids,
       index_transactions: self.index.index_transactions,
       inscription_number_to_sequence_number: &mut inscription_number_to_sequence_number,
+      jubilee_height: self.index.settings.jubilee_height(),
       lost_sats,
       next_sequence_number,
       outpoint_to_value: &mut outpoint_to_value,
+      recursive_inscriptions: &mut recursive_inscriptions,
       reward: Height(self.height).subsidy(),
       sat_to_sequence_number: &mut sat_to_sequence_number,
       satpoint_to_sequence_number: &mut satpoint_to_sequence_number,
       sequence_number_to_children: &mut sequence_number_to_children,
+      sequence_number_to_content_length: &mut sequence_number_to_content_length,
       sequence_number_to_entry: &mut sequence_number_to_inscription_entry,
       sequence_number_to_satpoint: &mut sequence_number_to_satpoint,
       timestamp: block.header.time,
@@ -486,9 +519,7 @@ impl<'index> Updater<'index> {
             .to_vec(),
           };
 
-          for chunk in sat_ranges.chunks_exact(11) {
-            input_sat_ranges.push_back(SatRange::load(chunk.try_into().unwrap()));
-          }
+          input_sat_ranges.extend(self.index.load_sat_ranges(&sat_ranges)?);
         }
 
         self.index_transaction_sats(
@@ -521,7 +552,8 @@ impl<'index> Updater<'index> {
       if !coinbase_inputs.is_empty() {
         let mut lost_sat_ranges = outpoint_to_sat_ranges
           .remove(&OutPoint::null().store())?
-          .map(|ranges| ranges.value().to_vec())
+          .map(|ranges| self.index.load_sat_ranges(ranges.value()))
+          .transpose()?
           .unwrap_or_default();
 
         for (start, end) in coinbase_inputs {
@@ -536,12 +568,15 @@ impl<'index> Updater<'index> {
             )?;
           }
 
-          lost_sat_ranges.extend_from_slice(&(start, end).store());
+          lost_sat_ranges.push((start, end));
 
           lost_sats += end - start;
         }
 
-        outpoint_to_sat_ranges.insert(&OutPoint::null().store(), lost_sat_ranges.as_slice())?;
+        outpoint_to_sat_ranges.insert(
+          &OutPoint::null().store(),
+          self.index.store_sat_ranges(&lost_sat_ranges).as_slice(),
+        )?;
       }
     } else if index_inscriptions {
       for (tx, txid) in block.txdata.iter().skip(1).chain(block.txdata.first()) {
@@ -580,6 +615,7 @@ impl<'index> Updater<'index> {
 
     if self.index.index_runes && self.height >= self.index.settings.first_rune_height() {
       let mut outpoint_to_rune_balances = wtx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
+      let mut rune_id_to_outpoints = wtx.open_multimap_table(RUNE_ID_TO_OUTPOINTS)?;
       let mut rune_id_to_rune_entry = wtx.open_table(RUNE_ID_TO_RUNE_ENTRY)?;
       let mut rune_to_rune_id = wtx.open_table(RUNE_TO_RUNE_ID)?;
       let mut sequence_number_to_rune_id = wtx.open_table(SEQUENCE_NUMBER_TO_RUNE_ID)?;
@@ -597,17 +633,23 @@ impl<'index> Updater<'index> {
         client: &self.index.client,
         height: self.height,
         id_to_entry: &mut rune_id_to_rune_entry,
+        index_transactions: self.index.index_transactions,
         inscription_id_to_sequence_number: &mut inscription_id_to_sequence_number,
         minimum: Rune::minimum_at_height(
           self.index.settings.chain().network(),
           Height(self.height),
         ),
         outpoint_to_balances: &mut outpoint_to_rune_balances,
+        rune_id_to_outpoints: &mut rune_id_to_outpoints,
         rune_to_id: &mut rune_to_rune_id,
         runes,
         sequence_number_to_rune_id: &mut sequence_number_to_rune_id,
         statistic_to_count: &mut statistic_to_count,
+        strict_runestone: self.index.settings.strict_runestone(),
+        transaction_buffer: Vec::new(),
         transaction_id_to_rune: &mut transaction_id_to_rune,
+        transaction_id_to_transaction: &mut transaction_id_to_transaction,
+        watched_runes: self.index.watched_runes(),
       };
 
       for (i, (tx, txid)) in block.txdata.iter().enumerate() {
@@ -680,7 +722,7 @@ impl<'index> Updater<'index> {
           range
         };
 
-        sats.extend_from_slice(&assigned.store());
+        sats.push(assigned);
 
         remaining -= assigned.1 - assigned.0;
 
@@ -689,13 +731,30 @@ impl<'index> Updater<'index> {
 
       *outputs_traversed += 1;
 
-      self.range_cache.insert(outpoint.store(), sats);
+      if self.index.settings.verify_sat_ranges() {
+        Self::verify_sat_ranges(outpoint, &sats, output.value)?;
+      }
+
+      self
+        .range_cache
+        .insert(outpoint.store(), self.index.store_sat_ranges(&sats));
       self.outputs_inserted_since_flush += 1;
     }
 
     Ok(())
   }
 
+  pub(super) fn verify_sat_ranges(outpoint: OutPoint, sats: &[(u64, u64)], value: u64) -> Result {
+    let total = sats.iter().map(|(start, end)| end - start).sum::<u64>();
+
+    ensure!(
+      total == value,
+      "sat ranges of output {outpoint} sum to {total} but output value is {value}",
+    );
+
+    Ok(())
+  }
+
   fn commit(&mut self, wtx: WriteTransaction, value_cache: HashMap<OutPoint, u64>) -> Result {
     log::info!(
       "Committing at block height {}, {} outputs traversed, {} in map, {} cached",
@@ -737,6 +796,8 @@ impl<'index> Updater<'index> {
     Index::increment_statistic(&wtx, Statistic::Commits, 1)?;
     wtx.commit()?;
 
+    self.index.invalidate_inscriptions_on_output_cache();
+
     Reorg::update_savepoints(self.index, self.height)?;
 
     Ok(())
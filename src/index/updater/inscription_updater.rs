@@ -23,11 +23,13 @@ pub(super) struct Flotsam {
 #[derive(Debug, Clone)]
 enum Origin {
   New {
+    content_length: Option<usize>,
     cursed: bool,
     fee: u64,
     hidden: bool,
     parents: Vec<InscriptionId>,
     pointer: Option<u64>,
+    recursive: bool,
     reinscription: bool,
     unbound: bool,
     vindicated: bool,
@@ -39,7 +41,8 @@ enum Origin {
 
 pub(super) struct InscriptionUpdater<'a, 'tx> {
   pub(super) blessed_inscription_count: u64,
-  pub(super) chain: Chain,
+  pub(super) content_sha256_to_inscription_id:
+    &'a mut Table<'tx, &'static [u8; 32], InscriptionIdValue>,
   pub(super) content_type_to_count: &'a mut Table<'tx, Option<&'static [u8]>, u64>,
   pub(super) cursed_inscription_count: u64,
   pub(super) event_sender: Option<&'a Sender<Event>>,
@@ -48,17 +51,21 @@ pub(super) struct InscriptionUpdater<'a, 'tx> {
   pub(super) home_inscription_count: u64,
   pub(super) home_inscriptions: &'a mut Table<'tx, u32, InscriptionIdValue>,
   pub(super) id_to_sequence_number: &'a mut Table<'tx, InscriptionIdValue, u32>,
+  pub(super) index_cids: bool,
   pub(super) index_transactions: bool,
   pub(super) inscription_number_to_sequence_number: &'a mut Table<'tx, i32, u32>,
+  pub(super) jubilee_height: u32,
   pub(super) lost_sats: u64,
   pub(super) next_sequence_number: u32,
   pub(super) outpoint_to_value: &'a mut Table<'tx, &'static OutPointValue, u64>,
+  pub(super) recursive_inscriptions: &'a mut Table<'tx, u32, ()>,
   pub(super) reward: u64,
   pub(super) transaction_buffer: Vec<u8>,
   pub(super) transaction_id_to_transaction: &'a mut Table<'tx, &'static TxidValue, &'static [u8]>,
   pub(super) sat_to_sequence_number: &'a mut MultimapTable<'tx, u64, u32>,
   pub(super) satpoint_to_sequence_number: &'a mut MultimapTable<'tx, &'static SatPointValue, u32>,
   pub(super) sequence_number_to_children: &'a mut MultimapTable<'tx, u32, u32>,
+  pub(super) sequence_number_to_content_length: &'a mut Table<'tx, u32, u64>,
   pub(super) sequence_number_to_entry: &'a mut Table<'tx, u32, InscriptionEntryValue>,
   pub(super) sequence_number_to_satpoint: &'a mut Table<'tx, u32, &'static SatPointValue>,
   pub(super) timestamp: u32,
@@ -77,7 +84,7 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
     let mut floating_inscriptions = Vec::new();
     let mut id_counter = 0;
     let mut inscribed_offsets = BTreeMap::new();
-    let jubilant = self.height >= self.chain.jubilee_height();
+    let jubilant = self.height >= self.jubilee_height;
     let mut total_input_value = 0;
     let total_output_value = tx.output.iter().map(|txout| txout.value).sum::<u64>();
 
@@ -194,27 +201,45 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
           .filter(|&pointer| pointer < total_output_value)
           .unwrap_or(offset);
 
-        let content_type = inscription.payload.content_type.as_deref();
+        let content_type =
+          inscription.payload.content_type.as_deref().map(
+            |content_type| match std::str::from_utf8(content_type) {
+              Ok(content_type) => Media::normalize_content_type(content_type).into_bytes(),
+              Err(_) => content_type.to_vec(),
+            },
+          );
 
         let content_type_count = self
           .content_type_to_count
-          .get(content_type)?
+          .get(content_type.as_deref())?
           .map(|entry| entry.value())
           .unwrap_or_default();
 
         self
           .content_type_to_count
-          .insert(content_type, content_type_count + 1)?;
+          .insert(content_type.as_deref(), content_type_count + 1)?;
+
+        if self.index_cids {
+          if let Some(cid) = inscription.payload.cid() {
+            if self.content_sha256_to_inscription_id.get(&cid.0)?.is_none() {
+              self
+                .content_sha256_to_inscription_id
+                .insert(&cid.0, inscription_id.store())?;
+            }
+          }
+        }
 
         floating_inscriptions.push(Flotsam {
           inscription_id,
           offset,
           origin: Origin::New {
+            content_length: inscription.payload.content_length(),
             cursed: curse.is_some() && !jubilant,
             fee: 0,
             hidden: inscription.payload.hidden(),
             parents: inscription.payload.parents(),
             pointer: inscription.payload.pointer(),
+            recursive: inscription.payload.recursive(),
             reinscription: inscribed_offsets.get(&offset).is_some(),
             unbound: current_input_value == 0
               || curse == Some(Curse::UnrecognizedEvenField)
@@ -419,11 +444,13 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
         (false, sequence_number)
       }
       Origin::New {
+        content_length,
         cursed,
         fee,
         hidden,
         parents,
         pointer: _,
+        recursive,
         reinscription,
         unbound,
         vindicated,
@@ -529,6 +556,16 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
           .id_to_sequence_number
           .insert(&inscription_id.store(), sequence_number)?;
 
+        if recursive {
+          self.recursive_inscriptions.insert(sequence_number, ())?;
+        }
+
+        if let Some(content_length) = content_length {
+          self
+            .sequence_number_to_content_length
+            .insert(sequence_number, u64::try_from(content_length).unwrap())?;
+        }
+
         if !hidden {
           self
             .home_inscriptions
@@ -7,37 +7,60 @@ pub(super) struct RuneUpdater<'a, 'tx, 'client> {
   pub(super) event_sender: Option<&'a Sender<Event>>,
   pub(super) height: u32,
   pub(super) id_to_entry: &'a mut Table<'tx, RuneIdValue, RuneEntryValue>,
+  pub(super) index_transactions: bool,
   pub(super) inscription_id_to_sequence_number: &'a Table<'tx, InscriptionIdValue, u32>,
   pub(super) minimum: Rune,
   pub(super) outpoint_to_balances: &'a mut Table<'tx, &'static OutPointValue, &'static [u8]>,
+  pub(super) rune_id_to_outpoints: &'a mut MultimapTable<'tx, RuneIdValue, &'static OutPointValue>,
   pub(super) rune_to_id: &'a mut Table<'tx, u128, RuneIdValue>,
   pub(super) runes: u64,
   pub(super) sequence_number_to_rune_id: &'a mut Table<'tx, u32, RuneIdValue>,
   pub(super) statistic_to_count: &'a mut Table<'tx, u64, u64>,
+  pub(super) strict_runestone: bool,
+  pub(super) transaction_buffer: Vec<u8>,
   pub(super) transaction_id_to_rune: &'a mut Table<'tx, &'static TxidValue, u128>,
+  pub(super) transaction_id_to_transaction: &'a mut Table<'tx, &'static TxidValue, &'static [u8]>,
+  pub(super) watched_runes: HashSet<RuneId>,
 }
 
 impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
   pub(super) fn index_runes(&mut self, tx_index: u32, tx: &Transaction, txid: Txid) -> Result<()> {
-    let artifact = Runestone::decipher(tx);
+    let artifact = if self.strict_runestone {
+      let artifact = Runestone::decipher_strict(tx);
+
+      if let Some(Artifact::Cenotaph(cenotaph)) = &artifact {
+        if let Some(flaw) = cenotaph.flaw {
+          log::info!("Burning runes in strict cenotaph {txid}: {flaw}");
+        }
+      }
+
+      artifact
+    } else {
+      Runestone::decipher(tx)
+    };
+
+    if self.index_transactions && artifact.is_some() {
+      tx.consensus_encode(&mut self.transaction_buffer)
+        .expect("in-memory writers don't error");
+
+      self
+        .transaction_id_to_transaction
+        .insert(&txid.store(), self.transaction_buffer.as_slice())?;
+
+      self.transaction_buffer.clear();
+    }
 
     let mut unallocated = self.unallocated(tx)?;
 
     let mut allocated: Vec<HashMap<RuneId, Lot>> = vec![HashMap::new(); tx.output.len()];
 
+    let mut minted = None;
+
     if let Some(artifact) = &artifact {
       if let Some(id) = artifact.mint() {
         if let Some(amount) = self.mint(id)? {
           *unallocated.entry(id).or_default() += amount;
-
-          if let Some(sender) = self.event_sender {
-            sender.blocking_send(Event::RuneMinted {
-              block_height: self.height,
-              txid,
-              rune_id: id,
-              amount: amount.n(),
-            })?;
-          }
+          minted = Some((id, amount));
         }
       }
 
@@ -168,6 +191,33 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
       }
     }
 
+    if let Some((id, amount)) = minted {
+      if let Some(sender) = self
+        .event_sender
+        .filter(|_| self.watched_runes.is_empty() || self.watched_runes.contains(&id))
+      {
+        // report the first non-OP_RETURN output holding the minted rune, if any
+        let outpoint = allocated
+          .iter()
+          .enumerate()
+          .find(|(vout, balances)| {
+            balances.contains_key(&id) && !tx.output[*vout].script_pubkey.is_op_return()
+          })
+          .map(|(vout, _balances)| OutPoint {
+            txid,
+            vout: vout.try_into().unwrap(),
+          });
+
+        sender.blocking_send(Event::RuneMinted {
+          block_height: self.height,
+          outpoint,
+          txid,
+          rune_id: id,
+          amount: amount.n(),
+        })?;
+      }
+    }
+
     // update outpoint balances
     let mut buffer: Vec<u8> = Vec::new();
     for (vout, balances) in allocated.into_iter().enumerate() {
@@ -198,6 +248,10 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
       for (id, balance) in balances {
         Index::encode_rune_balance(id, balance.n(), &mut buffer);
 
+        self
+          .rune_id_to_outpoints
+          .insert(id.store(), &outpoint.store())?;
+
         if let Some(sender) = self.event_sender {
           sender.blocking_send(Event::RuneTransferred {
             outpoint,
@@ -482,6 +536,9 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
           let ((id, balance), len) = Index::decode_rune_balance(&buffer[i..]).unwrap();
           i += len;
           *unallocated.entry(id).or_default() += balance;
+          self
+            .rune_id_to_outpoints
+            .remove(id.store(), &input.previous_output.store())?;
         }
       }
     }
@@ -2,10 +2,19 @@ use super::*;
 
 use tag::Tag;
 
-pub(crate) use self::{envelope::ParsedEnvelope, media::Media};
+pub(crate) use self::{
+  envelope::{ParsedEnvelope, RawEnvelope},
+  media::Media,
+};
 
-pub use self::{envelope::Envelope, inscription::Inscription, inscription_id::InscriptionId};
+pub use self::{
+  cid::Cid,
+  envelope::Envelope,
+  inscription::{Compression, Inscription},
+  inscription_id::InscriptionId,
+};
 
+mod cid;
 mod envelope;
 mod inscription;
 pub(crate) mod inscription_id;
@@ -0,0 +1,126 @@
+use super::*;
+
+const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+// CIDv1, raw codec, sha2-256 multihash, 32 byte digest
+const PREFIX: [u8; 4] = [0x01, 0x55, 0x12, 0x20];
+
+#[derive(Debug, PartialEq, Copy, Clone, DeserializeFromStr, SerializeDisplay)]
+pub struct Cid(pub(crate) [u8; 32]);
+
+impl Cid {
+  pub(crate) fn from_content(content: &[u8]) -> Self {
+    Self(bitcoin::hashes::sha256::Hash::hash(content).to_byte_array())
+  }
+}
+
+impl Display for Cid {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "b")?;
+
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for byte in PREFIX.iter().chain(&self.0) {
+      buffer = (buffer << 8) | u16::from(*byte);
+      bits += 8;
+
+      while bits >= 5 {
+        bits -= 5;
+        write!(
+          f,
+          "{}",
+          char::from(ALPHABET[usize::from((buffer >> bits) & 31)])
+        )?;
+      }
+    }
+
+    if bits > 0 {
+      write!(
+        f,
+        "{}",
+        char::from(ALPHABET[usize::from((buffer << (5 - bits)) & 31)])
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for Cid {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let Some(encoded) = s.strip_prefix('b') else {
+      bail!("CID must use base32 multibase prefix `b`");
+    };
+
+    let mut bytes = Vec::new();
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for c in encoded.bytes() {
+      let Some(value) = ALPHABET.iter().position(|&a| a == c) else {
+        bail!("invalid base32 character `{}`", char::from(c));
+      };
+
+      buffer = (buffer << 5) | u16::try_from(value).unwrap();
+      bits += 5;
+
+      if bits >= 8 {
+        bits -= 8;
+        bytes.push(u8::try_from((buffer >> bits) & 0xff).unwrap());
+      }
+    }
+
+    let Some(digest) = bytes.strip_prefix(PREFIX.as_slice()) else {
+      bail!("CID must be version 1 with raw codec and sha2-256 multihash");
+    };
+
+    Ok(Self(digest.try_into().map_err(|_| {
+      anyhow!("invalid sha2-256 digest length: {}", digest.len())
+    })?))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty_content() {
+    assert_eq!(
+      Cid::from_content(&[]).to_string(),
+      "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    let cid = Cid::from_content(b"foo");
+    assert_eq!(cid.to_string().parse::<Cid>().unwrap(), cid);
+  }
+
+  #[test]
+  fn from_str_errors() {
+    assert_eq!(
+      "zafk".parse::<Cid>().unwrap_err().to_string(),
+      "CID must use base32 multibase prefix `b`",
+    );
+
+    assert_eq!(
+      "bafk1".parse::<Cid>().unwrap_err().to_string(),
+      "invalid base32 character `1`",
+    );
+
+    assert_eq!(
+      "baaaa".parse::<Cid>().unwrap_err().to_string(),
+      "CID must be version 1 with raw codec and sha2-256 multihash",
+    );
+
+    assert_eq!(
+      "bafkreihdwdce".parse::<Cid>().unwrap_err().to_string(),
+      "invalid sha2-256 digest length: 3",
+    );
+  }
+}
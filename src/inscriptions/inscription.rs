@@ -8,6 +8,26 @@ use {
   std::str,
 };
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Compression {
+  pub quality: i32,
+  pub window: i32,
+}
+
+impl Compression {
+  pub(crate) const QUALITY: std::ops::RangeInclusive<i32> = 0..=11;
+  pub(crate) const WINDOW: std::ops::RangeInclusive<i32> = 10..=24;
+}
+
+impl Default for Compression {
+  fn default() -> Self {
+    Self {
+      quality: 11,
+      window: 24,
+    }
+  }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq, Default)]
 pub struct Inscription {
   pub body: Option<Vec<u8>>,
@@ -27,7 +47,7 @@ pub struct Inscription {
 impl Inscription {
   pub fn new(
     chain: Chain,
-    compress: bool,
+    compression: Option<Compression>,
     delegate: Option<InscriptionId>,
     metadata: Option<Vec<u8>>,
     metaprotocol: Option<String>,
@@ -43,7 +63,7 @@ impl Inscription {
 
       let content_type = Media::content_type_for_path(path)?.0;
 
-      let (body, content_encoding) = if compress {
+      let (body, content_encoding) = if let Some(compression) = compression {
         let compression_mode = Media::content_type_for_path(path)?.1;
         let mut compressed = Vec::new();
 
@@ -53,9 +73,9 @@ impl Inscription {
             body.len(),
             &BrotliEncoderParams {
               lgblock: 24,
-              lgwin: 24,
+              lgwin: compression.window,
               mode: compression_mode,
-              quality: 11,
+              quality: compression.quality,
               size_hint: body.len(),
               ..default()
             },
@@ -218,6 +238,29 @@ impl Inscription {
     Some(self.body.as_ref()?)
   }
 
+  // CIDs are only computed for content which IPFS stores as a single raw block,
+  // which is the case for files no larger than its default chunk size
+  pub(crate) fn cid(&self) -> Option<Cid> {
+    const MAX_CONTENT_SIZE: usize = 262_144;
+
+    let body = self.body()?;
+
+    match self.content_encoding.as_deref() {
+      None => (body.len() <= MAX_CONTENT_SIZE).then(|| Cid::from_content(body)),
+      Some(b"br") => {
+        let mut content = Vec::new();
+
+        brotli::Decompressor::new(body, body.len().max(1))
+          .take(u64::try_from(MAX_CONTENT_SIZE).unwrap() + 1)
+          .read_to_end(&mut content)
+          .ok()?;
+
+        (content.len() <= MAX_CONTENT_SIZE).then(|| Cid::from_content(&content))
+      }
+      Some(_) => None,
+    }
+  }
+
   pub(crate) fn into_body(self) -> Option<Vec<u8>> {
     self.body
   }
@@ -289,6 +332,22 @@ impl Inscription {
     witness
   }
 
+  // A heuristic, so text which merely mentions these paths will also be
+  // flagged, and compressed bodies are not inspected.
+  pub(crate) fn recursive(&self) -> bool {
+    use regex::bytes::Regex;
+
+    lazy_static! {
+      static ref RECURSIVE: Regex = Regex::new(r"/content/[[:xdigit:]]{64}i\d+|/r/[a-z]").unwrap();
+    }
+
+    self.content_encoding.is_none()
+      && self
+        .body()
+        .map(|body| RECURSIVE.is_match(body))
+        .unwrap_or_default()
+  }
+
   pub(crate) fn hidden(&self) -> bool {
     use regex::bytes::Regex;
 
@@ -306,6 +365,13 @@ impl Inscription {
       || self.metaprotocol.is_some()
       || matches!(self.media(), Media::Code(_) | Media::Text | Media::Unknown)
   }
+
+  pub(crate) fn matches_hidden_pattern(&self, patterns: &[regex::bytes::Regex]) -> bool {
+    self
+      .body()
+      .map(|body| patterns.iter().any(|pattern| pattern.is_match(body)))
+      .unwrap_or_default()
+  }
 }
 
 #[cfg(test)]
@@ -771,7 +837,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -786,7 +852,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -801,7 +867,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -816,7 +882,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -830,6 +896,39 @@ mod tests {
     assert_eq!(inscription.pointer, Some(vec![0, 1]));
   }
 
+  #[test]
+  fn recursive() {
+    #[track_caller]
+    fn case(body: Option<&str>, expected: bool) {
+      assert_eq!(
+        Inscription {
+          content_type: Some("text/html".as_bytes().into()),
+          body: body.map(|body| body.as_bytes().into()),
+          ..default()
+        }
+        .recursive(),
+        expected
+      );
+    }
+
+    case(None, false);
+    case(Some("<h1>hello</h1>"), false);
+    case(
+      Some("<img src=/content/09a8d837ec0bcaec668ecf405e696a16bee5990863659c224ff888fb6f8f45e7i0>"),
+      true,
+    );
+    case(Some("<script>fetch('/r/blockheight')</script>"), true);
+    case(Some("/content/"), false);
+    case(Some("/r/"), false);
+
+    assert!(!Inscription {
+      content_encoding: Some("br".as_bytes().into()),
+      body: Some("/r/blockheight".as_bytes().into()),
+      ..default()
+    }
+    .recursive());
+  }
+
   #[test]
   fn hidden() {
     #[track_caller]
@@ -907,4 +1006,61 @@ mod tests {
     }
     .hidden());
   }
+
+  #[test]
+  fn matches_hidden_pattern() {
+    let inscription = Inscription {
+      content_type: Some("text/html".as_bytes().into()),
+      body: Some("<h1>spam</h1>".as_bytes().into()),
+      ..default()
+    };
+
+    assert!(!inscription.hidden());
+    assert!(!inscription.matches_hidden_pattern(&[]));
+    assert!(!inscription.matches_hidden_pattern(&[regex::bytes::Regex::new("^eggs$").unwrap()]));
+    assert!(inscription.matches_hidden_pattern(&[
+      regex::bytes::Regex::new("^eggs$").unwrap(),
+      regex::bytes::Regex::new("spam").unwrap(),
+    ]));
+  }
+
+  #[test]
+  fn cid_is_computed_over_decoded_content() {
+    let content = "hello".repeat(100);
+
+    let mut compressed = Vec::new();
+
+    CompressorWriter::new(&mut compressed, 4096, 11, 22)
+      .write_all(content.as_bytes())
+      .unwrap();
+
+    assert_eq!(
+      Inscription {
+        content_encoding: Some("br".as_bytes().into()),
+        body: Some(compressed),
+        ..default()
+      }
+      .cid(),
+      Some(Cid::from_content(content.as_bytes())),
+    );
+
+    assert_eq!(
+      Inscription {
+        content_encoding: Some("gzip".as_bytes().into()),
+        body: Some(content.as_bytes().into()),
+        ..default()
+      }
+      .cid(),
+      None,
+    );
+
+    assert_eq!(
+      Inscription {
+        body: Some(vec![0; 262_145]),
+        ..default()
+      }
+      .cid(),
+      None,
+    );
+  }
 }
@@ -109,6 +109,49 @@ impl Media {
     ("video/webm",                  GENERIC, Video,            &["webm"]),
   ];
 
+  pub(crate) fn normalize_content_type(content_type: &str) -> String {
+    let mut parts = content_type.split(';');
+
+    let mut normalized = parts.next().unwrap_or_default().trim().to_lowercase();
+
+    for parameter in parts
+      .map(str::trim)
+      .filter(|parameter| !parameter.is_empty())
+    {
+      normalized.push(';');
+
+      match parameter.split_once('=') {
+        Some((name, value)) => {
+          let name = name.trim().to_lowercase();
+          let value = value.trim();
+
+          normalized.push_str(&name);
+          normalized.push('=');
+
+          if name == "charset" {
+            normalized.push_str(&value.to_lowercase());
+          } else {
+            normalized.push_str(value);
+          }
+        }
+        None => normalized.push_str(&parameter.to_lowercase()),
+      }
+    }
+
+    normalized
+  }
+
+  pub(crate) fn extension_matches_content_type(extension: &str, content_type: &str) -> bool {
+    let extension = extension.to_lowercase();
+
+    let normalized = Self::normalize_content_type(content_type);
+    let essence = normalized.split(';').next().unwrap_or_default();
+
+    Self::TABLE.iter().any(|(content_type, _, _, extensions)| {
+      content_type.split(';').next() == Some(essence) && extensions.contains(&extension.as_str())
+    })
+  }
+
   pub(crate) fn content_type_for_path(
     path: &Path,
   ) -> Result<(&'static str, BrotliEncoderMode), Error> {
@@ -169,8 +212,10 @@ impl FromStr for Media {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let normalized = Self::normalize_content_type(s);
+
     for entry in Self::TABLE {
-      if entry.0 == s {
+      if entry.0 == normalized {
         return Ok(entry.2);
       }
     }
@@ -210,6 +255,50 @@ mod tests {
     );
   }
 
+  #[test]
+  fn normalize_content_type() {
+    #[track_caller]
+    fn case(content_type: &str, expected: &str) {
+      assert_eq!(Media::normalize_content_type(content_type), expected);
+    }
+
+    case("text/plain", "text/plain");
+    case("Text/Plain", "text/plain");
+    case(" text/plain ", "text/plain");
+    case("text/plain; charset=UTF-8", "text/plain;charset=utf-8");
+    case("TEXT/PLAIN ;Charset = utf-8;", "text/plain;charset=utf-8");
+    case("text/plain;;charset=utf-8", "text/plain;charset=utf-8");
+    case("image/svg+xml; Name=Foo", "image/svg+xml;name=Foo");
+    case("multipart/mixed; Boundary", "multipart/mixed;boundary");
+  }
+
+  #[test]
+  fn media_is_matched_after_normalization() {
+    assert_eq!("Text/Plain".parse::<Media>().unwrap(), Media::Text);
+    assert_eq!(
+      "text/html; charset=UTF-8".parse::<Media>().unwrap(),
+      Media::Iframe
+    );
+    assert_eq!("IMAGE/PNG".parse::<Media>().unwrap(), Image(Pixelated));
+    assert!("text/plain; charset=ascii".parse::<Media>().is_err());
+  }
+
+  #[test]
+  fn extension_matches_content_type() {
+    assert!(Media::extension_matches_content_type("png", "image/png"));
+    assert!(Media::extension_matches_content_type("PNG", "image/png"));
+    assert!(Media::extension_matches_content_type("jpeg", "image/jpeg"));
+    assert!(Media::extension_matches_content_type("jpg", "image/jpeg"));
+    assert!(Media::extension_matches_content_type("txt", "text/plain"));
+    assert!(Media::extension_matches_content_type(
+      "html",
+      "text/html; charset=UTF-8"
+    ));
+    assert!(!Media::extension_matches_content_type("png", "image/jpeg"));
+    assert!(!Media::extension_matches_content_type("txt", "text/html"));
+    assert!(!Media::extension_matches_content_type("foo", "image/foo"));
+  }
+
   #[test]
   fn h264_in_mp4_is_allowed() {
     assert!(Media::check_mp4_codec(Path::new("examples/h264.mp4")).is_ok(),);
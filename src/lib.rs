@@ -21,7 +21,7 @@ use {
     inscriptions::{
       inscription_id,
       media::{self, ImageRendering, Media},
-      teleburn, ParsedEnvelope,
+      teleburn, ParsedEnvelope, RawEnvelope,
     },
     into_usize::IntoUsize,
     representation::Representation,
@@ -71,7 +71,7 @@ use {
     process::{self, Command, Stdio},
     str::FromStr,
     sync::{
-      atomic::{self, AtomicBool},
+      atomic::{self, AtomicBool, AtomicU64},
       Arc, Mutex,
     },
     thread,
@@ -85,7 +85,7 @@ pub use self::{
   chain::Chain,
   fee_rate::FeeRate,
   index::{Index, RuneEntry},
-  inscriptions::{Envelope, Inscription, InscriptionId},
+  inscriptions::{Cid, Compression, Envelope, Inscription, InscriptionId},
   object::Object,
   options::Options,
   wallet::transaction_builder::{Target, TransactionBuilder},
@@ -193,6 +193,10 @@ fn unbound_outpoint() -> OutPoint {
   }
 }
 
+fn is_unbound(outpoint: OutPoint) -> bool {
+  outpoint == unbound_outpoint()
+}
+
 fn uncheck(address: &Address) -> Address<NetworkUnchecked> {
   address.to_string().parse().unwrap()
 }
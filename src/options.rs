@@ -14,6 +14,16 @@ pub struct Options {
     help = "Authenticate to Bitcoin Core RPC with <BITCOIN_RPC_PASSWORD>."
   )]
   pub(crate) bitcoin_rpc_password: Option<String>,
+  #[arg(
+    long,
+    help = "Retry failed Bitcoin Core RPC block requests up to <BITCOIN_RPC_RETRIES> times, with exponential backoff. [default: 6]"
+  )]
+  pub(crate) bitcoin_rpc_retries: Option<u32>,
+  #[arg(
+    long,
+    help = "Time out Bitcoin Core RPC requests after <BITCOIN_RPC_TIMEOUT>. [default: 15s]"
+  )]
+  pub(crate) bitcoin_rpc_timeout: Option<humantime::Duration>,
   #[arg(long, help = "Connect to Bitcoin Core RPC at <BITCOIN_RPC_URL>.")]
   pub(crate) bitcoin_rpc_url: Option<String>,
   #[arg(
@@ -21,6 +31,11 @@ pub struct Options {
     help = "Authenticate to Bitcoin Core RPC as <BITCOIN_RPC_USERNAME>."
   )]
   pub(crate) bitcoin_rpc_username: Option<String>,
+  #[arg(
+    long,
+    help = "Redirect `/bounties` to <BOUNTIES_URL>. [default: https://docs.ordinals.com/bounty/]"
+  )]
+  pub(crate) bounties_url: Option<Url>,
   #[arg(long = "chain", value_enum, help = "Use <CHAIN>. [default: mainnet]")]
   pub(crate) chain_argument: Option<Chain>,
   #[arg(
@@ -28,6 +43,8 @@ pub struct Options {
     help = "Commit to index every <COMMIT_INTERVAL> blocks. [default: 5000]"
   )]
   pub(crate) commit_interval: Option<usize>,
+  #[arg(long, help = "Store sat ranges in compact delta-varint encoding.")]
+  pub(crate) compress_sat_ranges: bool,
   #[arg(long, help = "Load configuration from <CONFIG>.")]
   pub(crate) config: Option<PathBuf>,
   #[arg(long, help = "Load configuration from <CONFIG_DIR>.")]
@@ -36,6 +53,11 @@ pub struct Options {
   pub(crate) cookie_file: Option<PathBuf>,
   #[arg(long, alias = "datadir", help = "Store index in <DATA_DIR>.")]
   pub(crate) data_dir: Option<PathBuf>,
+  #[arg(
+    long,
+    help = "Redirect `/faq` to <FAQ_URL>. [default: https://docs.ordinals.com/faq/]"
+  )]
+  pub(crate) faq_url: Option<Url>,
   #[arg(
     long,
     help = "Don't look for inscriptions below <FIRST_INSCRIPTION_HEIGHT>."
@@ -43,6 +65,11 @@ pub struct Options {
   pub(crate) first_inscription_height: Option<u32>,
   #[arg(long, help = "Limit index to <HEIGHT_LIMIT> blocks.")]
   pub(crate) height_limit: Option<u32>,
+  #[arg(
+    long,
+    help = "Hide inscriptions whose content matches <HIDDEN_PATTERN> when serving. May be specified multiple times. Separate patterns in ORD_HIDDEN_PATTERNS with newlines."
+  )]
+  pub(crate) hidden_pattern: Vec<String>,
   #[arg(long, help = "Use index at <INDEX>.")]
   pub(crate) index: Option<PathBuf>,
   #[arg(
@@ -50,6 +77,11 @@ pub struct Options {
     help = "Set index cache size to <INDEX_CACHE_SIZE> bytes. [default: 1/4 available RAM]"
   )]
   pub(crate) index_cache_size: Option<usize>,
+  #[arg(
+    long,
+    help = "Index inscriptions by the CID of their content, to serve them at `/ipfs/<CID>`."
+  )]
+  pub(crate) index_cids: bool,
   #[arg(
     long,
     help = "Track location of runes. RUNES ARE IN AN UNFINISHED PRE-ALPHA STATE AND SUBJECT TO CHANGE AT ANY TIME."
@@ -57,12 +89,35 @@ pub struct Options {
   pub(crate) index_runes: bool,
   #[arg(long, help = "Track location of all satoshis.")]
   pub(crate) index_sats: bool,
+  #[arg(
+    long,
+    help = "Cache inscriptions on up to <INSCRIPTIONS_ON_OUTPUT_CACHE_SIZE> recently queried outputs. [default: 0]"
+  )]
+  pub(crate) inscriptions_on_output_cache_size: Option<usize>,
+  #[arg(
+    long,
+    help = "Redirect `/install.sh` to <INSTALL_SCRIPT_URL>. [default: https://raw.githubusercontent.com/ordinals/ord/master/install.sh]"
+  )]
+  pub(crate) install_script_url: Option<Url>,
   #[arg(long, help = "Keep sat index entries of spent outputs.")]
   pub(crate) index_spent_sats: bool,
-  #[arg(long, help = "Store transactions in index.")]
+  #[arg(
+    long,
+    help = "Store transactions that create inscriptions or carry runestones in index. Other transactions are fetched from bitcoind, which must not be pruned."
+  )]
   pub(crate) index_transactions: bool,
   #[arg(long, help = "Run in integration test mode.")]
   pub(crate) integration_test: bool,
+  #[arg(
+    long,
+    help = "Uncurse inscriptions from <JUBILEE_HEIGHT> instead of the chain's jubilee height. May not be used on mainnet."
+  )]
+  pub(crate) jubilee_height: Option<u32>,
+  #[arg(
+    long,
+    help = "Send at most <MAX_FETCH_REQUESTS> parallel Bitcoin Core RPC requests when fetching transactions during indexing. Keep this below Bitcoin Core's `rpcworkqueue`. [default: 12]"
+  )]
+  pub(crate) max_fetch_requests: Option<usize>,
   #[arg(long, help = "Minify JSON output.")]
   pub(crate) minify: bool,
   #[arg(
@@ -72,6 +127,17 @@ pub struct Options {
     help = "Do not index inscriptions."
   )]
   pub(crate) no_index_inscriptions: bool,
+  #[arg(
+    long,
+    short,
+    help = "Do not print progress messages or progress bars. Command output is still printed to stdout."
+  )]
+  pub(crate) quiet: bool,
+  #[arg(
+    long,
+    help = "POST the rolled back height range to <REORG_PURGE_WEBHOOK> after a reorg, so downstream caches can purge it."
+  )]
+  pub(crate) reorg_purge_webhook: Option<Url>,
   #[arg(
     long,
     help = "Require basic HTTP authentication with <SERVER_PASSWORD>. Credentials are sent in cleartext. Consider using authentication in conjunction with HTTPS."
@@ -86,6 +152,16 @@ pub struct Options {
   pub(crate) regtest: bool,
   #[arg(long, short, help = "Use signet. Equivalent to `--chain signet`.")]
   pub(crate) signet: bool,
+  #[arg(
+    long,
+    help = "Burn all runes in runestones with any flaw, including unrecognized odd tags and invalid field values. For consensus experimentation only."
+  )]
+  pub(crate) strict_runestone: bool,
   #[arg(long, short, help = "Use testnet. Equivalent to `--chain testnet`.")]
   pub(crate) testnet: bool,
+  #[arg(
+    long,
+    help = "Verify that sat ranges assigned to each new output sum to its value, halting the update on mismatch. Slows down sat indexing."
+  )]
+  pub(crate) verify_sat_ranges: bool,
 }
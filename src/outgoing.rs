@@ -21,8 +21,103 @@ impl Display for Outgoing {
   }
 }
 
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Form {
+  Amount,
+  InscriptionId,
+  Rune,
+  Sat,
+  SatPoint,
+}
+
+impl Display for Form {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Amount => write!(f, "an amount"),
+      Self::InscriptionId => write!(f, "an inscription ID"),
+      Self::Rune => write!(f, "a rune amount"),
+      Self::Sat => write!(f, "a sat"),
+      Self::SatPoint => write!(f, "a satpoint"),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+  Invalid {
+    input: String,
+    form: Form,
+    error: String,
+  },
+  Unrecognized {
+    input: String,
+    attempts: Vec<(Form, String)>,
+  },
+}
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Invalid { input, form, error } => {
+        write!(
+          f,
+          "invalid outgoing `{input}`: looks like {form} but {error}"
+        )
+      }
+      Self::Unrecognized { input, attempts } => {
+        write!(f, "unrecognized outgoing `{input}`")?;
+        for (i, (form, error)) in attempts.iter().enumerate() {
+          write!(f, "{}not {form}: {error}", if i == 0 { ": " } else { "; " })?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Outgoing {
+  fn parse_amount(s: &str) -> Result<Self, String> {
+    s.parse()
+      .map(Self::Amount)
+      .map_err(|err| format!("amount invalid: {err}"))
+  }
+
+  fn parse_inscription_id(s: &str) -> Result<Self, String> {
+    s.parse()
+      .map(Self::InscriptionId)
+      .map_err(|err| format!("inscription ID invalid: {err}"))
+  }
+
+  fn parse_rune(decimal: &str, rune: &str) -> Result<Self, String> {
+    Ok(Self::Rune {
+      decimal: decimal
+        .trim()
+        .parse()
+        .map_err(|err| format!("rune amount invalid: {err}"))?,
+      rune: rune
+        .trim()
+        .parse()
+        .map_err(|err| format!("rune name invalid: {err}"))?,
+    })
+  }
+
+  fn parse_sat(s: &str) -> Result<Self, String> {
+    s.parse()
+      .map(Self::Sat)
+      .map_err(|err| format!("sat name invalid: {err}"))
+  }
+
+  fn parse_satpoint(s: &str) -> Result<Self, String> {
+    s.parse()
+      .map(Self::SatPoint)
+      .map_err(|err| format!("satpoint invalid: {err}"))
+  }
+}
+
 impl FromStr for Outgoing {
-  type Err = Error;
+  type Err = ParseError;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     lazy_static! {
@@ -63,21 +158,55 @@ impl FromStr for Outgoing {
       .unwrap();
     }
 
-    Ok(if re::SAT_NAME.is_match(s) {
-      Self::Sat(s.parse()?)
+    let (form, result) = if re::SAT_NAME.is_match(s) {
+      (Form::Sat, Self::parse_sat(s))
     } else if re::SATPOINT.is_match(s) {
-      Self::SatPoint(s.parse()?)
+      (Form::SatPoint, Self::parse_satpoint(s))
     } else if re::INSCRIPTION_ID.is_match(s) {
-      Self::InscriptionId(s.parse()?)
+      (Form::InscriptionId, Self::parse_inscription_id(s))
     } else if AMOUNT.is_match(s) {
-      Self::Amount(s.parse()?)
+      (Form::Amount, Self::parse_amount(s))
     } else if let Some(captures) = RUNE.captures(s) {
-      Self::Rune {
-        decimal: captures[1].parse()?,
-        rune: captures[2].parse()?,
-      }
+      (Form::Rune, Self::parse_rune(&captures[1], &captures[2]))
     } else {
-      bail!("unrecognized outgoing: {s}");
+      let mut attempts = Vec::new();
+
+      if let Some((decimal, rune)) = s.split_once(':').filter(|(_, rune)| !rune.contains(':')) {
+        attempts.push((Form::Rune, Self::parse_rune(decimal, rune)));
+      }
+
+      for (form, parse) in [
+        (
+          Form::Amount,
+          Self::parse_amount as fn(&str) -> Result<Self, String>,
+        ),
+        (Form::InscriptionId, Self::parse_inscription_id),
+        (Form::SatPoint, Self::parse_satpoint),
+        (Form::Sat, Self::parse_sat),
+      ] {
+        attempts.push((form, parse(s)));
+      }
+
+      return Err(ParseError::Unrecognized {
+        input: s.into(),
+        attempts: attempts
+          .into_iter()
+          .map(|(form, result)| {
+            (
+              form,
+              result
+                .err()
+                .unwrap_or_else(|| "not in canonical form".into()),
+            )
+          })
+          .collect(),
+      });
+    };
+
+    result.map_err(|error| ParseError::Invalid {
+      input: s.into(),
+      form,
+      error,
     })
   }
 }
@@ -168,6 +297,52 @@ mod tests {
     );
   }
 
+  #[test]
+  fn from_str_errors() {
+    assert_eq!(
+      "1:XYZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        .parse::<Outgoing>()
+        .unwrap_err()
+        .to_string(),
+      "invalid outgoing `1:XYZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ`: \
+       looks like a rune amount but rune name invalid: name out of range",
+    );
+
+    let ParseError::Unrecognized { input, attempts } = "1:xyz".parse::<Outgoing>().unwrap_err()
+    else {
+      panic!("expected unrecognized outgoing");
+    };
+
+    assert_eq!(input, "1:xyz");
+
+    assert_eq!(
+      attempts
+        .iter()
+        .map(|(form, _)| *form)
+        .collect::<Vec<Form>>(),
+      [
+        Form::Rune,
+        Form::Amount,
+        Form::InscriptionId,
+        Form::SatPoint,
+        Form::Sat
+      ],
+    );
+
+    assert_eq!(attempts[0].1, "rune name invalid: invalid character `x`");
+
+    assert!("1000"
+      .parse::<Outgoing>()
+      .unwrap_err()
+      .to_string()
+      .starts_with("unrecognized outgoing `1000`: not an amount"));
+
+    assert!(matches!(
+      "1 XYZ".parse::<Outgoing>().unwrap_err(),
+      ParseError::Unrecognized { .. },
+    ));
+  }
+
   #[test]
   fn roundtrip() {
     #[track_caller]
@@ -9,6 +9,7 @@ lazy_static! {
   pub(crate) static ref INSCRIPTION_ID: Regex = re(r"[[:xdigit:]]{64}i\d+");
   pub(crate) static ref INSCRIPTION_NUMBER: Regex = re(r"-?[0-9]+");
   pub(crate) static ref OUTPOINT: Regex = re(r"[[:xdigit:]]{64}:\d+");
+  pub(crate) static ref RUNE_AMOUNT: Regex = re(r"(\d+|\.\d+|\d+\.\d+)(?:\s*:\s*|\s+)([A-Z•.]+)");
   pub(crate) static ref RUNE_ID: Regex = re(r"[0-9]+:[0-9]+");
   pub(crate) static ref RUNE_NUMBER: Regex = re(r"-?[0-9]+");
   pub(crate) static ref SATPOINT: Regex = re(r"[[:xdigit:]]{64}:\d+:\d+");
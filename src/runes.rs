@@ -6108,4 +6108,80 @@ mod tests {
         [],
       );
   }
+
+  #[test]
+  fn unrecognized_odd_tags_only_burn_runes_in_strict_mode() {
+    for strict in [false, true] {
+      let context = Context::builder()
+        .arg("--index-runes")
+        .args(strict.then_some("--strict-runestone"))
+        .build();
+
+      let (txid0, id) = context.etch(
+        Runestone {
+          edicts: vec![Edict {
+            id: RuneId::default(),
+            amount: u128::MAX,
+            output: 0,
+          }],
+          etching: Some(Etching {
+            rune: Some(Rune(RUNE)),
+            premine: Some(u128::MAX),
+            ..default()
+          }),
+          ..default()
+        },
+        1,
+      );
+
+      let mut payload = Vec::new();
+      for integer in [127, 1, 0, id.block.into(), id.tx.into(), u128::MAX, 0] {
+        varint::encode_to_vec(integer, &mut payload);
+      }
+
+      let payload: &script::PushBytes = payload.as_slice().try_into().unwrap();
+
+      let txid1 = context.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(id.block.try_into().unwrap(), 1, 0, Witness::new())],
+        op_return: Some(
+          script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(Runestone::MAGIC_NUMBER)
+            .push_slice(payload)
+            .into_script(),
+        ),
+        ..default()
+      });
+
+      context.mine_blocks(1);
+
+      let entry = RuneEntry {
+        block: id.block,
+        burned: if strict { u128::MAX } else { 0 },
+        etching: txid0,
+        spaced_rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        premine: u128::MAX,
+        timestamp: id.block,
+        ..default()
+      };
+
+      if strict {
+        context.assert_runes([(id, entry)], []);
+      } else {
+        context.assert_runes(
+          [(id, entry)],
+          [(
+            OutPoint {
+              txid: txid1,
+              vout: 0,
+            },
+            vec![(id, u128::MAX)],
+          )],
+        );
+      }
+    }
+  }
 }
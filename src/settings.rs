@@ -1,32 +1,61 @@
-use {super::*, bitcoincore_rpc::Auth};
-
+use {
+  super::*,
+  bitcoincore_rpc::{
+    jsonrpc::{self, simple_http::SimpleHttpTransport},
+    Auth,
+  },
+  serde_with::DisplayFromStr,
+};
+
+#[serde_with::serde_as]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Settings {
   bitcoin_data_dir: Option<PathBuf>,
   bitcoin_rpc_password: Option<String>,
+  bitcoin_rpc_retries: Option<u32>,
+  #[serde_as(as = "Option<DisplayFromStr>")]
+  bitcoin_rpc_timeout: Option<humantime::Duration>,
   bitcoin_rpc_url: Option<String>,
   bitcoin_rpc_username: Option<String>,
+  #[serde_as(as = "Option<DisplayFromStr>")]
+  bounties_url: Option<Url>,
   chain: Option<Chain>,
   commit_interval: Option<usize>,
+  compress_sat_ranges: bool,
   config: Option<PathBuf>,
   config_dir: Option<PathBuf>,
   cookie_file: Option<PathBuf>,
   data_dir: Option<PathBuf>,
+  #[serde_as(as = "Option<DisplayFromStr>")]
+  faq_url: Option<Url>,
   first_inscription_height: Option<u32>,
   height_limit: Option<u32>,
   hidden: Option<HashSet<InscriptionId>>,
+  hidden_patterns: Option<Vec<String>>,
   index: Option<PathBuf>,
   index_cache_size: Option<usize>,
+  index_cids: bool,
   index_runes: bool,
   index_sats: bool,
   index_spent_sats: bool,
   index_transactions: bool,
+  inscriptions_on_output_cache_size: Option<usize>,
+  #[serde_as(as = "Option<DisplayFromStr>")]
+  install_script_url: Option<Url>,
   integration_test: bool,
+  jubilee_height: Option<u32>,
+  max_fetch_requests: Option<usize>,
   no_index_inscriptions: bool,
+  quiet: bool,
+  #[serde_as(as = "Option<DisplayFromStr>")]
+  reorg_purge_webhook: Option<Url>,
+  serve_content_type: Option<BTreeMap<InscriptionId, String>>,
   server_password: Option<String>,
   server_url: Option<String>,
   server_username: Option<String>,
+  strict_runestone: bool,
+  verify_sat_ranges: bool,
 }
 
 impl Settings {
@@ -109,14 +138,19 @@ impl Settings {
     Self {
       bitcoin_data_dir: self.bitcoin_data_dir.or(source.bitcoin_data_dir),
       bitcoin_rpc_password: self.bitcoin_rpc_password.or(source.bitcoin_rpc_password),
+      bitcoin_rpc_retries: self.bitcoin_rpc_retries.or(source.bitcoin_rpc_retries),
+      bitcoin_rpc_timeout: self.bitcoin_rpc_timeout.or(source.bitcoin_rpc_timeout),
       bitcoin_rpc_url: self.bitcoin_rpc_url.or(source.bitcoin_rpc_url),
       bitcoin_rpc_username: self.bitcoin_rpc_username.or(source.bitcoin_rpc_username),
+      bounties_url: self.bounties_url.or(source.bounties_url),
       chain: self.chain.or(source.chain),
       commit_interval: self.commit_interval.or(source.commit_interval),
+      compress_sat_ranges: self.compress_sat_ranges || source.compress_sat_ranges,
       config: self.config.or(source.config),
       config_dir: self.config_dir.or(source.config_dir),
       cookie_file: self.cookie_file.or(source.cookie_file),
       data_dir: self.data_dir.or(source.data_dir),
+      faq_url: self.faq_url.or(source.faq_url),
       first_inscription_height: self
         .first_inscription_height
         .or(source.first_inscription_height),
@@ -130,17 +164,38 @@ impl Settings {
           .cloned()
           .collect(),
       ),
+      hidden_patterns: Some(
+        self
+          .hidden_patterns
+          .iter()
+          .flatten()
+          .chain(source.hidden_patterns.iter().flatten())
+          .cloned()
+          .collect(),
+      ),
       index: self.index.or(source.index),
       index_cache_size: self.index_cache_size.or(source.index_cache_size),
+      index_cids: self.index_cids || source.index_cids,
       index_runes: self.index_runes || source.index_runes,
       index_sats: self.index_sats || source.index_sats,
       index_spent_sats: self.index_spent_sats || source.index_spent_sats,
       index_transactions: self.index_transactions || source.index_transactions,
+      inscriptions_on_output_cache_size: self
+        .inscriptions_on_output_cache_size
+        .or(source.inscriptions_on_output_cache_size),
+      install_script_url: self.install_script_url.or(source.install_script_url),
       integration_test: self.integration_test || source.integration_test,
+      jubilee_height: self.jubilee_height.or(source.jubilee_height),
+      max_fetch_requests: self.max_fetch_requests.or(source.max_fetch_requests),
       no_index_inscriptions: self.no_index_inscriptions || source.no_index_inscriptions,
+      quiet: self.quiet || source.quiet,
+      reorg_purge_webhook: self.reorg_purge_webhook.or(source.reorg_purge_webhook),
+      serve_content_type: self.serve_content_type.or(source.serve_content_type),
       server_password: self.server_password.or(source.server_password),
       server_url: self.server_url.or(source.server_url),
       server_username: self.server_username.or(source.server_username),
+      strict_runestone: self.strict_runestone || source.strict_runestone,
+      verify_sat_ranges: self.verify_sat_ranges || source.verify_sat_ranges,
     }
   }
 
@@ -148,8 +203,11 @@ impl Settings {
     Self {
       bitcoin_data_dir: options.bitcoin_data_dir,
       bitcoin_rpc_password: options.bitcoin_rpc_password,
+      bitcoin_rpc_retries: options.bitcoin_rpc_retries,
+      bitcoin_rpc_timeout: options.bitcoin_rpc_timeout,
       bitcoin_rpc_url: options.bitcoin_rpc_url,
       bitcoin_rpc_username: options.bitcoin_rpc_username,
+      bounties_url: options.bounties_url,
       chain: options
         .signet
         .then_some(Chain::Signet)
@@ -157,24 +215,37 @@ impl Settings {
         .or(options.testnet.then_some(Chain::Testnet))
         .or(options.chain_argument),
       commit_interval: options.commit_interval,
+      compress_sat_ranges: options.compress_sat_ranges,
       config: options.config,
       config_dir: options.config_dir,
       cookie_file: options.cookie_file,
       data_dir: options.data_dir,
+      faq_url: options.faq_url,
       first_inscription_height: options.first_inscription_height,
       height_limit: options.height_limit,
       hidden: None,
+      hidden_patterns: (!options.hidden_pattern.is_empty()).then_some(options.hidden_pattern),
       index: options.index,
       index_cache_size: options.index_cache_size,
+      index_cids: options.index_cids,
       index_runes: options.index_runes,
       index_sats: options.index_sats,
       index_spent_sats: options.index_spent_sats,
       index_transactions: options.index_transactions,
+      inscriptions_on_output_cache_size: options.inscriptions_on_output_cache_size,
+      install_script_url: options.install_script_url,
       integration_test: options.integration_test,
+      jubilee_height: options.jubilee_height,
+      max_fetch_requests: options.max_fetch_requests,
       no_index_inscriptions: options.no_index_inscriptions,
+      quiet: options.quiet,
+      reorg_purge_webhook: options.reorg_purge_webhook,
+      serve_content_type: None,
       server_password: options.server_password,
       server_url: None,
       server_username: options.server_username,
+      strict_runestone: options.strict_runestone,
+      verify_sat_ranges: options.verify_sat_ranges,
     }
   }
 
@@ -213,6 +284,22 @@ impl Settings {
         })
     };
 
+    let get_duration = |key| {
+      env
+        .get(key)
+        .map(|duration| duration.parse::<humantime::Duration>())
+        .transpose()
+        .with_context(|| format!("failed to parse environment variable ORD_{key} as duration"))
+    };
+
+    let get_url = |key| {
+      env
+        .get(key)
+        .map(|url| url.parse::<Url>())
+        .transpose()
+        .with_context(|| format!("failed to parse environment variable ORD_{key} as URL"))
+    };
+
     let get_u32 = |key| {
       env
         .get(key)
@@ -231,28 +318,46 @@ impl Settings {
     Ok(Self {
       bitcoin_data_dir: get_path("BITCOIN_DATA_DIR"),
       bitcoin_rpc_password: get_string("BITCOIN_RPC_PASSWORD"),
+      bitcoin_rpc_retries: get_u32("BITCOIN_RPC_RETRIES")?,
+      bitcoin_rpc_timeout: get_duration("BITCOIN_RPC_TIMEOUT")?,
       bitcoin_rpc_url: get_string("BITCOIN_RPC_URL"),
       bitcoin_rpc_username: get_string("BITCOIN_RPC_USERNAME"),
+      bounties_url: get_url("BOUNTIES_URL")?,
       chain: get_chain("CHAIN")?,
       commit_interval: get_usize("COMMIT_INTERVAL")?,
+      compress_sat_ranges: get_bool("COMPRESS_SAT_RANGES"),
       config: get_path("CONFIG"),
       config_dir: get_path("CONFIG_DIR"),
       cookie_file: get_path("COOKIE_FILE"),
       data_dir: get_path("DATA_DIR"),
+      faq_url: get_url("FAQ_URL")?,
       first_inscription_height: get_u32("FIRST_INSCRIPTION_HEIGHT")?,
       height_limit: get_u32("HEIGHT_LIMIT")?,
       hidden: inscriptions("HIDDEN")?,
+      hidden_patterns: env
+        .get("HIDDEN_PATTERNS")
+        .map(|patterns| patterns.lines().map(str::to_string).collect()),
       index: get_path("INDEX"),
       index_cache_size: get_usize("INDEX_CACHE_SIZE")?,
+      index_cids: get_bool("INDEX_CIDS"),
       index_runes: get_bool("INDEX_RUNES"),
       index_sats: get_bool("INDEX_SATS"),
       index_spent_sats: get_bool("INDEX_SPENT_SATS"),
       index_transactions: get_bool("INDEX_TRANSACTIONS"),
+      inscriptions_on_output_cache_size: get_usize("INSCRIPTIONS_ON_OUTPUT_CACHE_SIZE")?,
+      install_script_url: get_url("INSTALL_SCRIPT_URL")?,
       integration_test: get_bool("INTEGRATION_TEST"),
+      jubilee_height: get_u32("JUBILEE_HEIGHT")?,
+      max_fetch_requests: get_usize("MAX_FETCH_REQUESTS")?,
       no_index_inscriptions: get_bool("NO_INDEX_INSCRIPTIONS"),
+      quiet: get_bool("QUIET"),
+      reorg_purge_webhook: get_url("REORG_PURGE_WEBHOOK")?,
+      serve_content_type: None,
       server_password: get_string("SERVER_PASSWORD"),
       server_url: get_string("SERVER_URL"),
       server_username: get_string("SERVER_USERNAME"),
+      strict_runestone: get_bool("STRICT_RUNESTONE"),
+      verify_sat_ranges: get_bool("VERIFY_SAT_RANGES"),
     })
   }
 
@@ -260,28 +365,44 @@ impl Settings {
     Self {
       bitcoin_data_dir: Some(dir.into()),
       bitcoin_rpc_password: None,
+      bitcoin_rpc_retries: None,
+      bitcoin_rpc_timeout: None,
       bitcoin_rpc_url: Some(rpc_url.into()),
       bitcoin_rpc_username: None,
+      bounties_url: None,
       chain: Some(Chain::Regtest),
       commit_interval: None,
+      compress_sat_ranges: false,
       config: None,
       config_dir: None,
       cookie_file: None,
       data_dir: Some(dir.into()),
+      faq_url: None,
       first_inscription_height: None,
       height_limit: None,
       hidden: None,
+      hidden_patterns: None,
       index: None,
       index_cache_size: None,
+      index_cids: false,
       index_runes: true,
       index_sats: true,
       index_spent_sats: false,
       index_transactions: false,
+      inscriptions_on_output_cache_size: None,
+      install_script_url: None,
       integration_test: false,
+      jubilee_height: None,
+      max_fetch_requests: None,
       no_index_inscriptions: false,
+      quiet: false,
+      reorg_purge_webhook: None,
+      serve_content_type: None,
       server_password: None,
       server_url: Some(server_url.into()),
       server_username: None,
+      strict_runestone: false,
+      verify_sat_ranges: false,
     }
   }
 
@@ -318,9 +439,25 @@ impl Settings {
       None => data_dir.join("index.redb"),
     };
 
+    ensure!(
+      self.jubilee_height.is_none() || chain != Chain::Mainnet,
+      "jubilee height may not be overridden on mainnet"
+    );
+
+    ensure!(
+      self.max_fetch_requests != Some(0),
+      "max fetch requests must be greater than zero"
+    );
+
     Ok(Self {
       bitcoin_data_dir: Some(bitcoin_data_dir),
       bitcoin_rpc_password: self.bitcoin_rpc_password,
+      bitcoin_rpc_retries: Some(self.bitcoin_rpc_retries.unwrap_or(6)),
+      bitcoin_rpc_timeout: Some(
+        self
+          .bitcoin_rpc_timeout
+          .unwrap_or_else(|| Duration::from_secs(15).into()),
+      ),
       bitcoin_rpc_url: Some(
         self
           .bitcoin_rpc_url
@@ -328,12 +465,15 @@ impl Settings {
           .unwrap_or_else(|| format!("127.0.0.1:{}", chain.default_rpc_port())),
       ),
       bitcoin_rpc_username: self.bitcoin_rpc_username,
+      bounties_url: self.bounties_url,
       chain: Some(chain),
       commit_interval: Some(self.commit_interval.unwrap_or(5000)),
+      compress_sat_ranges: self.compress_sat_ranges,
       config: None,
       config_dir: None,
       cookie_file: Some(cookie_file),
       data_dir: Some(data_dir),
+      faq_url: self.faq_url,
       first_inscription_height: Some(if self.integration_test {
         0
       } else {
@@ -343,6 +483,7 @@ impl Settings {
       }),
       height_limit: self.height_limit,
       hidden: self.hidden,
+      hidden_patterns: self.hidden_patterns,
       index: Some(index),
       index_cache_size: Some(match self.index_cache_size {
         Some(index_cache_size) => index_cache_size,
@@ -352,18 +493,40 @@ impl Settings {
           usize::try_from(sys.total_memory() / 4)?
         }
       }),
+      index_cids: self.index_cids,
       index_runes: self.index_runes,
       index_sats: self.index_sats,
       index_spent_sats: self.index_spent_sats,
       index_transactions: self.index_transactions,
+      inscriptions_on_output_cache_size: self.inscriptions_on_output_cache_size,
+      install_script_url: self.install_script_url,
       integration_test: self.integration_test,
+      jubilee_height: Some(
+        self
+          .jubilee_height
+          .unwrap_or_else(|| chain.jubilee_height()),
+      ),
+      max_fetch_requests: Some(self.max_fetch_requests.unwrap_or(12)),
       no_index_inscriptions: self.no_index_inscriptions,
+      quiet: self.quiet,
+      reorg_purge_webhook: self.reorg_purge_webhook,
+      serve_content_type: self.serve_content_type,
       server_password: self.server_password,
       server_url: self.server_url,
       server_username: self.server_username,
+      strict_runestone: self.strict_runestone,
+      verify_sat_ranges: self.verify_sat_ranges,
     })
   }
 
+  pub(crate) fn redact(self) -> Self {
+    Self {
+      bitcoin_rpc_password: self.bitcoin_rpc_password.map(|_| "<redacted>".into()),
+      server_password: self.server_password.map(|_| "<redacted>".into()),
+      ..self
+    }
+  }
+
   pub(crate) fn default_data_dir() -> Result<PathBuf> {
     Ok(
       dirs::data_dir()
@@ -407,21 +570,23 @@ impl Settings {
       );
     }
 
-    let client = Client::new(&rpc_url, bitcoin_credentials)
-      .with_context(|| format!("failed to connect to Bitcoin Core RPC at `{rpc_url}`"))?;
+    let (user, pass) = bitcoin_credentials.get_user_pass()?;
+
+    let mut transport = SimpleHttpTransport::builder()
+      .url(&rpc_url)
+      .with_context(|| format!("failed to connect to Bitcoin Core RPC at `{rpc_url}`"))?
+      .timeout(self.bitcoin_rpc_timeout.unwrap().into());
+
+    if let Some(user) = user {
+      transport = transport.auth(user, pass);
+    }
+
+    let client = Client::from_jsonrpc(jsonrpc::Client::with_transport(transport.build()));
 
     let mut checks = 0;
     let rpc_chain = loop {
       match client.get_blockchain_info() {
-        Ok(blockchain_info) => {
-          break match blockchain_info.chain.as_str() {
-            "main" => Chain::Mainnet,
-            "test" => Chain::Testnet,
-            "regtest" => Chain::Regtest,
-            "signet" => Chain::Signet,
-            other => bail!("Bitcoin RPC server on unknown chain: {other}"),
-          }
-        }
+        Ok(blockchain_info) => break Chain::from_core_chain_str(&blockchain_info.chain)?,
         Err(bitcoincore_rpc::Error::JsonRpc(bitcoincore_rpc::jsonrpc::Error::Rpc(err)))
           if err.code == -28 => {}
         Err(err) => bail!("Failed to connect to Bitcoin Core RPC at `{rpc_url}`:  {err}"),
@@ -445,6 +610,13 @@ impl Settings {
     Ok(client)
   }
 
+  pub(crate) fn bounties_url(&self) -> Url {
+    self
+      .bounties_url
+      .clone()
+      .unwrap_or_else(|| "https://docs.ordinals.com/bounty/".parse().unwrap())
+  }
+
   pub(crate) fn chain(&self) -> Chain {
     self.chain.unwrap()
   }
@@ -453,6 +625,10 @@ impl Settings {
     self.commit_interval.unwrap()
   }
 
+  pub(crate) fn compress_sat_ranges(&self) -> bool {
+    self.compress_sat_ranges
+  }
+
   pub(crate) fn cookie_file(&self) -> Result<PathBuf> {
     if let Some(cookie_file) = &self.cookie_file {
       return Ok(cookie_file.clone());
@@ -486,6 +662,13 @@ impl Settings {
     self.data_dir.as_ref().unwrap().into()
   }
 
+  pub(crate) fn faq_url(&self) -> Url {
+    self
+      .faq_url
+      .clone()
+      .unwrap_or_else(|| "https://docs.ordinals.com/faq/".parse().unwrap())
+  }
+
   pub(crate) fn first_inscription_height(&self) -> u32 {
     self.first_inscription_height.unwrap()
   }
@@ -518,6 +701,10 @@ impl Settings {
     self.index_cache_size.unwrap()
   }
 
+  pub(crate) fn index_cids(&self) -> bool {
+    self.index_cids
+  }
+
   pub(crate) fn index_sats(&self) -> bool {
     self.index_sats
   }
@@ -530,10 +717,50 @@ impl Settings {
     self.index_transactions
   }
 
+  pub(crate) fn inscriptions_on_output_cache_size(&self) -> usize {
+    self.inscriptions_on_output_cache_size.unwrap_or_default()
+  }
+
+  pub(crate) fn install_script_url(&self) -> Url {
+    self.install_script_url.clone().unwrap_or_else(|| {
+      "https://raw.githubusercontent.com/ordinals/ord/master/install.sh"
+        .parse()
+        .unwrap()
+    })
+  }
+
   pub(crate) fn integration_test(&self) -> bool {
     self.integration_test
   }
 
+  pub(crate) fn jubilee_height(&self) -> u32 {
+    self.jubilee_height.unwrap()
+  }
+
+  pub(crate) fn quiet(&self) -> bool {
+    self.quiet
+  }
+
+  pub(crate) fn reorg_purge_webhook(&self) -> Option<&Url> {
+    self.reorg_purge_webhook.as_ref()
+  }
+
+  pub(crate) fn serve_content_type(&self) -> BTreeMap<InscriptionId, String> {
+    self.serve_content_type.clone().unwrap_or_default()
+  }
+
+  pub(crate) fn strict_runestone(&self) -> bool {
+    self.strict_runestone
+  }
+
+  pub(crate) fn verify_sat_ranges(&self) -> bool {
+    self.verify_sat_ranges
+  }
+
+  pub(crate) fn hidden_patterns(&self) -> &[String] {
+    self.hidden_patterns.as_deref().unwrap_or_default()
+  }
+
   pub(crate) fn is_hidden(&self, inscription_id: InscriptionId) -> bool {
     self
       .hidden
@@ -542,6 +769,14 @@ impl Settings {
       .unwrap_or_default()
   }
 
+  pub(crate) fn bitcoin_rpc_retries(&self) -> u32 {
+    self.bitcoin_rpc_retries.unwrap()
+  }
+
+  pub(crate) fn max_fetch_requests(&self) -> usize {
+    self.max_fetch_requests.unwrap()
+  }
+
   pub(crate) fn bitcoin_rpc_url(&self, wallet_name: Option<String>) -> String {
     let base_url = self.bitcoin_rpc_url.as_ref().unwrap();
     match wallet_name {
@@ -885,6 +1120,103 @@ mod tests {
     assert_eq!(arguments.options.commit_interval, Some(500));
   }
 
+  #[test]
+  fn jubilee_height() {
+    assert_eq!(parse(&[]).jubilee_height(), 824544);
+    assert_eq!(parse(&["--regtest"]).jubilee_height(), 110);
+    assert_eq!(
+      parse(&["--regtest", "--jubilee-height=5"]).jubilee_height(),
+      5
+    );
+
+    assert_eq!(
+      Settings::from_options(Options::try_parse_from(["ord", "--jubilee-height=5"]).unwrap())
+        .or_defaults()
+        .unwrap_err()
+        .to_string(),
+      "jubilee height may not be overridden on mainnet"
+    );
+  }
+
+  #[test]
+  fn max_fetch_requests() {
+    assert_eq!(parse(&[]).max_fetch_requests(), 12);
+    assert_eq!(parse(&["--max-fetch-requests=4"]).max_fetch_requests(), 4);
+
+    assert_eq!(
+      Settings::from_options(Options::try_parse_from(["ord", "--max-fetch-requests=0"]).unwrap())
+        .or_defaults()
+        .unwrap_err()
+        .to_string(),
+      "max fetch requests must be greater than zero"
+    );
+  }
+
+  #[test]
+  fn hidden_patterns_env_var_is_newline_separated() {
+    assert_eq!(
+      Settings::from_env(
+        [("HIDDEN_PATTERNS".into(), "^foo bar$\n^a{2,5}$".into())]
+          .into_iter()
+          .collect()
+      )
+      .unwrap()
+      .hidden_patterns,
+      Some(vec!["^foo bar$".into(), "^a{2,5}$".into()]),
+    );
+  }
+
+  #[test]
+  fn redirect_urls_must_be_valid() {
+    assert_eq!(
+      parse(&["--faq-url=https://faq.example/"])
+        .faq_url()
+        .as_str(),
+      "https://faq.example/"
+    );
+
+    Options::try_parse_from(["ord", "--faq-url=foo"]).unwrap_err();
+
+    assert_eq!(
+      Settings::from_env(
+        [("BOUNTIES_URL".into(), "foo".into())]
+          .into_iter()
+          .collect()
+      )
+      .unwrap_err()
+      .to_string(),
+      "failed to parse environment variable ORD_BOUNTIES_URL as URL"
+    );
+
+    serde_yaml::from_str::<Settings>("install_script_url: foo").unwrap_err();
+  }
+
+  #[test]
+  fn reorg_purge_webhook_must_be_valid() {
+    assert_eq!(
+      parse(&["--reorg-purge-webhook=https://purge.example/"])
+        .reorg_purge_webhook()
+        .unwrap()
+        .as_str(),
+      "https://purge.example/"
+    );
+
+    Options::try_parse_from(["ord", "--reorg-purge-webhook=foo"]).unwrap_err();
+
+    assert_eq!(
+      Settings::from_env(
+        [("REORG_PURGE_WEBHOOK".into(), "foo".into())]
+          .into_iter()
+          .collect()
+      )
+      .unwrap_err()
+      .to_string(),
+      "failed to parse environment variable ORD_REORG_PURGE_WEBHOOK as URL"
+    );
+
+    serde_yaml::from_str::<Settings>("reorg_purge_webhook: foo").unwrap_err();
+  }
+
   #[test]
   fn index_runes() {
     assert!(parse(&["--chain=signet", "--index-runes"]).index_runes());
@@ -979,28 +1311,43 @@ mod tests {
     let env = vec![
       ("BITCOIN_DATA_DIR", "/bitcoin/data/dir"),
       ("BITCOIN_RPC_PASSWORD", "bitcoin password"),
+      ("BITCOIN_RPC_RETRIES", "7"),
+      ("BITCOIN_RPC_TIMEOUT", "30s"),
       ("BITCOIN_RPC_URL", "url"),
       ("BITCOIN_RPC_USERNAME", "bitcoin username"),
+      ("BOUNTIES_URL", "https://bounties.example/"),
       ("CHAIN", "signet"),
       ("COMMIT_INTERVAL", "1"),
+      ("COMPRESS_SAT_RANGES", "1"),
       ("CONFIG", "config"),
       ("CONFIG_DIR", "config dir"),
       ("COOKIE_FILE", "cookie file"),
       ("DATA_DIR", "/data/dir"),
+      ("FAQ_URL", "https://faq.example/"),
       ("FIRST_INSCRIPTION_HEIGHT", "2"),
       ("HEIGHT_LIMIT", "3"),
       ("HIDDEN", "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0 703e5f7c49d82aab99e605af306b9a30e991e57d42f982908a962a81ac439832i0"),
+      ("HIDDEN_PATTERNS", "^foo\nbar$"),
       ("INDEX", "index"),
       ("INDEX_CACHE_SIZE", "4"),
+      ("INDEX_CIDS", "1"),
       ("INDEX_RUNES", "1"),
       ("INDEX_SATS", "1"),
       ("INDEX_SPENT_SATS", "1"),
       ("INDEX_TRANSACTIONS", "1"),
+      ("INSCRIPTIONS_ON_OUTPUT_CACHE_SIZE", "5"),
+      ("INSTALL_SCRIPT_URL", "https://install.example/install.sh"),
       ("INTEGRATION_TEST", "1"),
+      ("JUBILEE_HEIGHT", "6"),
+      ("MAX_FETCH_REQUESTS", "7"),
       ("NO_INDEX_INSCRIPTIONS", "1"),
+      ("QUIET", "1"),
+      ("REORG_PURGE_WEBHOOK", "https://purge.example/"),
       ("SERVER_PASSWORD", "server password"),
       ("SERVER_URL", "server url"),
       ("SERVER_USERNAME", "server username"),
+      ("STRICT_RUNESTONE", "1"),
+      ("VERIFY_SAT_RANGES", "1"),
     ]
     .into_iter()
     .map(|(key, value)| (key.into(), value.into()))
@@ -1011,14 +1358,19 @@ mod tests {
       Settings {
         bitcoin_data_dir: Some("/bitcoin/data/dir".into()),
         bitcoin_rpc_password: Some("bitcoin password".into()),
+        bitcoin_rpc_retries: Some(7),
+        bitcoin_rpc_timeout: Some("30s".parse().unwrap()),
         bitcoin_rpc_url: Some("url".into()),
         bitcoin_rpc_username: Some("bitcoin username".into()),
+        bounties_url: Some("https://bounties.example/".parse().unwrap()),
         chain: Some(Chain::Signet),
         commit_interval: Some(1),
+        compress_sat_ranges: true,
         config: Some("config".into()),
         config_dir: Some("config dir".into()),
         cookie_file: Some("cookie file".into()),
         data_dir: Some("/data/dir".into()),
+        faq_url: Some("https://faq.example/".parse().unwrap()),
         first_inscription_height: Some(2),
         height_limit: Some(3),
         hidden: Some(
@@ -1033,17 +1385,28 @@ mod tests {
           .into_iter()
           .collect()
         ),
+        hidden_patterns: Some(vec!["^foo".into(), "bar$".into()]),
         index: Some("index".into()),
         index_cache_size: Some(4),
+        index_cids: true,
         index_runes: true,
         index_sats: true,
         index_spent_sats: true,
         index_transactions: true,
+        inscriptions_on_output_cache_size: Some(5),
+        install_script_url: Some("https://install.example/install.sh".parse().unwrap()),
         integration_test: true,
+        jubilee_height: Some(6),
+        max_fetch_requests: Some(7),
         no_index_inscriptions: true,
+        quiet: true,
+        reorg_purge_webhook: Some("https://purge.example/".parse().unwrap()),
+        serve_content_type: None,
         server_password: Some("server password".into()),
         server_url: Some("server url".into()),
         server_username: Some("server username".into()),
+        strict_runestone: true,
+        verify_sat_ranges: true,
       }
     );
   }
@@ -1056,54 +1419,86 @@ mod tests {
           "ord",
           "--bitcoin-data-dir=/bitcoin/data/dir",
           "--bitcoin-rpc-password=bitcoin password",
+          "--bitcoin-rpc-retries=7",
+          "--bitcoin-rpc-timeout=30s",
           "--bitcoin-rpc-url=url",
           "--bitcoin-rpc-username=bitcoin username",
+          "--bounties-url=https://bounties.example/",
           "--chain=signet",
           "--commit-interval=1",
+          "--compress-sat-ranges",
           "--config=config",
           "--config-dir=config dir",
           "--cookie-file=cookie file",
           "--datadir=/data/dir",
+          "--faq-url=https://faq.example/",
           "--first-inscription-height=2",
           "--height-limit=3",
+          "--hidden-pattern=^foo",
+          "--hidden-pattern=bar$",
           "--index-cache-size=4",
+          "--index-cids",
           "--index-runes",
           "--index-sats",
           "--index-spent-sats",
           "--index-transactions",
+          "--inscriptions-on-output-cache-size=5",
+          "--install-script-url=https://install.example/install.sh",
           "--index=index",
           "--integration-test",
+          "--jubilee-height=6",
+          "--max-fetch-requests=7",
           "--no-index-inscriptions",
+          "--quiet",
+          "--reorg-purge-webhook=https://purge.example/",
           "--server-password=server password",
           "--server-username=server username",
+          "--strict-runestone",
+          "--verify-sat-ranges",
         ])
         .unwrap()
       ),
       Settings {
         bitcoin_data_dir: Some("/bitcoin/data/dir".into()),
         bitcoin_rpc_password: Some("bitcoin password".into()),
+        bitcoin_rpc_retries: Some(7),
+        bitcoin_rpc_timeout: Some("30s".parse().unwrap()),
         bitcoin_rpc_url: Some("url".into()),
         bitcoin_rpc_username: Some("bitcoin username".into()),
+        bounties_url: Some("https://bounties.example/".parse().unwrap()),
         chain: Some(Chain::Signet),
         commit_interval: Some(1),
+        compress_sat_ranges: true,
         config: Some("config".into()),
         config_dir: Some("config dir".into()),
         cookie_file: Some("cookie file".into()),
         data_dir: Some("/data/dir".into()),
+        faq_url: Some("https://faq.example/".parse().unwrap()),
         first_inscription_height: Some(2),
         height_limit: Some(3),
         hidden: None,
+        hidden_patterns: Some(vec!["^foo".into(), "bar$".into()]),
         index: Some("index".into()),
         index_cache_size: Some(4),
+        index_cids: true,
         index_runes: true,
         index_sats: true,
         index_spent_sats: true,
         index_transactions: true,
+        inscriptions_on_output_cache_size: Some(5),
+        install_script_url: Some("https://install.example/install.sh".parse().unwrap()),
         integration_test: true,
+        jubilee_height: Some(6),
+        max_fetch_requests: Some(7),
         no_index_inscriptions: true,
+        quiet: true,
+        reorg_purge_webhook: Some("https://purge.example/".parse().unwrap()),
+        serve_content_type: None,
         server_password: Some("server password".into()),
         server_url: None,
         server_username: Some("server username".into()),
+        strict_runestone: true,
+        verify_sat_ranges: true,
       }
     );
   }
@@ -6,6 +6,7 @@ pub mod env;
 pub mod epochs;
 pub mod find;
 pub mod index;
+pub mod inscription;
 pub mod list;
 pub mod parse;
 pub mod runes;
@@ -31,12 +32,14 @@ pub(crate) enum Subcommand {
   Find(find::Find),
   #[command(subcommand, about = "Index commands")]
   Index(index::IndexSubcommand),
+  #[command(subcommand, about = "Inscription commands")]
+  Inscription(inscription::InscriptionSubcommand),
   #[command(about = "List the satoshis in an output")]
   List(list::List),
   #[command(about = "Parse a satoshi from ordinal notation")]
   Parse(parse::Parse),
-  #[command(about = "List all runes")]
-  Runes,
+  #[command(about = "List all runes, or rune balances of an output")]
+  Runes(runes::Runes),
   #[command(about = "Run the explorer server")]
   Server(server::Server),
   #[command(about = "Display settings")]
@@ -47,7 +50,7 @@ pub(crate) enum Subcommand {
   Supply,
   #[command(about = "Generate teleburn addresses")]
   Teleburn(teleburn::Teleburn),
-  #[command(about = "Display satoshi traits")]
+  #[command(about = "Display satoshi traits", visible_alias = "sat")]
   Traits(traits::Traits),
   #[command(about = "Wallet commands")]
   Wallet(wallet::WalletCommand),
@@ -62,9 +65,10 @@ impl Subcommand {
       Self::Epochs => epochs::run(),
       Self::Find(find) => find.run(settings),
       Self::Index(index) => index.run(settings),
+      Self::Inscription(inscription) => inscription.run(settings),
       Self::List(list) => list.run(settings),
       Self::Parse(parse) => parse.run(),
-      Self::Runes => runes::run(settings),
+      Self::Runes(runes) => runes.run(settings),
       Self::Server(server) => {
         let index = Arc::new(Index::open(&settings)?);
         let handle = axum_server::Handle::new();
@@ -1,11 +1,20 @@
-use super::*;
+use {super::*, crate::subcommand::wallet::sats::Sats};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Find {
-  #[arg(help = "Find output and offset of <SAT>.")]
-  sat: Sat,
+  #[arg(
+    required_unless_present = "tsv",
+    help = "Find output and offset of <SAT>."
+  )]
+  sat: Option<Sat>,
   #[clap(help = "Find output and offset of all sats in the range [<SAT>, <END>).")]
   end: Option<Sat>,
+  #[arg(
+    long,
+    conflicts_with_all = ["sat", "end"],
+    help = "Find output and offset of satoshis listed in first column of tab-separated value file <TSV>."
+  )]
+  tsv: Option<PathBuf>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -30,15 +39,38 @@ impl Find {
 
     index.update()?;
 
+    if let Some(path) = &self.tsv {
+      let tsv = fs::read_to_string(path)
+        .with_context(|| format!("I/O error reading `{}`", path.display()))?;
+
+      let needles = Sats::needles(&tsv)?;
+
+      let found = index.find_many(
+        &needles
+          .iter()
+          .map(|(sat, _value)| *sat)
+          .collect::<Vec<Sat>>(),
+      )?;
+
+      return Ok(Some(Box::new(
+        needles
+          .into_iter()
+          .map(|(sat, value)| (value.to_string(), found.get(&sat).copied()))
+          .collect::<BTreeMap<String, Option<SatPoint>>>(),
+      )));
+    }
+
+    let sat = self.sat.unwrap();
+
     match self.end {
-      Some(end) => match index.find_range(self.sat, end)? {
+      Some(end) => match index.find_range(sat, end)? {
         Some(mut results) => {
           results.sort_by_key(|find_range_output| find_range_output.start);
           Ok(Some(Box::new(results)))
         }
         None => Err(anyhow!("range has not been mined as of index height")),
       },
-      None => match index.find(self.sat)? {
+      None => match index.find(sat)? {
         Some(satpoint) => Ok(Some(Box::new(Output { satpoint }))),
         None => Err(anyhow!("sat has not been mined as of index height")),
       },
@@ -1,13 +1,19 @@
 use super::*;
 
+pub mod check;
 mod export;
+mod export_runes;
 pub mod info;
 mod update;
 
 #[derive(Debug, Parser)]
 pub(crate) enum IndexSubcommand {
+  #[command(about = "Check index table invariants without updating the index")]
+  Check(check::Check),
   #[command(about = "Write inscription numbers and ids to a tab-separated file")]
   Export(export::Export),
+  #[command(about = "Write rune and rune holder CSV files")]
+  ExportRunes(export_runes::ExportRunes),
   #[command(about = "Print index statistics")]
   Info(info::Info),
   #[command(about = "Update the index", alias = "run")]
@@ -17,7 +23,9 @@ pub(crate) enum IndexSubcommand {
 impl IndexSubcommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
     match self {
+      Self::Check(check) => check.run(settings),
       Self::Export(export) => export.run(settings),
+      Self::ExportRunes(export_runes) => export_runes.run(settings),
       Self::Info(info) => info.run(settings),
       Self::Update => update::run(settings),
     }
@@ -0,0 +1,85 @@
+use super::*;
+
+const DUPLICATE_SATPOINT_EXAMPLES: usize = 10;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Check {
+  #[arg(
+    long,
+    help = "Also check that no two inscriptions share a satpoint unless all but the first are reinscriptions. Scans every inscription satpoint."
+  )]
+  deep: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub duplicate_satpoints: Option<u64>,
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub duplicate_satpoint_examples: BTreeMap<SatPoint, Vec<InscriptionId>>,
+  pub inscriptions: u64,
+  pub missing_inscription_entries: u64,
+  pub missing_satpoints: u64,
+  pub rune_balance_outputs: u64,
+  pub sequence_number_gaps: u64,
+  pub undecodable_rune_balances: u64,
+}
+
+impl Output {
+  pub(crate) fn inconsistencies(&self) -> u64 {
+    self.duplicate_satpoints.unwrap_or_default()
+      + self.missing_inscription_entries
+      + self.missing_satpoints
+      + self.sequence_number_gaps
+      + self.undecodable_rune_balances
+  }
+
+  pub(crate) fn add_duplicate_satpoint(&mut self, satpoint: SatPoint, ids: Vec<InscriptionId>) {
+    *self.duplicate_satpoints.get_or_insert(0) += 1;
+
+    if self.duplicate_satpoint_examples.len() < DUPLICATE_SATPOINT_EXAMPLES {
+      self.duplicate_satpoint_examples.insert(satpoint, ids);
+    }
+  }
+}
+
+impl Check {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+
+    let output = index.check(self.deep)?;
+
+    if output.inconsistencies() > 0 {
+      bail!(
+        "index check found {} inconsistencies: {} missing inscription entries, {} missing satpoints, {} sequence number gaps, {} undecodable rune balances{}",
+        output.inconsistencies(),
+        output.missing_inscription_entries,
+        output.missing_satpoints,
+        output.sequence_number_gaps,
+        output.undecodable_rune_balances,
+        output
+          .duplicate_satpoints
+          .filter(|&duplicates| duplicates > 0)
+          .map(|duplicates| format!(
+            ", {duplicates} duplicate satpoints: {}",
+            output
+              .duplicate_satpoint_examples
+              .iter()
+              .map(|(satpoint, ids)| format!(
+                "{satpoint} ({})",
+                ids
+                  .iter()
+                  .map(|id| id.to_string())
+                  .collect::<Vec<String>>()
+                  .join(", ")
+              ))
+              .collect::<Vec<String>>()
+              .join(", ")
+          ))
+          .unwrap_or_default(),
+      );
+    }
+
+    Ok(Some(Box::new(output)))
+  }
+}
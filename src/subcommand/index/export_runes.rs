@@ -0,0 +1,147 @@
+use {
+  super::*,
+  flate2::write::GzEncoder,
+  std::io::{BufWriter, Write},
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct ExportRunes {
+  #[arg(
+    long,
+    help = "Write rune id, name, symbol, divisibility, supply, and holder count to <CSV>"
+  )]
+  csv: PathBuf,
+  #[arg(long, help = "Gzip compress output files")]
+  gzip: bool,
+  #[arg(
+    long,
+    help = "Write rune id, name, outpoint, and balance of every holder to <HOLDERS>"
+  )]
+  holders: Option<PathBuf>,
+}
+
+impl ExportRunes {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+
+    ensure!(
+      index.has_rune_index(),
+      "export-runes requires index created with `--index-runes` flag",
+    );
+
+    index.update()?;
+
+    let balances = index.get_rune_balance_map()?;
+
+    let mut runes = self.writer(&self.csv)?;
+
+    writeln!(runes, "id,rune,symbol,divisibility,supply,holders")?;
+
+    let mut holders = self
+      .holders
+      .as_ref()
+      .map(|path| self.writer(path))
+      .transpose()?;
+
+    if let Some(holders) = &mut holders {
+      writeln!(holders, "id,rune,outpoint,balance")?;
+    }
+
+    for (id, entry) in index.runes()? {
+      let rune_balances = balances.get(&entry.spaced_rune);
+
+      writeln!(
+        runes,
+        "{id},{},{},{},{},{}",
+        entry.spaced_rune,
+        entry.symbol.map(csv_field).unwrap_or_default(),
+        entry.divisibility,
+        Decimal {
+          value: entry.supply(),
+          scale: entry.divisibility,
+        },
+        rune_balances.map(BTreeMap::len).unwrap_or_default(),
+      )?;
+
+      if let (Some(holders), Some(rune_balances)) = (&mut holders, rune_balances) {
+        for (outpoint, pile) in rune_balances {
+          writeln!(
+            holders,
+            "{id},{},{outpoint},{}",
+            entry.spaced_rune,
+            Decimal {
+              value: pile.amount,
+              scale: pile.divisibility,
+            },
+          )?;
+        }
+      }
+
+      if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+        break;
+      }
+    }
+
+    runes.finish()?;
+
+    if let Some(holders) = holders {
+      holders.finish()?;
+    }
+
+    Ok(None)
+  }
+
+  fn writer(&self, path: &Path) -> Result<Writer> {
+    let file = BufWriter::new(
+      fs::File::create(path).with_context(|| format!("failed to create `{}`", path.display()))?,
+    );
+
+    Ok(if self.gzip {
+      Writer::Gzip(GzEncoder::new(file, flate2::Compression::default()))
+    } else {
+      Writer::Plain(file)
+    })
+  }
+}
+
+enum Writer {
+  Gzip(GzEncoder<BufWriter<fs::File>>),
+  Plain(BufWriter<fs::File>),
+}
+
+impl Writer {
+  fn finish(self) -> Result {
+    let mut file = match self {
+      Self::Gzip(encoder) => encoder.finish()?,
+      Self::Plain(file) => file,
+    };
+
+    file.flush()?;
+
+    Ok(())
+  }
+}
+
+impl Write for Writer {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    match self {
+      Self::Gzip(encoder) => encoder.write(buf),
+      Self::Plain(file) => file.write(buf),
+    }
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    match self {
+      Self::Gzip(encoder) => encoder.flush(),
+      Self::Plain(file) => file.flush(),
+    }
+  }
+}
+
+fn csv_field(symbol: char) -> String {
+  if matches!(symbol, ',' | '"' | '\n' | '\r') {
+    format!("\"{}\"", symbol.to_string().replace('"', "\"\""))
+  } else {
+    symbol.to_string()
+  }
+}
@@ -1,11 +1,55 @@
-use super::*;
+use {super::*, redb::TableStats};
+
+pub(crate) const TABLE_SAMPLE_SIZE: usize = 5;
 
 #[derive(Debug, Parser)]
 pub(crate) struct Info {
+  #[arg(
+    long,
+    help = "Print detailed statistics and a sample of decoded entries for table <TABLE>."
+  )]
+  table: Option<String>,
   #[arg(long)]
   transactions: bool,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableOutput {
+  pub branch_pages: u64,
+  pub entries: u64,
+  pub fragmented_bytes: u64,
+  pub leaf_pages: u64,
+  pub metadata_bytes: u64,
+  pub name: String,
+  pub sample: Vec<TableSample>,
+  pub stored_bytes: u64,
+  pub total_bytes: u64,
+  pub tree_height: u32,
+}
+
+impl TableOutput {
+  pub(crate) fn new(name: &str, stats: TableStats, entries: u64, sample: Vec<TableSample>) -> Self {
+    Self {
+      branch_pages: stats.branch_pages(),
+      entries,
+      fragmented_bytes: stats.fragmented_bytes(),
+      leaf_pages: stats.leaf_pages(),
+      metadata_bytes: stats.metadata_bytes(),
+      name: name.into(),
+      sample,
+      stored_bytes: stats.stored_bytes(),
+      total_bytes: stats.stored_bytes() + stats.metadata_bytes() + stats.fragmented_bytes(),
+      tree_height: stats.tree_height(),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableSample {
+  pub key: String,
+  pub value: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct TransactionsOutput {
   pub start: u32,
@@ -20,6 +64,10 @@ impl Info {
 
     index.update()?;
 
+    if let Some(table) = self.table {
+      return Ok(Some(Box::new(index.table_info(&table)?)));
+    }
+
     let info = index.info()?;
 
     if self.transactions {
@@ -0,0 +1,17 @@
+use super::*;
+
+pub mod dump;
+
+#[derive(Debug, Parser)]
+pub(crate) enum InscriptionSubcommand {
+  #[command(about = "Dump an inscription's raw envelope")]
+  Dump(dump::Dump),
+}
+
+impl InscriptionSubcommand {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    match self {
+      Self::Dump(dump) => dump.run(settings),
+    }
+  }
+}
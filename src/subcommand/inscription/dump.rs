@@ -0,0 +1,122 @@
+use {super::*, bitcoin::blockdata::opcodes};
+
+#[derive(Serialize, Eq, PartialEq, Deserialize, Debug)]
+pub struct Field {
+  pub tag: String,
+  pub value: String,
+}
+
+#[derive(Serialize, Eq, PartialEq, Deserialize, Debug)]
+pub struct Output {
+  pub body: Option<String>,
+  pub content_encoding: Option<String>,
+  pub content_type: Option<String>,
+  pub duplicate_field: bool,
+  pub envelope: String,
+  pub fields: Vec<Field>,
+  pub incomplete_field: bool,
+  pub input: u32,
+  pub inscription: InscriptionId,
+  pub metadata: Option<String>,
+  pub offset: u32,
+  pub pushnum: bool,
+  pub stutter: bool,
+  pub unrecognized_even_field: bool,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct Dump {
+  #[arg(help = "Dump envelope of <INSCRIPTION_ID>.")]
+  inscription_id: InscriptionId,
+  #[arg(
+    long,
+    help = "Also write raw envelope script, body, and metadata bytes to files in <OUTPUT_DIR>."
+  )]
+  output_dir: Option<PathBuf>,
+}
+
+impl Dump {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    // envelopes are read from the transaction rather than the index, so cursed
+    // and unbound inscriptions are dumped like any other
+    let InscriptionId { txid, index } = self.inscription_id;
+
+    let transaction = settings
+      .bitcoin_rpc_client(None)?
+      .get_raw_transaction(&txid, None)
+      .with_context(|| format!("failed to fetch transaction {txid}"))?;
+
+    let raw = RawEnvelope::from_transaction(&transaction)
+      .into_iter()
+      .nth(usize::try_from(index).unwrap())
+      .ok_or_else(|| anyhow!("transaction {txid} has no envelope with index {index}"))?;
+
+    let envelope = Self::script(&raw);
+
+    let body_index = raw
+      .payload
+      .iter()
+      .enumerate()
+      .position(|(i, push)| i % 2 == 0 && push.is_empty());
+
+    let fields = raw.payload[..body_index.unwrap_or(raw.payload.len())]
+      .chunks(2)
+      .map(|chunk| Field {
+        tag: hex::encode(&chunk[0]),
+        value: chunk.get(1).map(hex::encode).unwrap_or_default(),
+      })
+      .collect();
+
+    let ParsedEnvelope {
+      payload: inscription,
+      ..
+    } = raw.clone().into();
+
+    if let Some(output_dir) = &self.output_dir {
+      fs::create_dir_all(output_dir)?;
+
+      fs::write(output_dir.join("envelope.bin"), envelope.as_bytes())?;
+
+      if let Some(body) = &inscription.body {
+        fs::write(output_dir.join("body.bin"), body)?;
+      }
+
+      if let Some(metadata) = &inscription.metadata {
+        fs::write(output_dir.join("metadata.cbor"), metadata)?;
+      }
+    }
+
+    Ok(Some(Box::new(Output {
+      content_encoding: inscription
+        .content_encoding()
+        .and_then(|content_encoding| content_encoding.to_str().ok().map(str::to_string)),
+      content_type: inscription.content_type().map(str::to_string),
+      body: inscription.body.as_ref().map(hex::encode),
+      duplicate_field: inscription.duplicate_field,
+      envelope: hex::encode(envelope.as_bytes()),
+      fields,
+      incomplete_field: inscription.incomplete_field,
+      input: raw.input,
+      inscription: self.inscription_id,
+      metadata: inscription.metadata.as_ref().map(hex::encode),
+      offset: raw.offset,
+      pushnum: raw.pushnum,
+      stutter: raw.stutter,
+      unrecognized_even_field: inscription.unrecognized_even_field,
+    })))
+  }
+
+  // Pushnum opcodes in the original envelope are reconstructed as data pushes.
+  fn script(raw: &RawEnvelope) -> ScriptBuf {
+    let mut builder = script::Builder::new()
+      .push_opcode(opcodes::OP_FALSE)
+      .push_opcode(opcodes::all::OP_IF)
+      .push_slice(b"ord");
+
+    for push in &raw.payload {
+      builder = builder.push_slice::<&script::PushBytes>(push.as_slice().try_into().unwrap());
+    }
+
+    builder.push_opcode(opcodes::all::OP_ENDIF).into_script()
+  }
+}
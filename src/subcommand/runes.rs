@@ -1,5 +1,25 @@
 use super::*;
 
+#[derive(Debug, Parser)]
+pub(crate) struct Runes {
+  #[command(subcommand)]
+  subcommand: Option<RunesSubcommand>,
+}
+
+#[derive(Debug, Parser)]
+enum RunesSubcommand {
+  #[command(about = "List rune balances of an output")]
+  Balances {
+    #[arg(help = "List rune balances of <OUTPOINT>.")]
+    outpoint: OutPoint,
+  },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct BalancesOutput {
+  pub runes: BTreeMap<SpacedRune, Pile>,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub runes: BTreeMap<Rune, RuneInfo>,
@@ -24,7 +44,34 @@ pub struct RuneInfo {
   pub tx: u32,
 }
 
-pub(crate) fn run(settings: Settings) -> SubcommandResult {
+impl Runes {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    match self.subcommand {
+      Some(RunesSubcommand::Balances { outpoint }) => balances(settings, outpoint),
+      None => run(settings),
+    }
+  }
+}
+
+fn balances(settings: Settings, outpoint: OutPoint) -> SubcommandResult {
+  let index = Index::open(&settings)?;
+
+  ensure!(
+    index.has_rune_index(),
+    "`ord runes balances` requires index created with `--index-runes` flag",
+  );
+
+  index.update()?;
+
+  Ok(Some(Box::new(BalancesOutput {
+    runes: index
+      .get_rune_balances_for_outpoint(outpoint)?
+      .into_iter()
+      .collect(),
+  })))
+}
+
+fn run(settings: Settings) -> SubcommandResult {
   let index = Index::open(&settings)?;
 
   ensure!(
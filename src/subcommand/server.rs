@@ -2,9 +2,15 @@ use {
   self::{
     accept_encoding::AcceptEncoding,
     accept_json::AcceptJson,
+    accept_msgpack::AcceptMsgpack,
+    backoff::Backoff,
     error::{OptionExt, ServerError, ServerResult},
+    if_modified_since::IfModifiedSince,
+    parsed_path::ParsedPath,
+    range::{ByteRange, Range},
   },
   super::*,
+  crate::index::{lru::Lru, ContentTooLarge},
   crate::templates::{
     BlockHtml, BlocksHtml, ChildrenHtml, ClockSvg, CollectionsHtml, HomeHtml, InputHtml,
     InscriptionHtml, InscriptionsBlockHtml, InscriptionsHtml, OutputHtml, PageContent, PageHtml,
@@ -15,9 +21,9 @@ use {
   axum::{
     body,
     extract::{Extension, Json, Path, Query},
-    http::{header, HeaderValue, StatusCode, Uri},
+    http::{header, HeaderName, HeaderValue, StatusCode, Uri},
     response::{IntoResponse, Redirect, Response},
-    routing::{get, post},
+    routing::{get, post, MethodRouter},
     Router,
   },
   axum_server::Handle,
@@ -30,9 +36,11 @@ use {
     AcmeConfig,
   },
   std::{cmp::Ordering, str, sync::Arc},
+  tokio::io::{AsyncReadExt, AsyncSeekExt},
   tokio_stream::StreamExt,
+  tokio_util::io::ReaderStream,
   tower_http::{
-    compression::CompressionLayer,
+    compression::{predicate::DefaultPredicate, CompressionLayer, Predicate},
     cors::{Any, CorsLayer},
     set_header::SetResponseHeaderLayer,
     validate_request::ValidateRequestHeaderLayer,
@@ -43,9 +51,41 @@ pub(crate) use server_config::ServerConfig;
 
 mod accept_encoding;
 mod accept_json;
+mod accept_language;
+mod accept_msgpack;
+mod backoff;
 mod error;
+mod if_modified_since;
+mod parsed_path;
 pub mod query;
+mod range;
 mod server_config;
+#[cfg(unix)]
+mod unix_socket;
+
+// Blocks with fewer confirmations than this may still be reorged, so responses
+// derived from them are not given a `Last-Modified` header.
+const LAST_MODIFIED_MIN_CONFIRMATIONS: u32 = 6;
+
+// Inscriptions are exported in batches of `EXPORT_BATCH_SIZE`, with at most
+// `EXPORT_BUFFER` serialized lines waiting on a slow client before the export
+// thread blocks.
+const EXPORT_BATCH_SIZE: usize = 1000;
+const EXPORT_BUFFER: usize = 1000;
+
+const MAX_FIND_RANGE_SIZE: u64 = 50 * bitcoin::constants::COIN_VALUE;
+
+const MAX_METADATA_BATCH_SIZE: usize = 100;
+
+const MAX_RUNES_ETCHED_WINDOW: u64 = 4032;
+
+const DEFAULT_THUMBNAIL_SIZE: u32 = 128;
+const MAX_THUMBNAIL_SIZE: u32 = 512;
+const MAX_THUMBNAIL_SOURCE_DIMENSION: u32 = 4096;
+const THUMBNAIL_CACHE_SIZE: usize = 1000;
+
+const X_DELEGATE_ID: HeaderName = HeaderName::from_static("x-delegate-id");
+const X_INSCRIPTION_ID: HeaderName = HeaderName::from_static("x-inscription-id");
 
 enum SpawnConfig {
   Https(AxumAcceptor),
@@ -58,6 +98,57 @@ struct Search {
   query: String,
 }
 
+#[derive(Deserialize)]
+struct Pagination {
+  limit: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct Thumbnail {
+  size: Option<u32>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BalanceOrder {
+  Amount,
+  #[default]
+  Outpoint,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OutputSort {
+  #[default]
+  Position,
+  Rarity,
+}
+
+#[derive(Deserialize)]
+struct OutputQuery {
+  #[serde(default)]
+  sort: OutputSort,
+}
+
+#[derive(Deserialize)]
+struct RuneQuery {
+  amount: Option<DeserializeFromStr<Decimal>>,
+}
+
+#[derive(Deserialize)]
+struct RuneBalancesQuery {
+  #[serde(default)]
+  order: BalanceOrder,
+}
+
+struct OrderedBalances(Vec<(OutPoint, u128)>);
+
+impl Serialize for OrderedBalances {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(self.0.iter().map(|(outpoint, amount)| (outpoint, amount)))
+  }
+}
+
 #[derive(RustEmbed)]
 #[folder = "static"]
 struct StaticAssets;
@@ -91,6 +182,12 @@ pub struct Server {
     help = "Request ACME TLS certificate for <ACME_DOMAIN>. This ord instance must be reachable at <ACME_DOMAIN>:443 to respond to Let's Encrypt ACME challenges."
   )]
   pub(crate) acme_domain: Vec<String>,
+  #[arg(
+    long,
+    value_name = "HEADER",
+    help = "Expose <HEADER> to cross-origin scripts in addition to `Content-Range` and `ETag`. May be given multiple times."
+  )]
+  pub(crate) cors_expose_headers: Vec<HeaderName>,
   #[arg(
     long,
     help = "Use <CSP_ORIGIN> in Content-Security-Policy header. Set this to the public-facing URL of your ord instance."
@@ -98,11 +195,38 @@ pub struct Server {
   pub(crate) csp_origin: Option<String>,
   #[arg(
     long,
-    help = "Decompress encoded content. Currently only supports brotli. Be careful using this on production instances. A decompressed inscription may be arbitrarily large, making decompression a DoS vector."
+    help = "Decompress encoded content. Currently only supports brotli. Decompressed content is limited to <MAX_DECOMPRESSED_SIZE> bytes."
   )]
   pub(crate) decompress: bool,
+  #[arg(
+    long,
+    default_value = "67108864",
+    help = "Return 413 for content which decompresses to more than <MAX_DECOMPRESSED_SIZE> bytes."
+  )]
+  pub(crate) max_decompressed_size: usize,
+  #[arg(
+    long,
+    default_value = "2097152",
+    help = "Return 413 for `/content` responses larger than <MAX_CONTENT_RESPONSE_SIZE> bytes. Stored content length is checked before content is loaded."
+  )]
+  pub(crate) max_content_response_size: usize,
   #[arg(long, help = "Disable JSON API.")]
   pub(crate) disable_json_api: bool,
+  #[arg(
+    long,
+    help = "Serve downscaled JPEG thumbnails of raster image inscriptions at `/content/<INSCRIPTION_ID>/thumbnail`."
+  )]
+  pub(crate) enable_thumbnails: bool,
+  #[arg(
+    long,
+    help = "Stream every inscription as NDJSON at `/r/inscriptions/export`. Each request walks the whole index, so only enable this for mirrors that need bulk sync."
+  )]
+  pub(crate) enable_inscription_export: bool,
+  #[arg(
+    long,
+    help = "Serve the locations of sat ranges at `/find/<START>/<END>` when sats are indexed. Lookups of spent sats scan the whole sat index."
+  )]
+  pub(crate) enable_find_range: bool,
   #[arg(
     long,
     help = "Listen on <HTTP_PORT> for incoming HTTP requests. [default: 80]"
@@ -122,8 +246,40 @@ pub struct Server {
   pub(crate) http: bool,
   #[arg(long, help = "Serve HTTPS traffic on <HTTPS_PORT>.")]
   pub(crate) https: bool,
+  #[arg(
+    long,
+    help = "Serve inscription content by CIDv1 at `/ipfs/<CID>`, for use behind IPFS gateways. Requires an index built with `--index-cids`."
+  )]
+  pub(crate) ipfs: bool,
+  #[arg(
+    long,
+    conflicts_with = "disable_json_api",
+    help = "Only serve the JSON API, recursive endpoints, and inscription content. HTML pages and static assets return 404."
+  )]
+  pub(crate) json_api_only: bool,
+  #[arg(
+    long,
+    help = "Listen for HTTP requests on unix domain socket <LISTEN_UNIX_SOCKET>. TCP is not served unless `--http`, `--http-port`, `--https`, or `--https-port` is also given. TLS and ACME options do not apply to the socket."
+  )]
+  pub(crate) listen_unix_socket: Option<PathBuf>,
+  #[arg(
+    long,
+    help = "Serve an OpenAPI description of the JSON API at `/api/openapi.json`."
+  )]
+  pub(crate) openapi: bool,
   #[arg(long, help = "Redirect HTTP traffic to HTTPS.")]
   pub(crate) redirect_http_to_https: bool,
+  #[arg(
+    long,
+    help = "Identify server with `Server: ord/<VERSION>` response header. Off by default to avoid revealing the running version."
+  )]
+  pub(crate) server_header: bool,
+  #[arg(
+    long,
+    default_value = "100",
+    help = "Allow paginated endpoints to return up to <MAX_PAGE_SIZE> items per page with `?limit=`."
+  )]
+  pub(crate) max_page_size: usize,
   #[arg(long, alias = "nosync", help = "Do not update the index.")]
   pub(crate) no_sync: bool,
   #[arg(
@@ -131,33 +287,97 @@ pub struct Server {
     help = "Proxy `/content/INSCRIPTION_ID` requests to `<CONTENT_PROXY>/content/INSCRIPTION_ID` if the inscription is not present on current chain."
   )]
   pub(crate) content_proxy: Option<Url>,
+  #[arg(
+    long,
+    default_value = "8388608",
+    help = "Return 502 for proxied content larger than <CONTENT_PROXY_MAX_SIZE> bytes."
+  )]
+  pub(crate) content_proxy_max_size: usize,
+  #[arg(
+    long,
+    default_value = "10s",
+    help = "Return 504 if the content proxy does not respond within <CONTENT_PROXY_TIMEOUT>."
+  )]
+  pub(crate) content_proxy_timeout: humantime::Duration,
   #[arg(
     long,
     default_value = "5s",
     help = "Poll Bitcoin Core every <POLLING_INTERVAL>."
   )]
   pub(crate) polling_interval: humantime::Duration,
+  #[arg(
+    long,
+    help = "Once caught up to the chain tip, back off polling exponentially up to <POLLING_BACKOFF_MAX>, checking for new blocks with `getblockcount`."
+  )]
+  pub(crate) polling_backoff_max: Option<humantime::Duration>,
+  #[arg(
+    long,
+    value_parser = parse_content_type_override,
+    value_name = "INSCRIPTION_ID=MIME",
+    help = "Serve content of <INSCRIPTION_ID> with Content-Type <MIME>. May be given multiple times. Only changes the served header, not the inscription or its hash."
+  )]
+  pub(crate) serve_content_type: Vec<(InscriptionId, String)>,
+  #[arg(
+    long,
+    help = "Serve `/static` assets from <STATIC_DIR>, falling back to built-in assets for files not present."
+  )]
+  pub(crate) static_dir: Option<PathBuf>,
+  #[arg(
+    long,
+    help = "Refuse to start if the index was built with a different first inscription height than the one configured."
+  )]
+  pub(crate) strict: bool,
+}
+
+fn parse_content_type_override(s: &str) -> Result<(InscriptionId, String)> {
+  let (inscription_id, content_type) = s
+    .split_once('=')
+    .ok_or_else(|| anyhow!("content type override `{s}` must be of the form ID=MIME"))?;
+
+  Ok((inscription_id.parse()?, content_type.into()))
 }
 
 impl Server {
   pub fn run(self, settings: Settings, index: Arc<Index>, handle: Handle) -> SubcommandResult {
+    if let Some(indexed) = index.first_inscription_height_mismatch()? {
+      let message = format!(
+        "index was built with first inscription height {indexed}, but first inscription height {} is configured",
+        settings.first_inscription_height(),
+      );
+
+      if self.strict {
+        bail!("{message}");
+      }
+
+      log::warn!("{message}");
+    }
+
     Runtime::new()?.block_on(async {
       let index_clone = index.clone();
       let integration_test = settings.integration_test();
 
+      let mut backoff = self
+        .polling_backoff_max
+        .map(|max| Backoff::new(self.polling_interval.into(), max.into()));
+
       let index_thread = thread::spawn(move || loop {
         if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
           break;
         }
 
-        if !self.no_sync {
+        let caught_up =
+          !self.no_sync && backoff.is_some() && index_clone.is_caught_up().unwrap_or_default();
+
+        if !self.no_sync && !caught_up {
           if let Err(error) = index_clone.update() {
             log::warn!("Updating index: {error}");
           }
         }
 
-        thread::sleep(if integration_test {
+        Backoff::sleep(if integration_test {
           Duration::from_millis(100)
+        } else if let Some(backoff) = &mut backoff {
+          backoff.next(caught_up)
         } else {
           self.polling_interval.into()
         });
@@ -171,15 +391,49 @@ impl Server {
       let server_config = Arc::new(ServerConfig {
         chain: settings.chain(),
         content_proxy: self.content_proxy.clone(),
+        content_proxy_max_size: self.content_proxy_max_size,
+        content_proxy_timeout: self.content_proxy_timeout.into(),
         csp_origin: self.csp_origin.clone(),
         decompress: self.decompress,
         domain: acme_domains.first().cloned(),
+        enable_thumbnails: self.enable_thumbnails,
+        find_range: self.enable_find_range,
         index_sats: index.has_sat_index(),
+        inscription_export: self.enable_inscription_export,
+        ipfs: self.ipfs,
         json_api_enabled: !self.disable_json_api,
+        json_api_only: self.json_api_only,
+        max_content_response_size: self.max_content_response_size,
+        max_decompressed_size: self.max_decompressed_size,
+        max_page_size: self.max_page_size,
+        openapi: self.openapi,
+        serve_content_type: settings
+          .serve_content_type()
+          .into_iter()
+          .chain(self.serve_content_type.clone())
+          .map(|(inscription_id, content_type)| {
+            Ok((
+              inscription_id,
+              content_type
+                .parse()
+                .with_context(|| format!("invalid content type `{content_type}`"))?,
+            ))
+          })
+          .collect::<Result<_>>()?,
+        static_dir: self
+          .static_dir
+          .as_ref()
+          .map(|static_dir| {
+            static_dir
+              .canonicalize()
+              .with_context(|| format!("invalid static dir `{}`", static_dir.display()))
+          })
+          .transpose()?,
+        thumbnail_cache: Mutex::new(Lru::new(THUMBNAIL_CACHE_SIZE)),
       });
 
       let router = Router::new()
-        .route("/", get(Self::home))
+        .route("/api/openapi.json", get(Self::openapi))
         .route("/block/:query", get(Self::block))
         .route("/blockcount", get(Self::block_count))
         .route("/blockhash", get(Self::block_hash))
@@ -187,20 +441,16 @@ impl Server {
         .route("/blockheight", get(Self::block_height))
         .route("/blocks", get(Self::blocks))
         .route("/blocktime", get(Self::block_time))
-        .route("/bounties", get(Self::bounties))
-        .route("/children/:inscription_id", get(Self::children))
+        .route("/content/:inscription_id", get(Self::content))
         .route(
-          "/children/:inscription_id/:page",
-          get(Self::children_paginated),
+          "/content/:inscription_id/thumbnail",
+          get(Self::content_thumbnail),
         )
-        .route("/clock", get(Self::clock))
-        .route("/collections", get(Self::collections))
-        .route("/collections/:page", get(Self::collections_paginated))
-        .route("/content/:inscription_id", get(Self::content))
-        .route("/faq", get(Self::faq))
-        .route("/favicon.ico", get(Self::favicon))
-        .route("/feed.xml", get(Self::feed))
-        .route("/input/:block/:transaction/:input", get(Self::input))
+        .route(
+          "/content/number/:inscription_number",
+          get(Self::content_by_number),
+        )
+        .route("/find/:start/:end", get(Self::find_range))
         .route("/inscription/:inscription_query", get(Self::inscription))
         .route("/inscriptions", get(Self::inscriptions))
         .route("/inscriptions", post(Self::inscriptions_json))
@@ -213,56 +463,71 @@ impl Server {
           "/inscriptions/block/:height/:page",
           get(Self::inscriptions_in_block_paginated),
         )
-        .route("/install.sh", get(Self::install_script))
-        .route("/ordinal/:sat", get(Self::ordinal))
+        .route("/ipfs/:cid", get(Self::ipfs))
         .route("/output/:output", get(Self::output))
-        .route("/outputs", post(Self::outputs))
-        .route("/parents/:inscription_id", get(Self::parents))
-        .route(
-          "/parents/:inscription_id/:page",
-          get(Self::parents_paginated),
-        )
-        .route("/preview/:inscription_id", get(Self::preview))
-        .route("/r/blockhash", get(Self::block_hash_json))
-        .route(
-          "/r/blockhash/:height",
-          get(Self::block_hash_from_height_json),
-        )
-        .route("/r/blockheight", get(Self::block_height))
-        .route("/r/blocktime", get(Self::block_time))
-        .route("/r/blockinfo/:query", get(Self::block_info))
-        .route(
-          "/r/inscription/:inscription_id",
-          get(Self::inscription_recursive),
-        )
-        .route("/r/children/:inscription_id", get(Self::children_recursive))
-        .route(
-          "/r/children/:inscription_id/:page",
-          get(Self::children_recursive_paginated),
-        )
-        .route("/r/metadata/:inscription_id", get(Self::metadata))
-        .route("/r/sat/:sat_number", get(Self::sat_inscriptions))
-        .route(
-          "/r/sat/:sat_number/:page",
-          get(Self::sat_inscriptions_paginated),
-        )
+        .route("/outputs", post(Self::outputs));
+
+      let router = Self::recursive_routes()
+        .into_iter()
+        .fold(router, |router, (path, method_router)| {
+          router.route(path, method_router)
+        });
+
+      let router = router
+        .route("/rune/:rune", get(Self::rune))
+        .route("/rune/:rune/holders", get(Self::rune_holders))
         .route(
-          "/r/sat/:sat_number/at/:index",
-          get(Self::sat_inscription_at_index),
+          "/rune/:rune/holders/:page",
+          get(Self::rune_holders_paginated),
         )
-        .route("/range/:start/:end", get(Self::range))
-        .route("/rare.txt", get(Self::rare_txt))
-        .route("/rune/:rune", get(Self::rune))
         .route("/runes", get(Self::runes))
         .route("/runes/:page", get(Self::runes_paginated))
         .route("/runes/balances", get(Self::runes_balances))
+        .route("/runes/etched/:start/:end", get(Self::runes_etched))
+        .route(
+          "/runes/etched/:start/:end/:page",
+          get(Self::runes_etched_paginated),
+        )
+        .route("/runes/schedule", get(Self::runes_schedule))
         .route("/sat/:sat", get(Self::sat))
-        .route("/search", get(Self::search_by_query))
-        .route("/search/*query", get(Self::search_by_path))
-        .route("/static/*path", get(Self::static_asset))
         .route("/status", get(Self::status))
         .route("/tx/:txid", get(Self::transaction))
-        .route("/update", get(Self::update))
+        .route("/update", get(Self::update));
+
+      let router = if self.json_api_only {
+        router
+      } else {
+        router
+          .route("/", get(Self::home))
+          .route("/bounties", get(Self::bounties))
+          .route("/children/:inscription_id", get(Self::children))
+          .route(
+            "/children/:inscription_id/:page",
+            get(Self::children_paginated),
+          )
+          .route("/clock", get(Self::clock))
+          .route("/collections", get(Self::collections))
+          .route("/collections/:page", get(Self::collections_paginated))
+          .route("/faq", get(Self::faq))
+          .route("/favicon.ico", get(Self::favicon))
+          .route("/feed.xml", get(Self::feed))
+          .route("/input/:block/:transaction/:input", get(Self::input))
+          .route("/install.sh", get(Self::install_script))
+          .route("/ordinal/:sat", get(Self::ordinal))
+          .route("/parents/:inscription_id", get(Self::parents))
+          .route(
+            "/parents/:inscription_id/:page",
+            get(Self::parents_paginated),
+          )
+          .route("/preview/:inscription_id", get(Self::preview))
+          .route("/range/:start/:end", get(Self::range))
+          .route("/rare.txt", get(Self::rare_txt))
+          .route("/search", get(Self::search_by_query))
+          .route("/search/*query", get(Self::search_by_path))
+          .route("/static/*path", get(Self::static_asset))
+      };
+
+      let router = router
         .fallback(Self::fallback)
         .layer(Extension(index))
         .layer(Extension(server_config.clone()))
@@ -275,12 +540,39 @@ impl Server {
           header::STRICT_TRANSPORT_SECURITY,
           HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
         ))
+        .layer(SetResponseHeaderLayer::overriding(header::SERVER, {
+          let server = self
+            .server_header
+            .then(|| HeaderValue::from_static(concat!("ord/", env!("CARGO_PKG_VERSION"))));
+          move |_: &Response| server.clone()
+        }))
         .layer(
           CorsLayer::new()
             .allow_methods([http::Method::GET])
-            .allow_origin(Any),
+            .allow_origin(Any)
+            .expose_headers(
+              [
+                header::CONTENT_RANGE,
+                header::ETAG,
+                X_DELEGATE_ID,
+                X_INSCRIPTION_ID,
+              ]
+              .into_iter()
+              .chain(self.cors_expose_headers.iter().cloned())
+              .collect::<Vec<HeaderName>>(),
+            ),
+        )
+        .layer(SetResponseHeaderLayer::appending(
+          header::VARY,
+          AcceptMsgpack::vary,
+        ))
+        .layer(axum::middleware::from_fn(
+          accept_language::localize_error_pages,
+        ))
+        .layer(
+          CompressionLayer::new()
+            .compress_when(DefaultPredicate::new().and(Self::is_not_partial_content)),
         )
-        .layer(CompressionLayer::new())
         .with_state(server_config);
 
       let router = if let Some((username, password)) = settings.credentials() {
@@ -289,6 +581,12 @@ impl Server {
         router
       };
 
+      let unix_socket = self
+        .listen_unix_socket
+        .clone()
+        .map(|path| self.spawn_unix_socket(&settings, router.clone(), path))
+        .transpose()?;
+
       match (self.http_port(), self.https_port()) {
         (Some(http_port), None) => {
           self
@@ -335,7 +633,11 @@ impl Server {
           );
           http_result.and(https_result)??;
         }
-        (None, None) => unreachable!(),
+        (None, None) => assert!(unix_socket.is_some()),
+      }
+
+      if let Some(unix_socket) = unix_socket {
+        unix_socket.await??;
       }
 
       Ok(None)
@@ -366,7 +668,7 @@ impl Server {
       .next()
       .ok_or_else(|| anyhow!("failed to get socket addrs"))?;
 
-    if !settings.integration_test() && !cfg!(test) {
+    if !settings.integration_test() && !settings.quiet() && !cfg!(test) {
       eprintln!(
         "Listening on {}://{addr}",
         match config {
@@ -406,6 +708,32 @@ impl Server {
     }))
   }
 
+  #[cfg(unix)]
+  fn spawn_unix_socket(
+    &self,
+    settings: &Settings,
+    router: Router,
+    path: PathBuf,
+  ) -> Result<task::JoinHandle<io::Result<()>>> {
+    let unix_socket = unix_socket::UnixSocket::bind(path.clone())?;
+
+    if !settings.integration_test() && !settings.quiet() && !cfg!(test) {
+      eprintln!("Listening on unix:{}", path.display());
+    }
+
+    Ok(tokio::spawn(unix_socket.serve(router)))
+  }
+
+  #[cfg(not(unix))]
+  fn spawn_unix_socket(
+    &self,
+    _settings: &Settings,
+    _router: Router,
+    _path: PathBuf,
+  ) -> Result<task::JoinHandle<io::Result<()>>> {
+    bail!("--listen-unix-socket is only supported on unix");
+  }
+
   fn acme_cache(acme_cache: Option<&PathBuf>, settings: &Settings) -> PathBuf {
     match acme_cache {
       Some(acme_cache) => acme_cache.clone(),
@@ -424,7 +752,10 @@ impl Server {
   }
 
   fn http_port(&self) -> Option<u16> {
-    if self.http || self.http_port.is_some() || (self.https_port.is_none() && !self.https) {
+    if self.http
+      || self.http_port.is_some()
+      || (self.https_port.is_none() && !self.https && self.listen_unix_socket.is_none())
+    {
       Some(self.http_port.unwrap_or(80))
     } else {
       None
@@ -493,6 +824,76 @@ impl Server {
     })
   }
 
+  /// Compressing a byte range would make `Content-Range` offsets point into
+  /// the uncompressed body, so partial responses are sent as is.
+  fn is_not_partial_content(
+    status: StatusCode,
+    _version: http::Version,
+    headers: &HeaderMap,
+    _extensions: &http::Extensions,
+  ) -> bool {
+    status != StatusCode::PARTIAL_CONTENT && !headers.contains_key(header::CONTENT_RANGE)
+  }
+
+  /// Recursive endpoints, all of which must be described in `openapi.json`.
+  fn recursive_routes() -> Vec<(&'static str, MethodRouter<Arc<ServerConfig>>)> {
+    vec![
+      ("/r/blockhash", get(Self::block_hash_json)),
+      (
+        "/r/blockhash/:height",
+        get(Self::block_hash_from_height_json),
+      ),
+      ("/r/blockhash/:height/header", get(Self::raw_block_header)),
+      ("/r/blockheight", get(Self::block_height)),
+      ("/r/blocktime", get(Self::block_time)),
+      ("/r/blockheader/:query", get(Self::block_header)),
+      ("/r/blockinfo/:query", get(Self::block_info)),
+      (
+        "/r/inscription/:inscription_id",
+        get(Self::inscription_recursive),
+      ),
+      (
+        "/r/inscription/:inscription_id/content-type",
+        get(Self::inscription_content_type_recursive),
+      ),
+      (
+        "/r/inscription/:inscription_id/rune",
+        get(Self::inscription_rune_recursive),
+      ),
+      ("/r/children/:inscription_id", get(Self::children_recursive)),
+      (
+        "/r/children/:inscription_id/count",
+        get(Self::children_count_recursive),
+      ),
+      (
+        "/r/children/:inscription_id/:page",
+        get(Self::children_recursive_paginated),
+      ),
+      ("/r/content-types", get(Self::content_types)),
+      ("/r/inscriptions/export", get(Self::inscriptions_export)),
+      ("/r/metadata", post(Self::metadata_batch)),
+      ("/r/metadata/:inscription_id", get(Self::metadata)),
+      ("/r/reorgs", get(Self::reorgs)),
+      ("/r/sat/:sat_number", get(Self::sat_inscriptions)),
+      (
+        "/r/sat/:sat_number/:page",
+        get(Self::sat_inscriptions_paginated),
+      ),
+      (
+        "/r/sat/:sat_number/at/:index",
+        get(Self::sat_inscription_at_index),
+      ),
+      (
+        "/r/sat/:sat_number/inscriptions/count",
+        get(Self::sat_inscription_count),
+      ),
+      (
+        "/r/sat/:sat_number/satpoint",
+        get(Self::sat_satpoint_recursive),
+      ),
+    ]
+  }
+
   async fn fallback(Extension(index): Extension<Arc<Index>>, uri: Uri) -> ServerResult<Response> {
     task::block_in_place(|| {
       let path = urlencoding::decode(uri.path().trim_matches('/'))
@@ -521,7 +922,7 @@ impl Server {
   async fn sat(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(DeserializeFromStr(sat)): Path<DeserializeFromStr<Sat>>,
+    ParsedPath(DeserializeFromStr(sat)): ParsedPath<DeserializeFromStr<Sat>>,
     AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -577,15 +978,32 @@ impl Server {
   async fn output(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(outpoint): Path<OutPoint>,
+    ParsedPath(outpoint): ParsedPath<OutPoint>,
+    Query(query): Query<OutputQuery>,
     AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let (output_info, txout) = index
+      let (mut output_info, txout) = index
         .get_output_info(outpoint)?
         .ok_or_not_found(|| format!("output {outpoint}"))?;
 
+      if let OutputSort::Rarity = query.sort {
+        if let Some(sat_ranges) = &mut output_info.sat_ranges {
+          sat_ranges.sort_by_key(|(start, _)| Reverse(Sat(*start).rarity()));
+        }
+      }
+
       Ok(if accept_json {
+        if outpoint != OutPoint::null() && !is_unbound(outpoint) {
+          let confirmed = index.is_output_confirmed(outpoint)?;
+          output_info.confirmed = Some(confirmed);
+          output_info.height = if confirmed {
+            index.get_transaction_height(outpoint.txid)?
+          } else {
+            None
+          };
+        }
+
         Json(output_info).into_response()
       } else {
         OutputHtml {
@@ -606,6 +1024,7 @@ impl Server {
   async fn outputs(
     Extension(index): Extension<Arc<Index>>,
     _: AcceptJson,
+    accept_msgpack: AcceptMsgpack,
     Json(outputs): Json<Vec<OutPoint>>,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -617,13 +1036,66 @@ impl Server {
 
         response.push(output_info);
       }
-      Ok(Json(response).into_response())
+      Ok(accept_msgpack.response(response))
+    })
+  }
+
+  async fn find_range(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath((DeserializeFromStr(start), DeserializeFromStr(end))): ParsedPath<(
+      DeserializeFromStr<Sat>,
+      DeserializeFromStr<Sat>,
+    )>,
+    AcceptJson(accept_json): AcceptJson,
+    accept_msgpack: AcceptMsgpack,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !server_config.find_range {
+        return Err(ServerError::NotFound(
+          "sat range search is not served by this server".into(),
+        ));
+      }
+
+      if !accept_json {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+      }
+
+      if !index.has_sat_index() {
+        return Err(ServerError::NotFound(
+          "this server has no sat index".to_string(),
+        ));
+      }
+
+      match start.cmp(&end) {
+        Ordering::Equal => return Err(ServerError::BadRequest("empty range".to_string())),
+        Ordering::Greater => {
+          return Err(ServerError::BadRequest(
+            "range start greater than range end".to_string(),
+          ))
+        }
+        Ordering::Less => {}
+      }
+
+      if end.n() - start.n() > MAX_FIND_RANGE_SIZE {
+        return Err(ServerError::BadRequest(format!(
+          "range may contain at most {MAX_FIND_RANGE_SIZE} sats"
+        )));
+      }
+
+      let mut results = index
+        .find_range(start, end)?
+        .ok_or_not_found(|| format!("range {start}-{end}"))?;
+
+      results.sort_by_key(|find_range_output| find_range_output.start);
+
+      Ok(accept_msgpack.response(results))
     })
   }
 
   async fn range(
     Extension(server_config): Extension<Arc<ServerConfig>>,
-    Path((DeserializeFromStr(start), DeserializeFromStr(end))): Path<(
+    ParsedPath((DeserializeFromStr(start), DeserializeFromStr(end))): ParsedPath<(
       DeserializeFromStr<Sat>,
       DeserializeFromStr<Sat>,
     )>,
@@ -644,7 +1116,8 @@ impl Server {
   async fn rune(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(DeserializeFromStr(rune_query)): Path<DeserializeFromStr<query::Rune>>,
+    ParsedPath(DeserializeFromStr(rune_query)): ParsedPath<DeserializeFromStr<query::Rune>>,
+    Query(query): Query<RuneQuery>,
     AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -654,15 +1127,7 @@ impl Server {
         ));
       }
 
-      let rune = match rune_query {
-        query::Rune::Spaced(spaced_rune) => spaced_rune.rune,
-        query::Rune::Id(rune_id) => index
-          .get_rune_by_id(rune_id)?
-          .ok_or_not_found(|| format!("rune {rune_id}"))?,
-        query::Rune::Number(number) => index
-          .get_rune_by_number(usize::try_from(number).unwrap())?
-          .ok_or_not_found(|| format!("rune number {number}"))?,
-      };
+      let rune = Self::resolve_rune(&index, rune_query)?;
 
       let (id, entry, parent) = index
         .rune(rune)?
@@ -672,8 +1137,15 @@ impl Server {
 
       let mintable = entry.mintable((block_height.n() + 1).into()).is_ok();
 
+      let amount = query
+        .amount
+        .map(|DeserializeFromStr(amount)| amount.to_integer(entry.divisibility))
+        .transpose()
+        .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
       Ok(if accept_json {
         Json(api::Rune {
+          amount,
           entry,
           id,
           mintable,
@@ -682,6 +1154,7 @@ impl Server {
         .into_response()
       } else {
         RuneHtml {
+          amount,
           entry,
           id,
           mintable,
@@ -693,16 +1166,107 @@ impl Server {
     })
   }
 
+  fn resolve_rune(index: &Index, rune_query: query::Rune) -> ServerResult<Rune> {
+    Ok(match rune_query {
+      query::Rune::Spaced(spaced_rune) => spaced_rune.rune,
+      query::Rune::Id(rune_id) => index
+        .get_rune_by_id(rune_id)?
+        .ok_or_not_found(|| format!("rune {rune_id}"))?,
+      query::Rune::Number(number) => index
+        .get_rune_by_number(usize::try_from(number).unwrap())?
+        .ok_or_not_found(|| format!("rune number {number}"))?,
+    })
+  }
+
+  async fn rune_holders(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath(rune_query): ParsedPath<DeserializeFromStr<query::Rune>>,
+    accept_json: AcceptJson,
+    pagination: Query<Pagination>,
+  ) -> ServerResult<Response> {
+    Self::rune_holders_paginated(
+      Extension(server_config),
+      Extension(index),
+      ParsedPath((rune_query, 0)),
+      accept_json,
+      pagination,
+    )
+    .await
+  }
+
+  async fn rune_holders_paginated(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath((DeserializeFromStr(rune_query), page_index)): ParsedPath<(
+      DeserializeFromStr<query::Rune>,
+      usize,
+    )>,
+    AcceptJson(accept_json): AcceptJson,
+    Query(pagination): Query<Pagination>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      let page_size = server_config.page_size(pagination.limit, 100, page_index)?;
+
+      let rune = Self::resolve_rune(&index, rune_query)?;
+
+      let (id, entry, _parent) = index
+        .rune(rune)?
+        .ok_or_not_found(|| format!("rune {rune}"))?;
+
+      let (holders, holder_count) = index.get_rune_holders(id, page_size, page_index)?;
+
+      let more = u64::try_from(page_index.saturating_add(1).saturating_mul(page_size))
+        .map(|end| end < holder_count)
+        .unwrap_or_default();
+
+      let holders = api::RuneHolders {
+        spaced_rune: entry.spaced_rune,
+        holder_count,
+        holders: holders
+          .into_iter()
+          .map(|(outpoint, amount)| {
+            (
+              outpoint,
+              Pile {
+                amount,
+                divisibility: entry.divisibility,
+                symbol: entry.symbol,
+              },
+            )
+          })
+          .collect(),
+        more,
+        prev: page_index.checked_sub(1),
+        next: more.then_some(page_index + 1),
+      };
+
+      Ok(if accept_json {
+        Json(holders).into_response()
+      } else {
+        holders.page(server_config).into_response()
+      })
+    })
+  }
+
   async fn runes(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     accept_json: AcceptJson,
+    pagination: Query<Pagination>,
   ) -> ServerResult<Response> {
     Self::runes_paginated(
       Extension(server_config),
       Extension(index),
       Path(0),
       accept_json,
+      pagination,
     )
     .await
   }
@@ -712,17 +1276,24 @@ impl Server {
     Extension(index): Extension<Arc<Index>>,
     Path(page_index): Path<usize>,
     AcceptJson(accept_json): AcceptJson,
+    Query(pagination): Query<Pagination>,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let (entries, more) = index.runes_paginated(50, page_index)?;
+      let (entries, more) = index.runes_paginated(
+        server_config.page_size(pagination.limit, 50, page_index)?,
+        page_index,
+      )?;
 
       let prev = page_index.checked_sub(1);
 
       let next = more.then_some(page_index + 1);
 
       Ok(if accept_json {
-        Json(RunesHtml {
-          entries,
+        Json(api::Runes {
+          entries: entries
+            .into_iter()
+            .map(|(id, entry)| (id, entry.into()))
+            .collect(),
           more,
           prev,
           next,
@@ -744,42 +1315,155 @@ impl Server {
   async fn runes_balances(
     Extension(index): Extension<Arc<Index>>,
     AcceptJson(accept_json): AcceptJson,
+    accept_msgpack: AcceptMsgpack,
+    Query(query): Query<RuneBalancesQuery>,
   ) -> ServerResult {
     task::block_in_place(|| {
       Ok(if accept_json {
-        Json(
+        accept_msgpack.response(
           index
             .get_rune_balance_map()?
             .into_iter()
             .map(|(rune, balances)| {
-              (
-                rune,
-                balances
-                  .into_iter()
-                  .map(|(outpoint, pile)| (outpoint, pile.amount))
-                  .collect(),
-              )
+              let mut balances = balances
+                .into_iter()
+                .map(|(outpoint, pile)| (outpoint, pile.amount))
+                .collect::<Vec<(OutPoint, u128)>>();
+
+              if let BalanceOrder::Amount = query.order {
+                balances.sort_by(|(a_outpoint, a_amount), (b_outpoint, b_amount)| {
+                  b_amount.cmp(a_amount).then(a_outpoint.cmp(b_outpoint))
+                });
+              }
+
+              (rune, OrderedBalances(balances))
             })
-            .collect::<BTreeMap<SpacedRune, BTreeMap<OutPoint, u128>>>(),
+            .collect::<BTreeMap<SpacedRune, OrderedBalances>>(),
         )
-        .into_response()
       } else {
         StatusCode::NOT_FOUND.into_response()
       })
     })
   }
 
+  async fn runes_etched(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((start, end)): Path<(u64, u64)>,
+    AcceptJson(accept_json): AcceptJson,
+    accept_msgpack: AcceptMsgpack,
+    Query(pagination): Query<Pagination>,
+  ) -> ServerResult {
+    Self::runes_etched_paginated(
+      Extension(server_config),
+      Extension(index),
+      Path((start, end, 0)),
+      AcceptJson(accept_json),
+      accept_msgpack,
+      Query(pagination),
+    )
+    .await
+  }
+
+  async fn runes_etched_paginated(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((start, end, page_index)): Path<(u64, u64, usize)>,
+    AcceptJson(accept_json): AcceptJson,
+    accept_msgpack: AcceptMsgpack,
+    Query(pagination): Query<Pagination>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !accept_json {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+      }
+
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      if start > end {
+        return Err(ServerError::BadRequest(format!(
+          "start height {start} is greater than end height {end}"
+        )));
+      }
+
+      if end - start >= MAX_RUNES_ETCHED_WINDOW {
+        return Err(ServerError::BadRequest(format!(
+          "height range may span at most {MAX_RUNES_ETCHED_WINDOW} blocks"
+        )));
+      }
+
+      let (ids, more) = index.runes_etched_between(
+        start,
+        end,
+        server_config.page_size(pagination.limit, 100, page_index)?,
+        page_index,
+      )?;
+
+      Ok(accept_msgpack.response(api::RunesEtched {
+        ids,
+        more,
+        page: page_index,
+      }))
+    })
+  }
+
+  async fn runes_schedule(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    AcceptJson(accept_json): AcceptJson,
+    Query(pagination): Query<Pagination>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !accept_json {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+      }
+
+      let next_height = index.block_count()?;
+
+      let blocks =
+        u32::try_from(server_config.page_size(pagination.limit, 100, 0)?).unwrap_or(u32::MAX);
+
+      Ok(
+        Json(
+          (next_height..next_height.saturating_add(blocks))
+            .map(|height| api::MinimumRune {
+              height,
+              rune: Rune::minimum_at_height(server_config.chain.network(), Height(height)),
+            })
+            .collect::<Vec<api::MinimumRune>>(),
+        )
+        .into_response(),
+      )
+    })
+  }
+
   async fn home(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
   ) -> ServerResult<PageHtml<HomeHtml>> {
     task::block_in_place(|| {
-      Ok(
-        HomeHtml {
-          inscriptions: index.get_home_inscriptions()?,
+      let mut inscriptions = index.get_home_inscriptions()?;
+
+      if !index.hidden_patterns().is_empty() {
+        let mut visible = Vec::new();
+
+        for inscription_id in inscriptions {
+          if !index
+            .get_inscription_by_id(inscription_id)?
+            .is_some_and(|inscription| inscription.matches_hidden_pattern(index.hidden_patterns()))
+          {
+            visible.push(inscription_id);
+          }
         }
-        .page(server_config),
-      )
+
+        inscriptions = visible;
+      }
+
+      Ok(HomeHtml { inscriptions }.page(server_config))
     })
   }
 
@@ -808,15 +1492,16 @@ impl Server {
     })
   }
 
-  async fn install_script() -> Redirect {
-    Redirect::to("https://raw.githubusercontent.com/ordinals/ord/master/install.sh")
+  async fn install_script(Extension(settings): Extension<Arc<Settings>>) -> Redirect {
+    Redirect::to(settings.install_script_url().as_str())
   }
 
   async fn block(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(DeserializeFromStr(query)): Path<DeserializeFromStr<query::Block>>,
+    ParsedPath(DeserializeFromStr(query)): ParsedPath<DeserializeFromStr<query::Block>>,
     AcceptJson(accept_json): AcceptJson,
+    if_modified_since: IfModifiedSince,
   ) -> ServerResult {
     task::block_in_place(|| {
       let (block, height) = match query {
@@ -840,70 +1525,125 @@ impl Server {
         }
       };
 
-      let runes = index.get_runes_in_block(u64::from(height))?;
-      Ok(if accept_json {
-        let inscriptions = index.get_inscriptions_in_block(height)?;
-        Json(api::Block::new(
-          block,
-          Height(height),
-          Self::index_height(&index)?,
-          inscriptions,
-          runes,
-        ))
-        .into_response()
-      } else {
-        let (featured_inscriptions, total_num) =
-          index.get_highest_paying_inscriptions_in_block(height, 8)?;
-        BlockHtml::new(
-          block,
-          Height(height),
-          Self::index_height(&index)?,
-          total_num,
-          featured_inscriptions,
-          runes,
-        )
-        .page(server_config)
-        .into_response()
-      })
+      Self::conditional_response(
+        Self::last_modified(&index, height)?,
+        if_modified_since,
+        || {
+          let runes = index.get_runes_in_block(u64::from(height))?;
+          Ok(if accept_json {
+            let inscriptions = index.get_inscriptions_in_block(height)?;
+            Json(api::Block::new(
+              block,
+              Height(height),
+              Self::index_height(&index)?,
+              inscriptions,
+              runes,
+            ))
+            .into_response()
+          } else {
+            let (featured_inscriptions, total_num) =
+              index.get_highest_paying_inscriptions_in_block(height, 8)?;
+            BlockHtml::new(
+              block,
+              Height(height),
+              Self::index_height(&index)?,
+              total_num,
+              featured_inscriptions,
+              runes,
+            )
+            .page(server_config)
+            .into_response()
+          })
+        },
+      )
     })
   }
 
   async fn transaction(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(txid): Path<Txid>,
+    ParsedPath(txid): ParsedPath<Txid>,
     AcceptJson(accept_json): AcceptJson,
+    if_modified_since: IfModifiedSince,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let transaction = index
-        .get_transaction(txid)?
+      let (transaction, height) = index
+        .get_transaction_with_height(txid)?
         .ok_or_not_found(|| format!("transaction {txid}"))?;
 
-      let inscription_count = index.inscription_count(txid)?;
+      let last_modified = match height {
+        Some(height) => Self::last_modified(&index, height)?,
+        None => None,
+      };
 
-      Ok(if accept_json {
-        Json(api::Transaction {
-          chain: server_config.chain,
-          etching: index.get_etching(txid)?,
-          inscription_count,
-          transaction,
-          txid,
+      Self::conditional_response(last_modified, if_modified_since, || {
+        let inscription_count = index.inscription_count(txid)?;
+
+        Ok(if accept_json {
+          Json(api::Transaction {
+            chain: server_config.chain,
+            etching: index.get_etching(txid)?,
+            inscription_count,
+            transaction,
+            txid,
+          })
+          .into_response()
+        } else {
+          TransactionHtml {
+            chain: server_config.chain,
+            etching: index.get_etching(txid)?,
+            inscription_count,
+            transaction,
+            txid,
+          }
+          .page(server_config)
+          .into_response()
         })
-        .into_response()
-      } else {
-        TransactionHtml {
-          chain: server_config.chain,
-          etching: index.get_etching(txid)?,
-          inscription_count,
-          transaction,
-          txid,
-        }
-        .page(server_config)
-        .into_response()
       })
     })
   }
 
+  fn last_modified(index: &Index, height: u32) -> ServerResult<Option<DateTime<Utc>>> {
+    let confirmations = Self::index_height(index)?
+      .n()
+      .checked_sub(height)
+      .map(|depth| depth + 1)
+      .unwrap_or_default();
+
+    if confirmations < LAST_MODIFIED_MIN_CONFIRMATIONS {
+      return Ok(None);
+    }
+
+    Ok(match index.block_time(Height(height))? {
+      Blocktime::Confirmed(timestamp) => Some(timestamp),
+      Blocktime::Expected(_) => None,
+    })
+  }
+
+  fn conditional_response(
+    last_modified: Option<DateTime<Utc>>,
+    if_modified_since: IfModifiedSince,
+    response: impl FnOnce() -> ServerResult,
+  ) -> ServerResult {
+    let Some(last_modified) = last_modified else {
+      return response();
+    };
+
+    let mut response = if if_modified_since.is_modified(last_modified) {
+      response()?
+    } else {
+      StatusCode::NOT_MODIFIED.into_response()
+    };
+
+    response.headers_mut().insert(
+      header::LAST_MODIFIED,
+      HeaderValue::from_str(&IfModifiedSince::http_date(last_modified))
+        .map_err(|err| ServerError::Internal(Error::from(err)))?,
+    );
+
+    Ok(response)
+  }
+
   async fn update(
     Extension(settings): Extension<Arc<Settings>>,
     Extension(index): Extension<Arc<Index>>,
@@ -920,7 +1660,7 @@ impl Server {
 
   async fn metadata(
     Extension(index): Extension<Arc<Index>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
   ) -> ServerResult<Json<String>> {
     task::block_in_place(|| {
       let metadata = index
@@ -933,62 +1673,265 @@ impl Server {
     })
   }
 
+  async fn content_types(
+    Extension(index): Extension<Arc<Index>>,
+  ) -> ServerResult<Json<Vec<api::ContentTypeCount>>> {
+    task::block_in_place(|| {
+      Ok(Json(
+        index
+          .get_content_type_counts()?
+          .into_iter()
+          .map(|(content_type, count)| api::ContentTypeCount {
+            content_type: content_type
+              .map(|content_type| String::from_utf8_lossy(&content_type).into()),
+            count,
+          })
+          .collect(),
+      ))
+    })
+  }
+
+  async fn reorgs(Extension(index): Extension<Arc<Index>>) -> Json<Vec<api::Reorg>> {
+    Json(index.reorgs())
+  }
+
+  async fn metadata_batch(
+    Extension(index): Extension<Arc<Index>>,
+    Json(inscription_ids): Json<Vec<InscriptionId>>,
+  ) -> ServerResult<Json<BTreeMap<InscriptionId, Option<String>>>> {
+    if inscription_ids.len() > MAX_METADATA_BATCH_SIZE {
+      return Err(ServerError::BadRequest(format!(
+        "metadata batch size {} exceeds maximum of {MAX_METADATA_BATCH_SIZE}",
+        inscription_ids.len()
+      )));
+    }
+
+    let lookups = inscription_ids.into_iter().map(|inscription_id| {
+      let index = index.clone();
+      task::spawn_blocking(move || {
+        let metadata = index
+          .get_inscription_by_id(inscription_id)?
+          .and_then(|inscription| inscription.metadata)
+          .map(hex::encode);
+
+        Ok((inscription_id, metadata))
+      })
+    });
+
+    Ok(Json(
+      futures::future::try_join_all(lookups)
+        .await
+        .map_err(|err| ServerError::Internal(err.into()))?
+        .into_iter()
+        .collect::<ServerResult<_>>()?,
+    ))
+  }
+
   async fn inscription_recursive(
     Extension(index): Extension<Arc<Index>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let inscription = index
-        .get_inscription_by_id(inscription_id)?
-        .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+      Ok(Json(Self::get_inscription_recursive(&index, inscription_id)?).into_response())
+    })
+  }
 
-      let entry = index
-        .get_inscription_entry(inscription_id)
-        .unwrap()
-        .unwrap();
+  fn get_inscription_recursive(
+    index: &Index,
+    inscription_id: InscriptionId,
+  ) -> ServerResult<api::InscriptionRecursive> {
+    let inscription = index
+      .get_inscription_by_id(inscription_id)?
+      .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
 
-      let satpoint = index
-        .get_inscription_satpoint_by_id(inscription_id)
-        .ok()
-        .flatten()
-        .unwrap();
+    let entry = index
+      .get_inscription_entry(inscription_id)
+      .unwrap()
+      .unwrap();
 
-      let output = if satpoint.outpoint == unbound_outpoint() {
-        None
-      } else {
-        Some(
-          index
-            .get_transaction(satpoint.outpoint.txid)?
-            .ok_or_not_found(|| format!("inscription {inscription_id} current transaction"))?
-            .output
-            .into_iter()
-            .nth(satpoint.outpoint.vout.try_into().unwrap())
-            .ok_or_not_found(|| {
-              format!("inscription {inscription_id} current transaction output")
-            })?,
-        )
-      };
+    let satpoint = index
+      .get_inscription_satpoint_by_id(inscription_id)
+      .ok()
+      .flatten()
+      .unwrap();
 
-      Ok(
-        Json(api::InscriptionRecursive {
-          charms: Charm::charms(entry.charms),
-          content_type: inscription.content_type().map(|s| s.to_string()),
-          content_length: inscription.content_length(),
-          fee: entry.fee,
-          height: entry.height,
-          id: inscription_id,
-          number: entry.inscription_number,
-          output: satpoint.outpoint,
-          value: output.as_ref().map(|o| o.value),
-          sat: entry.sat,
-          satpoint,
-          timestamp: timestamp(entry.timestamp.into()).timestamp(),
-        })
-        .into_response(),
+    let output = if is_unbound(satpoint.outpoint) {
+      None
+    } else {
+      Some(
+        index
+          .get_transaction(satpoint.outpoint.txid)?
+          .ok_or_not_found(|| format!("inscription {inscription_id} current transaction"))?
+          .output
+          .into_iter()
+          .nth(satpoint.outpoint.vout.try_into().unwrap())
+          .ok_or_not_found(|| format!("inscription {inscription_id} current transaction output"))?,
+      )
+    };
+
+    Ok(api::InscriptionRecursive {
+      charms: Charm::charms(entry.charms),
+      content_type: inscription.content_type().map(|s| s.to_string()),
+      content_length: inscription.content_length(),
+      fee: entry.fee,
+      height: entry.height,
+      id: inscription_id,
+      number: entry.inscription_number,
+      output: satpoint.outpoint,
+      value: output.as_ref().map(|o| o.value),
+      rarity: entry.sat.map(Sat::rarity),
+      rune: index
+        .get_rune_by_inscription_id(inscription_id)?
+        .map(|(_id, rune)| rune),
+      sat: entry.sat,
+      satpoint,
+      timestamp: timestamp(entry.timestamp.into()).timestamp(),
+    })
+  }
+
+  async fn inscriptions_export(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+  ) -> ServerResult {
+    if !server_config.inscription_export {
+      return Err(ServerError::NotFound(
+        "inscription export is not served by this server".into(),
+      ));
+    }
+
+    let (sender, receiver) = tokio::sync::mpsc::channel::<io::Result<Vec<u8>>>(EXPORT_BUFFER);
+
+    task::spawn_blocking(move || {
+      let mut start = 0;
+
+      loop {
+        if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+          break;
+        }
+
+        let ids = match index.get_inscription_ids_by_sequence_number_range(start, EXPORT_BATCH_SIZE)
+        {
+          Ok(ids) => ids,
+          Err(err) => {
+            sender
+              .blocking_send(Err(io::Error::other(format!(
+                "inscription export failed: {err}"
+              ))))
+              .ok();
+            break;
+          }
+        };
+
+        if ids.is_empty() {
+          break;
+        }
+
+        start += u32::try_from(ids.len()).unwrap();
+
+        for id in ids {
+          let line = Self::get_inscription_recursive(&index, id).and_then(|inscription| {
+            let mut line =
+              serde_json::to_vec(&inscription).map_err(|err| ServerError::Internal(err.into()))?;
+            line.push(b'\n');
+            Ok(line)
+          });
+
+          let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+              sender
+                .blocking_send(Err(io::Error::other(format!(
+                  "inscription export failed at {id}: {err:?}"
+                ))))
+                .ok();
+              return;
+            }
+          };
+
+          if sender.blocking_send(Ok(line)).is_err() {
+            return;
+          }
+        }
+      }
+    });
+
+    Ok(
+      (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        body::StreamBody::new(tokio_stream::wrappers::ReceiverStream::new(receiver)),
       )
+        .into_response(),
+    )
+  }
+
+  async fn inscription_content_type_recursive(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(settings): Extension<Arc<Settings>>,
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
+  ) -> ServerResult<Json<api::InscriptionContentType>> {
+    task::block_in_place(|| {
+      if settings.is_hidden(inscription_id) {
+        return Err(ServerError::NotFound(format!(
+          "inscription {inscription_id} not found"
+        )));
+      }
+
+      let (_, inscription) = index
+        .get_inscription_by_id_resolved(inscription_id, None)?
+        .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+      if inscription.matches_hidden_pattern(index.hidden_patterns()) {
+        return Err(ServerError::NotFound(format!(
+          "inscription {inscription_id} not found"
+        )));
+      }
+
+      let content_type = match server_config.serve_content_type.get(&inscription_id) {
+        Some(content_type) => Some(
+          content_type
+            .to_str()
+            .map_err(|err| anyhow!(err))?
+            .to_string(),
+        ),
+        None => inscription.content_type().map(str::to_string),
+      };
+
+      Ok(Json(api::InscriptionContentType {
+        content_type,
+        content_length: inscription.content_length(),
+      }))
+    })
+  }
+
+  async fn inscription_rune_recursive(
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
+  ) -> ServerResult<Json<api::InscriptionRune>> {
+    task::block_in_place(|| {
+      let (id, rune) = index
+        .get_rune_by_inscription_id(inscription_id)?
+        .ok_or_not_found(|| format!("inscription {inscription_id} rune"))?;
+
+      Ok(Json(api::InscriptionRune { id, rune }))
     })
   }
 
+  async fn openapi(Extension(server_config): Extension<Arc<ServerConfig>>) -> ServerResult {
+    if !server_config.openapi {
+      return Err(ServerError::NotFound(
+        "OpenAPI description is not served by this server".into(),
+      ));
+    }
+
+    let mut spec = serde_json::from_str::<serde_json::Value>(include_str!("server/openapi.json"))
+      .map_err(|err| anyhow!(err))?;
+
+    spec["info"]["version"] = env!("CARGO_PKG_VERSION").into();
+
+    Ok(Json(spec).into_response())
+  }
+
   async fn status(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -1035,6 +1978,28 @@ impl Server {
         Ok(Redirect::to(&format!("/output/{query}")))
       } else if re::INSCRIPTION_ID.is_match(query) || re::INSCRIPTION_NUMBER.is_match(query) {
         Ok(Redirect::to(&format!("/inscription/{query}")))
+      } else if let Some(captures) = re::RUNE_AMOUNT.captures(query) {
+        let decimal = captures[1]
+          .parse::<Decimal>()
+          .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+        let spaced_rune = captures[2]
+          .parse::<SpacedRune>()
+          .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+        if index.has_rune_index() {
+          let (_, entry, _) = index
+            .rune(spaced_rune.rune)?
+            .ok_or_not_found(|| format!("rune {spaced_rune}"))?;
+
+          decimal
+            .to_integer(entry.divisibility)
+            .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+        }
+
+        Ok(Redirect::to(&format!(
+          "/rune/{spaced_rune}?amount={decimal}"
+        )))
       } else if re::SPACED_RUNE.is_match(query) {
         Ok(Redirect::to(&format!("/rune/{query}")))
       } else if re::RUNE_ID.is_match(query) {
@@ -1051,11 +2016,15 @@ impl Server {
     })
   }
 
-  async fn favicon() -> ServerResult {
+  async fn favicon(Extension(server_config): Extension<Arc<ServerConfig>>) -> ServerResult {
     Ok(
-      Self::static_asset(Path("/favicon.png".to_string()))
-        .await
-        .into_response(),
+      Self::static_asset(
+        Extension(server_config),
+        Path("/favicon.png".to_string()),
+        Range::default(),
+      )
+      .await
+      .into_response(),
     )
   }
 
@@ -1074,7 +2043,7 @@ impl Server {
 
       builder.generator(Some("ord".to_string()));
 
-      for (number, id) in index.get_feed_inscriptions(300)? {
+      for (number, id, seconds) in index.get_feed_inscriptions(300)? {
         builder.item(
           rss::ItemBuilder::default()
             .title(Some(format!("Inscription {number}")))
@@ -1083,6 +2052,7 @@ impl Server {
               value: format!("/inscription/{id}"),
               permalink: true,
             }))
+            .pub_date(Some(timestamp(seconds.into()).to_rfc2822()))
             .build(),
         );
       }
@@ -1103,28 +2073,95 @@ impl Server {
     })
   }
 
-  async fn static_asset(Path(path): Path<String>) -> ServerResult {
-    let content = StaticAssets::get(if let Some(stripped) = path.strip_prefix('/') {
-      stripped
-    } else {
-      &path
-    })
-    .ok_or_not_found(|| format!("asset {path}"))?;
-    let body = body::boxed(body::Full::from(content.data));
-    let mime = mime_guess::from_path(path).first_or_octet_stream();
+  async fn static_asset(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Path(path): Path<String>,
+    range: Range,
+  ) -> ServerResult {
+    let relative = path.strip_prefix('/').unwrap_or(&path);
+    let mime = mime_guess::from_path(&path).first_or_octet_stream();
+
+    let Some(mut file) = Self::static_dir_asset(&server_config, relative).await? else {
+      let content = StaticAssets::get(relative).ok_or_not_found(|| format!("asset {path}"))?;
+
+      return Ok(
+        Response::builder()
+          .header(header::CONTENT_TYPE, mime.as_ref())
+          .body(body::boxed(body::Full::from(content.data)))
+          .unwrap(),
+      );
+    };
+
+    let len = file
+      .metadata()
+      .await
+      .with_context(|| format!("failed to read static asset `{relative}` metadata"))?
+      .len();
+
+    let response = Response::builder()
+      .header(header::CONTENT_TYPE, mime.as_ref())
+      .header(header::ACCEPT_RANGES, "bytes");
+
     Ok(
-      Response::builder()
-        .header(header::CONTENT_TYPE, mime.as_ref())
-        .body(body)
-        .unwrap(),
+      match range.resolve(len) {
+        ByteRange::Full => response
+          .header(header::CONTENT_LENGTH, len)
+          .body(body::boxed(body::StreamBody::new(ReaderStream::new(file)))),
+        ByteRange::Partial { start, end } => {
+          file
+            .seek(io::SeekFrom::Start(start))
+            .await
+            .with_context(|| format!("failed to seek static asset `{relative}`"))?;
+
+          response
+            .status(StatusCode::PARTIAL_CONTENT)
+            .header(header::CONTENT_RANGE, format!("bytes {start}-{end}/{len}"))
+            .header(header::CONTENT_LENGTH, end - start + 1)
+            .body(body::boxed(body::StreamBody::new(ReaderStream::new(
+              file.take(end - start + 1),
+            ))))
+        }
+        ByteRange::Unsatisfiable => response
+          .status(StatusCode::RANGE_NOT_SATISFIABLE)
+          .header(header::CONTENT_RANGE, format!("bytes */{len}"))
+          .body(body::boxed(body::Empty::new())),
+      }
+      .unwrap(),
     )
   }
 
-  async fn block_count(Extension(index): Extension<Arc<Index>>) -> ServerResult<String> {
-    task::block_in_place(|| Ok(index.block_count()?.to_string()))
-  }
+  async fn static_dir_asset(
+    server_config: &ServerConfig,
+    relative: &str,
+  ) -> ServerResult<Option<tokio::fs::File>> {
+    let Some(static_dir) = &server_config.static_dir else {
+      return Ok(None);
+    };
 
-  async fn block_height(Extension(index): Extension<Arc<Index>>) -> ServerResult<String> {
+    let Ok(path) = tokio::fs::canonicalize(static_dir.join(relative)).await else {
+      return Ok(None);
+    };
+
+    if !path.starts_with(static_dir) {
+      return Err(ServerError::NotFound(format!(
+        "asset /{relative} not found"
+      )));
+    }
+
+    if !path.is_file() {
+      return Ok(None);
+    }
+
+    Ok(Some(tokio::fs::File::open(&path).await.with_context(
+      || format!("failed to read static asset `{}`", path.display()),
+    )?))
+  }
+
+  async fn block_count(Extension(index): Extension<Arc<Index>>) -> ServerResult<String> {
+    task::block_in_place(|| Ok(index.block_count()?.to_string()))
+  }
+
+  async fn block_height(Extension(index): Extension<Arc<Index>>) -> ServerResult<String> {
     task::block_in_place(|| {
       Ok(
         index
@@ -1185,9 +2222,76 @@ impl Server {
     })
   }
 
+  async fn raw_block_header(
+    Extension(index): Extension<Arc<Index>>,
+    Path(height): Path<u32>,
+  ) -> ServerResult<Json<api::RawBlockHeader>> {
+    task::block_in_place(|| {
+      let header = index
+        .block_header_by_height(height)?
+        .ok_or_not_found(|| format!("block {height}"))?;
+
+      Ok(Json(api::RawBlockHeader {
+        bits: header.bits.to_consensus(),
+        hash: header.block_hash(),
+        height,
+        hex: consensus::encode::serialize_hex(&header),
+        merkle_root: header.merkle_root,
+        nonce: header.nonce,
+        previous_block: header.prev_blockhash,
+        timestamp: header.time,
+        #[allow(clippy::cast_sign_loss)]
+        version: header.version.to_consensus() as u32,
+      }))
+    })
+  }
+
+  async fn block_header(
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath(DeserializeFromStr(query)): ParsedPath<DeserializeFromStr<query::Block>>,
+  ) -> ServerResult<Json<api::BlockHeader>> {
+    task::block_in_place(|| {
+      let hash = match query {
+        query::Block::Hash(hash) => hash,
+        query::Block::Height(height) => index
+          .block_hash(Some(height))?
+          .ok_or_not_found(|| format!("block {height}"))?,
+      };
+
+      let header = index
+        .block_header(hash)?
+        .ok_or_not_found(|| format!("block {hash}"))?;
+
+      let info = index
+        .block_header_info(hash)?
+        .ok_or_not_found(|| format!("block {hash}"))?;
+
+      Ok(Json(api::BlockHeader {
+        bits: header.bits.to_consensus(),
+        chainwork: info.chainwork.try_into().unwrap(),
+        confirmations: info.confirmations,
+        difficulty: info.difficulty,
+        hash,
+        height: info.height.try_into().unwrap(),
+        median_time: info
+          .median_time
+          .map(|median_time| median_time.try_into().unwrap()),
+        merkle_root: info.merkle_root,
+        next_block: info.next_block_hash,
+        nonce: info.nonce,
+        previous_block: info.previous_block_hash,
+        target: target_as_block_hash(header.target()),
+        timestamp: info.time.try_into().unwrap(),
+        transaction_count: info.n_tx.try_into().unwrap(),
+        #[allow(clippy::cast_sign_loss)]
+        version: info.version.to_consensus() as u32,
+      }))
+    })
+  }
+
   async fn block_info(
     Extension(index): Extension<Arc<Index>>,
-    Path(DeserializeFromStr(query)): Path<DeserializeFromStr<query::Block>>,
+    ParsedPath(DeserializeFromStr(query)): ParsedPath<DeserializeFromStr<query::Block>>,
   ) -> ServerResult<Json<api::BlockInfo>> {
     task::block_in_place(|| {
       let hash = match query {
@@ -1209,6 +2313,32 @@ impl Server {
         .block_stats(info.height.try_into().unwrap())?
         .ok_or_not_found(|| format!("block {hash}"))?;
 
+      let height = info.height.try_into().unwrap();
+
+      let inscription_count = if index.has_inscription_index() {
+        Some(
+          index
+            .get_inscriptions_in_block(height)?
+            .len()
+            .try_into()
+            .unwrap(),
+        )
+      } else {
+        None
+      };
+
+      let rune_etching_count = if index.has_rune_index() {
+        Some(
+          index
+            .get_runes_in_block(height.into())?
+            .len()
+            .try_into()
+            .unwrap(),
+        )
+      } else {
+        None
+      };
+
       Ok(Json(api::BlockInfo {
         average_fee: stats.avg_fee.to_sat(),
         average_fee_rate: stats.avg_fee_rate.to_sat(),
@@ -1217,7 +2347,8 @@ impl Server {
         confirmations: info.confirmations,
         difficulty: info.difficulty,
         hash,
-        height: info.height.try_into().unwrap(),
+        height,
+        inscription_count,
         max_fee: stats.max_fee.to_sat(),
         max_fee_rate: stats.max_fee_rate.to_sat(),
         max_tx_size: stats.max_tx_size,
@@ -1231,6 +2362,7 @@ impl Server {
         next_block: info.next_block_hash,
         nonce: info.nonce,
         previous_block: info.previous_block_hash,
+        rune_etching_count,
         subsidy: stats.subsidy.to_sat(),
         target: target_as_block_hash(header.target()),
         timestamp: info.time.try_into().unwrap(),
@@ -1283,19 +2415,47 @@ impl Server {
     })
   }
 
-  async fn faq() -> Redirect {
-    Redirect::to("https://docs.ordinals.com/faq/")
+  async fn faq(Extension(settings): Extension<Arc<Settings>>) -> Redirect {
+    Redirect::to(settings.faq_url().as_str())
   }
 
-  async fn bounties() -> Redirect {
-    Redirect::to("https://docs.ordinals.com/bounty/")
+  async fn bounties(Extension(settings): Extension<Arc<Settings>>) -> Redirect {
+    Redirect::to(settings.bounties_url().as_str())
   }
 
-  fn proxy_content(proxy: &Url, inscription_id: InscriptionId) -> ServerResult<Response> {
-    let response = reqwest::blocking::Client::new()
+  fn proxy_content(
+    server_config: &ServerConfig,
+    proxy: &Url,
+    inscription_id: InscriptionId,
+  ) -> ServerResult<Response> {
+    let proxy_error = |err: reqwest::Error| {
+      if err.is_timeout() {
+        ServerError::GatewayTimeout(format!("content proxy timed out: {err}"))
+      } else {
+        ServerError::BadGateway(format!("content proxy request failed: {err}"))
+      }
+    };
+
+    let response = reqwest::blocking::Client::builder()
+      .timeout(server_config.content_proxy_timeout)
+      .build()
+      .map_err(|err| anyhow!(err))?
       .get(format!("{}content/{}", proxy, inscription_id))
       .send()
-      .map_err(|err| anyhow!(err))?;
+      .map_err(proxy_error)?;
+
+    let max_size = server_config.content_proxy_max_size;
+
+    if response
+      .content_length()
+      .is_some_and(|content_length| content_length > max_size as u64)
+    {
+      return Err(ServerError::BadGateway(format!(
+        "proxied content exceeds maximum size of {max_size} bytes"
+      )));
+    }
+
+    let status = response.status();
 
     let mut headers = response.headers().clone();
 
@@ -1307,21 +2467,36 @@ impl Server {
       .map_err(|err| ServerError::Internal(Error::from(err)))?,
     );
 
-    Ok(
-      (
-        response.status(),
-        headers,
-        response.bytes().map_err(|err| anyhow!(err))?,
-      )
-        .into_response(),
-    )
+    let mut body = Vec::new();
+
+    response
+      .take(max_size as u64 + 1)
+      .read_to_end(&mut body)
+      .map_err(
+        |err| match err.into_inner().map(|err| err.downcast::<reqwest::Error>()) {
+          Some(Ok(err)) => proxy_error(*err),
+          Some(Err(err)) => ServerError::BadGateway(format!("content proxy request failed: {err}")),
+          None => ServerError::BadGateway("content proxy request failed".into()),
+        },
+      )?;
+
+    if body.len() > max_size {
+      return Err(ServerError::BadGateway(format!(
+        "proxied content exceeds maximum size of {max_size} bytes"
+      )));
+    }
+
+    Ok((status, headers, body).into_response())
   }
 
   async fn content(
     Extension(index): Extension<Arc<Index>>,
     Extension(settings): Extension<Arc<Settings>>,
     Extension(server_config): Extension<Arc<ServerConfig>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(DeserializeFromStr(query::Content {
+      inscription_id,
+      extension,
+    })): ParsedPath<DeserializeFromStr<query::Content>>,
     accept_encoding: AcceptEncoding,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -1329,38 +2504,275 @@ impl Server {
         return Ok(PreviewUnknownHtml.into_response());
       }
 
-      let Some(mut inscription) = index.get_inscription_by_id(inscription_id)? else {
+      if !index.inscription_exists(inscription_id)? {
         return if let Some(proxy) = server_config.content_proxy.as_ref() {
-          Self::proxy_content(proxy, inscription_id)
+          Self::proxy_content(&server_config, proxy, inscription_id)
         } else {
           Err(ServerError::NotFound(format!(
             "{} not found",
             inscription_id
           )))
         };
+      }
+
+      let max_size = server_config.max_content_response_size;
+
+      let (resolved_id, inscription) = index
+        .get_inscription_by_id_resolved(inscription_id, Some(max_size.try_into().unwrap()))
+        .map_err(|err| match err.downcast_ref::<ContentTooLarge>() {
+          Some(_) => ServerError::PayloadTooLarge(format!(
+            "inscription {inscription_id} content larger than {max_size} bytes"
+          )),
+          None => ServerError::Internal(err),
+        })?
+        .ok_or_not_found(|| format!("delegate {inscription_id}"))?;
+
+      if inscription.matches_hidden_pattern(index.hidden_patterns()) {
+        return Ok(PreviewUnknownHtml.into_response());
+      }
+
+      if let Some(extension) = extension {
+        if !inscription.content_type().is_some_and(|content_type| {
+          Media::extension_matches_content_type(&extension, content_type)
+        }) {
+          return Err(ServerError::NotFound(format!(
+            "inscription {inscription_id} content with extension `.{extension}` not found"
+          )));
+        }
+      }
+
+      let (headers, body) = Self::content_response(
+        inscription_id,
+        (resolved_id != inscription_id).then_some(resolved_id),
+        inscription,
+        accept_encoding,
+        &server_config,
+      )?
+      .ok_or_not_found(|| format!("inscription {inscription_id} content"))?;
+
+      Ok((headers, body).into_response())
+    })
+  }
+
+  async fn content_by_number(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(settings): Extension<Arc<Settings>>,
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Path(inscription_number): Path<i32>,
+    accept_encoding: AcceptEncoding,
+  ) -> ServerResult {
+    let inscription_id = task::block_in_place(|| {
+      index
+        .get_inscription_id_by_inscription_number(inscription_number)?
+        .ok_or_not_found(|| format!("inscription {inscription_number}"))
+    })?;
+
+    let mut response = Self::content(
+      Extension(index),
+      Extension(settings),
+      Extension(server_config),
+      ParsedPath(DeserializeFromStr(query::Content {
+        inscription_id,
+        extension: None,
+      })),
+      accept_encoding,
+    )
+    .await?;
+
+    // inscription numbers can be reassigned by a reorg, so unlike content
+    // addressed by inscription id, these responses are not immutable
+    if response.status().is_success() {
+      response.headers_mut().insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static("public, max-age=600"),
+      );
+    }
+
+    Ok(response)
+  }
+
+  async fn content_thumbnail(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(settings): Extension<Arc<Settings>>,
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
+    Query(thumbnail): Query<Thumbnail>,
+    accept_encoding: AcceptEncoding,
+  ) -> ServerResult {
+    if !server_config.enable_thumbnails {
+      return Err(ServerError::NotFound(
+        "thumbnails are not served by this server".into(),
+      ));
+    }
+
+    let size = thumbnail.size.unwrap_or(DEFAULT_THUMBNAIL_SIZE);
+
+    if size == 0 || size > MAX_THUMBNAIL_SIZE {
+      return Err(ServerError::BadRequest(format!(
+        "thumbnail size must be between 1 and {MAX_THUMBNAIL_SIZE}"
+      )));
+    }
+
+    let thumbnail = task::block_in_place(|| {
+      if settings.is_hidden(inscription_id) {
+        return Ok(None);
+      }
+
+      let key = (inscription_id, size);
+
+      if let Some(thumbnail) = server_config.thumbnail_cache.lock().unwrap().get(&key) {
+        return Ok(Some(thumbnail));
+      }
+
+      let Some((_, inscription)) = index.get_inscription_by_id_resolved(inscription_id, None)?
+      else {
+        return Ok(None);
       };
 
-      if let Some(delegate) = inscription.delegate() {
-        inscription = index
-          .get_inscription_by_id(delegate)?
-          .ok_or_not_found(|| format!("delegate {inscription_id}"))?
+      if inscription.matches_hidden_pattern(index.hidden_patterns()) {
+        return Ok(None);
       }
 
-      Ok(
-        Self::content_response(inscription, accept_encoding, &server_config)?
-          .ok_or_not_found(|| format!("inscription {inscription_id} content"))?
+      let thumbnail = Self::thumbnail(&inscription, size);
+
+      if let Some(thumbnail) = &thumbnail {
+        server_config
+          .thumbnail_cache
+          .lock()
+          .unwrap()
+          .insert(key, thumbnail.clone());
+      }
+
+      Ok::<_, ServerError>(thumbnail)
+    })?;
+
+    match thumbnail {
+      Some(thumbnail) => Ok(
+        (
+          [
+            (header::CONTENT_TYPE, "image/jpeg"),
+            (header::CACHE_CONTROL, "public, max-age=1209600, immutable"),
+          ],
+          thumbnail,
+        )
           .into_response(),
+      ),
+      None => {
+        Self::content(
+          Extension(index),
+          Extension(settings),
+          Extension(server_config),
+          ParsedPath(DeserializeFromStr(query::Content {
+            inscription_id,
+            extension: None,
+          })),
+          accept_encoding,
+        )
+        .await
+      }
+    }
+  }
+
+  // Returns `None` if the inscription is not a raster image that can be
+  // safely decoded and downscaled, in which case the original is served.
+  fn thumbnail(inscription: &Inscription, size: u32) -> Option<Vec<u8>> {
+    if inscription.content_encoding().is_some() {
+      return None;
+    }
+
+    let format = image::ImageFormat::from_mime_type(inscription.content_type()?)?;
+
+    let mut reader = image::io::Reader::with_format(io::Cursor::new(inscription.body()?), format);
+
+    let mut limits = image::io::Limits::default();
+    limits.max_image_width = Some(MAX_THUMBNAIL_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_THUMBNAIL_SOURCE_DIMENSION);
+    reader.limits(limits);
+
+    let image = reader.decode().ok()?;
+
+    if image.width() <= size && image.height() <= size {
+      return None;
+    }
+
+    let mut thumbnail = Vec::new();
+
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut thumbnail, 80)
+      .encode_image(&image.thumbnail(size, size).to_rgb8())
+      .ok()?;
+
+    Some(thumbnail)
+  }
+
+  async fn ipfs(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(settings): Extension<Arc<Settings>>,
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Path(cid): Path<Cid>,
+    accept_encoding: AcceptEncoding,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !server_config.ipfs {
+        return Err(ServerError::NotFound(
+          "IPFS content is not served by this server".into(),
+        ));
+      }
+
+      if !index.has_cid_index() {
+        return Err(ServerError::NotFound("this server has no CID index".into()));
+      }
+
+      let inscription_id = index
+        .get_inscription_id_by_cid(cid)?
+        .ok_or_not_found(|| format!("CID {cid}"))?;
+
+      if settings.is_hidden(inscription_id) {
+        return Ok(PreviewUnknownHtml.into_response());
+      }
+
+      let inscription = index
+        .get_inscription_by_id(inscription_id)?
+        .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+      if inscription.matches_hidden_pattern(index.hidden_patterns()) {
+        return Ok(PreviewUnknownHtml.into_response());
+      }
+
+      Ok(
+        Self::content_response(
+          inscription_id,
+          None,
+          inscription,
+          accept_encoding,
+          &server_config,
+        )?
+        .ok_or_not_found(|| format!("CID {cid} content"))?
+        .into_response(),
       )
     })
   }
 
   fn content_response(
+    inscription_id: InscriptionId,
+    delegate_id: Option<InscriptionId>,
     inscription: Inscription,
     accept_encoding: AcceptEncoding,
     server_config: &ServerConfig,
   ) -> ServerResult<Option<(HeaderMap, Vec<u8>)>> {
     let mut headers = HeaderMap::new();
 
+    headers.insert(
+      X_INSCRIPTION_ID,
+      HeaderValue::from_str(&inscription_id.to_string()).unwrap(),
+    );
+
+    if let Some(delegate_id) = delegate_id {
+      headers.insert(
+        X_DELEGATE_ID,
+        HeaderValue::from_str(&delegate_id.to_string()).unwrap(),
+      );
+    }
+
     match &server_config.csp_origin {
       None => {
         headers.insert(
@@ -1394,6 +2806,10 @@ impl Server {
         .unwrap_or(HeaderValue::from_static("application/octet-stream")),
     );
 
+    if let Some(content_type) = server_config.serve_content_type.get(&inscription_id) {
+      headers.insert(header::CONTENT_TYPE, content_type.clone());
+    }
+
     if let Some(content_encoding) = inscription.content_encoding() {
       if accept_encoding.is_acceptable(&content_encoding) {
         headers.insert(header::CONTENT_ENCODING, content_encoding);
@@ -1402,12 +2818,27 @@ impl Server {
           return Ok(None);
         };
 
+        let max_size = server_config
+          .max_decompressed_size
+          .min(server_config.max_content_response_size);
+
         let mut decompressed = Vec::new();
 
         Decompressor::new(body.as_slice(), 4096)
+          .take(
+            u64::try_from(max_size)
+              .unwrap_or(u64::MAX)
+              .saturating_add(1),
+          )
           .read_to_end(&mut decompressed)
           .map_err(|err| ServerError::Internal(err.into()))?;
 
+        if decompressed.len() > max_size {
+          return Err(ServerError::PayloadTooLarge(format!(
+            "decompressed content larger than {max_size} bytes"
+          )));
+        }
+
         return Ok(Some((headers, decompressed)));
       } else {
         return Err(ServerError::NotAcceptable {
@@ -1428,7 +2859,7 @@ impl Server {
     Extension(index): Extension<Arc<Index>>,
     Extension(settings): Extension<Arc<Settings>>,
     Extension(server_config): Extension<Arc<ServerConfig>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
     accept_encoding: AcceptEncoding,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -1436,23 +2867,33 @@ impl Server {
         return Ok(PreviewUnknownHtml.into_response());
       }
 
-      let mut inscription = index
-        .get_inscription_by_id(inscription_id)?
-        .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+      if !index.inscription_exists(inscription_id)? {
+        return Err(ServerError::NotFound(format!(
+          "inscription {inscription_id} not found"
+        )));
+      }
+
+      let (resolved_id, inscription) = index
+        .get_inscription_by_id_resolved(inscription_id, None)?
+        .ok_or_not_found(|| format!("delegate {inscription_id}"))?;
 
-      if let Some(delegate) = inscription.delegate() {
-        inscription = index
-          .get_inscription_by_id(delegate)?
-          .ok_or_not_found(|| format!("delegate {inscription_id}"))?
+      if inscription.matches_hidden_pattern(index.hidden_patterns()) {
+        return Ok(PreviewUnknownHtml.into_response());
       }
 
       let media = inscription.media();
 
       if let Media::Iframe = media {
         return Ok(
-          Self::content_response(inscription, accept_encoding, &server_config)?
-            .ok_or_not_found(|| format!("inscription {inscription_id} content"))?
-            .into_response(),
+          Self::content_response(
+            inscription_id,
+            (resolved_id != inscription_id).then_some(resolved_id),
+            inscription,
+            accept_encoding,
+            &server_config,
+          )?
+          .ok_or_not_found(|| format!("inscription {inscription_id} content"))?
+          .into_response(),
         );
       }
 
@@ -1513,7 +2954,7 @@ impl Server {
   async fn inscription(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(DeserializeFromStr(query)): Path<DeserializeFromStr<query::Inscription>>,
+    ParsedPath(DeserializeFromStr(query)): ParsedPath<DeserializeFromStr<query::Inscription>>,
     AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -1560,6 +3001,7 @@ impl Server {
   async fn inscriptions_json(
     Extension(index): Extension<Arc<Index>>,
     _: AcceptJson,
+    accept_msgpack: AcceptMsgpack,
     Json(inscriptions): Json<Vec<InscriptionId>>,
   ) -> ServerResult {
     task::block_in_place(|| {
@@ -1573,24 +3015,35 @@ impl Server {
         response.push(info);
       }
 
-      Ok(Json(response).into_response())
+      Ok(accept_msgpack.response(response))
     })
   }
 
   async fn collections(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
+    pagination: Query<Pagination>,
   ) -> ServerResult {
-    Self::collections_paginated(Extension(server_config), Extension(index), Path(0)).await
+    Self::collections_paginated(
+      Extension(server_config),
+      Extension(index),
+      Path(0),
+      pagination,
+    )
+    .await
   }
 
   async fn collections_paginated(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(page_index): Path<usize>,
+    Query(pagination): Query<Pagination>,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let (collections, more_collections) = index.get_collections_paginated(100, page_index)?;
+      let (collections, more_collections) = index.get_collections_paginated(
+        server_config.page_size(pagination.limit, 100, page_index)?,
+        page_index,
+      )?;
 
       let prev = page_index.checked_sub(1);
 
@@ -1611,12 +3064,14 @@ impl Server {
   async fn children(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
+    pagination: Query<Pagination>,
   ) -> ServerResult {
     Self::children_paginated(
       Extension(server_config),
       Extension(index),
-      Path((inscription_id, 0)),
+      ParsedPath((inscription_id, 0)),
+      pagination,
     )
     .await
   }
@@ -1624,7 +3079,8 @@ impl Server {
   async fn children_paginated(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path((parent, page)): Path<(InscriptionId, usize)>,
+    ParsedPath((parent, page)): ParsedPath<(InscriptionId, usize)>,
+    Query(pagination): Query<Pagination>,
   ) -> ServerResult {
     task::block_in_place(|| {
       let entry = index
@@ -1633,8 +3089,11 @@ impl Server {
 
       let parent_number = entry.inscription_number;
 
-      let (children, more_children) =
-        index.get_children_by_sequence_number_paginated(entry.sequence_number, 100, page)?;
+      let (children, more_children) = index.get_children_by_sequence_number_paginated(
+        entry.sequence_number,
+        server_config.page_size(pagination.limit, 100, page)?,
+        page,
+      )?;
 
       let prev_page = page.checked_sub(1);
 
@@ -1655,15 +3114,25 @@ impl Server {
   }
 
   async fn children_recursive(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
+    pagination: Query<Pagination>,
   ) -> ServerResult {
-    Self::children_recursive_paginated(Extension(index), Path((inscription_id, 0))).await
+    Self::children_recursive_paginated(
+      Extension(server_config),
+      Extension(index),
+      ParsedPath((inscription_id, 0)),
+      pagination,
+    )
+    .await
   }
 
   async fn children_recursive_paginated(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path((parent, page)): Path<(InscriptionId, usize)>,
+    ParsedPath((parent, page)): ParsedPath<(InscriptionId, usize)>,
+    Query(pagination): Query<Pagination>,
   ) -> ServerResult {
     task::block_in_place(|| {
       let parent_sequence_number = index
@@ -1671,35 +3140,70 @@ impl Server {
         .ok_or_not_found(|| format!("inscription {parent}"))?
         .sequence_number;
 
-      let (ids, more) =
-        index.get_children_by_sequence_number_paginated(parent_sequence_number, 100, page)?;
+      let (ids, more) = index.get_children_by_sequence_number_paginated(
+        parent_sequence_number,
+        server_config.page_size(pagination.limit, 100, page)?,
+        page,
+      )?;
 
       Ok(Json(api::Children { ids, more, page }).into_response())
     })
   }
 
-  async fn inscriptions(
-    Extension(server_config): Extension<Arc<ServerConfig>>,
+  async fn children_count_recursive(
     Extension(index): Extension<Arc<Index>>,
-    accept_json: AcceptJson,
+    ParsedPath(parent): ParsedPath<InscriptionId>,
   ) -> ServerResult {
-    Self::inscriptions_paginated(
-      Extension(server_config),
-      Extension(index),
-      Path(0),
-      accept_json,
-    )
-    .await
-  }
+    task::block_in_place(|| {
+      let parent_sequence_number = index
+        .get_inscription_entry(parent)?
+        .ok_or_not_found(|| format!("inscription {parent}"))?
+        .sequence_number;
+
+      let count = index.get_children_count_by_sequence_number(parent_sequence_number)?;
+
+      Ok(Json(api::Count { count }).into_response())
+    })
+  }
+
+  async fn inscriptions(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    accept_json: AcceptJson,
+    pagination: Query<Pagination>,
+  ) -> ServerResult {
+    Self::inscriptions_paginated(
+      Extension(server_config),
+      Extension(index),
+      Path(0),
+      accept_json,
+      pagination,
+    )
+    .await
+  }
 
   async fn inscriptions_paginated(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(page_index): Path<u32>,
     AcceptJson(accept_json): AcceptJson,
+    Query(pagination): Query<Pagination>,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let (inscriptions, more) = index.get_inscriptions_paginated(100, page_index)?;
+      let page_size = u32::try_from(server_config.page_size(
+        pagination.limit,
+        100,
+        page_index.try_into().unwrap_or(usize::MAX),
+      )?)
+      .unwrap_or(u32::MAX);
+
+      if page_index.checked_mul(page_size).is_none() {
+        return Err(ServerError::BadRequest(format!(
+          "page {page_index} is out of range"
+        )));
+      }
+
+      let (inscriptions, more) = index.get_inscriptions_paginated(page_size, page_index)?;
 
       let prev = page_index.checked_sub(1);
 
@@ -1788,12 +3292,12 @@ impl Server {
   async fn parents(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(inscription_id): Path<InscriptionId>,
+    ParsedPath(inscription_id): ParsedPath<InscriptionId>,
   ) -> ServerResult<Response> {
     Self::parents_paginated(
       Extension(server_config),
       Extension(index),
-      Path((inscription_id, 0)),
+      ParsedPath((inscription_id, 0)),
     )
     .await
   }
@@ -1801,7 +3305,7 @@ impl Server {
   async fn parents_paginated(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path((id, page)): Path<(InscriptionId, usize)>,
+    ParsedPath((id, page)): ParsedPath<(InscriptionId, usize)>,
   ) -> ServerResult<Response> {
     task::block_in_place(|| {
       let child = index
@@ -1830,14 +3334,14 @@ impl Server {
 
   async fn sat_inscriptions(
     Extension(index): Extension<Arc<Index>>,
-    Path(sat): Path<u64>,
+    ParsedPath(sat): ParsedPath<DeserializeFromStr<Sat>>,
   ) -> ServerResult<Json<api::SatInscriptions>> {
-    Self::sat_inscriptions_paginated(Extension(index), Path((sat, 0))).await
+    Self::sat_inscriptions_paginated(Extension(index), ParsedPath((sat, 0))).await
   }
 
   async fn sat_inscriptions_paginated(
     Extension(index): Extension<Arc<Index>>,
-    Path((sat, page)): Path<(u64, u64)>,
+    ParsedPath((DeserializeFromStr(sat), page)): ParsedPath<(DeserializeFromStr<Sat>, u64)>,
   ) -> ServerResult<Json<api::SatInscriptions>> {
     task::block_in_place(|| {
       if !index.has_sat_index() {
@@ -1846,7 +3350,7 @@ impl Server {
         ));
       }
 
-      let (ids, more) = index.get_inscription_ids_by_sat_paginated(Sat(sat), 100, page)?;
+      let (ids, more) = index.get_inscription_ids_by_sat_paginated(sat, 100, page)?;
 
       Ok(Json(api::SatInscriptions { ids, more, page }))
     })
@@ -1854,7 +3358,10 @@ impl Server {
 
   async fn sat_inscription_at_index(
     Extension(index): Extension<Arc<Index>>,
-    Path((DeserializeFromStr(sat), inscription_index)): Path<(DeserializeFromStr<Sat>, isize)>,
+    ParsedPath((DeserializeFromStr(sat), inscription_index)): ParsedPath<(
+      DeserializeFromStr<Sat>,
+      isize,
+    )>,
   ) -> ServerResult<Json<api::SatInscription>> {
     task::block_in_place(|| {
       if !index.has_sat_index() {
@@ -1869,6 +3376,40 @@ impl Server {
     })
   }
 
+  async fn sat_inscription_count(
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath(DeserializeFromStr(sat)): ParsedPath<DeserializeFromStr<Sat>>,
+  ) -> ServerResult<Json<api::Count>> {
+    task::block_in_place(|| {
+      if !index.has_sat_index() {
+        return Err(ServerError::NotFound(
+          "this server has no sat index".to_string(),
+        ));
+      }
+
+      let count = index.get_inscription_count_by_sat(sat)?;
+
+      Ok(Json(api::Count { count }))
+    })
+  }
+
+  async fn sat_satpoint_recursive(
+    Extension(index): Extension<Arc<Index>>,
+    ParsedPath(DeserializeFromStr(sat)): ParsedPath<DeserializeFromStr<Sat>>,
+  ) -> ServerResult<Json<api::SatLocation>> {
+    task::block_in_place(|| {
+      if !index.has_sat_index() {
+        return Err(ServerError::NotFound(
+          "this server has no sat index".to_string(),
+        ));
+      }
+
+      Ok(Json(api::SatLocation {
+        satpoint: index.get_sat_satpoint(sat)?,
+      }))
+    })
+  }
+
   async fn redirect_http_to_https(
     Extension(mut destination): Extension<String>,
     uri: Uri,
@@ -1884,7 +3425,8 @@ impl Server {
 #[cfg(test)]
 mod tests {
   use {
-    super::*, reqwest::Url, serde::de::DeserializeOwned, std::net::TcpListener, tempfile::TempDir,
+    super::*, crate::subcommand::find::FindRangeOutput, reqwest::Url, serde::de::DeserializeOwned,
+    std::net::TcpListener, tempfile::TempDir,
   };
 
   const RUNE: u128 = 99246114928149462;
@@ -2156,6 +3698,23 @@ mod tests {
       reqwest::blocking::get(self.join_url(path.as_ref())).unwrap()
     }
 
+    #[track_caller]
+    fn post_json(
+      &self,
+      path: impl AsRef<str>,
+      body: &impl Serialize,
+    ) -> reqwest::blocking::Response {
+      if let Err(error) = self.index.update() {
+        log::error!("{error}");
+      }
+
+      reqwest::blocking::Client::new()
+        .post(self.join_url(path.as_ref()))
+        .json(body)
+        .send()
+        .unwrap()
+    }
+
     #[track_caller]
     pub(crate) fn get_json<T: DeserializeOwned>(&self, path: impl AsRef<str>) -> T {
       if let Err(error) = self.index.update() {
@@ -2278,6 +3837,48 @@ mod tests {
     assert_eq!(parse_server_args("ord server").1.http_port(), Some(80));
   }
 
+  #[test]
+  fn http_port_defaults_to_none_with_unix_socket() {
+    assert_eq!(
+      parse_server_args("ord server --listen-unix-socket ord.sock")
+        .1
+        .http_port(),
+      None
+    );
+    assert_eq!(
+      parse_server_args("ord server --listen-unix-socket ord.sock --http")
+        .1
+        .http_port(),
+      Some(80)
+    );
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn listen_unix_socket() {
+    use std::{
+      io::{Read, Write},
+      os::unix::net::UnixStream,
+    };
+
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("ord.sock");
+
+    let _server = TestServer::builder()
+      .server_option("--listen-unix-socket", path.to_str().unwrap())
+      .build();
+
+    let mut stream = UnixStream::connect(&path).unwrap();
+    stream
+      .write_all(b"GET /status HTTP/1.0\r\nHost: localhost\r\n\r\n")
+      .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.0 200 OK"), "{response}");
+  }
+
   #[test]
   fn https_port_defaults_to_none() {
     assert_eq!(parse_server_args("ord server").1.https_port(), None);
@@ -2458,6 +4059,23 @@ mod tests {
     TestServer::new().assert_redirect("/faq", "https://docs.ordinals.com/faq/");
   }
 
+  #[test]
+  fn redirect_destinations_are_configurable() {
+    let server = TestServer::builder()
+      .config(
+        "
+bounties_url: https://docs.example.com/bounty/
+faq_url: https://docs.example.com/faq/
+install_script_url: https://example.com/install.sh
+",
+      )
+      .build();
+
+    server.assert_redirect("/bounties", "https://docs.example.com/bounty/");
+    server.assert_redirect("/faq", "https://docs.example.com/faq/");
+    server.assert_redirect("/install.sh", "https://example.com/install.sh");
+  }
+
   #[test]
   fn search_by_query_returns_rune() {
     TestServer::new().assert_redirect("/search?query=ABCD", "/rune/ABCD");
@@ -2534,7 +4152,13 @@ mod tests {
   }
 
   #[test]
-  fn search_by_rune_id_returns_rune() {
+  fn search_by_rune_amount_returns_rune() {
+    TestServer::new().assert_redirect("/search/100 AB•CD", "/rune/AB•CD?amount=100");
+    TestServer::new().assert_redirect("/search?query=1.5:AB•CD", "/rune/AB•CD?amount=1.5");
+  }
+
+  #[test]
+  fn search_by_rune_amount_checks_divisibility() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -2544,7 +4168,11 @@ mod tests {
 
     let rune = Rune(RUNE);
 
-    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
+    server.assert_response(
+      format!("/search/1 {rune}"),
+      StatusCode::NOT_FOUND,
+      &format!("rune {rune} not found"),
+    );
 
     server.etch(
       Runestone {
@@ -2554,6 +4182,7 @@ mod tests {
           output: 0,
         }],
         etching: Some(Etching {
+          divisibility: Some(1),
           rune: Some(rune),
           ..default()
         }),
@@ -2565,64 +4194,32 @@ mod tests {
 
     server.mine_blocks(1);
 
-    server.assert_redirect("/search/8:1", "/rune/AAAAAAAAAAAAA");
-    server.assert_redirect("/search?query=8:1", "/rune/AAAAAAAAAAAAA");
+    server.assert_redirect(
+      &format!("/search/1.5 {rune}"),
+      &format!("/rune/{rune}?amount=1.5"),
+    );
 
     server.assert_response_regex(
-      "/search/100000000000000000000:200000000000000000",
-      StatusCode::BAD_REQUEST,
-      ".*",
+      format!("/rune/{rune}?amount=1.5"),
+      StatusCode::OK,
+      ".*<dl>\n  <dt>amount</dt>\n  <dd>1.5\u{A0}.</dd>.*",
     );
-  }
 
-  #[test]
-  fn html_runes_balances_not_found() {
-    TestServer::builder()
-      .chain(Chain::Regtest)
-      .build()
-      .assert_response("/runes/balances", StatusCode::NOT_FOUND, "");
-  }
-
-  #[test]
-  fn fallback() {
-    let server = TestServer::new();
-
-    server.assert_redirect("/0", "/inscription/0");
-    server.assert_redirect("/0/", "/inscription/0");
-    server.assert_redirect("/0//", "/inscription/0");
-    server.assert_redirect(
-      "/521f8eccffa4c41a3a7728dd012ea5a4a02feed81f41159231251ecf1e5c79dai0",
-      "/inscription/521f8eccffa4c41a3a7728dd012ea5a4a02feed81f41159231251ecf1e5c79dai0",
-    );
-    server.assert_redirect("/-1", "/inscription/-1");
-    server.assert_redirect("/FOO", "/rune/FOO");
-    server.assert_redirect("/FO.O", "/rune/FO.O");
-    server.assert_redirect("/FO•O", "/rune/FO•O");
-    server.assert_redirect("/0:0", "/rune/0:0");
-    server.assert_redirect(
-      "/4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
-      "/output/4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
-    );
-    server.assert_redirect(
-      "/search/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
-      "/block/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
-    );
-    server.assert_redirect(
-      "/search/0000000000000000000000000000000000000000000000000000000000000000",
-      "/tx/0000000000000000000000000000000000000000000000000000000000000000",
+    server.assert_response(
+      format!("/rune/{rune}?amount=1.55"),
+      StatusCode::BAD_REQUEST,
+      "excessive precision",
     );
 
-    server.assert_response_regex("/hello", StatusCode::NOT_FOUND, "");
-
-    server.assert_response_regex(
-      "/%C3%28",
+    server.assert_response(
+      format!("/search/1.55 {rune}"),
       StatusCode::BAD_REQUEST,
-      "invalid utf-8 sequence of 1 bytes from index 0",
+      "excessive precision",
     );
   }
 
   #[test]
-  fn runes_can_be_queried_by_rune_id() {
+  fn search_by_rune_id_returns_rune() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -2632,7 +4229,7 @@ mod tests {
 
     let rune = Rune(RUNE);
 
-    server.assert_response_regex("/rune/9:1", StatusCode::NOT_FOUND, ".*");
+    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
 
     server.etch(
       Runestone {
@@ -2653,138 +4250,177 @@ mod tests {
 
     server.mine_blocks(1);
 
+    server.assert_redirect("/search/8:1", "/rune/AAAAAAAAAAAAA");
+    server.assert_redirect("/search?query=8:1", "/rune/AAAAAAAAAAAAA");
+
     server.assert_response_regex(
-      "/rune/8:1",
-      StatusCode::OK,
-      ".*<title>Rune AAAAAAAAAAAAA</title>.*",
+      "/search/100000000000000000000:200000000000000000",
+      StatusCode::BAD_REQUEST,
+      ".*",
     );
   }
 
   #[test]
-  fn runes_can_be_queried_by_rune_number() {
-    let server = TestServer::builder()
+  fn html_runes_balances_not_found() {
+    TestServer::builder()
       .chain(Chain::Regtest)
-      .index_runes()
-      .build();
+      .build()
+      .assert_response("/runes/balances", StatusCode::NOT_FOUND, "");
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn runes_schedule() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    server.assert_response_regex("/rune/0", StatusCode::NOT_FOUND, ".*");
+    server.assert_response("/runes/schedule", StatusCode::NOT_FOUND, "");
 
-    for i in 0..10 {
-      let rune = Rune(RUNE + i);
-      server.etch(
-        Runestone {
-          edicts: vec![Edict {
-            id: RuneId::default(),
-            amount: u128::MAX,
-            output: 0,
-          }],
-          etching: Some(Etching {
-            rune: Some(rune),
-            ..default()
-          }),
-          ..default()
+    pretty_assert_eq!(
+      server.get_json::<Vec<api::MinimumRune>>("/runes/schedule?limit=2"),
+      vec![
+        api::MinimumRune {
+          height: 1,
+          rune: "ZZXZUDIVTVQA".parse().unwrap(),
         },
-        1,
-        None,
-      );
+        api::MinimumRune {
+          height: 2,
+          rune: "ZZWZRFAGQTKZ".parse().unwrap(),
+        },
+      ],
+    );
 
-      server.mine_blocks(1);
-    }
+    server.mine_blocks(1);
 
-    server.assert_response_regex(
-      "/rune/0",
-      StatusCode::OK,
-      ".*<title>Rune AAAAAAAAAAAAA</title>.*",
+    assert_eq!(
+      server.get_json::<Vec<api::MinimumRune>>("/runes/schedule?limit=1"),
+      vec![api::MinimumRune {
+        height: 2,
+        rune: "ZZWZRFAGQTKZ".parse().unwrap(),
+      }],
     );
 
-    for i in 1..6 {
-      server.assert_response_regex(
-        format!("/rune/{}", i),
-        StatusCode::OK,
-        ".*<title>Rune AAAAAAAAAAAA.*</title>.*",
-      );
-    }
-
-    server.assert_response_regex(
-      "/rune/9",
-      StatusCode::OK,
-      ".*<title>Rune AAAAAAAAAAAAJ</title>.*",
+    assert_eq!(
+      server
+        .get_json::<Vec<api::MinimumRune>>("/runes/schedule")
+        .len(),
+      100,
     );
   }
 
   #[test]
-  fn runes_are_displayed_on_runes_page() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_runes()
-      .build();
+  fn content_types() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      "/runes",
-      StatusCode::OK,
-      ".*<title>Runes</title>.*<h1>Runes</h1>\n<ul>\n</ul>\n<div class=center>\n    prev\n      next\n  </div>.*",
+    assert_eq!(
+      server.get_json::<Vec<api::ContentTypeCount>>("/r/content-types"),
+      Vec::new(),
     );
 
-    let (txid, id) = server.etch(
-      Runestone {
-        edicts: vec![Edict {
-          id: RuneId::default(),
-          amount: u128::MAX,
-          output: 0,
-        }],
-        etching: Some(Etching {
-          rune: Some(Rune(RUNE)),
-          symbol: Some('%'),
-          premine: Some(u128::MAX),
-          ..default()
-        }),
+    for (height, content_type) in ["text/plain", "image/png", "text/plain"]
+      .into_iter()
+      .enumerate()
+    {
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          height + 1,
+          0,
+          0,
+          inscription(content_type, "foo").to_witness(),
+        )],
         ..default()
-      },
-      1,
-      Default::default(),
+      });
+
+      server.mine_blocks(1);
+    }
+
+    assert_eq!(
+      server.get_json::<Vec<api::ContentTypeCount>>("/r/content-types"),
+      [
+        api::ContentTypeCount {
+          content_type: Some("text/plain".into()),
+          count: 2,
+        },
+        api::ContentTypeCount {
+          content_type: Some("image/png".into()),
+          count: 1,
+        },
+      ],
     );
+  }
 
-    pretty_assert_eq!(
-      server.index.runes().unwrap(),
-      [(
-        id,
-        RuneEntry {
-          block: id.block,
-          etching: txid,
-          spaced_rune: SpacedRune {
-            rune: Rune(RUNE),
-            spacers: 0
+  #[test]
+  fn reorgs() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(1);
+
+    assert_eq!(server.get_json::<Vec<api::Reorg>>("/r/reorgs"), Vec::new());
+
+    assert!(!server
+      .get_json::<serde_json::Value>("/status")
+      .as_object()
+      .unwrap()
+      .contains_key("reorgs"));
+  }
+
+  #[test]
+  fn runes_balances_can_be_ordered_by_amount() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let (txid, _id) = server.etch(
+      Runestone {
+        edicts: vec![
+          Edict {
+            id: RuneId::default(),
+            amount: 100,
+            output: 0,
           },
-          premine: u128::MAX,
-          timestamp: id.block,
-          symbol: Some('%'),
+          Edict {
+            id: RuneId::default(),
+            amount: 900,
+            output: 1,
+          },
+        ],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(1000),
           ..default()
-        }
-      )]
+        }),
+        ..default()
+      },
+      2,
+      None,
     );
 
+    let get = |path: &str| {
+      reqwest::blocking::Client::new()
+        .get(server.join_url(path))
+        .header(header::ACCEPT, "application/json")
+        .send()
+        .unwrap()
+        .text()
+        .unwrap()
+    };
+
     assert_eq!(
-      server.index.get_rune_balances().unwrap(),
-      [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
+      get("/runes/balances"),
+      format!(r#"{{"AAAAAAAAAAAAA":{{"{txid}:0":100,"{txid}:1":900}}}}"#),
     );
 
-    server.assert_response_regex(
-      "/runes",
-      StatusCode::OK,
-      ".*<title>Runes</title>.*
-<h1>Runes</h1>
-<ul>
-  <li><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></li>
-</ul>.*",
+    assert_eq!(
+      get("/runes/balances?order=amount"),
+      format!(r#"{{"AAAAAAAAAAAAA":{{"{txid}:1":900,"{txid}:0":100}}}}"#),
     );
   }
 
   #[test]
-  fn runes_are_displayed_on_rune_page() {
+  fn runes_balances_can_be_requested_as_msgpack() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -2792,118 +4428,154 @@ mod tests {
 
     server.mine_blocks(1);
 
-    let rune = Rune(RUNE);
-
-    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
-
-    let (txid, id) = server.etch(
+    let (txid, _id) = server.etch(
       Runestone {
         edicts: vec![Edict {
           id: RuneId::default(),
-          amount: u128::MAX,
+          amount: 1000,
           output: 0,
         }],
         etching: Some(Etching {
-          rune: Some(rune),
-          symbol: Some('%'),
-          premine: Some(u128::MAX),
-          turbo: true,
+          rune: Some(Rune(RUNE)),
+          premine: Some(1000),
           ..default()
         }),
         ..default()
       },
       1,
-      Some(
-        Inscription {
-          content_type: Some("text/plain".into()),
-          body: Some("hello".into()),
-          rune: Some(rune.commitment()),
-          ..default()
-        }
-        .to_witness(),
-      ),
+      None,
     );
 
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/runes/balances"))
+      .header(header::ACCEPT, AcceptMsgpack::CONTENT_TYPE)
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
     assert_eq!(
-      server.index.runes().unwrap(),
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      AcceptMsgpack::CONTENT_TYPE
+    );
+    assert!(response
+      .headers()
+      .get_all(header::VARY)
+      .iter()
+      .any(|value| value == "accept"));
+
+    let body = response.bytes().unwrap();
+
+    let balances = BTreeMap::<SpacedRune, BTreeMap<OutPoint, u128>>::deserialize(
+      &mut rmp_serde::Deserializer::new(body.as_ref()).with_human_readable(),
+    )
+    .unwrap();
+
+    pretty_assert_eq!(
+      balances,
       [(
-        id,
-        RuneEntry {
-          block: id.block,
-          etching: txid,
-          spaced_rune: SpacedRune { rune, spacers: 0 },
-          premine: u128::MAX,
-          symbol: Some('%'),
-          timestamp: id.block,
-          turbo: true,
-          ..default()
-        }
+        SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0
+        },
+        [(OutPoint { txid, vout: 0 }, 1000)].into()
       )]
+      .into()
     );
+  }
 
-    assert_eq!(
-      server.index.get_rune_balances().unwrap(),
-      [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
-    );
+  #[test]
+  fn accept_header_media_types_are_parsed() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
 
-    server.assert_response_regex(
-      format!("/rune/{rune}"),
-      StatusCode::OK,
-      format!(
-        ".*<title>Rune AAAAAAAAAAAAA</title>.*
-<h1>AAAAAAAAAAAAA</h1>
-.*<a.*<iframe .* src=/preview/{txid}i0></iframe></a>.*
-<dl>
-  <dt>number</dt>
-  <dd>0</dd>
-  <dt>timestamp</dt>
-  <dd><time>1970-01-01 00:00:08 UTC</time></dd>
-  <dt>id</dt>
-  <dd>8:1</dd>
-  <dt>etching block</dt>
-  <dd><a href=/block/8>8</a></dd>
-  <dt>etching transaction</dt>
-  <dd>1</dd>
-  <dt>mint</dt>
-  <dd>no</dd>
-  <dt>supply</dt>
-  <dd>340282366920938463463374607431768211455\u{A0}%</dd>
-  <dt>premine</dt>
-  <dd>340282366920938463463374607431768211455\u{A0}%</dd>
-  <dt>premine percentage</dt>
-  <dd>100%</dd>
-  <dt>burned</dt>
-  <dd>0\u{A0}%</dd>
-  <dt>divisibility</dt>
-  <dd>0</dd>
-  <dt>symbol</dt>
-  <dd>%</dd>
-  <dt>turbo</dt>
-  <dd>true</dd>
-  <dt>etching</dt>
-  <dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
-  <dt>parent</dt>
-  <dd><a class=monospace href=/inscription/{txid}i0>{txid}i0</a></dd>
-</dl>
-.*"
+    for (accept, status, content_type) in [
+      (
+        "application/json; charset=utf-8",
+        StatusCode::OK,
+        Some("application/json"),
+      ),
+      (
+        "text/html;q=0.5, application/json",
+        StatusCode::OK,
+        Some("application/json"),
+      ),
+      (
+        "text/html, application/json;q=0.9",
+        StatusCode::NOT_FOUND,
+        None,
+      ),
+      ("application/json;q=0", StatusCode::NOT_FOUND, None),
+      (
+        "application/json;q=0.5, application/msgpack",
+        StatusCode::OK,
+        Some(AcceptMsgpack::CONTENT_TYPE),
+      ),
+      (
+        "application/json, application/msgpack;q=0.5",
+        StatusCode::OK,
+        Some("application/json"),
       ),
+    ] {
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url("/runes/balances"))
+        .header(header::ACCEPT, accept)
+        .send()
+        .unwrap();
+
+      assert_eq!(response.status(), status, "{accept}");
+
+      if let Some(content_type) = content_type {
+        assert_eq!(
+          response.headers().get(header::CONTENT_TYPE).unwrap(),
+          content_type,
+          "{accept}"
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn fallback() {
+    let server = TestServer::new();
+
+    server.assert_redirect("/0", "/inscription/0");
+    server.assert_redirect("/0/", "/inscription/0");
+    server.assert_redirect("/0//", "/inscription/0");
+    server.assert_redirect(
+      "/521f8eccffa4c41a3a7728dd012ea5a4a02feed81f41159231251ecf1e5c79dai0",
+      "/inscription/521f8eccffa4c41a3a7728dd012ea5a4a02feed81f41159231251ecf1e5c79dai0",
+    );
+    server.assert_redirect("/-1", "/inscription/-1");
+    server.assert_redirect("/FOO", "/rune/FOO");
+    server.assert_redirect("/FO.O", "/rune/FO.O");
+    server.assert_redirect("/FO•O", "/rune/FO•O");
+    server.assert_redirect("/0:0", "/rune/0:0");
+    server.assert_redirect(
+      "/4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
+      "/output/4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
+    );
+    server.assert_redirect(
+      "/search/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
+      "/block/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
+    );
+    server.assert_redirect(
+      "/search/0000000000000000000000000000000000000000000000000000000000000000",
+      "/tx/0000000000000000000000000000000000000000000000000000000000000000",
     );
 
+    server.assert_response_regex("/hello", StatusCode::NOT_FOUND, "");
+
     server.assert_response_regex(
-      format!("/inscription/{txid}i0"),
-      StatusCode::OK,
-      ".*
-<dl>
-  <dt>rune</dt>
-  <dd><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></dd>
-  .*
-</dl>
-.*",
+      "/%C3%28",
+      StatusCode::BAD_REQUEST,
+      "invalid utf-8 sequence of 1 bytes from index 0",
     );
   }
 
   #[test]
-  fn etched_runes_are_displayed_on_block_page() {
+  fn runes_can_be_queried_by_rune_id() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -2911,9 +4583,11 @@ mod tests {
 
     server.mine_blocks(1);
 
-    let rune0 = Rune(RUNE);
+    let rune = Rune(RUNE);
 
-    let (_txid, id) = server.etch(
+    server.assert_response_regex("/rune/9:1", StatusCode::NOT_FOUND, ".*");
+
+    server.etch(
       Runestone {
         edicts: vec![Edict {
           id: RuneId::default(),
@@ -2921,7 +4595,7 @@ mod tests {
           output: 0,
         }],
         etching: Some(Etching {
-          rune: Some(rune0),
+          rune: Some(rune),
           ..default()
         }),
         ..default()
@@ -2930,25 +4604,17 @@ mod tests {
       None,
     );
 
-    assert_eq!(
-      server.index.get_runes_in_block(id.block - 1).unwrap().len(),
-      0
-    );
-    assert_eq!(server.index.get_runes_in_block(id.block).unwrap().len(), 1);
-    assert_eq!(
-      server.index.get_runes_in_block(id.block + 1).unwrap().len(),
-      0
-    );
+    server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/block/{}", id.block),
+      "/rune/8:1",
       StatusCode::OK,
-      format!(".*<h2>1 Rune</h2>.*<li><a href=/rune/{rune0}>{rune0}</a></li>.*"),
+      ".*<title>Rune AAAAAAAAAAAAA</title>.*",
     );
   }
 
   #[test]
-  fn runes_are_spaced() {
+  fn runes_can_be_queried_by_rune_number() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -2956,98 +4622,138 @@ mod tests {
 
     server.mine_blocks(1);
 
-    let rune = Rune(RUNE);
-
-    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
-
-    let (txid, id) = server.etch(
-      Runestone {
-        edicts: vec![Edict {
-          id: RuneId::default(),
-          amount: u128::MAX,
-          output: 0,
-        }],
-        etching: Some(Etching {
-          rune: Some(rune),
-          symbol: Some('%'),
-          spacers: Some(1),
-          premine: Some(u128::MAX),
-          ..default()
-        }),
-        ..default()
-      },
-      1,
-      Some(
-        Inscription {
-          content_type: Some("text/plain".into()),
-          body: Some("hello".into()),
-          rune: Some(rune.commitment()),
-          ..default()
-        }
-        .to_witness(),
-      ),
-    );
+    server.assert_response_regex("/rune/0", StatusCode::NOT_FOUND, ".*");
 
-    pretty_assert_eq!(
-      server.index.runes().unwrap(),
-      [(
-        id,
-        RuneEntry {
-          block: id.block,
-          etching: txid,
-          spaced_rune: SpacedRune { rune, spacers: 1 },
-          premine: u128::MAX,
-          symbol: Some('%'),
-          timestamp: id.block,
+    for i in 0..10 {
+      let rune = Rune(RUNE + i);
+      server.etch(
+        Runestone {
+          edicts: vec![Edict {
+            id: RuneId::default(),
+            amount: u128::MAX,
+            output: 0,
+          }],
+          etching: Some(Etching {
+            rune: Some(rune),
+            ..default()
+          }),
           ..default()
-        }
-      )]
-    );
+        },
+        1,
+        None,
+      );
 
-    assert_eq!(
-      server.index.get_rune_balances().unwrap(),
-      [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
-    );
+      server.mine_blocks(1);
+    }
 
     server.assert_response_regex(
-      format!("/rune/{rune}"),
+      "/rune/0",
       StatusCode::OK,
-      r".*<title>Rune A•AAAAAAAAAAAA</title>.*<h1>A•AAAAAAAAAAAA</h1>.*",
+      ".*<title>Rune AAAAAAAAAAAAA</title>.*",
     );
 
+    for i in 1..6 {
+      server.assert_response_regex(
+        format!("/rune/{}", i),
+        StatusCode::OK,
+        ".*<title>Rune AAAAAAAAAAAA.*</title>.*",
+      );
+    }
+
     server.assert_response_regex(
-      format!("/inscription/{txid}i0"),
+      "/rune/9",
       StatusCode::OK,
-      ".*<dt>rune</dt>.*<dd><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></dd>.*",
+      ".*<title>Rune AAAAAAAAAAAAJ</title>.*",
     );
+  }
 
-    server.assert_response_regex(
-      "/runes",
-      StatusCode::OK,
-      ".*<li><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></li>.*",
+  #[test]
+  fn runes_etched_between_heights() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let mut ids = Vec::new();
+
+    for i in 0..3 {
+      let (_txid, id) = server.etch(
+        Runestone {
+          edicts: vec![Edict {
+            id: RuneId::default(),
+            amount: u128::MAX,
+            output: 0,
+          }],
+          etching: Some(Etching {
+            rune: Some(Rune(RUNE + i)),
+            ..default()
+          }),
+          ..default()
+        },
+        1,
+        None,
+      );
+
+      ids.push(id);
+    }
+
+    pretty_assert_eq!(
+      server.get_json::<api::RunesEtched>("/runes/etched/0/100"),
+      api::RunesEtched {
+        ids: ids.clone(),
+        more: false,
+        page: 0,
+      }
     );
 
-    server.assert_response_regex(
-      format!("/tx/{txid}"),
-      StatusCode::OK,
-      ".*
-  <dt>etching</dt>
-  <dd><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></dd>
-.*",
+    pretty_assert_eq!(
+      server
+        .get_json::<api::RunesEtched>(format!("/runes/etched/{}/{}", ids[1].block, ids[1].block)),
+      api::RunesEtched {
+        ids: vec![ids[1]],
+        more: false,
+        page: 0,
+      }
     );
 
-    server.assert_response_regex(
-      format!("/output/{txid}:0"),
-      StatusCode::OK,
-      ".*<tr>
-        <td><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></td>
-        <td>340282366920938463463374607431768211455\u{A0}%</td>
-      </tr>.*",
+    pretty_assert_eq!(
+      server.get_json::<api::RunesEtched>("/runes/etched/0/100?limit=2"),
+      api::RunesEtched {
+        ids: ids[..2].into(),
+        more: true,
+        page: 0,
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::RunesEtched>("/runes/etched/0/100/1?limit=2"),
+      api::RunesEtched {
+        ids: ids[2..].into(),
+        more: false,
+        page: 1,
+      }
     );
+
+    server.assert_response("/runes/etched/0/100", StatusCode::NOT_FOUND, "");
+
+    let status = |path: &str| {
+      reqwest::blocking::Client::new()
+        .get(server.join_url(path))
+        .header(header::ACCEPT, "application/json")
+        .send()
+        .unwrap()
+        .status()
+    };
+
+    assert_eq!(status("/runes/etched/100/0"), StatusCode::BAD_REQUEST);
+    assert_eq!(status("/runes/etched/0/4031"), StatusCode::OK);
+    assert_eq!(status("/runes/etched/0/4032"), StatusCode::BAD_REQUEST);
   }
 
   #[test]
-  fn transactions_link_to_etching() {
+  fn runes_are_displayed_on_runes_page() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -3058,7 +4764,7 @@ mod tests {
     server.assert_response_regex(
       "/runes",
       StatusCode::OK,
-      ".*<title>Runes</title>.*<h1>Runes</h1>\n<ul>\n</ul>.*",
+      ".*<title>Runes</title>.*<h1>Runes</h1>\n<ul>\n</ul>\n<div class=center>\n    prev\n      next\n  </div>.*",
     );
 
     let (txid, id) = server.etch(
@@ -3070,13 +4776,14 @@ mod tests {
         }],
         etching: Some(Etching {
           rune: Some(Rune(RUNE)),
+          symbol: Some('%'),
           premine: Some(u128::MAX),
           ..default()
         }),
         ..default()
       },
       1,
-      None,
+      Default::default(),
     );
 
     pretty_assert_eq!(
@@ -3092,28 +4799,30 @@ mod tests {
           },
           premine: u128::MAX,
           timestamp: id.block,
+          symbol: Some('%'),
           ..default()
         }
       )]
     );
 
-    pretty_assert_eq!(
+    assert_eq!(
       server.index.get_rune_balances().unwrap(),
       [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
     );
 
     server.assert_response_regex(
-      format!("/tx/{txid}"),
+      "/runes",
       StatusCode::OK,
-      ".*
-  <dt>etching</dt>
-  <dd><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></dd>
-.*",
+      ".*<title>Runes</title>.*
+<h1>Runes</h1>
+<ul>
+  <li><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></li>
+</ul>.*",
     );
   }
 
   #[test]
-  fn runes_are_displayed_on_output_page() {
+  fn runes_are_paginated() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_runes()
@@ -3121,10 +4830,6 @@ mod tests {
 
     server.mine_blocks(1);
 
-    let rune = Rune(RUNE);
-
-    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
-
     let (txid, id) = server.etch(
       Runestone {
         edicts: vec![Edict {
@@ -3133,8 +4838,7 @@ mod tests {
           output: 0,
         }],
         etching: Some(Etching {
-          divisibility: Some(1),
-          rune: Some(rune),
+          rune: Some(Rune(RUNE)),
           premine: Some(u128::MAX),
           ..default()
         }),
@@ -3144,1898 +4848,4232 @@ mod tests {
       None,
     );
 
+    let entry = RuneEntry {
+      block: id.block,
+      etching: txid,
+      spaced_rune: SpacedRune {
+        rune: Rune(RUNE),
+        spacers: 0,
+      },
+      premine: u128::MAX,
+      timestamp: id.block,
+      ..default()
+    };
+
+    assert!(reqwest::blocking::Client::new()
+      .get(server.join_url("/runes/0"))
+      .header(header::ACCEPT, "application/json")
+      .send()
+      .unwrap()
+      .text()
+      .unwrap()
+      .contains(&format!(r#""supply":{}"#, u128::MAX)));
+
     pretty_assert_eq!(
-      server.index.runes().unwrap(),
-      [(
-        id,
-        RuneEntry {
-          block: id.block,
-          divisibility: 1,
-          etching: txid,
-          spaced_rune: SpacedRune { rune, spacers: 0 },
-          premine: u128::MAX,
-          timestamp: id.block,
-          ..default()
-        }
-      )]
+      server.get_json::<api::Runes>("/runes/0"),
+      api::Runes {
+        entries: vec![(id, entry.into())],
+        more: false,
+        prev: None,
+        next: None,
+      }
     );
 
-    let output = OutPoint { txid, vout: 0 };
-
-    assert_eq!(
-      server.index.get_rune_balances().unwrap(),
-      [(output, vec![(id, u128::MAX)])]
+    pretty_assert_eq!(
+      server.get_json::<api::Runes>("/runes/1"),
+      api::Runes {
+        entries: Vec::new(),
+        more: false,
+        prev: Some(0),
+        next: None,
+      }
     );
 
     server.assert_response_regex(
-      format!("/output/{output}"),
+      "/runes/1",
       StatusCode::OK,
-      format!(
-        ".*<title>Output {output}</title>.*<h1>Output <span class=monospace>{output}</span></h1>.*
-  <dt>runes</dt>
-  <dd>
-    <table>
-      <tr>
-        <th>rune</th>
-        <th>balance</th>
-      </tr>
-      <tr>
-        <td><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></td>
-        <td>34028236692093846346337460743176821145.5\u{A0}¤</td>
-      </tr>
-    </table>
-  </dd>
-.*"
-      ),
+      ".*<a class=prev href=/runes/0>prev</a>.*",
     );
+  }
 
-    let address = default_address(Chain::Regtest);
+  #[test]
+  fn rune_holders_are_paginated_with_holder_count() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
 
-    pretty_assert_eq!(
-      server.get_json::<api::Output>(format!("/output/{output}")),
-      api::Output {
-        value: 5000000000,
-        script_pubkey: address.script_pubkey().to_asm_string(),
-        address: Some(uncheck(&address)),
-        transaction: txid.to_string(),
-        sat_ranges: None,
-        indexed: true,
-        inscriptions: Vec::new(),
-        runes: vec![(
-          SpacedRune {
-            rune: Rune(RUNE),
-            spacers: 0
-          },
-          Pile {
-            amount: 340282366920938463463374607431768211455,
-            divisibility: 1,
-            symbol: None,
-          }
+    server.mine_blocks(1);
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![
+          Edict {
+            id: RuneId::default(),
+            amount: 1000,
+            output: 0,
+          },
+          Edict {
+            id: RuneId::default(),
+            amount: 2000,
+            output: 1,
+          },
+        ],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          symbol: Some('%'),
+          premine: Some(3000),
+          ..default()
+        }),
+        ..default()
+      },
+      2,
+      None,
+    );
+
+    let pile = |amount| Pile {
+      amount,
+      divisibility: 0,
+      symbol: Some('%'),
+    };
+
+    pretty_assert_eq!(
+      server.get_json::<api::RuneHolders>(format!("/rune/{id}/holders")),
+      api::RuneHolders {
+        spaced_rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        holder_count: 2,
+        holders: vec![
+          (OutPoint { txid, vout: 0 }, pile(1000)),
+          (OutPoint { txid, vout: 1 }, pile(2000)),
+        ],
+        more: false,
+        prev: None,
+        next: None,
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::RuneHolders>("/rune/AAAAAAAAAAAAA/holders/1"),
+      api::RuneHolders {
+        spaced_rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        holder_count: 2,
+        holders: Vec::new(),
+        more: false,
+        prev: Some(0),
+        next: None,
+      }
+    );
+
+    server.assert_response_regex(
+      "/rune/AAAAAAAAAAAAA/holders",
+      StatusCode::OK,
+      ".*<title>Rune AAAAAAAAAAAAA Holders</title>.*<dt>holders</dt>\n  <dd>2</dd>.*",
+    );
+
+    server.assert_response_regex("/rune/AAAAAAAAAAAAB/holders", StatusCode::NOT_FOUND, ".*");
+
+    pretty_assert_eq!(
+      server.get_json::<api::RuneHolders>(format!("/rune/{id}/holders/1?limit=1")),
+      api::RuneHolders {
+        spaced_rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        holder_count: 2,
+        holders: vec![(OutPoint { txid, vout: 1 }, pile(2000))],
+        more: false,
+        prev: Some(0),
+        next: None,
+      }
+    );
+
+    assert!(
+      server
+        .get_json::<api::RuneHolders>(format!("/rune/{id}/holders?limit=1"))
+        .more
+    );
+
+    server.assert_response(
+      format!("/rune/{id}/holders/{}?limit=2", usize::MAX),
+      StatusCode::BAD_REQUEST,
+      &format!("page {} is out of range", usize::MAX),
+    );
+
+    let merged = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (
+          id.block.try_into().unwrap(),
+          id.tx.try_into().unwrap(),
+          0,
+          Witness::new(),
+        ),
+        (
+          id.block.try_into().unwrap(),
+          id.tx.try_into().unwrap(),
+          1,
+          Witness::new(),
+        ),
+      ],
+      outputs: 1,
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    pretty_assert_eq!(
+      server.get_json::<api::RuneHolders>(format!("/rune/{id}/holders")),
+      api::RuneHolders {
+        spaced_rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        holder_count: 1,
+        holders: vec![(
+          OutPoint {
+            txid: merged,
+            vout: 0,
+          },
+          pile(3000)
         )],
-        spent: false,
+        more: false,
+        prev: None,
+        next: None,
       }
     );
   }
 
   #[test]
-  fn http_to_https_redirect_with_path() {
+  fn runes_are_displayed_on_rune_page() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune),
+          symbol: Some('%'),
+          premine: Some(u128::MAX),
+          turbo: true,
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      Some(
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("hello".into()),
+          rune: Some(rune.commitment()),
+          ..default()
+        }
+        .to_witness(),
+      ),
+    );
+
+    assert_eq!(
+      server.index.runes().unwrap(),
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          etching: txid,
+          spaced_rune: SpacedRune { rune, spacers: 0 },
+          premine: u128::MAX,
+          symbol: Some('%'),
+          timestamp: id.block,
+          turbo: true,
+          ..default()
+        }
+      )]
+    );
+
+    assert_eq!(
+      server.index.get_rune_balances().unwrap(),
+      [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
+    );
+
+    server.assert_response_regex(
+      format!("/rune/{rune}"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Rune AAAAAAAAAAAAA</title>.*
+<h1>AAAAAAAAAAAAA</h1>
+.*<a.*<iframe .* src=/preview/{txid}i0></iframe></a>.*
+<dl>
+  <dt>number</dt>
+  <dd>0</dd>
+  <dt>timestamp</dt>
+  <dd><time>1970-01-01 00:00:08 UTC</time></dd>
+  <dt>id</dt>
+  <dd>8:1</dd>
+  <dt>etching block</dt>
+  <dd><a href=/block/8>8</a></dd>
+  <dt>etching transaction</dt>
+  <dd>1</dd>
+  <dt>mint</dt>
+  <dd>no</dd>
+  <dt>supply</dt>
+  <dd>340282366920938463463374607431768211455\u{A0}%</dd>
+  <dt>premine</dt>
+  <dd>340282366920938463463374607431768211455\u{A0}%</dd>
+  <dt>premine percentage</dt>
+  <dd>100%</dd>
+  <dt>burned</dt>
+  <dd>0\u{A0}%</dd>
+  <dt>divisibility</dt>
+  <dd>0</dd>
+  <dt>symbol</dt>
+  <dd>%</dd>
+  <dt>turbo</dt>
+  <dd>true</dd>
+  <dt>etching</dt>
+  <dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
+  <dt>parent</dt>
+  <dd><a class=monospace href=/inscription/{txid}i0>{txid}i0</a></dd>
+</dl>
+.*"
+      ),
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{txid}i0"),
+      StatusCode::OK,
+      ".*
+<dl>
+  <dt>rune</dt>
+  <dd><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></dd>
+  .*
+</dl>
+.*",
+    );
+  }
+
+  #[test]
+  fn etched_runes_are_displayed_on_block_page() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune0 = Rune(RUNE);
+
+    let (_txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune0),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    assert_eq!(
+      server.index.get_runes_in_block(id.block - 1).unwrap().len(),
+      0
+    );
+    assert_eq!(server.index.get_runes_in_block(id.block).unwrap().len(), 1);
+    assert_eq!(
+      server.index.get_runes_in_block(id.block + 1).unwrap().len(),
+      0
+    );
+
+    server.assert_response_regex(
+      format!("/block/{}", id.block),
+      StatusCode::OK,
+      format!(".*<h2>1 Rune</h2>.*<li><a href=/rune/{rune0}>{rune0}</a></li>.*"),
+    );
+  }
+
+  #[test]
+  fn inscription_rune_recursive_endpoints() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune),
+          spacers: Some(1),
+          premine: Some(u128::MAX),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      Some(
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("hello".into()),
+          rune: Some(rune.commitment()),
+          ..default()
+        }
+        .to_witness(),
+      ),
+    );
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let spaced_rune = SpacedRune { rune, spacers: 1 };
+
+    assert_eq!(
+      server
+        .get_json::<api::InscriptionRecursive>(format!("/r/inscription/{inscription_id}"))
+        .rune,
+      Some(spaced_rune),
+    );
+
+    assert_eq!(
+      server.get_json::<api::InscriptionRune>(format!("/r/inscription/{inscription_id}/rune")),
+      api::InscriptionRune {
+        id,
+        rune: spaced_rune,
+      },
+    );
+
+    server.mine_blocks(1);
+
+    let height = usize::try_from(server.index.block_count().unwrap()).unwrap() - 1;
+
+    let plain = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(height, 0, 0, inscription("text/plain", "hi").to_witness())],
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server
+        .get_json::<api::InscriptionRecursive>(format!("/r/inscription/{plain}"))
+        .rune,
+      None,
+    );
+
+    server.assert_response(
+      format!("/r/inscription/{plain}/rune"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {plain} rune not found"),
+    );
+  }
+
+  #[test]
+  fn runes_are_spaced() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune),
+          symbol: Some('%'),
+          spacers: Some(1),
+          premine: Some(u128::MAX),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      Some(
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("hello".into()),
+          rune: Some(rune.commitment()),
+          ..default()
+        }
+        .to_witness(),
+      ),
+    );
+
+    pretty_assert_eq!(
+      server.index.runes().unwrap(),
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          etching: txid,
+          spaced_rune: SpacedRune { rune, spacers: 1 },
+          premine: u128::MAX,
+          symbol: Some('%'),
+          timestamp: id.block,
+          ..default()
+        }
+      )]
+    );
+
+    assert_eq!(
+      server.index.get_rune_balances().unwrap(),
+      [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
+    );
+
+    server.assert_response_regex(
+      format!("/rune/{rune}"),
+      StatusCode::OK,
+      r".*<title>Rune A•AAAAAAAAAAAA</title>.*<h1>A•AAAAAAAAAAAA</h1>.*",
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{txid}i0"),
+      StatusCode::OK,
+      ".*<dt>rune</dt>.*<dd><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></dd>.*",
+    );
+
+    server.assert_response_regex(
+      "/runes",
+      StatusCode::OK,
+      ".*<li><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></li>.*",
+    );
+
+    server.assert_response_regex(
+      format!("/tx/{txid}"),
+      StatusCode::OK,
+      ".*
+  <dt>etching</dt>
+  <dd><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></dd>
+.*",
+    );
+
+    server.assert_response_regex(
+      format!("/output/{txid}:0"),
+      StatusCode::OK,
+      ".*<tr>
+        <td><a href=/rune/A•AAAAAAAAAAAA>A•AAAAAAAAAAAA</a></td>
+        <td>340282366920938463463374607431768211455\u{A0}%</td>
+      </tr>.*",
+    );
+  }
+
+  #[test]
+  fn transactions_link_to_etching() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/runes",
+      StatusCode::OK,
+      ".*<title>Runes</title>.*<h1>Runes</h1>\n<ul>\n</ul>.*",
+    );
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(u128::MAX),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    pretty_assert_eq!(
+      server.index.runes().unwrap(),
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          etching: txid,
+          spaced_rune: SpacedRune {
+            rune: Rune(RUNE),
+            spacers: 0
+          },
+          premine: u128::MAX,
+          timestamp: id.block,
+          ..default()
+        }
+      )]
+    );
+
+    pretty_assert_eq!(
+      server.index.get_rune_balances().unwrap(),
+      [(OutPoint { txid, vout: 0 }, vec![(id, u128::MAX)])]
+    );
+
+    server.assert_response_regex(
+      format!("/tx/{txid}"),
+      StatusCode::OK,
+      ".*
+  <dt>etching</dt>
+  <dd><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></dd>
+.*",
+    );
+  }
+
+  #[test]
+  fn runes_are_displayed_on_output_page() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    server.assert_response_regex(format!("/rune/{rune}"), StatusCode::NOT_FOUND, ".*");
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          divisibility: Some(1),
+          rune: Some(rune),
+          premine: Some(u128::MAX),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    pretty_assert_eq!(
+      server.index.runes().unwrap(),
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          divisibility: 1,
+          etching: txid,
+          spaced_rune: SpacedRune { rune, spacers: 0 },
+          premine: u128::MAX,
+          timestamp: id.block,
+          ..default()
+        }
+      )]
+    );
+
+    let output = OutPoint { txid, vout: 0 };
+
+    assert_eq!(
+      server.index.get_rune_balances().unwrap(),
+      [(output, vec![(id, u128::MAX)])]
+    );
+
+    server.assert_response_regex(
+      format!("/output/{output}"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Output {output}</title>.*<h1>Output <span class=monospace>{output}</span></h1>.*
+  <dt>runes</dt>
+  <dd>
+    <table>
+      <tr>
+        <th>rune</th>
+        <th>balance</th>
+      </tr>
+      <tr>
+        <td><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a></td>
+        <td>34028236692093846346337460743176821145.5\u{A0}¤</td>
+      </tr>
+    </table>
+  </dd>
+.*"
+      ),
+    );
+
+    let address = default_address(Chain::Regtest);
+
+    pretty_assert_eq!(
+      server.get_json::<api::Output>(format!("/output/{output}")),
+      api::Output {
+        value: 5000000000,
+        script_pubkey: address.script_pubkey().to_asm_string(),
+        address: Some(uncheck(&address)),
+        confirmed: Some(true),
+        height: Some(id.block.try_into().unwrap()),
+        transaction: txid.to_string(),
+        sat_ranges: None,
+        indexed: true,
+        inscriptions: Vec::new(),
+        rarity_counts: None,
+        runes: vec![(
+          SpacedRune {
+            rune: Rune(RUNE),
+            spacers: 0
+          },
+          Pile {
+            amount: 340282366920938463463374607431768211455,
+            divisibility: 1,
+            symbol: None,
+          }
+        )],
+        spent: false,
+      }
+    );
+  }
+
+  #[test]
+  fn http_to_https_redirect_with_path() {
+    TestServer::builder()
+      .redirect_http_to_https()
+      .https()
+      .build()
+      .assert_redirect(
+        "/sat/0",
+        &format!("https://{}/sat/0", System::host_name().unwrap()),
+      );
+  }
+
+  #[test]
+  fn http_to_https_redirect_with_empty() {
+    TestServer::builder()
+      .redirect_http_to_https()
+      .https()
+      .build()
+      .assert_redirect("/", &format!("https://{}/", System::host_name().unwrap()));
+  }
+
+  #[test]
+  fn status() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(3);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/plain;charset=utf-8", "hello").to_witness(),
+      )],
+      ..default()
+    });
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        2,
+        0,
+        0,
+        inscription("text/plain;charset=utf-8", "hello").to_witness(),
+      )],
+      ..default()
+    });
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        3,
+        0,
+        0,
+        Inscription {
+          content_type: None,
+          body: Some("hello".as_bytes().into()),
+          ..default()
+        }
+        .to_witness(),
+      )],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/status",
+      StatusCode::OK,
+      ".*<h1>Status</h1>
+<dl>
+  <dt>chain</dt>
+  <dd>regtest</dd>
+  <dt>height</dt>
+  <dd><a href=/block/4>4</a></dd>
+  <dt>inscriptions</dt>
+  <dd><a href=/inscriptions>3</a></dd>
+  <dt>blessed inscriptions</dt>
+  <dd>3</dd>
+  <dt>cursed inscriptions</dt>
+  <dd>0</dd>
+  <dt>first inscription height</dt>
+  <dd>0</dd>
+  <dt>runes</dt>
+  <dd><a href=/runes>0</a></dd>
+  <dt>lost sats</dt>
+  <dd>.*</dd>
+  <dt>started</dt>
+  <dd>.*</dd>
+  <dt>uptime</dt>
+  <dd>.*</dd>
+  <dt>minimum rune for next block</dt>
+  <dd>.*</dd>
+  <dt>version</dt>
+  <dd>.*</dd>
+  <dt>unrecoverably reorged</dt>
+  <dd>false</dd>
+  <dt>rune index</dt>
+  <dd>false</dd>
+  <dt>sat index</dt>
+  <dd>false</dd>
+  <dt>transaction index</dt>
+  <dd>false</dd>
+  <dt>git branch</dt>
+  <dd>.*</dd>
+  <dt>git commit</dt>
+  <dd>
+    <a href=https://github.com/ordinals/ord/commit/[[:xdigit:]]{40}>
+      [[:xdigit:]]{40}
+    </a>
+  </dd>
+  <dt>inscription content types</dt>
+  <dd>
+    <dl>
+      <dt>text/plain;charset=utf-8</dt>
+      <dd>2</dt>
+      <dt><em>none</em></dt>
+      <dd>1</dt>
+    </dl>
+  </dd>
+</dl>
+.*",
+    );
+  }
+
+  #[test]
+  fn status_shows_supply_with_sat_index() {
+    TestServer::new().assert_response_regex(
+      "/status",
+      StatusCode::OK,
+      "(?s).*<dt>lost sats</dt>\n  <dd>0</dd>\n  <dt>started</dt>.*",
+    );
+
+    let server = TestServer::builder().index_sats().build();
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/status",
+      StatusCode::OK,
+      ".*
+  <dt>lost sats</dt>
+  <dd>0</dd>
+  <dt>sats mined</dt>
+  <dd>10000000000</dd>
+  <dt>sats circulating</dt>
+  <dd>10000000000</dd>
+  <dt>sats unmined</dt>
+  <dd>2099989997690000</dd>
+  <dt>started</dt>
+.*",
+    );
+  }
+
+  #[test]
+  fn block_count_endpoint() {
+    let test_server = TestServer::new();
+
+    let response = test_server.get("/blockcount");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().unwrap(), "1");
+
+    test_server.mine_blocks(1);
+
+    let response = test_server.get("/blockcount");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().unwrap(), "2");
+  }
+
+  #[test]
+  fn block_height_endpoint() {
+    let test_server = TestServer::new();
+
+    let response = test_server.get("/blockheight");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().unwrap(), "0");
+
+    test_server.mine_blocks(2);
+
+    let response = test_server.get("/blockheight");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().unwrap(), "2");
+  }
+
+  #[test]
+  fn block_hash_endpoint() {
+    let test_server = TestServer::new();
+
+    let response = test_server.get("/blockhash");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.text().unwrap(),
+      "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+    );
+  }
+
+  #[test]
+  fn block_hash_from_height_endpoint() {
+    let test_server = TestServer::new();
+
+    let response = test_server.get("/blockhash/0");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.text().unwrap(),
+      "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+    );
+  }
+
+  #[test]
+  fn block_time_endpoint() {
+    let test_server = TestServer::new();
+
+    let response = test_server.get("/blocktime");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().unwrap(), "1231006505");
+  }
+
+  #[test]
+  fn find_range() {
+    let server = TestServer::builder()
+      .index_sats()
+      .server_flag("--enable-find-range")
+      .build();
+
+    pretty_assert_eq!(
+      server.get_json::<Vec<FindRangeOutput>>("/find/0/100"),
+      vec![FindRangeOutput {
+        start: 0,
+        size: 100,
+        satpoint: server.index.find(Sat(0)).unwrap().unwrap(),
+      }]
+    );
+
+    let status = |path: &str| {
+      reqwest::blocking::Client::new()
+        .get(server.join_url(path))
+        .header(header::ACCEPT, "application/json")
+        .send()
+        .unwrap()
+        .status()
+    };
+
+    assert_eq!(status("/find/1/0"), StatusCode::BAD_REQUEST);
+    assert_eq!(status("/find/0/0"), StatusCode::BAD_REQUEST);
+    assert_eq!(status("/find/0/5000000001"), StatusCode::BAD_REQUEST);
+    assert_eq!(status("/find/5000000000/5000000001"), StatusCode::NOT_FOUND);
+
+    server.assert_response("/find/0/100", StatusCode::NOT_FOUND, "");
+  }
+
+  #[test]
+  fn find_range_requires_flag() {
+    let server = TestServer::builder().index_sats().build();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/find/0/100"))
+      .header(header::ACCEPT, "application/json")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+      response.text().unwrap(),
+      "sat range search is not served by this server"
+    );
+  }
+
+  #[test]
+  fn find_range_requires_sat_index() {
+    let server = TestServer::builder()
+      .server_flag("--enable-find-range")
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/find/0/100"))
+      .header(header::ACCEPT, "application/json")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.text().unwrap(), "this server has no sat index");
+  }
+
+  #[test]
+  fn range_end_before_range_start_returns_400() {
+    TestServer::new().assert_response(
+      "/range/1/0",
+      StatusCode::BAD_REQUEST,
+      "range start greater than range end",
+    );
+  }
+
+  #[test]
+  fn invalid_range_start_returns_400() {
+    TestServer::new().assert_response(
+      "/range/=/0",
+      StatusCode::BAD_REQUEST,
+      "invalid sat `=`, expected an integer, decimal, degree, percentile, or name: failed to parse sat `=`: invalid integer: invalid digit found in string",
+    );
+  }
+
+  #[test]
+  fn invalid_range_end_returns_400() {
+    TestServer::new().assert_response(
+      "/range/0/=",
+      StatusCode::BAD_REQUEST,
+      "invalid sat `=`, expected an integer, decimal, degree, percentile, or name: failed to parse sat `=`: invalid integer: invalid digit found in string",
+    );
+  }
+
+  #[test]
+  fn empty_range_returns_400() {
+    TestServer::new().assert_response("/range/0/0", StatusCode::BAD_REQUEST, "empty range");
+  }
+
+  #[test]
+  fn range() {
+    TestServer::new().assert_response_regex(
+      "/range/0/1",
+      StatusCode::OK,
+      r".*<title>Sat Range 0–1</title>.*<h1>Sat Range 0–1</h1>
+<dl>
+  <dt>value</dt><dd>1</dd>
+  <dt>first</dt><dd><a href=/sat/0 class=mythic>0</a></dd>
+</dl>.*",
+    );
+  }
+  #[test]
+  fn sat_number() {
+    TestServer::new().assert_response_regex("/sat/0", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
+  }
+
+  #[test]
+  fn sat_decimal() {
+    TestServer::new().assert_response_regex("/sat/0.0", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
+  }
+
+  #[test]
+  fn sat_degree() {
+    TestServer::new().assert_response_regex("/sat/0°0′0″0‴", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
+  }
+
+  #[test]
+  fn sat_name() {
+    TestServer::new().assert_response_regex(
+      "/sat/nvtdijuwxlp",
+      StatusCode::OK,
+      ".*<h1>Sat 0</h1>.*",
+    );
+  }
+
+  #[test]
+  fn sat() {
+    TestServer::new().assert_response_regex(
+      "/sat/0",
+      StatusCode::OK,
+      ".*<title>Sat 0</title>.*<h1>Sat 0</h1>.*",
+    );
+  }
+
+  #[test]
+  fn block() {
+    TestServer::new().assert_response_regex(
+      "/block/0",
+      StatusCode::OK,
+      ".*<title>Block 0</title>.*<h1>Block 0</h1>.*",
+    );
+  }
+
+  #[test]
+  fn sat_out_of_range() {
+    TestServer::new().assert_response(
+      "/sat/2099999997690000",
+      StatusCode::BAD_REQUEST,
+      "invalid sat `2099999997690000`, expected an integer, decimal, degree, percentile, or name: failed to parse sat `2099999997690000`: invalid integer range",
+    );
+  }
+
+  #[test]
+  fn invalid_outpoint_hash_returns_400() {
+    TestServer::new().assert_response(
+      "/output/foo:0",
+      StatusCode::BAD_REQUEST,
+      "invalid outpoint `foo:0`, expected `<TXID>:<VOUT>`: error parsing TXID",
+    );
+  }
+
+  #[test]
+  fn malformed_path_parameters_return_400() {
+    let server = TestServer::new();
+
+    server.assert_response(
+      "/output/foo",
+      StatusCode::BAD_REQUEST,
+      "invalid outpoint `foo`, expected `<TXID>:<VOUT>`: OutPoint not in <txid>:<vout> format",
+    );
+
+    server.assert_response_regex(
+      "/tx/foo",
+      StatusCode::BAD_REQUEST,
+      "invalid transaction ID `foo`, expected 64 hexadecimal characters: .*",
+    );
+
+    for path in [
+      "/children/foo",
+      "/children/foo/0",
+      "/parents/foo",
+      "/parents/foo/0",
+      "/preview/foo",
+      "/r/children/foo/0",
+      "/r/inscription/foo",
+      "/r/metadata/foo",
+    ] {
+      server.assert_response(
+        path,
+        StatusCode::BAD_REQUEST,
+        "invalid inscription ID `foo`, expected `<TXID>i<INDEX>`: invalid length: 3",
+      );
+    }
+
+    server.assert_response(
+      "/block/foo",
+      StatusCode::BAD_REQUEST,
+      "invalid block `foo`, expected a block height or hash: invalid digit found in string",
+    );
+
+    server.assert_response_regex(
+      "/rune/foo:bar/holders/0",
+      StatusCode::BAD_REQUEST,
+      "invalid rune `foo:bar`, expected a rune name, number, or `<BLOCK>:<TX>` ID: .*",
+    );
+
+    for value in ["foo", "foo.txt"] {
+      server.assert_response(
+        format!("/content/{value}"),
+        StatusCode::BAD_REQUEST,
+        &format!(
+          "invalid inscription ID `{value}`, expected `<TXID>i<INDEX>` with an optional `.<EXTENSION>`: invalid length: 3"
+        ),
+      );
+    }
+
+    server.assert_response(
+      "/inscription/foo:bar",
+      StatusCode::BAD_REQUEST,
+      "invalid inscription `foo:bar`, expected an inscription ID, number, or sat name: bad inscription query foo:bar",
+    );
+
+    for path in [
+      "/r/sat/=",
+      "/r/sat/=/0",
+      "/r/sat/=/at/0",
+      "/r/sat/=/satpoint",
+    ] {
+      server.assert_response(
+        path,
+        StatusCode::BAD_REQUEST,
+        "invalid sat `=`, expected an integer, decimal, degree, percentile, or name: failed to parse sat `=`: invalid integer: invalid digit found in string",
+      );
+    }
+
+    let response = server.post_json("/r/metadata", &["foo"]);
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+  }
+
+  #[test]
+  fn output_with_sat_index() {
+    let txid = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+    TestServer::builder()
+      .index_sats()
+      .build()
+      .assert_response_regex(
+        format!("/output/{txid}:0"),
+        StatusCode::OK,
+        format!(
+          ".*<title>Output {txid}:0</title>.*<h1>Output <span class=monospace>{txid}:0</span></h1>
+<dl>
+  <dt>value</dt><dd>5000000000</dd>
+  <dt>script pubkey</dt><dd class=monospace>OP_PUSHBYTES_65 [[:xdigit:]]{{130}} OP_CHECKSIG</dd>
+  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
+  <dt>spent</dt><dd>false</dd>
+</dl>
+<h2>1 Sat Range</h2>
+<ul class=monospace>
+  <li><a href=/range/0/5000000000 class=mythic>0–5000000000</a></li>
+</ul>.*"
+        ),
+      );
+  }
+
+  #[test]
+  fn output_without_sat_index() {
+    let txid = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+    TestServer::new().assert_response_regex(
+      format!("/output/{txid}:0"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Output {txid}:0</title>.*<h1>Output <span class=monospace>{txid}:0</span></h1>
+<dl>
+  <dt>value</dt><dd>5000000000</dd>
+  <dt>script pubkey</dt><dd class=monospace>OP_PUSHBYTES_65 [[:xdigit:]]{{130}} OP_CHECKSIG</dd>
+  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
+  <dt>spent</dt><dd>false</dd>
+</dl>.*"
+      ),
+    );
+  }
+
+  #[test]
+  fn null_output_is_initially_empty() {
+    let txid = "0000000000000000000000000000000000000000000000000000000000000000";
+    TestServer::builder().index_sats().build().assert_response_regex(
+      format!("/output/{txid}:4294967295"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Output {txid}:4294967295</title>.*<h1>Output <span class=monospace>{txid}:4294967295</span></h1>
+<dl>
+  <dt>value</dt><dd>0</dd>
+  <dt>script pubkey</dt><dd class=monospace></dd>
+  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
+  <dt>spent</dt><dd>false</dd>
+</dl>
+<h2>0 Sat Ranges</h2>
+<ul class=monospace>
+</ul>.*"
+      ),
+    );
+  }
+
+  #[test]
+  fn null_output_receives_lost_sats() {
+    let server = TestServer::builder().index_sats().build();
+
+    server.mine_blocks_with_subsidy(1, 0);
+
+    let txid = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    server.assert_response_regex(
+      format!("/output/{txid}:4294967295"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Output {txid}:4294967295</title>.*<h1>Output <span class=monospace>{txid}:4294967295</span></h1>
+<dl>
+  <dt>value</dt><dd>5000000000</dd>
+  <dt>script pubkey</dt><dd class=monospace></dd>
+  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
+  <dt>spent</dt><dd>false</dd>
+</dl>
+<h2>1 Sat Range</h2>
+<ul class=monospace>
+  <li><a href=/range/5000000000/10000000000 class=uncommon>5000000000–10000000000</a></li>
+</ul>.*"
+      ),
+    );
+  }
+
+  #[test]
+  fn unbound_output_receives_unbound_inscriptions() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      fee: 50 * 100_000_000,
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        2,
+        1,
+        0,
+        inscription("text/plain;charset=utf-8", "hello").to_witness(),
+      )],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    server.assert_response_regex(
+      format!("/inscription/{}", inscription_id),
+      StatusCode::OK,
+      format!(
+        ".*<dl>
+  <dt>id</dt>
+  <dd class=monospace>{inscription_id}</dd>.*<dt>output</dt>
+  <dd><a class=monospace href=/output/0000000000000000000000000000000000000000000000000000000000000000:0>0000000000000000000000000000000000000000000000000000000000000000:0</a></dd>.*"
+      ),
+    );
+
+    let json = server.get_json::<api::Inscription>(format!("/inscription/{inscription_id}"));
+
+    assert!(json.unbound);
+    assert_eq!(json.satpoint.outpoint, unbound_outpoint());
+    assert_eq!(json.address, None);
+    assert_eq!(json.value, None);
+
+    server.assert_response_regex(
+      "/output/0000000000000000000000000000000000000000000000000000000000000000:0",
+      StatusCode::OK,
+      ".*<h1>Output <span class=monospace>0000000000000000000000000000000000000000000000000000000000000000:0</span></h1>
+<dl>
+  <dt>inscriptions</dt>
+  <dd class=thumbnails>
+    <a href=/inscription/.*><iframe sandbox=allow-scripts scrolling=no loading=lazy src=/preview/.*></iframe></a>
+  </dd>.*",
+    );
+  }
+
+  #[test]
+  fn unbound_output_returns_200() {
+    TestServer::new().assert_response_regex(
+      "/output/0000000000000000000000000000000000000000000000000000000000000000:0",
+      StatusCode::OK,
+      ".*",
+    );
+  }
+
+  #[test]
+  fn invalid_output_returns_400() {
+    TestServer::new().assert_response(
+      "/output/foo:0",
+      StatusCode::BAD_REQUEST,
+      "invalid outpoint `foo:0`, expected `<TXID>:<VOUT>`: error parsing TXID",
+    );
+  }
+
+  #[test]
+  fn home() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(1);
+
+    let mut ids = Vec::new();
+
+    for i in 0..101 {
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("image/png", "hello").to_witness())],
+        ..default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+      server.mine_blocks(1);
+    }
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(102, 0, 0, inscription("text/plain", "{}").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/",
+      StatusCode::OK,
+      format!(
+        r".*<title>Ordinals</title>.*
+<h1>Latest Inscriptions</h1>
+<div class=thumbnails>
+  <a href=/inscription/{}>.*</a>
+  (<a href=/inscription/[[:xdigit:]]{{64}}i0>.*</a>\s*){{99}}
+</div>
+.*
+",
+        ids[100]
+      ),
+    );
+  }
+
+  #[test]
+  fn blocks() {
+    let test_server = TestServer::new();
+
+    test_server.mine_blocks(1);
+
+    test_server.assert_response_regex(
+      "/blocks",
+      StatusCode::OK,
+      ".*<title>Blocks</title>.*
+<h1>Blocks</h1>
+<div class=block>
+  <h2><a href=/block/1>Block 1</a></h2>
+  <div class=thumbnails>
+  </div>
+</div>
+<div class=block>
+  <h2><a href=/block/0>Block 0</a></h2>
+  <div class=thumbnails>
+  </div>
+</div>
+</ol>.*",
+    );
+  }
+
+  #[test]
+  fn nav_displays_chain() {
+    TestServer::builder()
+      .chain(Chain::Regtest)
+      .build()
+      .assert_response_regex(
+        "/",
+        StatusCode::OK,
+        ".*<a href=/ title=home>Ordinals<sup>regtest</sup></a>.*",
+      );
+  }
+
+  #[test]
+  fn blocks_block_limit() {
+    let test_server = TestServer::new();
+
+    test_server.mine_blocks(101);
+
+    test_server.assert_response_regex(
+      "/blocks",
+      StatusCode::OK,
+      ".*<ol start=96 reversed class=block-list>\n(  <li><a href=/block/[[:xdigit:]]{64}>[[:xdigit:]]{64}</a></li>\n){95}</ol>.*"
+    );
+  }
+
+  #[test]
+  fn block_not_found() {
+    TestServer::new().assert_response(
+      "/block/467a86f0642b1d284376d13a98ef58310caa49502b0f9a560ee222e0a122fe16",
+      StatusCode::NOT_FOUND,
+      "block 467a86f0642b1d284376d13a98ef58310caa49502b0f9a560ee222e0a122fe16 not found",
+    );
+  }
+
+  #[test]
+  fn unmined_sat() {
+    TestServer::new().assert_response_regex(
+      "/sat/0",
+      StatusCode::OK,
+      ".*<dt>timestamp</dt><dd><time>2009-01-03 18:15:05 UTC</time></dd>.*",
+    );
+  }
+
+  #[test]
+  fn mined_sat() {
+    TestServer::new().assert_response_regex(
+      "/sat/5000000000",
+      StatusCode::OK,
+      ".*<dt>timestamp</dt><dd><time>.*</time> \\(expected\\)</dd>.*",
+    );
+  }
+
+  #[test]
+  fn static_asset() {
+    TestServer::new().assert_response_regex(
+      "/static/index.css",
+      StatusCode::OK,
+      r".*\.rare \{
+  background-color: var\(--rare\);
+}.*",
+    );
+  }
+
+  #[test]
+  fn static_asset_from_static_dir() {
+    let tempdir = TempDir::new().unwrap();
+    let static_dir = tempdir.path().join("static");
+    fs::create_dir(&static_dir).unwrap();
+    fs::write(static_dir.join("index.css"), "body { color: red; }").unwrap();
+    fs::write(tempdir.path().join("secret.txt"), "secret").unwrap();
+
+    let server = TestServer::builder()
+      .server_option("--static-dir", static_dir.to_str().unwrap())
+      .build();
+
+    server.assert_response("/static/index.css", StatusCode::OK, "body { color: red; }");
+
+    server.assert_response_regex("/static/index.js", StatusCode::OK, ".+");
+
+    server.assert_response(
+      "/static/..%2Fsecret.txt",
+      StatusCode::NOT_FOUND,
+      "asset /../secret.txt not found",
+    );
+  }
+
+  #[test]
+  fn cors_exposes_headers() {
+    let server = TestServer::builder()
+      .server_option("--cors-expose-headers", "x-custom")
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/status"))
+      .header(header::ORIGIN, "https://example.com")
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers()[header::ACCESS_CONTROL_EXPOSE_HEADERS],
+      "content-range,etag,x-delegate-id,x-inscription-id,x-custom"
+    );
+  }
+
+  #[test]
+  fn server_header() {
+    assert_eq!(
+      TestServer::new()
+        .get("/status")
+        .headers()
+        .get(header::SERVER),
+      None,
+    );
+
+    let server = TestServer::builder().server_flag("--server-header").build();
+
+    assert_eq!(
+      server.get("/status").headers()[header::SERVER],
+      format!("ord/{}", env!("CARGO_PKG_VERSION")),
+    );
+
+    assert_eq!(
+      server.get_json::<api::Status>("/status").version,
+      env!("CARGO_PKG_VERSION"),
+    );
+  }
+
+  #[test]
+  fn static_dir_assets_support_range_requests() {
+    let tempdir = TempDir::new().unwrap();
+    fs::write(tempdir.path().join("model.bin"), "0123456789").unwrap();
+
+    let server = TestServer::builder()
+      .server_option("--static-dir", tempdir.path().to_str().unwrap())
+      .build();
+
+    let get = |range: &str| {
+      reqwest::blocking::Client::new()
+        .get(server.join_url("/static/model.bin"))
+        .header(header::RANGE, range)
+        .send()
+        .unwrap()
+    };
+
+    let response = get("bytes=2-5");
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[header::ACCEPT_RANGES], "bytes");
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes 2-5/10");
+    assert_eq!(response.text().unwrap(), "2345");
+
+    let response = get("bytes=20-");
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes */10");
+
+    let response = get("bytes=0-1,4-5");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().unwrap(), "0123456789");
+
+    let response = server.get("/static/index.css");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::ACCEPT_RANGES).is_none());
+  }
+
+  #[test]
+  fn range_responses_are_not_compressed() {
+    let tempdir = TempDir::new().unwrap();
+    fs::write(tempdir.path().join("model.txt"), "0123456789".repeat(100)).unwrap();
+
+    let server = TestServer::builder()
+      .server_option("--static-dir", tempdir.path().to_str().unwrap())
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/static/model.txt"))
+      .header(header::ACCEPT_ENCODING, "gzip")
+      .header(header::RANGE, "bytes=100-199")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(
+      response.headers()[header::CONTENT_RANGE],
+      "bytes 100-199/1000"
+    );
+    assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    assert_eq!(response.text().unwrap(), "0123456789".repeat(10));
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/static/model.txt"))
+      .header(header::ACCEPT_ENCODING, "gzip")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+  }
+
+  #[test]
+  fn static_dir_assets_reject_out_of_bounds_ranges() {
+    let tempdir = TempDir::new().unwrap();
+    fs::write(tempdir.path().join("model.bin"), "0123456789").unwrap();
+
+    let server = TestServer::builder()
+      .server_option("--static-dir", tempdir.path().to_str().unwrap())
+      .build();
+
+    for range in ["bytes=10-", "bytes=10-20", "bytes=-0"] {
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url("/static/model.bin"))
+        .header(header::RANGE, range)
+        .send()
+        .unwrap();
+
+      assert_eq!(
+        response.status(),
+        StatusCode::RANGE_NOT_SATISFIABLE,
+        "{range}"
+      );
+      assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes */10");
+      assert_eq!(response.text().unwrap(), "");
+    }
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/static/model.bin"))
+      .header(header::RANGE, "bytes=8-100")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes 8-9/10");
+    assert_eq!(response.headers()[header::CONTENT_LENGTH], "2");
+    assert_eq!(response.text().unwrap(), "89");
+  }
+
+  #[test]
+  fn favicon() {
+    TestServer::new().assert_response_regex("/favicon.ico", StatusCode::OK, r".*");
+  }
+
+  #[test]
+  fn clock_updates() {
+    let test_server = TestServer::new();
+    test_server.assert_response_regex("/clock", StatusCode::OK, ".*<text.*>0</text>.*");
+    test_server.mine_blocks(1);
+    test_server.assert_response_regex("/clock", StatusCode::OK, ".*<text.*>1</text>.*");
+  }
+
+  #[test]
+  fn block_by_hash() {
+    let test_server = TestServer::new();
+
+    test_server.mine_blocks(1);
+    let transaction = TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      fee: 0,
+      ..default()
+    };
+    test_server.core.broadcast_tx(transaction);
+    let block_hash = test_server.mine_blocks(1)[0].block_hash();
+
+    test_server.assert_response_regex(
+      format!("/block/{block_hash}"),
+      StatusCode::OK,
+      ".*<h1>Block 2</h1>.*",
+    );
+  }
+
+  #[test]
+  fn confirmed_blocks_and_transactions_honor_if_modified_since() {
+    let server = TestServer::new();
+
+    server.mine_blocks(5);
+
+    let genesis = "Sat, 03 Jan 2009 18:15:05 GMT";
+
+    let txid = server.core.tx(0, 0).txid();
+
+    for path in ["/block/0".to_string(), format!("/tx/{txid}")] {
+      let response = server.get(&path);
+      assert_eq!(response.status(), StatusCode::OK);
+      assert_eq!(
+        response.headers().get(header::LAST_MODIFIED).unwrap(),
+        genesis
+      );
+
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url(&path))
+        .header(header::IF_MODIFIED_SINCE, genesis)
+        .send()
+        .unwrap();
+      assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+      assert_eq!(
+        response.headers().get(header::LAST_MODIFIED).unwrap(),
+        genesis
+      );
+
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url(&path))
+        .header(header::IF_MODIFIED_SINCE, "Fri, 02 Jan 2009 18:15:05 GMT")
+        .send()
+        .unwrap();
+      assert_eq!(response.status(), StatusCode::OK);
+    }
+  }
+
+  #[test]
+  fn tip_adjacent_blocks_are_not_given_last_modified() {
+    let server = TestServer::new();
+
+    server.mine_blocks(4);
+
+    for path in ["/block/0", "/block/4"] {
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url(path))
+        .header(header::IF_MODIFIED_SINCE, "Fri, 01 Jan 2100 00:00:00 GMT")
+        .send()
+        .unwrap();
+      assert_eq!(response.status(), StatusCode::OK);
+      assert_eq!(response.headers().get(header::LAST_MODIFIED), None);
+    }
+  }
+
+  #[test]
+  fn block_by_height() {
+    let test_server = TestServer::new();
+
+    test_server.assert_response_regex("/block/0", StatusCode::OK, ".*<h1>Block 0</h1>.*");
+  }
+
+  #[test]
+  fn transaction() {
+    let test_server = TestServer::new();
+
+    let coinbase_tx = test_server.mine_blocks(1)[0].txdata[0].clone();
+    let txid = coinbase_tx.txid();
+
+    test_server.assert_response_regex(
+      format!("/tx/{txid}"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Transaction {txid}</title>.*<h1>Transaction <span class=monospace>{txid}</span></h1>
+<dl>
+</dl>
+<h2>1 Input</h2>
+<ul>
+  <li><a class=monospace href=/output/0000000000000000000000000000000000000000000000000000000000000000:4294967295>0000000000000000000000000000000000000000000000000000000000000000:4294967295</a></li>
+</ul>
+<h2>1 Output</h2>
+<ul class=monospace>
+  <li>
+    <a href=/output/{txid}:0 class=monospace>
+      {txid}:0
+    </a>
+    <dl>
+      <dt>value</dt><dd>5000000000</dd>
+      <dt>script pubkey</dt><dd class=monospace>.*</dd>
+    </dl>
+  </li>
+</ul>.*"
+      ),
+    );
+  }
+
+  #[test]
+  fn detect_unrecoverable_reorg() {
+    let test_server = TestServer::new();
+
+    test_server.mine_blocks(21);
+
+    test_server.assert_response_regex(
+      "/status",
+      StatusCode::OK,
+      ".*<dt>unrecoverably reorged</dt>\n  <dd>false</dd>.*",
+    );
+
+    for _ in 0..15 {
+      test_server.core.invalidate_tip();
+    }
+
+    test_server.core.mine_blocks(21);
+
+    test_server.assert_response_regex(
+      "/status",
+      StatusCode::OK,
+      ".*<dt>unrecoverably reorged</dt>\n  <dd>true</dd>.*",
+    );
+  }
+
+  #[test]
+  fn rare_with_sat_index() {
+    TestServer::builder().index_sats().build().assert_response(
+      "/rare.txt",
+      StatusCode::OK,
+      "sat\tsatpoint
+0\t4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0
+",
+    );
+  }
+
+  #[test]
+  fn rare_without_sat_index() {
+    TestServer::new().assert_response(
+      "/rare.txt",
+      StatusCode::OK,
+      "sat\tsatpoint
+",
+    );
+  }
+
+  #[test]
+  fn show_rare_txt_in_header_with_sat_index() {
     TestServer::builder()
-      .redirect_http_to_https()
-      .https()
+      .index_sats()
       .build()
-      .assert_redirect(
-        "/sat/0",
-        &format!("https://{}/sat/0", System::host_name().unwrap()),
+      .assert_response_regex(
+        "/",
+        StatusCode::OK,
+        ".*
+      <a href=/clock title=clock>.*</a>
+      <a href=/rare.txt title=rare>.*</a>.*",
       );
   }
 
   #[test]
-  fn http_to_https_redirect_with_empty() {
+  fn rare_sat_location() {
     TestServer::builder()
-      .redirect_http_to_https()
-      .https()
+      .index_sats()
       .build()
-      .assert_redirect("/", &format!("https://{}/", System::host_name().unwrap()));
+      .assert_response_regex(
+        "/sat/0",
+        StatusCode::OK,
+        ".*>4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0<.*",
+      );
   }
 
   #[test]
-  fn status() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn dont_show_rare_txt_in_header_without_sat_index() {
+    TestServer::new().assert_response_regex(
+      "/",
+      StatusCode::OK,
+      ".*
+      <a href=/clock title=clock>.*</a>
+      <a href=https://docs.ordinals.com/.*",
+    );
+  }
 
-    server.mine_blocks(3);
+  #[test]
+  fn input() {
+    TestServer::new().assert_response_regex(
+      "/input/0/0/0",
+      StatusCode::OK,
+      ".*<title>Input /0/0/0</title>.*<h1>Input /0/0/0</h1>.*<dt>text</dt><dd>.*The Times 03/Jan/2009 Chancellor on brink of second bailout for banks</dd>.*",
+    );
+  }
 
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        inscription("text/plain;charset=utf-8", "hello").to_witness(),
-      )],
-      ..default()
-    });
+  #[test]
+  fn input_missing() {
+    TestServer::new().assert_response(
+      "/input/1/1/1",
+      StatusCode::NOT_FOUND,
+      "input /1/1/1 not found",
+    );
+  }
 
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        2,
-        0,
-        0,
-        inscription("text/plain;charset=utf-8", "hello").to_witness(),
-      )],
-      ..default()
-    });
+  #[test]
+  fn commits_are_tracked() {
+    let server = TestServer::new();
+
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(server.index.statistic(crate::index::Statistic::Commits), 1);
+
+    let info = server.index.info().unwrap();
+    assert_eq!(info.transactions.len(), 1);
+    assert_eq!(info.transactions[0].starting_block_count, 0);
+
+    server.index.update().unwrap();
+
+    assert_eq!(server.index.statistic(crate::index::Statistic::Commits), 1);
+
+    let info = server.index.info().unwrap();
+    assert_eq!(info.transactions.len(), 1);
+    assert_eq!(info.transactions[0].starting_block_count, 0);
+
+    server.mine_blocks(1);
+
+    thread::sleep(Duration::from_millis(10));
+    server.index.update().unwrap();
+
+    assert_eq!(server.index.statistic(crate::index::Statistic::Commits), 2);
+
+    let info = server.index.info().unwrap();
+    assert_eq!(info.transactions.len(), 2);
+    assert_eq!(info.transactions[0].starting_block_count, 0);
+    assert_eq!(info.transactions[1].starting_block_count, 1);
+    assert!(
+      info.transactions[1].starting_timestamp - info.transactions[0].starting_timestamp >= 10
+    );
+  }
+
+  #[test]
+  fn outputs_traversed_are_tracked() {
+    let server = TestServer::builder().index_sats().build();
+
+    assert_eq!(
+      server
+        .index
+        .statistic(crate::index::Statistic::OutputsTraversed),
+      1
+    );
+
+    server.index.update().unwrap();
+
+    assert_eq!(
+      server
+        .index
+        .statistic(crate::index::Statistic::OutputsTraversed),
+      1
+    );
+
+    server.mine_blocks(2);
+
+    server.index.update().unwrap();
+
+    assert_eq!(
+      server
+        .index
+        .statistic(crate::index::Statistic::OutputsTraversed),
+      3
+    );
+  }
+
+  #[test]
+  fn output_sat_ranges_can_be_sorted_by_rarity() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    server.mine_blocks(2);
 
     server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        3,
-        0,
-        0,
-        Inscription {
-          content_type: None,
-          body: Some("hello".as_bytes().into()),
-          ..default()
-        }
-        .to_witness(),
-      )],
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
       ..default()
     });
 
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      "/status",
-      StatusCode::OK,
-      ".*<h1>Status</h1>
-<dl>
-  <dt>chain</dt>
-  <dd>regtest</dd>
-  <dt>height</dt>
-  <dd><a href=/block/4>4</a></dd>
-  <dt>inscriptions</dt>
-  <dd><a href=/inscriptions>3</a></dd>
-  <dt>blessed inscriptions</dt>
-  <dd>3</dd>
-  <dt>cursed inscriptions</dt>
-  <dd>0</dd>
-  <dt>runes</dt>
-  <dd><a href=/runes>0</a></dd>
-  <dt>lost sats</dt>
-  <dd>.*</dd>
-  <dt>started</dt>
-  <dd>.*</dd>
-  <dt>uptime</dt>
-  <dd>.*</dd>
-  <dt>minimum rune for next block</dt>
-  <dd>.*</dd>
-  <dt>version</dt>
-  <dd>.*</dd>
-  <dt>unrecoverably reorged</dt>
-  <dd>false</dd>
-  <dt>rune index</dt>
-  <dd>false</dd>
-  <dt>sat index</dt>
-  <dd>false</dd>
-  <dt>transaction index</dt>
-  <dd>false</dd>
-  <dt>git branch</dt>
-  <dd>.*</dd>
-  <dt>git commit</dt>
-  <dd>
-    <a href=https://github.com/ordinals/ord/commit/[[:xdigit:]]{40}>
-      [[:xdigit:]]{40}
-    </a>
-  </dd>
-  <dt>inscription content types</dt>
-  <dd>
-    <dl>
-      <dt>text/plain;charset=utf-8</dt>
-      <dd>2</dt>
-      <dt><em>none</em></dt>
-      <dd>1</dt>
-    </dl>
-  </dd>
-</dl>
-.*",
-    );
-  }
-
-  #[test]
-  fn block_count_endpoint() {
-    let test_server = TestServer::new();
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 1, 1, Default::default()), (2, 0, 0, Default::default())],
+      ..default()
+    });
 
-    let response = test_server.get("/blockcount");
+    server.mine_blocks(1);
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(response.text().unwrap(), "1");
+    let positional = server
+      .get_json::<api::Output>(format!("/output/{txid}:0"))
+      .sat_ranges
+      .unwrap();
 
-    test_server.mine_blocks(1);
+    assert_eq!(positional.len(), 2);
+    assert_eq!(Sat(positional[0].0).rarity(), Rarity::Common);
+    assert_eq!(Sat(positional[1].0).rarity(), Rarity::Uncommon);
 
-    let response = test_server.get("/blockcount");
+    let sorted = server
+      .get_json::<api::Output>(format!("/output/{txid}:0?sort=rarity"))
+      .sat_ranges
+      .unwrap();
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(response.text().unwrap(), "2");
+    assert_eq!(sorted, [positional[1], positional[0]]);
   }
 
   #[test]
-  fn block_height_endpoint() {
-    let test_server = TestServer::new();
+  fn output_confirmation_is_included_in_json() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    let response = test_server.get("/blockheight");
+    server.mine_blocks(1);
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(response.text().unwrap(), "0");
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      ..default()
+    });
 
-    test_server.mine_blocks(2);
+    server.mine_blocks(1);
 
-    let response = test_server.get("/blockheight");
+    let output = server.get_json::<api::Output>(format!("/output/{txid}:0"));
+    assert_eq!(output.confirmed, Some(true));
+    assert_eq!(output.height, Some(2));
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(response.text().unwrap(), "2");
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Default::default())],
+      ..default()
+    });
+
+    let output = server.get_json::<api::Output>(format!("/output/{txid}:0"));
+    assert_eq!(output.confirmed, Some(false));
+    assert_eq!(output.height, None);
   }
 
   #[test]
-  fn block_hash_endpoint() {
-    let test_server = TestServer::new();
+  fn output_rarity_counts() {
+    let server = TestServer::builder().index_sats().build();
 
-    let response = test_server.get("/blockhash");
+    let outpoint = OutPoint {
+      txid: Chain::Mainnet.genesis_block().coinbase().unwrap().txid(),
+      vout: 0,
+    };
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(
-      response.text().unwrap(),
-      "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+    let output = server.get_json::<api::Output>(format!("/output/{outpoint}"));
+
+    pretty_assert_eq!(
+      output.rarity_counts,
+      Some(
+        [(Rarity::Common, 50 * COIN_VALUE - 1), (Rarity::Mythic, 1)]
+          .into_iter()
+          .collect()
+      )
     );
-  }
 
-  #[test]
-  fn block_hash_from_height_endpoint() {
-    let test_server = TestServer::new();
+    let server = TestServer::new();
 
-    let response = test_server.get("/blockhash/0");
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url(&format!("/output/{outpoint}")))
+      .header(header::ACCEPT, "application/json")
+      .send()
+      .unwrap();
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(
-      response.text().unwrap(),
-      "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
-    );
+    assert!(!response.text().unwrap().contains("rarity_counts"));
   }
 
   #[test]
-  fn block_time_endpoint() {
-    let test_server = TestServer::new();
+  fn coinbase_sat_ranges_are_tracked() {
+    let server = TestServer::builder().index_sats().build();
 
-    let response = test_server.get("/blocktime");
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      1
+    );
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(response.text().unwrap(), "1231006505");
-  }
+    server.mine_blocks(1);
 
-  #[test]
-  fn range_end_before_range_start_returns_400() {
-    TestServer::new().assert_response(
-      "/range/1/0",
-      StatusCode::BAD_REQUEST,
-      "range start greater than range end",
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      2
     );
-  }
 
-  #[test]
-  fn invalid_range_start_returns_400() {
-    TestServer::new().assert_response(
-      "/range/=/0",
-      StatusCode::BAD_REQUEST,
-      "Invalid URL: failed to parse sat `=`: invalid integer: invalid digit found in string",
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      3
     );
   }
 
   #[test]
-  fn invalid_range_end_returns_400() {
-    TestServer::new().assert_response(
-      "/range/0/=",
-      StatusCode::BAD_REQUEST,
-      "Invalid URL: failed to parse sat `=`: invalid integer: invalid digit found in string",
+  fn split_sat_ranges_are_tracked() {
+    let server = TestServer::builder().index_sats().build();
+
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      1
     );
-  }
 
-  #[test]
-  fn empty_range_returns_400() {
-    TestServer::new().assert_response("/range/0/0", StatusCode::BAD_REQUEST, "empty range");
-  }
+    server.mine_blocks(1);
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      fee: 0,
+      ..default()
+    });
+    server.mine_blocks(1);
 
-  #[test]
-  fn range() {
-    TestServer::new().assert_response_regex(
-      "/range/0/1",
-      StatusCode::OK,
-      r".*<title>Sat Range 0–1</title>.*<h1>Sat Range 0–1</h1>
-<dl>
-  <dt>value</dt><dd>1</dd>
-  <dt>first</dt><dd><a href=/sat/0 class=mythic>0</a></dd>
-</dl>.*",
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      4,
     );
   }
-  #[test]
-  fn sat_number() {
-    TestServer::new().assert_response_regex("/sat/0", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
-  }
 
   #[test]
-  fn sat_decimal() {
-    TestServer::new().assert_response_regex("/sat/0.0", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
-  }
+  fn fee_sat_ranges_are_tracked() {
+    let server = TestServer::builder().index_sats().build();
 
-  #[test]
-  fn sat_degree() {
-    TestServer::new().assert_response_regex("/sat/0°0′0″0‴", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
-  }
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      1
+    );
 
-  #[test]
-  fn sat_name() {
-    TestServer::new().assert_response_regex(
-      "/sat/nvtdijuwxlp",
-      StatusCode::OK,
-      ".*<h1>Sat 0</h1>.*",
+    server.mine_blocks(1);
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      fee: 2,
+      ..default()
+    });
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.index.statistic(crate::index::Statistic::SatRanges),
+      5,
     );
   }
 
   #[test]
-  fn sat() {
-    TestServer::new().assert_response_regex(
-      "/sat/0",
-      StatusCode::OK,
-      ".*<title>Sat 0</title>.*<h1>Sat 0</h1>.*",
+  fn content_response_no_content() {
+    assert_eq!(
+      Server::content_response(
+        inscription_id(1),
+        None,
+        Inscription {
+          content_type: Some("text/plain".as_bytes().to_vec()),
+          body: None,
+          ..default()
+        },
+        AcceptEncoding::default(),
+        &ServerConfig::default(),
+      )
+      .unwrap(),
+      None
     );
   }
 
   #[test]
-  fn block() {
-    TestServer::new().assert_response_regex(
-      "/block/0",
-      StatusCode::OK,
-      ".*<title>Block 0</title>.*<h1>Block 0</h1>.*",
-    );
+  fn content_response_with_content() {
+    let (headers, body) = Server::content_response(
+      inscription_id(1),
+      None,
+      Inscription {
+        content_type: Some("text/plain".as_bytes().to_vec()),
+        body: Some(vec![1, 2, 3]),
+        ..default()
+      },
+      AcceptEncoding::default(),
+      &ServerConfig::default(),
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(headers["content-type"], "text/plain");
+    assert_eq!(body, vec![1, 2, 3]);
   }
 
   #[test]
-  fn sat_out_of_range() {
-    TestServer::new().assert_response(
-      "/sat/2099999997690000",
-      StatusCode::BAD_REQUEST,
-      "Invalid URL: failed to parse sat `2099999997690000`: invalid integer range",
+  fn content_security_policy_no_origin() {
+    let (headers, _) = Server::content_response(
+      inscription_id(1),
+      None,
+      Inscription {
+        content_type: Some("text/plain".as_bytes().to_vec()),
+        body: Some(vec![1, 2, 3]),
+        ..default()
+      },
+      AcceptEncoding::default(),
+      &ServerConfig::default(),
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+      headers["content-security-policy"],
+      HeaderValue::from_static("default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:")
     );
   }
 
   #[test]
-  fn invalid_outpoint_hash_returns_400() {
-    TestServer::new().assert_response(
-      "/output/foo:0",
-      StatusCode::BAD_REQUEST,
-      "Invalid URL: error parsing TXID",
-    );
+  fn content_security_policy_with_origin() {
+    let (headers, _) = Server::content_response(
+      inscription_id(1),
+      None,
+      Inscription {
+        content_type: Some("text/plain".as_bytes().to_vec()),
+        body: Some(vec![1, 2, 3]),
+        ..default()
+      },
+      AcceptEncoding::default(),
+      &ServerConfig {
+        csp_origin: Some("https://ordinals.com".into()),
+        ..default()
+      },
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(headers["content-security-policy"], HeaderValue::from_static("default-src https://ordinals.com/content/ https://ordinals.com/blockheight https://ordinals.com/blockhash https://ordinals.com/blockhash/ https://ordinals.com/blocktime https://ordinals.com/r/ 'unsafe-eval' 'unsafe-inline' data: blob:"));
   }
 
   #[test]
-  fn output_with_sat_index() {
-    let txid = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
-    TestServer::builder()
-      .index_sats()
-      .build()
-      .assert_response_regex(
-        format!("/output/{txid}:0"),
+  fn preview_content_security_policy() {
+    {
+      let server = TestServer::builder().chain(Chain::Regtest).build();
+
+      server.mine_blocks(1);
+
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..default()
+      });
+
+      server.mine_blocks(1);
+
+      let inscription_id = InscriptionId { txid, index: 0 };
+
+      server.assert_response_csp(
+        format!("/preview/{}", inscription_id),
         StatusCode::OK,
-        format!(
-          ".*<title>Output {txid}:0</title>.*<h1>Output <span class=monospace>{txid}:0</span></h1>
-<dl>
-  <dt>value</dt><dd>5000000000</dd>
-  <dt>script pubkey</dt><dd class=monospace>OP_PUSHBYTES_65 [[:xdigit:]]{{130}} OP_CHECKSIG</dd>
-  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
-  <dt>spent</dt><dd>false</dd>
-</dl>
-<h2>1 Sat Range</h2>
-<ul class=monospace>
-  <li><a href=/range/0/5000000000 class=mythic>0–5000000000</a></li>
-</ul>.*"
-        ),
+        "default-src 'self'",
+        format!(".*<html lang=en data-inscription={}>.*", inscription_id),
       );
-  }
+    }
 
-  #[test]
-  fn output_without_sat_index() {
-    let txid = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
-    TestServer::new().assert_response_regex(
-      format!("/output/{txid}:0"),
-      StatusCode::OK,
-      format!(
-        ".*<title>Output {txid}:0</title>.*<h1>Output <span class=monospace>{txid}:0</span></h1>
-<dl>
-  <dt>value</dt><dd>5000000000</dd>
-  <dt>script pubkey</dt><dd class=monospace>OP_PUSHBYTES_65 [[:xdigit:]]{{130}} OP_CHECKSIG</dd>
-  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
-  <dt>spent</dt><dd>false</dd>
-</dl>.*"
-      ),
-    );
-  }
+    {
+      let server = TestServer::builder()
+        .chain(Chain::Regtest)
+        .server_option("--csp-origin", "https://ordinals.com")
+        .build();
 
-  #[test]
-  fn null_output_is_initially_empty() {
-    let txid = "0000000000000000000000000000000000000000000000000000000000000000";
-    TestServer::builder().index_sats().build().assert_response_regex(
-      format!("/output/{txid}:4294967295"),
-      StatusCode::OK,
-      format!(
-        ".*<title>Output {txid}:4294967295</title>.*<h1>Output <span class=monospace>{txid}:4294967295</span></h1>
-<dl>
-  <dt>value</dt><dd>0</dd>
-  <dt>script pubkey</dt><dd class=monospace></dd>
-  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
-  <dt>spent</dt><dd>false</dd>
-</dl>
-<h2>0 Sat Ranges</h2>
-<ul class=monospace>
-</ul>.*"
-      ),
-    );
+      server.mine_blocks(1);
+
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..default()
+      });
+
+      server.mine_blocks(1);
+
+      let inscription_id = InscriptionId { txid, index: 0 };
+
+      server.assert_response_csp(
+        format!("/preview/{}", inscription_id),
+        StatusCode::OK,
+        "default-src https://ordinals.com",
+        format!(".*<html lang=en data-inscription={}>.*", inscription_id),
+      );
+    }
   }
 
   #[test]
-  fn null_output_receives_lost_sats() {
-    let server = TestServer::builder().index_sats().build();
+  fn code_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-    server.mine_blocks_with_subsidy(1, 0);
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/javascript", "hello").to_witness(),
+      )],
+      ..default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    let txid = "0000000000000000000000000000000000000000000000000000000000000000";
+    server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/output/{txid}:4294967295"),
+      format!("/preview/{inscription_id}"),
       StatusCode::OK,
-      format!(
-        ".*<title>Output {txid}:4294967295</title>.*<h1>Output <span class=monospace>{txid}:4294967295</span></h1>
-<dl>
-  <dt>value</dt><dd>5000000000</dd>
-  <dt>script pubkey</dt><dd class=monospace></dd>
-  <dt>transaction</dt><dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
-  <dt>spent</dt><dd>false</dd>
-</dl>
-<h2>1 Sat Range</h2>
-<ul class=monospace>
-  <li><a href=/range/5000000000/10000000000 class=uncommon>5000000000–10000000000</a></li>
-</ul>.*"
-      ),
+      format!(r".*<html lang=en data-inscription={inscription_id} data-language=javascript>.*"),
     );
   }
 
   #[test]
-  fn unbound_output_receives_unbound_inscriptions() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
+  fn content_response_no_content_type() {
+    let (headers, body) = Server::content_response(
+      inscription_id(1),
+      None,
+      Inscription {
+        content_type: None,
+        body: Some(Vec::new()),
+        ..default()
+      },
+      AcceptEncoding::default(),
+      &ServerConfig::default(),
+    )
+    .unwrap()
+    .unwrap();
 
-    server.mine_blocks(1);
+    assert_eq!(headers["content-type"], "application/octet-stream");
+    assert!(body.is_empty());
+  }
+
+  #[test]
+  fn content_response_bad_content_type() {
+    let (headers, body) = Server::content_response(
+      inscription_id(1),
+      None,
+      Inscription {
+        content_type: Some("\n".as_bytes().to_vec()),
+        body: Some(Vec::new()),
+        ..Default::default()
+      },
+      AcceptEncoding::default(),
+      &ServerConfig::default(),
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(headers["content-type"], "application/octet-stream");
+    assert!(body.is_empty());
+  }
+
+  #[test]
+  fn content_response_content_type_override() {
+    let (headers, body) = Server::content_response(
+      inscription_id(1),
+      None,
+      Inscription {
+        content_type: Some("text/plain".as_bytes().to_vec()),
+        body: Some(vec![1, 2, 3]),
+        ..default()
+      },
+      AcceptEncoding::default(),
+      &ServerConfig {
+        serve_content_type: [(inscription_id(1), HeaderValue::from_static("image/png"))].into(),
+        ..default()
+      },
+    )
+    .unwrap()
+    .unwrap();
 
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, Default::default())],
-      fee: 50 * 100_000_000,
-      ..default()
-    });
+    assert_eq!(headers["content-type"], "image/png");
+    assert_eq!(body, vec![1, 2, 3]);
+  }
 
+  #[test]
+  fn text_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
       inputs: &[(
-        2,
         1,
         0,
+        0,
         inscription("text/plain;charset=utf-8", "hello").to_witness(),
       )],
       ..default()
     });
 
-    server.mine_blocks(1);
-
     let inscription_id = InscriptionId { txid, index: 0 };
 
-    server.assert_response_regex(
-      format!("/inscription/{}", inscription_id),
-      StatusCode::OK,
-      format!(
-        ".*<dl>
-  <dt>id</dt>
-  <dd class=monospace>{inscription_id}</dd>.*<dt>output</dt>
-  <dd><a class=monospace href=/output/0000000000000000000000000000000000000000000000000000000000000000:0>0000000000000000000000000000000000000000000000000000000000000000:0</a></dd>.*"
-      ),
-    );
+    server.mine_blocks(1);
 
-    server.assert_response_regex(
-      "/output/0000000000000000000000000000000000000000000000000000000000000000:0",
+    server.assert_response_csp(
+      format!("/preview/{}", inscription_id),
       StatusCode::OK,
-      ".*<h1>Output <span class=monospace>0000000000000000000000000000000000000000000000000000000000000000:0</span></h1>
-<dl>
-  <dt>inscriptions</dt>
-  <dd class=thumbnails>
-    <a href=/inscription/.*><iframe sandbox=allow-scripts scrolling=no loading=lazy src=/preview/.*></iframe></a>
-  </dd>.*",
+      "default-src 'self'",
+      format!(".*<html lang=en data-inscription={}>.*", inscription_id),
     );
   }
 
   #[test]
-  fn unbound_output_returns_200() {
-    TestServer::new().assert_response_regex(
-      "/output/0000000000000000000000000000000000000000000000000000000000000000:0",
-      StatusCode::OK,
-      ".*",
-    );
-  }
+  fn audio_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-  #[test]
-  fn invalid_output_returns_400() {
-    TestServer::new().assert_response(
-      "/output/foo:0",
-      StatusCode::BAD_REQUEST,
-      "Invalid URL: error parsing TXID",
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("audio/flac", "hello").to_witness())],
+      ..default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/preview/{inscription_id}"),
+      StatusCode::OK,
+      format!(r".*<audio .*>\s*<source src=/content/{inscription_id}>.*"),
     );
   }
 
   #[test]
-  fn home() {
+  fn font_preview() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
-
     server.mine_blocks(1);
 
-    let mut ids = Vec::new();
-
-    for i in 0..101 {
-      let txid = server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(i + 1, 0, 0, inscription("image/png", "hello").to_witness())],
-        ..default()
-      });
-      ids.push(InscriptionId { txid, index: 0 });
-      server.mine_blocks(1);
-    }
-
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(102, 0, 0, inscription("text/plain", "{}").to_witness())],
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("font/ttf", "hello").to_witness())],
       ..default()
     });
+    let inscription_id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      "/",
+      format!("/preview/{inscription_id}"),
       StatusCode::OK,
-      format!(
-        r".*<title>Ordinals</title>.*
-<h1>Latest Inscriptions</h1>
-<div class=thumbnails>
-  <a href=/inscription/{}>.*</a>
-  (<a href=/inscription/[[:xdigit:]]{{64}}i0>.*</a>\s*){{99}}
-</div>
-.*
-",
-        ids[100]
-      ),
+      format!(r".*src: url\(/content/{inscription_id}\).*"),
     );
   }
 
   #[test]
-  fn blocks() {
-    let test_server = TestServer::new();
+  fn pdf_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-    test_server.mine_blocks(1);
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("application/pdf", "hello").to_witness(),
+      )],
+      ..default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    test_server.assert_response_regex(
-      "/blocks",
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/preview/{inscription_id}"),
       StatusCode::OK,
-      ".*<title>Blocks</title>.*
-<h1>Blocks</h1>
-<div class=block>
-  <h2><a href=/block/1>Block 1</a></h2>
-  <div class=thumbnails>
-  </div>
-</div>
-<div class=block>
-  <h2><a href=/block/0>Block 0</a></h2>
-  <div class=thumbnails>
-  </div>
-</div>
-</ol>.*",
+      format!(r".*<canvas data-inscription={inscription_id}></canvas>.*"),
     );
   }
 
   #[test]
-  fn nav_displays_chain() {
-    TestServer::builder()
-      .chain(Chain::Regtest)
-      .build()
-      .assert_response_regex(
-        "/",
-        StatusCode::OK,
-        ".*<a href=/ title=home>Ordinals<sup>regtest</sup></a>.*",
-      );
-  }
+  fn markdown_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-  #[test]
-  fn blocks_block_limit() {
-    let test_server = TestServer::new();
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/markdown", "hello").to_witness())],
+      ..default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    test_server.mine_blocks(101);
+    server.mine_blocks(1);
 
-    test_server.assert_response_regex(
-      "/blocks",
+    server.assert_response_regex(
+      format!("/preview/{inscription_id}"),
       StatusCode::OK,
-      ".*<ol start=96 reversed class=block-list>\n(  <li><a href=/block/[[:xdigit:]]{64}>[[:xdigit:]]{64}</a></li>\n){95}</ol>.*"
+      format!(r".*<html lang=en data-inscription={inscription_id}>.*"),
     );
   }
 
   #[test]
-  fn block_not_found() {
-    TestServer::new().assert_response(
-      "/block/467a86f0642b1d284376d13a98ef58310caa49502b0f9a560ee222e0a122fe16",
-      StatusCode::NOT_FOUND,
-      "block 467a86f0642b1d284376d13a98ef58310caa49502b0f9a560ee222e0a122fe16 not found",
-    );
-  }
+  fn image_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-  #[test]
-  fn unmined_sat() {
-    TestServer::new().assert_response_regex(
-      "/sat/0",
-      StatusCode::OK,
-      ".*<dt>timestamp</dt><dd><time>2009-01-03 18:15:05 UTC</time></dd>.*",
-    );
-  }
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("image/png", "hello").to_witness())],
+      ..default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-  #[test]
-  fn mined_sat() {
-    TestServer::new().assert_response_regex(
-      "/sat/5000000000",
-      StatusCode::OK,
-      ".*<dt>timestamp</dt><dd><time>.*</time> \\(expected\\)</dd>.*",
-    );
-  }
+    server.mine_blocks(1);
 
-  #[test]
-  fn static_asset() {
-    TestServer::new().assert_response_regex(
-      "/static/index.css",
+    server.assert_response_csp(
+      format!("/preview/{inscription_id}"),
       StatusCode::OK,
-      r".*\.rare \{
-  background-color: var\(--rare\);
-}.*",
+      "default-src 'self' 'unsafe-inline'",
+      format!(r".*background-image: url\(/content/{inscription_id}\);.*"),
     );
   }
 
   #[test]
-  fn favicon() {
-    TestServer::new().assert_response_regex("/favicon.ico", StatusCode::OK, r".*");
-  }
-
-  #[test]
-  fn clock_updates() {
-    let test_server = TestServer::new();
-    test_server.assert_response_regex("/clock", StatusCode::OK, ".*<text.*>0</text>.*");
-    test_server.mine_blocks(1);
-    test_server.assert_response_regex("/clock", StatusCode::OK, ".*<text.*>1</text>.*");
-  }
-
-  #[test]
-  fn block_by_hash() {
-    let test_server = TestServer::new();
+  fn iframe_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-    test_server.mine_blocks(1);
-    let transaction = TransactionTemplate {
-      inputs: &[(1, 0, 0, Default::default())],
-      fee: 0,
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/html;charset=utf-8", "hello").to_witness(),
+      )],
       ..default()
-    };
-    test_server.core.broadcast_tx(transaction);
-    let block_hash = test_server.mine_blocks(1)[0].block_hash();
+    });
 
-    test_server.assert_response_regex(
-      format!("/block/{block_hash}"),
+    server.mine_blocks(1);
+
+    server.assert_response_csp(
+      format!("/preview/{}", InscriptionId { txid, index: 0 }),
       StatusCode::OK,
-      ".*<h1>Block 2</h1>.*",
+      "default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:",
+      "hello",
     );
   }
 
   #[test]
-  fn block_by_height() {
-    let test_server = TestServer::new();
-
-    test_server.assert_response_regex("/block/0", StatusCode::OK, ".*<h1>Block 0</h1>.*");
-  }
-
-  #[test]
-  fn transaction() {
-    let test_server = TestServer::new();
+  fn unknown_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-    let coinbase_tx = test_server.mine_blocks(1)[0].txdata[0].clone();
-    let txid = coinbase_tx.txid();
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
 
-    test_server.assert_response_regex(
-      format!("/tx/{txid}"),
+    server.mine_blocks(1);
+
+    server.assert_response_csp(
+      format!("/preview/{}", InscriptionId { txid, index: 0 }),
       StatusCode::OK,
-      format!(
-        ".*<title>Transaction {txid}</title>.*<h1>Transaction <span class=monospace>{txid}</span></h1>
-<dl>
-</dl>
-<h2>1 Input</h2>
-<ul>
-  <li><a class=monospace href=/output/0000000000000000000000000000000000000000000000000000000000000000:4294967295>0000000000000000000000000000000000000000000000000000000000000000:4294967295</a></li>
-</ul>
-<h2>1 Output</h2>
-<ul class=monospace>
-  <li>
-    <a href=/output/{txid}:0 class=monospace>
-      {txid}:0
-    </a>
-    <dl>
-      <dt>value</dt><dd>5000000000</dd>
-      <dt>script pubkey</dt><dd class=monospace>.*</dd>
-    </dl>
-  </li>
-</ul>.*"
-      ),
+      "default-src 'self'",
+      fs::read_to_string("templates/preview-unknown.html").unwrap(),
     );
   }
 
   #[test]
-  fn detect_unrecoverable_reorg() {
-    let test_server = TestServer::new();
+  fn video_preview() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-    test_server.mine_blocks(21);
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("video/webm", "hello").to_witness())],
+      ..default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    test_server.assert_response_regex(
-      "/status",
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/preview/{inscription_id}"),
       StatusCode::OK,
-      ".*<dt>unrecoverably reorged</dt>\n  <dd>false</dd>.*",
+      format!(r".*<video .*>\s*<source src=/content/{inscription_id}>.*"),
     );
+  }
 
-    for _ in 0..15 {
-      test_server.core.invalidate_tip();
-    }
+  #[test]
+  fn inscription_page_title() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+    server.mine_blocks(1);
 
-    test_server.core.mine_blocks(21);
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
 
-    test_server.assert_response_regex(
-      "/status",
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
       StatusCode::OK,
-      ".*<dt>unrecoverably reorged</dt>\n  <dd>true</dd>.*",
+      ".*<title>Inscription 0</title>.*",
     );
   }
 
   #[test]
-  fn rare_with_sat_index() {
-    TestServer::builder().index_sats().build().assert_response(
-      "/rare.txt",
+  fn inscription_page_has_sat_when_sats_are_tracked() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+    server.mine_blocks(1);
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
       StatusCode::OK,
-      "sat\tsatpoint
-0\t4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0
-",
+      r".*<dt>sat</dt>\s*<dd><a href=/sat/5000000000>5000000000</a></dd>\s*<dt>preview</dt>.*",
     );
   }
 
   #[test]
-  fn rare_without_sat_index() {
-    TestServer::new().assert_response(
-      "/rare.txt",
+  fn inscriptions_can_be_looked_up_by_sat_name() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+    server.mine_blocks(1);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/inscription/{}", Sat(5000000000).name()),
       StatusCode::OK,
-      "sat\tsatpoint
-",
+      ".*<title>Inscription 0</title.*",
     );
   }
 
   #[test]
-  fn show_rare_txt_in_header_with_sat_index() {
-    TestServer::builder()
+  fn inscriptions_can_be_looked_up_by_sat_name_with_letter_i() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
       .index_sats()
-      .build()
-      .assert_response_regex(
-        "/",
-        StatusCode::OK,
-        ".*
-      <a href=/clock title=clock>.*</a>
-      <a href=/rare.txt title=rare>.*</a>.*",
-      );
+      .build();
+    server.assert_response_regex("/inscription/i", StatusCode::NOT_FOUND, ".*");
   }
 
   #[test]
-  fn rare_sat_location() {
-    TestServer::builder()
-      .index_sats()
-      .build()
-      .assert_response_regex(
-        "/sat/0",
-        StatusCode::OK,
-        ".*>4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0<.*",
-      );
-  }
+  fn inscription_page_does_not_have_sat_when_sats_are_not_tracked() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
 
-  #[test]
-  fn dont_show_rare_txt_in_header_without_sat_index() {
-    TestServer::new().assert_response_regex(
-      "/",
-      StatusCode::OK,
-      ".*
-      <a href=/clock title=clock>.*</a>
-      <a href=https://docs.ordinals.com/.*",
-    );
-  }
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
 
-  #[test]
-  fn input() {
-    TestServer::new().assert_response_regex(
-      "/input/0/0/0",
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
       StatusCode::OK,
-      ".*<title>Input /0/0/0</title>.*<h1>Input /0/0/0</h1>.*<dt>text</dt><dd>.*The Times 03/Jan/2009 Chancellor on brink of second bailout for banks</dd>.*",
+      r".*<dt>value</dt>\s*<dd>5000000000</dd>\s*<dt>preview</dt>.*",
     );
   }
 
   #[test]
-  fn input_missing() {
-    TestServer::new().assert_response(
-      "/input/1/1/1",
-      StatusCode::NOT_FOUND,
-      "input /1/1/1 not found",
+  fn strict_transport_security_header_is_set() {
+    assert_eq!(
+      TestServer::new()
+        .get("/status")
+        .headers()
+        .get(header::STRICT_TRANSPORT_SECURITY)
+        .unwrap(),
+      "max-age=31536000; includeSubDomains; preload",
     );
   }
 
   #[test]
-  fn commits_are_tracked() {
-    let server = TestServer::new();
-
-    thread::sleep(Duration::from_millis(100));
-    assert_eq!(server.index.statistic(crate::index::Statistic::Commits), 1);
-
-    let info = server.index.info().unwrap();
-    assert_eq!(info.transactions.len(), 1);
-    assert_eq!(info.transactions[0].starting_block_count, 0);
+  fn feed() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+    server.mine_blocks(1);
 
-    server.index.update().unwrap();
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
 
-    assert_eq!(server.index.statistic(crate::index::Statistic::Commits), 1);
+    server.mine_blocks(1);
 
-    let info = server.index.info().unwrap();
-    assert_eq!(info.transactions.len(), 1);
-    assert_eq!(info.transactions[0].starting_block_count, 0);
+    server.assert_response_regex(
+      "/feed.xml",
+      StatusCode::OK,
+      ".*<title>Inscription 0</title>.*<pubDate>Thu, 1 Jan 1970 00:00:02 \\+0000</pubDate>.*",
+    );
+  }
 
+  #[test]
+  fn inscription_with_unknown_type_and_no_body_has_unknown_preview() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
     server.mine_blocks(1);
 
-    thread::sleep(Duration::from_millis(10));
-    server.index.update().unwrap();
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        Inscription {
+          content_type: Some("foo/bar".as_bytes().to_vec()),
+          body: None,
+          ..default()
+        }
+        .to_witness(),
+      )],
+      ..default()
+    });
 
-    assert_eq!(server.index.statistic(crate::index::Statistic::Commits), 2);
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    let info = server.index.info().unwrap();
-    assert_eq!(info.transactions.len(), 2);
-    assert_eq!(info.transactions[0].starting_block_count, 0);
-    assert_eq!(info.transactions[1].starting_block_count, 1);
-    assert!(
-      info.transactions[1].starting_timestamp - info.transactions[0].starting_timestamp >= 10
+    server.mine_blocks(1);
+
+    server.assert_response(
+      format!("/preview/{inscription_id}"),
+      StatusCode::OK,
+      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
     );
   }
 
   #[test]
-  fn outputs_traversed_are_tracked() {
-    let server = TestServer::builder().index_sats().build();
-
-    assert_eq!(
-      server
-        .index
-        .statistic(crate::index::Statistic::OutputsTraversed),
-      1
-    );
-
-    server.index.update().unwrap();
+  fn inscription_with_known_type_and_no_body_has_unknown_preview() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+    server.mine_blocks(1);
 
-    assert_eq!(
-      server
-        .index
-        .statistic(crate::index::Statistic::OutputsTraversed),
-      1
-    );
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        Inscription {
+          content_type: Some("image/png".as_bytes().to_vec()),
+          body: None,
+          ..default()
+        }
+        .to_witness(),
+      )],
+      ..default()
+    });
 
-    server.mine_blocks(2);
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    server.index.update().unwrap();
+    server.mine_blocks(1);
 
-    assert_eq!(
-      server
-        .index
-        .statistic(crate::index::Statistic::OutputsTraversed),
-      3
+    server.assert_response(
+      format!("/preview/{inscription_id}"),
+      StatusCode::OK,
+      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
     );
   }
 
   #[test]
-  fn coinbase_sat_ranges_are_tracked() {
-    let server = TestServer::builder().index_sats().build();
-
-    assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      1
-    );
-
+  fn content_responses_have_cache_control_headers() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
 
-    assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      2
-    );
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
 
     server.mine_blocks(1);
 
+    let response = server.get(format!("/content/{}", InscriptionId { txid, index: 0 }));
+
+    assert_eq!(response.status(), StatusCode::OK);
     assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      3
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "public, max-age=1209600, immutable"
     );
   }
 
   #[test]
-  fn split_sat_ranges_are_tracked() {
-    let server = TestServer::builder().index_sats().build();
+  fn content_is_served_by_number() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(3);
 
-    assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      1
-    );
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
 
-    server.mine_blocks(1);
     server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, Default::default())],
-      outputs: 2,
-      fee: 0,
+      inputs: &[
+        (2, 0, 0, Witness::default()),
+        (3, 0, 0, inscription("text/plain", "cursed").to_witness()),
+      ],
       ..default()
     });
+
     server.mine_blocks(1);
 
+    let response = server.get("/content/number/0");
+    assert_eq!(response.status(), StatusCode::OK);
     assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      4,
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "public, max-age=600"
     );
-  }
-
-  #[test]
-  fn fee_sat_ranges_are_tracked() {
-    let server = TestServer::builder().index_sats().build();
-
     assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      1
+      response.headers().get(header::CONTENT_SECURITY_POLICY),
+      server
+        .get(format!("/content/{}", InscriptionId { txid, index: 0 }))
+        .headers()
+        .get(header::CONTENT_SECURITY_POLICY),
+    );
+    assert_eq!(response.text().unwrap(), "hello");
+
+    server.assert_response("/content/number/-1", StatusCode::OK, "cursed");
+    server.assert_response(
+      "/content/number/1",
+      StatusCode::NOT_FOUND,
+      "inscription 1 not found",
     );
+  }
 
+  #[test]
+  fn content_is_served_with_matching_extension() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, Default::default())],
-      outputs: 2,
-      fee: 2,
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
       ..default()
     });
+
     server.mine_blocks(1);
 
-    assert_eq!(
-      server.index.statistic(crate::index::Statistic::SatRanges),
-      5,
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let response = server.get(format!("/content/{inscription_id}.txt"));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain");
+    assert_eq!(response.text().unwrap(), "hello");
+
+    server.assert_response(
+      format!("/content/{inscription_id}.png"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {inscription_id} content with extension `.png` not found"),
     );
   }
 
   #[test]
-  fn content_response_no_content() {
+  fn error_content_responses_have_max_age_zero_cache_control_headers() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    let response =
+      server.get("/content/6ac5cacb768794f4fd7a78bf00f2074891fce68bd65c4ff36e77177237aacacai0");
+
+    assert_eq!(response.status(), 404);
     assert_eq!(
-      Server::content_response(
-        Inscription {
-          content_type: Some("text/plain".as_bytes().to_vec()),
-          body: None,
-          ..default()
-        },
-        AcceptEncoding::default(),
-        &ServerConfig::default(),
-      )
-      .unwrap(),
-      None
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "no-store"
     );
   }
 
   #[test]
-  fn content_response_with_content() {
-    let (headers, body) = Server::content_response(
-      Inscription {
-        content_type: Some("text/plain".as_bytes().to_vec()),
-        body: Some(vec![1, 2, 3]),
+  fn inscriptions_page_with_no_prev_or_next() {
+    TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build()
+      .assert_response_regex("/inscriptions", StatusCode::OK, ".*prev\nnext.*");
+  }
+
+  #[test]
+  fn inscriptions_page_size_can_be_limited() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .server_option("--max-page-size", "2")
+      .build();
+
+    for i in 0..3 {
+      server.mine_blocks(1);
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
         ..default()
-      },
-      AcceptEncoding::default(),
-      &ServerConfig::default(),
-    )
-    .unwrap()
-    .unwrap();
+      });
+    }
 
-    assert_eq!(headers["content-type"], "text/plain");
-    assert_eq!(body, vec![1, 2, 3]);
+    server.mine_blocks(1);
+
+    let inscriptions = server.get_json::<api::Inscriptions>("/inscriptions?limit=1");
+    assert_eq!(inscriptions.ids.len(), 1);
+    assert!(inscriptions.more);
+
+    let inscriptions = server.get_json::<api::Inscriptions>("/inscriptions/1?limit=1");
+    assert_eq!(inscriptions.ids.len(), 1);
+    assert!(inscriptions.more);
+
+    let inscriptions = server.get_json::<api::Inscriptions>("/inscriptions?limit=100");
+    assert_eq!(inscriptions.ids.len(), 2);
+    assert!(inscriptions.more);
+
+    let inscriptions = server.get_json::<api::Inscriptions>("/inscriptions/1");
+    assert_eq!(inscriptions.ids.len(), 1);
+    assert!(!inscriptions.more);
+
+    server.assert_response_regex("/inscriptions?limit=foo", StatusCode::BAD_REQUEST, ".*");
+
+    server.assert_response(
+      format!("/inscriptions/{}?limit=2", u32::MAX),
+      StatusCode::BAD_REQUEST,
+      &format!("page {} is out of range", u32::MAX),
+    );
+
+    server.assert_response(
+      format!("/r/children/{}/{}?limit=2", inscriptions.ids[0], usize::MAX),
+      StatusCode::BAD_REQUEST,
+      &format!("page {} is out of range", usize::MAX),
+    );
   }
 
   #[test]
-  fn content_security_policy_no_origin() {
-    let (headers, _) = Server::content_response(
-      Inscription {
-        content_type: Some("text/plain".as_bytes().to_vec()),
-        body: Some(vec![1, 2, 3]),
+  fn inscriptions_page_with_no_next() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    for i in 0..101 {
+      server.mine_blocks(1);
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
         ..default()
-      },
-      AcceptEncoding::default(),
-      &ServerConfig::default(),
-    )
-    .unwrap()
-    .unwrap();
+      });
+    }
 
-    assert_eq!(
-      headers["content-security-policy"],
-      HeaderValue::from_static("default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:")
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/inscriptions/1",
+      StatusCode::OK,
+      ".*<a class=prev href=/inscriptions/0>prev</a>\nnext.*",
     );
   }
 
   #[test]
-  fn content_security_policy_with_origin() {
-    let (headers, _) = Server::content_response(
-      Inscription {
-        content_type: Some("text/plain".as_bytes().to_vec()),
-        body: Some(vec![1, 2, 3]),
-        ..default()
-      },
-      AcceptEncoding::default(),
-      &ServerConfig {
-        csp_origin: Some("https://ordinals.com".into()),
-        ..default()
-      },
-    )
-    .unwrap()
-    .unwrap();
+  fn inscriptions_page_with_no_prev() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    for i in 0..101 {
+      server.mine_blocks(1);
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
+        ..default()
+      });
+    }
 
-    assert_eq!(headers["content-security-policy"], HeaderValue::from_static("default-src https://ordinals.com/content/ https://ordinals.com/blockheight https://ordinals.com/blockhash https://ordinals.com/blockhash/ https://ordinals.com/blocktime https://ordinals.com/r/ 'unsafe-eval' 'unsafe-inline' data: blob:"));
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/inscriptions/0",
+      StatusCode::OK,
+      ".*prev\n<a class=next href=/inscriptions/1>next</a>.*",
+    );
   }
 
   #[test]
-  fn preview_content_security_policy() {
-    {
-      let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn collections_page_prev_and_next() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
 
+    let mut parent_ids = Vec::new();
+
+    for i in 0..101 {
       server.mine_blocks(1);
 
-      let txid = server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
-        ..default()
+      parent_ids.push(InscriptionId {
+        txid: server.core.broadcast_tx(TransactionTemplate {
+          inputs: &[(i + 1, 0, 0, inscription("text/plain", "hello").to_witness())],
+          ..default()
+        }),
+        index: 0,
       });
+    }
 
+    for (i, parent_id) in parent_ids.iter().enumerate().take(101) {
       server.mine_blocks(1);
 
-      let inscription_id = InscriptionId { txid, index: 0 };
-
-      server.assert_response_csp(
-        format!("/preview/{}", inscription_id),
-        StatusCode::OK,
-        "default-src 'self'",
-        format!(".*<html lang=en data-inscription={}>.*", inscription_id),
-      );
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[
+          (i + 2, 1, 0, Default::default()),
+          (
+            i + 102,
+            0,
+            0,
+            Inscription {
+              content_type: Some("text/plain".into()),
+              body: Some("hello".into()),
+              parents: vec![parent_id.value()],
+              ..default()
+            }
+            .to_witness(),
+          ),
+        ],
+        outputs: 2,
+        output_values: &[50 * COIN_VALUE, 50 * COIN_VALUE],
+        ..default()
+      });
     }
 
-    {
-      let server = TestServer::builder()
-        .chain(Chain::Regtest)
-        .server_option("--csp-origin", "https://ordinals.com")
-        .build();
+    server.mine_blocks(1);
 
-      server.mine_blocks(1);
+    server.assert_response_regex(
+      "/collections",
+      StatusCode::OK,
+      r".*
+<h1>Collections</h1>
+<div class=thumbnails>
+  <a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>
+  (<a href=/inscription/[[:xdigit:]]{64}i0>.*</a>\s*){99}
+</div>
+<div class=center>
+prev
+<a class=next href=/collections/1>next</a>
+</div>.*"
+        .to_string()
+        .unindent(),
+    );
 
-      let txid = server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
-        ..default()
-      });
+    server.assert_response_regex(
+      "/collections/1",
+      StatusCode::OK,
+      ".*
+<h1>Collections</h1>
+<div class=thumbnails>
+  <a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>
+</div>
+<div class=center>
+<a class=prev href=/collections/0>prev</a>
+next
+</div>.*"
+        .unindent(),
+    );
+  }
 
-      server.mine_blocks(1);
+  #[test]
+  fn responses_are_gzipped() {
+    let server = TestServer::new();
 
-      let inscription_id = InscriptionId { txid, index: 0 };
+    let mut headers = HeaderMap::new();
 
-      server.assert_response_csp(
-        format!("/preview/{}", inscription_id),
-        StatusCode::OK,
-        "default-src https://ordinals.com",
-        format!(".*<html lang=en data-inscription={}>.*", inscription_id),
-      );
-    }
+    headers.insert(header::ACCEPT_ENCODING, "gzip".parse().unwrap());
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(headers)
+      .build()
+      .unwrap()
+      .get(server.join_url("/"))
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers().get(header::CONTENT_ENCODING).unwrap(),
+      "gzip"
+    );
   }
 
   #[test]
-  fn code_preview() {
+  fn responses_are_brotlied() {
+    let server = TestServer::new();
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert(header::ACCEPT_ENCODING, "br".parse().unwrap());
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(headers)
+      .brotli(false)
+      .build()
+      .unwrap()
+      .get(server.join_url("/"))
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers().get(header::CONTENT_ENCODING).unwrap(),
+      "br"
+    );
+  }
+
+  #[test]
+  fn inscription_links_to_parent() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
 
+    let parent_txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let parent_inscription_id = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        inscription("text/javascript", "hello").to_witness(),
-      )],
+      inputs: &[
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (2, 1, 0, Default::default()),
+      ],
       ..default()
     });
-    let inscription_id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
+    let inscription_id = InscriptionId { txid, index: 0 };
+
     server.assert_response_regex(
-      format!("/preview/{inscription_id}"),
+      format!("/inscription/{inscription_id}"),
       StatusCode::OK,
-      format!(r".*<html lang=en data-inscription={inscription_id} data-language=javascript>.*"),
+      format!(".*<title>Inscription 1</title>.*<dt>parents</dt>.*<div class=thumbnails>.**<a href=/inscription/{parent_inscription_id}><iframe .* src=/preview/{parent_inscription_id}></iframe></a>.*"),
+    );
+    server.assert_response_regex(
+      format!("/inscription/{parent_inscription_id}"),
+      StatusCode::OK,
+      format!(".*<title>Inscription 0</title>.*<dt>children</dt>.*<a href=/inscription/{inscription_id}>.*</a>.*"),
     );
-  }
 
-  #[test]
-  fn content_response_no_content_type() {
-    let (headers, body) = Server::content_response(
-      Inscription {
-        content_type: None,
-        body: Some(Vec::new()),
-        ..default()
-      },
-      AcceptEncoding::default(),
-      &ServerConfig::default(),
-    )
-    .unwrap()
-    .unwrap();
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{inscription_id}"))
+        .parents,
+      vec![parent_inscription_id],
+    );
 
-    assert_eq!(headers["content-type"], "application/octet-stream");
-    assert!(body.is_empty());
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{parent_inscription_id}"))
+        .children,
+      [inscription_id],
+    );
   }
 
   #[test]
-  fn content_response_bad_content_type() {
-    let (headers, body) = Server::content_response(
-      Inscription {
-        content_type: Some("\n".as_bytes().to_vec()),
-        body: Some(Vec::new()),
-        ..Default::default()
-      },
-      AcceptEncoding::default(),
-      &ServerConfig::default(),
-    )
-    .unwrap()
-    .unwrap();
+  fn recursive_inscriptions_are_flagged() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(2);
+
+    let recursive = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          1,
+          0,
+          0,
+          inscription("text/html", "<script src=/r/blockheight></script>").to_witness(),
+        )],
+        ..default()
+      }),
+      index: 0,
+    };
+
+    let plain = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          2,
+          0,
+          0,
+          inscription("text/html", "<h1>hi</h1>").to_witness(),
+        )],
+        ..default()
+      }),
+      index: 0,
+    };
 
-    assert_eq!(headers["content-type"], "application/octet-stream");
-    assert!(body.is_empty());
+    server.mine_blocks(1);
+
+    assert!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{recursive}"))
+        .recursive
+    );
+
+    assert!(
+      !server
+        .get_json::<api::Inscription>(format!("/inscription/{plain}"))
+        .recursive
+    );
   }
 
   #[test]
-  fn text_preview() {
+  fn inscription_with_and_without_children_page() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        inscription("text/plain;charset=utf-8", "hello").to_witness(),
-      )],
+    let parent_txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
       ..default()
     });
 
-    let inscription_id = InscriptionId { txid, index: 0 };
-
     server.mine_blocks(1);
 
-    server.assert_response_csp(
-      format!("/preview/{}", inscription_id),
+    let parent_inscription_id = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    server.assert_response_regex(
+      format!("/children/{parent_inscription_id}"),
       StatusCode::OK,
-      "default-src 'self'",
-      format!(".*<html lang=en data-inscription={}>.*", inscription_id),
+      ".*<h3>No children</h3>.*",
     );
-  }
-
-  #[test]
-  fn audio_preview() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("audio/flac", "hello").to_witness())],
+      inputs: &[
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (2, 1, 0, Default::default()),
+      ],
       ..default()
     });
-    let inscription_id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
+    let inscription_id = InscriptionId { txid, index: 0 };
+
     server.assert_response_regex(
-      format!("/preview/{inscription_id}"),
+      format!("/children/{parent_inscription_id}"),
       StatusCode::OK,
-      format!(r".*<audio .*>\s*<source src=/content/{inscription_id}>.*"),
+      format!(".*<title>Inscription 0 Children</title>.*<h1><a href=/inscription/{parent_inscription_id}>Inscription 0</a> Children</h1>.*<div class=thumbnails>.*<a href=/inscription/{inscription_id}><iframe .* src=/preview/{inscription_id}></iframe></a>.*"),
     );
   }
 
   #[test]
-  fn font_preview() {
+  fn inscriptions_page_shows_max_four_children() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("font/ttf", "hello").to_witness())],
+    let parent_txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(6);
+
+    let parent_inscription_id = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    let _txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (
+          3,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (
+          4,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (
+          5,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (
+          6,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![parent_inscription_id.value()],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (2, 1, 0, Default::default()),
+      ],
       ..default()
     });
-    let inscription_id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/preview/{inscription_id}"),
+      format!("/inscription/{parent_inscription_id}"),
       StatusCode::OK,
-      format!(r".*src: url\(/content/{inscription_id}\).*"),
+      format!(
+        ".*<title>Inscription 0</title>.*
+.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
+.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
+.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
+.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
+    <div class=center>
+      <a href=/children/{parent_inscription_id}>all</a>
+    </div>.*"
+      ),
     );
   }
 
   #[test]
-  fn pdf_preview() {
+  fn inscription_with_parent_page() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(2);
+
+    let parent_a_txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    let parent_b_txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
     server.mine_blocks(1);
 
+    let parent_a_inscription_id = InscriptionId {
+      txid: parent_a_txid,
+      index: 0,
+    };
+
+    let parent_b_inscription_id = InscriptionId {
+      txid: parent_b_txid,
+      index: 0,
+    };
+
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        inscription("application/pdf", "hello").to_witness(),
-      )],
+      inputs: &[
+        (
+          3,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parents: vec![
+              parent_a_inscription_id.value(),
+              parent_b_inscription_id.value(),
+            ],
+            ..default()
+          }
+          .to_witness(),
+        ),
+        (3, 1, 0, Default::default()),
+        (3, 2, 0, Default::default()),
+      ],
       ..default()
     });
-    let inscription_id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
+    let inscription_id = InscriptionId { txid, index: 0 };
+
     server.assert_response_regex(
-      format!("/preview/{inscription_id}"),
+      format!("/parents/{inscription_id}"),
       StatusCode::OK,
-      format!(r".*<canvas data-inscription={inscription_id}></canvas>.*"),
+      format!(".*<title>Inscription -1 Parents</title>.*<h1><a href=/inscription/{inscription_id}>Inscription -1</a> Parents</h1>.*<div class=thumbnails>.*<a href=/inscription/{parent_a_inscription_id}><iframe .* src=/preview/{parent_b_inscription_id}></iframe></a>.*"),
     );
   }
 
   #[test]
-  fn markdown_preview() {
+  fn inscription_parent_page_pagination() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
+
     server.mine_blocks(1);
 
+    let mut parent_ids = Vec::new();
+    let mut inputs = Vec::new();
+    for i in 0..101 {
+      parent_ids.push(
+        InscriptionId {
+          txid: server.core.broadcast_tx(TransactionTemplate {
+            inputs: &[(i + 1, 0, 0, inscription("text/plain", "hello").to_witness())],
+            ..default()
+          }),
+          index: 0,
+        }
+        .value(),
+      );
+
+      inputs.push((i + 2, 1, 0, Witness::default()));
+
+      server.mine_blocks(1);
+    }
+
+    inputs.insert(
+      0,
+      (
+        102,
+        0,
+        0,
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("hello".into()),
+          parents: parent_ids,
+          ..default()
+        }
+        .to_witness(),
+      ),
+    );
+
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/markdown", "hello").to_witness())],
+      inputs: &inputs,
       ..default()
     });
-    let inscription_id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
+    let inscription_id = InscriptionId { txid, index: 0 };
+
     server.assert_response_regex(
-      format!("/preview/{inscription_id}"),
+      format!("/parents/{inscription_id}"),
       StatusCode::OK,
-      format!(r".*<html lang=en data-inscription={inscription_id}>.*"),
+      format!(".*<title>Inscription -1 Parents</title>.*<h1><a href=/inscription/{inscription_id}>Inscription -1</a> Parents</h1>.*<div class=thumbnails>(.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*){{100}}.*"),
     );
-  }
-
-  #[test]
-  fn image_preview() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("image/png", "hello").to_witness())],
-      ..default()
-    });
-    let inscription_id = InscriptionId { txid, index: 0 };
-
-    server.mine_blocks(1);
-
-    server.assert_response_csp(
-      format!("/preview/{inscription_id}"),
+    server.assert_response_regex(
+      format!("/parents/{inscription_id}/1"),
       StatusCode::OK,
-      "default-src 'self' 'unsafe-inline'",
-      format!(r".*background-image: url\(/content/{inscription_id}\);.*"),
+      format!(".*<title>Inscription -1 Parents</title>.*<h1><a href=/inscription/{inscription_id}>Inscription -1</a> Parents</h1>.*<div class=thumbnails>(.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*){{1}}.*"),
     );
-  }
 
-  #[test]
-  fn iframe_preview() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
-
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        inscription("text/html;charset=utf-8", "hello").to_witness(),
-      )],
-      ..default()
-    });
-
-    server.mine_blocks(1);
-
-    server.assert_response_csp(
-      format!("/preview/{}", InscriptionId { txid, index: 0 }),
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}"),
       StatusCode::OK,
-      "default-src 'self' 'unsafe-eval' 'unsafe-inline' data: blob:",
-      "hello",
+      ".*<title>Inscription -1</title>.*<h1>Inscription -1</h1>.*<div class=thumbnails>(.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*){4}.*",
     );
   }
 
   #[test]
-  fn unknown_preview() {
+  fn inscription_number_endpoint() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
+    server.mine_blocks(2);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      inputs: &[
+        (1, 0, 0, inscription("text/plain", "hello").to_witness()),
+        (2, 0, 0, inscription("text/plain", "cursed").to_witness()),
+      ],
+      outputs: 2,
       ..default()
     });
 
+    let inscription_id = InscriptionId { txid, index: 0 };
+    let cursed_inscription_id = InscriptionId { txid, index: 1 };
+
     server.mine_blocks(1);
 
-    server.assert_response_csp(
-      format!("/preview/{}", InscriptionId { txid, index: 0 }),
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}"),
       StatusCode::OK,
-      "default-src 'self'",
-      fs::read_to_string("templates/preview-unknown.html").unwrap(),
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{inscription_id}</dd>.*"
+      ),
+    );
+    server.assert_response_regex(
+      "/inscription/0",
+      StatusCode::OK,
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{inscription_id}</dd>.*"
+      ),
     );
+
+    server.assert_response_regex(
+      "/inscription/-1",
+      StatusCode::OK,
+      format!(
+        ".*<h1>Inscription -1</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{cursed_inscription_id}</dd>.*"
+      ),
+    )
   }
 
   #[test]
-  fn video_preview() {
+  fn charm_cursed() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
+
+    server.mine_blocks(2);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("video/webm", "hello").to_witness())],
+      inputs: &[
+        (1, 0, 0, Witness::default()),
+        (2, 0, 0, inscription("text/plain", "cursed").to_witness()),
+      ],
+      outputs: 2,
       ..default()
     });
-    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/preview/{inscription_id}"),
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      format!(r".*<video .*>\s*<source src=/content/{inscription_id}>.*"),
+      format!(
+        ".*<h1>Inscription -1</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>
+    <span title=cursed>👹</span>
+  </dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn inscription_page_title() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
-    server.mine_blocks(1);
+  fn charm_vindicated() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(110);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      inputs: &[
+        (1, 0, 0, Witness::default()),
+        (2, 0, 0, inscription("text/plain", "cursed").to_witness()),
+      ],
+      outputs: 2,
       ..default()
     });
 
+    let id = InscriptionId { txid, index: 0 };
+
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      ".*<title>Inscription 0</title>.*",
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  .*
+  <dt>value</dt>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn inscription_page_has_sat_when_sats_are_tracked() {
+  fn charm_coin() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_sats()
       .build();
-    server.mine_blocks(1);
+
+    server.mine_blocks(2);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
       ..default()
     });
 
+    let id = InscriptionId { txid, index: 0 };
+
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      r".*<dt>sat</dt>\s*<dd><a href=/sat/5000000000>5000000000</a></dd>\s*<dt>preview</dt>.*",
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>.*<span title=coin>🪙</span>.*</dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn inscriptions_can_be_looked_up_by_sat_name() {
+  fn charm_uncommon() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_sats()
       .build();
-    server.mine_blocks(1);
 
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+    server.mine_blocks(2);
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
       ..default()
     });
 
+    let id = InscriptionId { txid, index: 0 };
+
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/inscription/{}", Sat(5000000000).name()),
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      ".*<title>Inscription 0</title.*",
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>.*<span title=uncommon>🌱</span>.*</dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn inscriptions_can_be_looked_up_by_sat_name_with_letter_i() {
+  fn charm_nineball() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_sats()
       .build();
-    server.assert_response_regex("/inscription/i", StatusCode::NOT_FOUND, ".*");
-  }
 
-  #[test]
-  fn inscription_page_does_not_have_sat_when_sats_are_not_tracked() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
+    server.mine_blocks(9);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      inputs: &[(9, 0, 0, inscription("text/plain", "foo").to_witness())],
       ..default()
     });
 
+    let id = InscriptionId { txid, index: 0 };
+
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      r".*<dt>value</dt>\s*<dd>5000000000</dd>\s*<dt>preview</dt>.*",
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>.*<span title=nineball>9️⃣</span>.*</dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn strict_transport_security_header_is_set() {
-    assert_eq!(
-      TestServer::new()
-        .get("/status")
-        .headers()
-        .get(header::STRICT_TRANSPORT_SECURITY)
-        .unwrap(),
-      "max-age=31536000; includeSubDomains; preload",
-    );
-  }
+  fn charm_reinscription() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-  #[test]
-  fn feed() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
     server.mine_blocks(1);
 
     server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
       ..default()
     });
 
     server.mine_blocks(1);
 
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, inscription("text/plain", "bar").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let id = InscriptionId { txid, index: 0 };
+
     server.assert_response_regex(
-      "/feed.xml",
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      ".*<title>Inscription 0</title>.*",
+      format!(
+        ".*<h1>Inscription -1</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>
+    <span title=reinscription>♻️</span>
+    <span title=cursed>👹</span>
+  </dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn inscription_with_unknown_type_and_no_body_has_unknown_preview() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
+  fn charm_reinscription_in_same_tx_input() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
     server.mine_blocks(1);
 
+    let script = script::Builder::new()
+      .push_opcode(opcodes::OP_FALSE)
+      .push_opcode(opcodes::all::OP_IF)
+      .push_slice(b"ord")
+      .push_slice([1])
+      .push_slice(b"text/plain;charset=utf-8")
+      .push_slice([])
+      .push_slice(b"foo")
+      .push_opcode(opcodes::all::OP_ENDIF)
+      .push_opcode(opcodes::OP_FALSE)
+      .push_opcode(opcodes::all::OP_IF)
+      .push_slice(b"ord")
+      .push_slice([1])
+      .push_slice(b"text/plain;charset=utf-8")
+      .push_slice([])
+      .push_slice(b"bar")
+      .push_opcode(opcodes::all::OP_ENDIF)
+      .push_opcode(opcodes::OP_FALSE)
+      .push_opcode(opcodes::all::OP_IF)
+      .push_slice(b"ord")
+      .push_slice([1])
+      .push_slice(b"text/plain;charset=utf-8")
+      .push_slice([])
+      .push_slice(b"qix")
+      .push_opcode(opcodes::all::OP_ENDIF)
+      .into_script();
+
+    let witness = Witness::from_slice(&[script.into_bytes(), Vec::new()]);
+
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        Inscription {
-          content_type: Some("foo/bar".as_bytes().to_vec()),
-          body: None,
-          ..default()
-        }
-        .to_witness(),
-      )],
+      inputs: &[(1, 0, 0, witness)],
       ..default()
     });
 
-    let inscription_id = InscriptionId { txid, index: 0 };
-
     server.mine_blocks(1);
 
-    server.assert_response(
-      format!("/preview/{inscription_id}"),
+    let id = InscriptionId { txid, index: 0 };
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  .*
+  <dt>value</dt>
+  .*
+</dl>
+.*
+"
+      ),
+    );
+
+    let id = InscriptionId { txid, index: 1 };
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
+      StatusCode::OK,
+      ".*
+    <span title=reinscription>♻️</span>
+    <span title=cursed>👹</span>.*",
+    );
+
+    let id = InscriptionId { txid, index: 2 };
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
+      StatusCode::OK,
+      ".*
+    <span title=reinscription>♻️</span>
+    <span title=cursed>👹</span>.*",
     );
   }
 
   #[test]
-  fn inscription_with_known_type_and_no_body_has_unknown_preview() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
-    server.mine_blocks(1);
+  fn charm_reinscription_in_same_tx_with_pointer() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(3);
+
+    let cursed_inscription = inscription("text/plain", "bar");
+    let reinscription: Inscription = InscriptionTemplate {
+      pointer: Some(0),
+      ..default()
+    }
+    .into();
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        Inscription {
-          content_type: Some("image/png".as_bytes().to_vec()),
-          body: None,
-          ..default()
-        }
-        .to_witness(),
-      )],
+      inputs: &[
+        (1, 0, 0, inscription("text/plain", "foo").to_witness()),
+        (2, 0, 0, cursed_inscription.to_witness()),
+        (3, 0, 0, reinscription.to_witness()),
+      ],
       ..default()
     });
 
-    let inscription_id = InscriptionId { txid, index: 0 };
-
     server.mine_blocks(1);
 
-    server.assert_response(
-      format!("/preview/{inscription_id}"),
+    let id = InscriptionId { txid, index: 0 };
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  .*
+  <dt>value</dt>
+  .*
+</dl>
+.*
+"
+      ),
+    );
+
+    let id = InscriptionId { txid, index: 1 };
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
+      StatusCode::OK,
+      ".*
+    <span title=cursed>👹</span>.*",
+    );
+
+    let id = InscriptionId { txid, index: 2 };
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
+      StatusCode::OK,
+      ".*
+    <span title=reinscription>♻️</span>
+    <span title=cursed>👹</span>.*",
     );
   }
 
   #[test]
-  fn content_responses_have_cache_control_headers() {
+  fn charm_unbound() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
+
     server.mine_blocks(1);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      inputs: &[(1, 0, 0, envelope(&[b"ord", &[128], &[0]]))],
       ..default()
     });
 
     server.mine_blocks(1);
 
-    let response = server.get(format!("/content/{}", InscriptionId { txid, index: 0 }));
+    let id = InscriptionId { txid, index: 0 };
 
-    assert_eq!(response.status(), StatusCode::OK);
-    assert_eq!(
-      response.headers().get(header::CACHE_CONTROL).unwrap(),
-      "public, max-age=1209600, immutable"
+    server.assert_response_regex(
+      format!("/inscription/{id}"),
+      StatusCode::OK,
+      format!(
+        ".*<h1>Inscription -1</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>
+    <span title=cursed>👹</span>
+    <span title=unbound>🔓</span>
+  </dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn error_content_responses_have_max_age_zero_cache_control_headers() {
+  fn charm_lost() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
-    let response =
-      server.get("/content/6ac5cacb768794f4fd7a78bf00f2074891fce68bd65c4ff36e77177237aacacai0");
-
-    assert_eq!(response.status(), 404);
-    assert_eq!(
-      response.headers().get(header::CACHE_CONTROL).unwrap(),
-      "no-store"
-    );
-  }
-
-  #[test]
-  fn inscriptions_page_with_no_prev_or_next() {
-    TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build()
-      .assert_response_regex("/inscriptions", StatusCode::OK, ".*prev\nnext.*");
-  }
 
-  #[test]
-  fn inscriptions_page_with_no_next() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
+    server.mine_blocks(1);
 
-    for i in 0..101 {
-      server.mine_blocks(1);
-      server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
-        ..default()
-      });
-    }
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
+      ..default()
+    });
+
+    let id = InscriptionId { txid, index: 0 };
 
     server.mine_blocks(1);
 
     server.assert_response_regex(
-      "/inscriptions/1",
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      ".*<a class=prev href=/inscriptions/0>prev</a>\nnext.*",
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  .*
+  <dt>value</dt>
+  <dd>5000000000</dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
-  }
-
-  #[test]
-  fn inscriptions_page_with_no_prev() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
 
-    for i in 0..101 {
-      server.mine_blocks(1);
-      server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
-        ..default()
-      });
-    }
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Default::default())],
+      fee: 50 * COIN_VALUE,
+      ..default()
+    });
 
-    server.mine_blocks(1);
+    server.mine_blocks_with_subsidy(1, 0);
 
     server.assert_response_regex(
-      "/inscriptions/0",
+      format!("/inscription/{id}"),
       StatusCode::OK,
-      ".*prev\n<a class=next href=/inscriptions/1>next</a>.*",
+      format!(
+        ".*<h1>Inscription 0</h1>.*
+<dl>
+  <dt>id</dt>
+  <dd class=monospace>{id}</dd>
+  <dt>charms</dt>
+  <dd>
+    <span title=lost>🤔</span>
+  </dd>
+  .*
+</dl>
+.*
+"
+      ),
     );
   }
 
   #[test]
-  fn collections_page_prev_and_next() {
+  fn sat_recursive_endpoints() {
+    TestServer::new().assert_response(
+      "/r/sat/0/inscriptions/count",
+      StatusCode::NOT_FOUND,
+      "this server has no sat index",
+    );
+
     let server = TestServer::builder()
       .chain(Chain::Regtest)
       .index_sats()
       .build();
 
-    let mut parent_ids = Vec::new();
+    assert_eq!(
+      server.get_json::<api::SatInscriptions>("/r/sat/5000000000"),
+      api::SatInscriptions {
+        ids: Vec::new(),
+        page: 0,
+        more: false
+      }
+    );
 
-    for i in 0..101 {
-      server.mine_blocks(1);
+    assert_eq!(
+      server.get_json::<api::SatInscription>("/r/sat/5000000000/at/0"),
+      api::SatInscription { id: None }
+    );
 
-      parent_ids.push(InscriptionId {
-        txid: server.core.broadcast_tx(TransactionTemplate {
-          inputs: &[(i + 1, 0, 0, inscription("text/plain", "hello").to_witness())],
-          ..default()
-        }),
-        index: 0,
-      });
-    }
+    assert_eq!(
+      server.get_json::<api::Count>("/r/sat/5000000000/inscriptions/count"),
+      api::Count { count: 0 }
+    );
 
-    for (i, parent_id) in parent_ids.iter().enumerate().take(101) {
-      server.mine_blocks(1);
+    server.mine_blocks(1);
 
-      server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[
-          (i + 2, 1, 0, Default::default()),
-          (
-            i + 102,
-            0,
-            0,
-            Inscription {
-              content_type: Some("text/plain".into()),
-              body: Some("hello".into()),
-              parents: vec![parent_id.value()],
-              ..default()
-            }
-            .to_witness(),
-          ),
-        ],
-        outputs: 2,
-        output_values: &[50 * COIN_VALUE, 50 * COIN_VALUE],
-        ..default()
-      });
-    }
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
+      ..default()
+    });
 
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      "/collections",
-      StatusCode::OK,
-      r".*
-<h1>Collections</h1>
-<div class=thumbnails>
-  <a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>
-  (<a href=/inscription/[[:xdigit:]]{64}i0>.*</a>\s*){99}
-</div>
-<div class=center>
-prev
-<a class=next href=/collections/1>next</a>
-</div>.*"
-        .to_string()
-        .unindent(),
-    );
+    let mut ids = Vec::new();
+    ids.push(InscriptionId { txid, index: 0 });
 
-    server.assert_response_regex(
-      "/collections/1",
-      StatusCode::OK,
-      ".*
-<h1>Collections</h1>
-<div class=thumbnails>
-  <a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>
-</div>
-<div class=center>
-<a class=prev href=/collections/0>prev</a>
-next
-</div>.*"
-        .unindent(),
-    );
-  }
+    for i in 1..111 {
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 1, 0, inscription("text/plain", "foo").to_witness())],
+        ..default()
+      });
 
-  #[test]
-  fn responses_are_gzipped() {
-    let server = TestServer::new();
+      server.mine_blocks(1);
 
-    let mut headers = HeaderMap::new();
+      ids.push(InscriptionId { txid, index: 0 });
+    }
 
-    headers.insert(header::ACCEPT_ENCODING, "gzip".parse().unwrap());
+    let paginated_response = server.get_json::<api::SatInscriptions>("/r/sat/5000000000");
 
-    let response = reqwest::blocking::Client::builder()
-      .default_headers(headers)
-      .build()
-      .unwrap()
-      .get(server.join_url("/"))
-      .send()
-      .unwrap();
+    let equivalent_paginated_response =
+      server.get_json::<api::SatInscriptions>("/r/sat/5000000000/0");
+
+    assert_eq!(paginated_response.ids.len(), 100);
+    assert!(paginated_response.more);
+    assert_eq!(paginated_response.page, 0);
 
     assert_eq!(
-      response.headers().get(header::CONTENT_ENCODING).unwrap(),
-      "gzip"
+      paginated_response.ids.len(),
+      equivalent_paginated_response.ids.len()
     );
-  }
+    assert_eq!(paginated_response.more, equivalent_paginated_response.more);
+    assert_eq!(paginated_response.page, equivalent_paginated_response.page);
 
-  #[test]
-  fn responses_are_brotlied() {
-    let server = TestServer::new();
+    let paginated_response = server.get_json::<api::SatInscriptions>("/r/sat/5000000000/1");
 
-    let mut headers = HeaderMap::new();
+    assert_eq!(paginated_response.ids.len(), 11);
+    assert!(!paginated_response.more);
+    assert_eq!(paginated_response.page, 1);
 
-    headers.insert(header::ACCEPT_ENCODING, "br".parse().unwrap());
+    assert_eq!(
+      server.get_json::<api::Count>("/r/sat/5000000000/inscriptions/count"),
+      api::Count { count: 111 }
+    );
 
-    let response = reqwest::blocking::Client::builder()
-      .default_headers(headers)
-      .brotli(false)
-      .build()
-      .unwrap()
-      .get(server.join_url("/"))
-      .send()
-      .unwrap();
+    assert_eq!(
+      server
+        .get_json::<api::SatInscription>("/r/sat/5000000000/at/0")
+        .id,
+      Some(ids[0])
+    );
 
     assert_eq!(
-      response.headers().get(header::CONTENT_ENCODING).unwrap(),
-      "br"
+      server
+        .get_json::<api::SatInscription>("/r/sat/5000000000/at/-111")
+        .id,
+      Some(ids[0])
+    );
+
+    assert_eq!(
+      server
+        .get_json::<api::SatInscription>("/r/sat/5000000000/at/110")
+        .id,
+      Some(ids[110])
     );
+
+    assert_eq!(
+      server
+        .get_json::<api::SatInscription>("/r/sat/5000000000/at/-1")
+        .id,
+      Some(ids[110])
+    );
+
+    assert!(server
+      .get_json::<api::SatInscription>("/r/sat/5000000000/at/111")
+      .id
+      .is_none());
   }
 
   #[test]
-  fn inscription_links_to_parent() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn sat_satpoint_recursive_endpoint() {
+    TestServer::new().assert_response(
+      "/r/sat/0/satpoint",
+      StatusCode::NOT_FOUND,
+      "this server has no sat index",
+    );
+
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      ..default()
+    });
+
     server.mine_blocks(1);
 
-    let parent_txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+    let sat = 7_500_000_000;
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 1, inscription("text/plain", "foo").to_witness())],
       ..default()
     });
 
     server.mine_blocks(1);
 
-    let parent_inscription_id = InscriptionId {
-      txid: parent_txid,
-      index: 0,
-    };
+    assert_eq!(
+      server.get_json::<api::SatLocation>(format!("/r/sat/{sat}/satpoint")),
+      api::SatLocation {
+        satpoint: Some(SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        }),
+      },
+    );
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (
-          2,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (2, 1, 0, Default::default()),
-      ],
+      inputs: &[(2, 1, 0, Default::default()), (3, 1, 0, Default::default())],
       ..default()
     });
 
     server.mine_blocks(1);
 
-    let inscription_id = InscriptionId { txid, index: 0 };
+    let satpoint = SatPoint {
+      outpoint: OutPoint { txid, vout: 0 },
+      offset: 2_500_000_000,
+    };
 
-    server.assert_response_regex(
-      format!("/inscription/{inscription_id}"),
-      StatusCode::OK,
-      format!(".*<title>Inscription 1</title>.*<dt>parents</dt>.*<div class=thumbnails>.**<a href=/inscription/{parent_inscription_id}><iframe .* src=/preview/{parent_inscription_id}></iframe></a>.*"),
-    );
-    server.assert_response_regex(
-      format!("/inscription/{parent_inscription_id}"),
-      StatusCode::OK,
-      format!(".*<title>Inscription 0</title>.*<dt>children</dt>.*<a href=/inscription/{inscription_id}>.*</a>.*"),
+    assert_eq!(
+      server
+        .get_json::<api::SatLocation>(format!("/r/sat/{sat}/satpoint"))
+        .satpoint,
+      Some(satpoint),
     );
 
+    assert_eq!(server.index.find(Sat(sat)).unwrap(), Some(satpoint));
+
     assert_eq!(
       server
-        .get_json::<api::Inscription>(format!("/inscription/{inscription_id}"))
-        .parents,
-      vec![parent_inscription_id],
+        .get_json::<api::SatLocation>("/r/sat/5000000000/satpoint")
+        .satpoint,
+      Some(SatPoint {
+        outpoint: OutPoint { txid, vout: 0 },
+        offset: 0,
+      }),
     );
 
     assert_eq!(
       server
-        .get_json::<api::Inscription>(format!("/inscription/{parent_inscription_id}"))
-        .children,
-      [inscription_id],
+        .get_json::<api::SatLocation>(format!("/r/sat/{}/satpoint", sat + 1))
+        .satpoint,
+      None,
     );
   }
 
   #[test]
-  fn inscription_with_and_without_children_page() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(1);
+  fn error_pages_are_localized() {
+    let server = TestServer::new();
 
-    let parent_txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
-      ..default()
-    });
+    let get = |accept: &str, accept_language: &str| {
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url("/inscription/0"))
+        .header(header::ACCEPT, accept)
+        .header(header::ACCEPT_LANGUAGE, accept_language)
+        .send()
+        .unwrap();
 
-    server.mine_blocks(1);
+      assert_eq!(response.status(), StatusCode::NOT_FOUND);
+      assert!(response
+        .headers()
+        .get_all(header::VARY)
+        .iter()
+        .any(|vary| vary == "Accept, Accept-Language"));
 
-    let parent_inscription_id = InscriptionId {
-      txid: parent_txid,
-      index: 0,
+      response.text().unwrap()
     };
 
-    server.assert_response_regex(
-      format!("/children/{parent_inscription_id}"),
-      StatusCode::OK,
-      ".*<h3>No children</h3>.*",
+    assert_eq!(
+      get("text/html", "de-DE,de;q=0.9,en;q=0.8"),
+      "Nicht gefunden: inscription 0 not found",
     );
-
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (
-          2,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (2, 1, 0, Default::default()),
-      ],
-      ..default()
-    });
-
-    server.mine_blocks(1);
-
-    let inscription_id = InscriptionId { txid, index: 0 };
-
-    server.assert_response_regex(
-      format!("/children/{parent_inscription_id}"),
-      StatusCode::OK,
-      format!(".*<title>Inscription 0 Children</title>.*<h1><a href=/inscription/{parent_inscription_id}>Inscription 0</a> Children</h1>.*<div class=thumbnails>.*<a href=/inscription/{inscription_id}><iframe .* src=/preview/{inscription_id}></iframe></a>.*"),
+    assert_eq!(
+      get("text/html", "es"),
+      "No encontrado: inscription 0 not found"
     );
+    assert_eq!(get("text/html", "en"), "inscription 0 not found");
+    assert_eq!(get("text/html", "fr"), "inscription 0 not found");
+    assert_eq!(get("application/json", "de"), "inscription 0 not found");
   }
 
   #[test]
-  fn inscriptions_page_shows_max_four_children() {
+  fn children_recursive_endpoint() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
     server.mine_blocks(1);
 
@@ -5044,1168 +9082,1129 @@ next
       ..default()
     });
 
-    server.mine_blocks(6);
-
     let parent_inscription_id = InscriptionId {
       txid: parent_txid,
       index: 0,
     };
 
-    let _txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (
-          2,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (
-          3,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (
-          4,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (
-          5,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (
-          6,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![parent_inscription_id.value()],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (2, 1, 0, Default::default()),
-      ],
-      ..default()
-    });
+    server.assert_response(
+      format!("/r/children/{parent_inscription_id}"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {parent_inscription_id} not found"),
+    );
+
+    server.assert_response(
+      format!("/r/children/{parent_inscription_id}/count"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {parent_inscription_id} not found"),
+    );
 
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      format!("/inscription/{parent_inscription_id}"),
-      StatusCode::OK,
-      format!(
-        ".*<title>Inscription 0</title>.*
-.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
-.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
-.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
-.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
-    <div class=center>
-      <a href=/children/{parent_inscription_id}>all</a>
-    </div>.*"
-      ),
+    let children_json =
+      server.get_json::<api::Children>(format!("/r/children/{parent_inscription_id}"));
+    assert_eq!(children_json.ids.len(), 0);
+
+    assert_eq!(
+      server.get_json::<api::Count>(format!("/r/children/{parent_inscription_id}/count")),
+      api::Count { count: 0 },
     );
-  }
 
-  #[test]
-  fn inscription_with_parent_page() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(2);
+    let mut builder = script::Builder::new();
+    for _ in 0..111 {
+      builder = Inscription {
+        content_type: Some("text/plain".into()),
+        body: Some("hello".into()),
+        parents: vec![parent_inscription_id.value()],
+        unrecognized_even_field: false,
+        ..default()
+      }
+      .append_reveal_script_to_builder(builder);
+    }
 
-    let parent_a_txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
-      ..default()
-    });
+    let witness = Witness::from_slice(&[builder.into_bytes(), Vec::new()]);
 
-    let parent_b_txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(2, 0, 0, inscription("text/plain", "hello").to_witness())],
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, witness), (2, 1, 0, Default::default())],
       ..default()
     });
 
     server.mine_blocks(1);
 
-    let parent_a_inscription_id = InscriptionId {
-      txid: parent_a_txid,
-      index: 0,
-    };
-
-    let parent_b_inscription_id = InscriptionId {
-      txid: parent_b_txid,
-      index: 0,
-    };
-
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (
-          3,
-          0,
-          0,
-          Inscription {
-            content_type: Some("text/plain".into()),
-            body: Some("hello".into()),
-            parents: vec![
-              parent_a_inscription_id.value(),
-              parent_b_inscription_id.value(),
-            ],
-            ..default()
-          }
-          .to_witness(),
-        ),
-        (3, 1, 0, Default::default()),
-        (3, 2, 0, Default::default()),
-      ],
-      ..default()
-    });
+    let first_child_inscription_id = InscriptionId { txid, index: 0 };
+    let hundredth_child_inscription_id = InscriptionId { txid, index: 99 };
+    let hundred_first_child_inscription_id = InscriptionId { txid, index: 100 };
+    let hundred_eleventh_child_inscription_id = InscriptionId { txid, index: 110 };
 
-    server.mine_blocks(1);
+    let children_json =
+      server.get_json::<api::Children>(format!("/r/children/{parent_inscription_id}"));
 
-    let inscription_id = InscriptionId { txid, index: 0 };
+    assert_eq!(children_json.ids.len(), 100);
+    assert_eq!(children_json.ids[0], first_child_inscription_id);
+    assert_eq!(children_json.ids[99], hundredth_child_inscription_id);
+    assert!(children_json.more);
+    assert_eq!(children_json.page, 0);
 
-    server.assert_response_regex(
-      format!("/parents/{inscription_id}"),
-      StatusCode::OK,
-      format!(".*<title>Inscription -1 Parents</title>.*<h1><a href=/inscription/{inscription_id}>Inscription -1</a> Parents</h1>.*<div class=thumbnails>.*<a href=/inscription/{parent_a_inscription_id}><iframe .* src=/preview/{parent_b_inscription_id}></iframe></a>.*"),
+    let children_json =
+      server.get_json::<api::Children>(format!("/r/children/{parent_inscription_id}/1"));
+
+    assert_eq!(children_json.ids.len(), 11);
+    assert_eq!(children_json.ids[0], hundred_first_child_inscription_id);
+    assert_eq!(children_json.ids[10], hundred_eleventh_child_inscription_id);
+    assert!(!children_json.more);
+    assert_eq!(children_json.page, 1);
+
+    assert_eq!(
+      server.get_json::<api::Count>(format!("/r/children/{parent_inscription_id}/count")),
+      api::Count { count: 111 },
     );
   }
 
   #[test]
-  fn inscription_parent_page_pagination() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-
-    server.mine_blocks(1);
-
-    let mut parent_ids = Vec::new();
-    let mut inputs = Vec::new();
-    for i in 0..101 {
-      parent_ids.push(
-        InscriptionId {
-          txid: server.core.broadcast_tx(TransactionTemplate {
-            inputs: &[(i + 1, 0, 0, inscription("text/plain", "hello").to_witness())],
-            ..default()
-          }),
-          index: 0,
-        }
-        .value(),
-      );
-
-      inputs.push((i + 2, 1, 0, Witness::default()));
+  fn inscriptions_in_block_page() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
 
+    for _ in 0..101 {
       server.mine_blocks(1);
     }
 
-    inputs.insert(
-      0,
-      (
-        102,
-        0,
-        0,
-        Inscription {
-          content_type: Some("text/plain".into()),
-          body: Some("hello".into()),
-          parents: parent_ids,
-          ..default()
-        }
-        .to_witness(),
-      ),
-    );
-
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &inputs,
-      ..default()
-    });
+    for i in 0..101 {
+      server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
+        ..default()
+      });
+    }
 
     server.mine_blocks(1);
 
-    let inscription_id = InscriptionId { txid, index: 0 };
-
     server.assert_response_regex(
-      format!("/parents/{inscription_id}"),
+      "/inscriptions/block/102",
       StatusCode::OK,
-      format!(".*<title>Inscription -1 Parents</title>.*<h1><a href=/inscription/{inscription_id}>Inscription -1</a> Parents</h1>.*<div class=thumbnails>(.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*){{100}}.*"),
+      r".*(<a href=/inscription/[[:xdigit:]]{64}i0>.*</a>.*){100}.*",
     );
 
     server.assert_response_regex(
-      format!("/parents/{inscription_id}/1"),
+      "/inscriptions/block/102/1",
       StatusCode::OK,
-      format!(".*<title>Inscription -1 Parents</title>.*<h1><a href=/inscription/{inscription_id}>Inscription -1</a> Parents</h1>.*<div class=thumbnails>(.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*){{1}}.*"),
+      r".*<a href=/inscription/[[:xdigit:]]{64}i0>.*</a>.*",
     );
+  }
 
-    server.assert_response_regex(
-      format!("/inscription/{inscription_id}"),
-      StatusCode::OK,
-      ".*<title>Inscription -1</title>.*<h1>Inscription -1</h1>.*<div class=thumbnails>(.*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*){4}.*",
+  #[test]
+  fn inscription_query_display() {
+    assert_eq!(
+      query::Inscription::Id(inscription_id(1)).to_string(),
+      "1111111111111111111111111111111111111111111111111111111111111111i1"
     );
+    assert_eq!(query::Inscription::Number(1).to_string(), "1")
   }
 
   #[test]
-  fn inscription_number_endpoint() {
+  fn inscription_not_found() {
+    TestServer::builder()
+      .chain(Chain::Regtest)
+      .build()
+      .assert_response(
+        "/inscription/0",
+        StatusCode::NOT_FOUND,
+        "inscription 0 not found",
+      );
+  }
+
+  #[test]
+  fn looking_up_inscription_by_sat_requires_sat_index() {
+    TestServer::builder()
+      .chain(Chain::Regtest)
+      .build()
+      .assert_response(
+        "/inscription/abcd",
+        StatusCode::NOT_FOUND,
+        "sat index required",
+      );
+  }
+
+  #[test]
+  fn delegate() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
-    server.mine_blocks(2);
+
+    server.mine_blocks(1);
+
+    let delegate = Inscription {
+      content_type: Some("text/html".into()),
+      body: Some("foo".into()),
+      metadata: Some(vec![0xaa]),
+      ..default()
+    };
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (1, 0, 0, inscription("text/plain", "hello").to_witness()),
-        (2, 0, 0, inscription("text/plain", "cursed").to_witness()),
-      ],
-      outputs: 2,
+      inputs: &[(1, 0, 0, delegate.to_witness())],
       ..default()
     });
 
-    let inscription_id = InscriptionId { txid, index: 0 };
-    let cursed_inscription_id = InscriptionId { txid, index: 1 };
+    let delegate = InscriptionId { txid, index: 0 };
+
+    server.mine_blocks(1);
+
+    let inscription = Inscription {
+      delegate: Some(delegate.value()),
+      metadata: Some(vec![0xbb]),
+      ..default()
+    };
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, inscription.to_witness())],
+      ..default()
+    });
 
     server.mine_blocks(1);
 
+    let id = InscriptionId { txid, index: 0 };
+
     server.assert_response_regex(
-      format!("/inscription/{inscription_id}"),
+      format!("/inscription/{id}"),
       StatusCode::OK,
       format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{inscription_id}</dd>.*"
-      ),
+        ".*<h1>Inscription 1</h1>.*
+        <dl>
+          <dt>id</dt>
+          <dd class=monospace>{id}</dd>
+          .*
+          <dt>delegate</dt>
+          <dd><a href=/inscription/{delegate}>{delegate}</a></dd>
+          .*
+        </dl>.*"
+      )
+      .unindent(),
     );
-    server.assert_response_regex(
-      "/inscription/0",
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{inscription_id}</dd>.*"
-      ),
+
+    server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
+
+    server.assert_response(format!("/preview/{id}"), StatusCode::OK, "foo");
+
+    let response = server.get(format!("/content/{id}"));
+    assert_eq!(response.headers()["x-inscription-id"], id.to_string());
+    assert_eq!(response.headers()["x-delegate-id"], delegate.to_string());
+
+    let response = server.get(format!("/content/{delegate}"));
+    assert_eq!(response.headers()["x-inscription-id"], delegate.to_string());
+    assert_eq!(response.headers().get("x-delegate-id"), None);
+
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{id}"))
+        .delegate,
+      Some(delegate),
     );
 
-    server.assert_response_regex(
-      "/inscription/-1",
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription -1</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{cursed_inscription_id}</dd>.*"
-      ),
-    )
+    server.assert_response(format!("/r/metadata/{id}"), StatusCode::OK, "\"bb\"");
+
+    assert_eq!(
+      server.get_json::<api::InscriptionContentType>(format!("/r/inscription/{id}/content-type")),
+      api::InscriptionContentType {
+        content_type: Some("text/html".into()),
+        content_length: Some(3),
+      },
+    );
+
+    let unknown = inscription_id(1);
+
+    server.assert_response(
+      format!("/r/inscription/{unknown}/content-type"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {unknown} not found"),
+    );
   }
 
   #[test]
-  fn charm_cursed() {
+  fn nested_delegates_are_resolved() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    server.mine_blocks(2);
+    server.mine_blocks(3);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (1, 0, 0, Witness::default()),
-        (2, 0, 0, inscription("text/plain", "cursed").to_witness()),
-      ],
-      outputs: 2,
+      inputs: &[(1, 0, 0, inscription("text/html", "foo").to_witness())],
       ..default()
     });
 
-    let id = InscriptionId { txid, index: 0 };
-
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription -1</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>
-    <span title=cursed>👹</span>
-  </dd>
-  .*
-</dl>
-.*
-"
-      ),
-    );
-  }
+    let mut delegate = InscriptionId { txid, index: 0 };
 
-  #[test]
-  fn charm_vindicated() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+    for i in 2..4 {
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          i,
+          0,
+          0,
+          Inscription {
+            delegate: Some(delegate.value()),
+            ..default()
+          }
+          .to_witness(),
+        )],
+        ..default()
+      });
 
-    server.mine_blocks(110);
+      server.mine_blocks(1);
+
+      delegate = InscriptionId { txid, index: 0 };
+    }
+
+    server.assert_response(format!("/content/{delegate}"), StatusCode::OK, "foo");
+    server.assert_response(format!("/preview/{delegate}"), StatusCode::OK, "foo");
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (1, 0, 0, Witness::default()),
-        (2, 0, 0, inscription("text/plain", "cursed").to_witness()),
-      ],
-      outputs: 2,
+      inputs: &[(
+        4,
+        0,
+        0,
+        Inscription {
+          delegate: Some(
+            InscriptionId::from_str(
+              "0000000000000000000000000000000000000000000000000000000000000000i0",
+            )
+            .unwrap()
+            .value(),
+          ),
+          ..default()
+        }
+        .to_witness(),
+      )],
       ..default()
     });
 
-    let id = InscriptionId { txid, index: 0 };
-
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  .*
-  <dt>value</dt>
-  .*
-</dl>
-.*
-"
-      ),
+    let id = InscriptionId { txid, index: 0 };
+
+    server.assert_response(
+      format!("/content/{id}"),
+      StatusCode::NOT_FOUND,
+      &format!("delegate {id} not found"),
     );
   }
 
   #[test]
-  fn charm_coin() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
+  fn proxy() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    server.mine_blocks(2);
+    server.mine_blocks(1);
+
+    let inscription = Inscription {
+      content_type: Some("text/html".into()),
+      body: Some("foo".into()),
+      ..default()
+    };
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
+      inputs: &[(1, 0, 0, inscription.to_witness())],
       ..default()
     });
 
+    server.mine_blocks(1);
+
     let id = InscriptionId { txid, index: 0 };
 
-    server.mine_blocks(1);
+    server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>.*<span title=coin>🪙</span>.*</dd>
-  .*
-</dl>
-.*
-"
-      ),
-    );
+    let server_with_proxy = TestServer::builder()
+      .chain(Chain::Regtest)
+      .server_option("--content-proxy", server.url.as_ref())
+      .build();
+
+    server_with_proxy.mine_blocks(1);
+
+    server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
+    server_with_proxy.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
   }
 
   #[test]
-  fn charm_uncommon() {
-    let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
-      .build();
+  fn proxy_content_larger_than_max_size_is_bad_gateway() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    server.mine_blocks(2);
+    server.mine_blocks(1);
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
+      inputs: &[(1, 0, 0, inscription("text/html", "foo").to_witness())],
       ..default()
     });
 
+    server.mine_blocks(1);
+
     let id = InscriptionId { txid, index: 0 };
 
-    server.mine_blocks(1);
+    let server_with_proxy = TestServer::builder()
+      .chain(Chain::Regtest)
+      .server_option("--content-proxy", server.url.as_ref())
+      .server_option("--content-proxy-max-size", "2")
+      .build();
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>.*<span title=uncommon>🌱</span>.*</dd>
-  .*
-</dl>
-.*
-"
-      ),
+    server_with_proxy.assert_response(
+      format!("/content/{id}"),
+      StatusCode::BAD_GATEWAY,
+      "proxied content exceeds maximum size of 2 bytes",
     );
   }
 
   #[test]
-  fn charm_nineball() {
+  fn content_larger_than_max_content_response_size_is_rejected() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
-      .index_sats()
+      .server_option("--max-content-response-size", "3")
       .build();
 
-    server.mine_blocks(9);
+    server.mine_blocks(2);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(9, 0, 0, inscription("text/plain", "foo").to_witness())],
-      ..default()
-    });
+    let small = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
+        ..default()
+      }),
+      index: 0,
+    };
 
-    let id = InscriptionId { txid, index: 0 };
+    let large = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 0, 0, inscription("text/plain", "foob").to_witness())],
+        ..default()
+      }),
+      index: 0,
+    };
 
     server.mine_blocks(1);
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>.*<span title=nineball>9️⃣</span>.*</dd>
-  .*
-</dl>
-.*
-"
-      ),
-    );
-  }
-
-  #[test]
-  fn charm_reinscription() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+    let delegating = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          3,
+          0,
+          0,
+          Inscription {
+            delegate: Some(large.value()),
+            ..default()
+          }
+          .to_witness(),
+        )],
+        ..default()
+      }),
+      index: 0,
+    };
 
     server.mine_blocks(1);
 
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
-      ..default()
-    });
+    server.assert_response(format!("/content/{small}"), StatusCode::OK, "foo");
 
-    server.mine_blocks(1);
+    server.assert_response(
+      format!("/content/{large}"),
+      StatusCode::PAYLOAD_TOO_LARGE,
+      &format!("inscription {large} content larger than 3 bytes"),
+    );
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(2, 1, 0, inscription("text/plain", "bar").to_witness())],
-      ..default()
-    });
+    server.assert_response(
+      format!("/content/{delegating}"),
+      StatusCode::PAYLOAD_TOO_LARGE,
+      &format!("inscription {delegating} content larger than 3 bytes"),
+    );
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn proxy_timeout_is_gateway_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
 
-    let id = InscriptionId { txid, index: 0 };
+    let server_with_proxy = TestServer::builder()
+      .chain(Chain::Regtest)
+      .server_option(
+        "--content-proxy",
+        &format!("http://{}/", listener.local_addr().unwrap()),
+      )
+      .server_option("--content-proxy-timeout", "100ms")
+      .build();
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription -1</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>
-    <span title=reinscription>♻️</span>
-    <span title=cursed>👹</span>
-  </dd>
-  .*
-</dl>
-.*
-"
-      ),
+    assert_eq!(
+      server_with_proxy
+        .get(format!("/content/{}", inscription_id(1)))
+        .status(),
+      StatusCode::GATEWAY_TIMEOUT,
     );
   }
 
   #[test]
-  fn charm_reinscription_in_same_tx_input() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
-
-    server.mine_blocks(1);
-
-    let script = script::Builder::new()
-      .push_opcode(opcodes::OP_FALSE)
-      .push_opcode(opcodes::all::OP_IF)
-      .push_slice(b"ord")
-      .push_slice([1])
-      .push_slice(b"text/plain;charset=utf-8")
-      .push_slice([])
-      .push_slice(b"foo")
-      .push_opcode(opcodes::all::OP_ENDIF)
-      .push_opcode(opcodes::OP_FALSE)
-      .push_opcode(opcodes::all::OP_IF)
-      .push_slice(b"ord")
-      .push_slice([1])
-      .push_slice(b"text/plain;charset=utf-8")
-      .push_slice([])
-      .push_slice(b"bar")
-      .push_opcode(opcodes::all::OP_ENDIF)
-      .push_opcode(opcodes::OP_FALSE)
-      .push_opcode(opcodes::all::OP_IF)
-      .push_slice(b"ord")
-      .push_slice([1])
-      .push_slice(b"text/plain;charset=utf-8")
-      .push_slice([])
-      .push_slice(b"qix")
-      .push_opcode(opcodes::all::OP_ENDIF)
-      .into_script();
+  fn raw_block_header() {
+    let server = TestServer::new();
 
-    let witness = Witness::from_slice(&[script.into_bytes(), Vec::new()]);
+    pretty_assert_eq!(
+      server.get_json::<api::RawBlockHeader>("/r/blockhash/0/header"),
+      api::RawBlockHeader {
+        bits: 486604799,
+        hash: "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+          .parse()
+          .unwrap(),
+        height: 0,
+        hex: "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c".into(),
+        merkle_root: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+          .parse()
+          .unwrap(),
+        nonce: 2083236893,
+        previous_block: BlockHash::all_zeros(),
+        timestamp: 1231006505,
+        version: 1,
+      },
+    );
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, witness)],
-      ..default()
-    });
+    server.assert_response(
+      "/r/blockhash/1/header",
+      StatusCode::NOT_FOUND,
+      "block 1 not found",
+    );
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn block_header() {
+    let server = TestServer::new();
 
-    let id = InscriptionId { txid, index: 0 };
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  .*
-  <dt>value</dt>
-  .*
-</dl>
-.*
-"
-      ),
+    pretty_assert_eq!(
+      server.get_json::<api::BlockHeader>("/r/blockheader/0"),
+      api::BlockHeader {
+        bits: 486604799,
+        chainwork: [0; 32],
+        confirmations: 0,
+        difficulty: 0.0,
+        hash: "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+          .parse()
+          .unwrap(),
+        height: 0,
+        median_time: None,
+        merkle_root: TxMerkleNode::all_zeros(),
+        next_block: None,
+        nonce: 0,
+        previous_block: None,
+        target: "00000000ffff0000000000000000000000000000000000000000000000000000"
+          .parse()
+          .unwrap(),
+        timestamp: 0,
+        transaction_count: 0,
+        version: 1,
+      },
     );
 
-    let id = InscriptionId { txid, index: 1 };
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      ".*
-    <span title=reinscription>♻️</span>
-    <span title=cursed>👹</span>.*",
+    pretty_assert_eq!(
+      server
+        .get_json::<api::BlockHeader>(
+          "/r/blockheader/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+        )
+        .height,
+      0,
     );
 
-    let id = InscriptionId { txid, index: 2 };
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      ".*
-    <span title=reinscription>♻️</span>
-    <span title=cursed>👹</span>.*",
+    server.assert_response(
+      "/r/blockheader/1",
+      StatusCode::NOT_FOUND,
+      "block 1 not found",
     );
   }
 
   #[test]
-  fn charm_reinscription_in_same_tx_with_pointer() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn block_info() {
+    let server = TestServer::new();
 
-    server.mine_blocks(3);
+    pretty_assert_eq!(
+      server.get_json::<api::BlockInfo>("/r/blockinfo/0"),
+      api::BlockInfo {
+        average_fee: 0,
+        average_fee_rate: 0,
+        bits: 486604799,
+        chainwork: [0; 32],
+        confirmations: 0,
+        difficulty: 0.0,
+        hash: "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+          .parse()
+          .unwrap(),
+        height: 0,
+        inscription_count: Some(0),
+        max_fee: 0,
+        max_fee_rate: 0,
+        max_tx_size: 0,
+        median_fee: 0,
+        median_time: None,
+        merkle_root: TxMerkleNode::all_zeros(),
+        min_fee: 0,
+        min_fee_rate: 0,
+        next_block: None,
+        nonce: 0,
+        previous_block: None,
+        rune_etching_count: None,
+        subsidy: 0,
+        target: "00000000ffff0000000000000000000000000000000000000000000000000000"
+          .parse()
+          .unwrap(),
+        timestamp: 0,
+        total_fee: 0,
+        total_size: 0,
+        total_weight: 0,
+        transaction_count: 0,
+        version: 1,
+      },
+    );
 
-    let cursed_inscription = inscription("text/plain", "bar");
-    let reinscription: Inscription = InscriptionTemplate {
-      pointer: Some(0),
-      ..default()
-    }
-    .into();
+    server.mine_blocks(1);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[
-        (1, 0, 0, inscription("text/plain", "foo").to_witness()),
-        (2, 0, 0, cursed_inscription.to_witness()),
-        (3, 0, 0, reinscription.to_witness()),
-      ],
+    pretty_assert_eq!(
+      server.get_json::<api::BlockInfo>("/r/blockinfo/1"),
+      api::BlockInfo {
+        average_fee: 0,
+        average_fee_rate: 0,
+        bits: 0,
+        chainwork: [0; 32],
+        confirmations: 0,
+        difficulty: 0.0,
+        hash: "56d05060a0280d0712d113f25321158747310ece87ea9e299bde06cf385b8d85"
+          .parse()
+          .unwrap(),
+        height: 1,
+        inscription_count: Some(0),
+        max_fee: 0,
+        max_fee_rate: 0,
+        max_tx_size: 0,
+        median_fee: 0,
+        median_time: None,
+        merkle_root: TxMerkleNode::all_zeros(),
+        min_fee: 0,
+        min_fee_rate: 0,
+        next_block: None,
+        nonce: 0,
+        previous_block: None,
+        rune_etching_count: None,
+        subsidy: 0,
+        target: BlockHash::all_zeros(),
+        timestamp: 0,
+        total_fee: 0,
+        total_size: 0,
+        total_weight: 0,
+        transaction_count: 0,
+        version: 1,
+      },
+    )
+  }
+
+  #[test]
+  fn block_info_counts_inscriptions_and_rune_etchings() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
       ..default()
     });
 
     server.mine_blocks(1);
 
-    let id = InscriptionId { txid, index: 0 };
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  .*
-  <dt>value</dt>
-  .*
-</dl>
-.*
-"
-      ),
-    );
+    let info = server.get_json::<api::BlockInfo>("/r/blockinfo/2");
+    assert_eq!(info.inscription_count, Some(1));
+    assert_eq!(info.rune_etching_count, Some(0));
 
-    let id = InscriptionId { txid, index: 1 };
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      ".*
-    <span title=cursed>👹</span>.*",
+    let (_txid, id) = server.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
     );
 
-    let id = InscriptionId { txid, index: 2 };
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      ".*
-    <span title=reinscription>♻️</span>
-    <span title=cursed>👹</span>.*",
-    );
+    let info = server.get_json::<api::BlockInfo>(format!("/r/blockinfo/{}", id.block));
+    assert_eq!(info.inscription_count, Some(0));
+    assert_eq!(info.rune_etching_count, Some(1));
   }
 
   #[test]
-  fn charm_unbound() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn authentication_requires_username_and_password() {
+    assert!(Arguments::try_parse_from(["ord", "--server-username", "server", "foo"]).is_err());
+    assert!(Arguments::try_parse_from(["ord", "--server-password", "server", "bar"]).is_err());
+    assert!(Arguments::try_parse_from([
+      "ord",
+      "--server-username",
+      "foo",
+      "--server-password",
+      "bar",
+      "server"
+    ])
+    .is_ok());
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn inscriptions_can_be_hidden_with_config() {
+    let core = mockcore::builder()
+      .network(Chain::Regtest.network())
+      .build();
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, envelope(&[b"ord", &[128], &[0]]))],
+    core.mine_blocks(1);
+
+    let txid = core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
       ..default()
     });
 
-    server.mine_blocks(1);
+    core.mine_blocks(1);
 
-    let id = InscriptionId { txid, index: 0 };
+    let inscription = InscriptionId { txid, index: 0 };
+
+    let server = TestServer::builder()
+      .core(core)
+      .config(&format!("hidden: [{inscription}]"))
+      .build();
+
+    server.assert_response_regex(format!("/inscription/{inscription}"), StatusCode::OK, ".*");
 
     server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription -1</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>
-    <span title=cursed>👹</span>
-    <span title=unbound>🔓</span>
-  </dd>
-  .*
-</dl>
-.*
-"
-      ),
+      format!("/content/{inscription}"),
+      StatusCode::OK,
+      PreviewUnknownHtml.to_string(),
+    );
+
+    server.assert_response(
+      format!("/r/inscription/{inscription}/content-type"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {inscription} not found"),
     );
   }
 
   #[test]
-  fn charm_lost() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn inscriptions_can_be_hidden_with_patterns() {
+    let core = mockcore::builder()
+      .network(Chain::Regtest.network())
+      .build();
 
-    server.mine_blocks(1);
+    core.mine_blocks(1);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
+    let txid = core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("image/png", "spam").to_witness())],
       ..default()
     });
 
-    let id = InscriptionId { txid, index: 0 };
-
-    server.mine_blocks(1);
+    core.mine_blocks(1);
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  .*
-  <dt>value</dt>
-  <dd>5000000000</dd>
-  .*
-</dl>
-.*
-"
-      ),
-    );
+    let hidden = InscriptionId { txid, index: 0 };
 
-    server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(2, 1, 0, Default::default())],
-      fee: 50 * COIN_VALUE,
+    let txid = core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, inscription("image/png", "eggs").to_witness())],
       ..default()
     });
 
-    server.mine_blocks_with_subsidy(1, 0);
+    core.mine_blocks(1);
 
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
-      StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 0</h1>.*
-<dl>
-  <dt>id</dt>
-  <dd class=monospace>{id}</dd>
-  <dt>charms</dt>
-  <dd>
-    <span title=lost>🤔</span>
-  </dd>
-  .*
-</dl>
-.*
-"
-      ),
-    );
-  }
+    let visible = InscriptionId { txid, index: 0 };
 
-  #[test]
-  fn sat_recursive_endpoints() {
     let server = TestServer::builder()
-      .chain(Chain::Regtest)
-      .index_sats()
+      .core(core)
+      .config("hidden_patterns: ['^sp[a]m$']")
       .build();
 
-    assert_eq!(
-      server.get_json::<api::SatInscriptions>("/r/sat/5000000000"),
-      api::SatInscriptions {
-        ids: Vec::new(),
-        page: 0,
-        more: false
-      }
+    server.assert_response_regex(format!("/inscription/{hidden}"), StatusCode::OK, ".*");
+
+    for path in ["content", "preview"] {
+      server.assert_response_regex(
+        format!("/{path}/{hidden}"),
+        StatusCode::OK,
+        PreviewUnknownHtml.to_string(),
+      );
+    }
+
+    server.assert_response(format!("/content/{visible}"), StatusCode::OK, "eggs");
+
+    server.assert_response(
+      format!("/r/inscription/{hidden}/content-type"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {hidden} not found"),
     );
 
-    assert_eq!(
-      server.get_json::<api::SatInscription>("/r/sat/5000000000/at/0"),
-      api::SatInscription { id: None }
+    server.assert_response_regex(
+      format!("/r/inscription/{visible}/content-type"),
+      StatusCode::OK,
+      ".*image/png.*",
     );
 
-    server.mine_blocks(1);
+    server.assert_response_regex("/", StatusCode::OK, format!(".*/preview/{visible}.*"));
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "foo").to_witness())],
-      ..default()
-    });
+    assert!(!server
+      .get("/")
+      .text()
+      .unwrap()
+      .contains(&hidden.to_string()));
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn metadata_batch() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    let mut ids = Vec::new();
-    ids.push(InscriptionId { txid, index: 0 });
+    server.mine_blocks(2);
 
-    for i in 1..111 {
-      let txid = server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(i + 1, 1, 0, inscription("text/plain", "foo").to_witness())],
+    let with_metadata = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          1,
+          0,
+          0,
+          Inscription {
+            metadata: Some(vec![0xa0]),
+            ..default()
+          }
+          .to_witness(),
+        )],
         ..default()
-      });
+      }),
+      index: 0,
+    };
 
-      server.mine_blocks(1);
+    server.mine_blocks(1);
 
-      ids.push(InscriptionId { txid, index: 0 });
-    }
+    let without_metadata = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 0, 0, inscription("text/plain", "foo").to_witness())],
+        ..default()
+      }),
+      index: 0,
+    };
 
-    let paginated_response = server.get_json::<api::SatInscriptions>("/r/sat/5000000000");
+    server.mine_blocks(1);
 
-    let equivalent_paginated_response =
-      server.get_json::<api::SatInscriptions>("/r/sat/5000000000/0");
+    let response = server.post_json("/r/metadata", &[with_metadata, without_metadata]);
 
-    assert_eq!(paginated_response.ids.len(), 100);
-    assert!(paginated_response.more);
-    assert_eq!(paginated_response.page, 0);
+    assert_eq!(response.status(), StatusCode::OK);
 
-    assert_eq!(
-      paginated_response.ids.len(),
-      equivalent_paginated_response.ids.len()
+    pretty_assert_eq!(
+      response
+        .json::<BTreeMap<InscriptionId, Option<String>>>()
+        .unwrap(),
+      [(with_metadata, Some("a0".into())), (without_metadata, None),]
+        .into_iter()
+        .collect(),
     );
-    assert_eq!(paginated_response.more, equivalent_paginated_response.more);
-    assert_eq!(paginated_response.page, equivalent_paginated_response.page);
-
-    let paginated_response = server.get_json::<api::SatInscriptions>("/r/sat/5000000000/1");
 
-    assert_eq!(paginated_response.ids.len(), 11);
-    assert!(!paginated_response.more);
-    assert_eq!(paginated_response.page, 1);
+    let response = server.post_json("/r/metadata", &[with_metadata, InscriptionId::default()]);
 
-    assert_eq!(
-      server
-        .get_json::<api::SatInscription>("/r/sat/5000000000/at/0")
-        .id,
-      Some(ids[0])
-    );
+    assert_eq!(response.status(), StatusCode::OK);
 
-    assert_eq!(
-      server
-        .get_json::<api::SatInscription>("/r/sat/5000000000/at/-111")
-        .id,
-      Some(ids[0])
+    pretty_assert_eq!(
+      response
+        .json::<BTreeMap<InscriptionId, Option<String>>>()
+        .unwrap(),
+      [
+        (with_metadata, Some("a0".into())),
+        (InscriptionId::default(), None)
+      ]
+      .into_iter()
+      .collect(),
     );
 
-    assert_eq!(
-      server
-        .get_json::<api::SatInscription>("/r/sat/5000000000/at/110")
-        .id,
-      Some(ids[110])
+    let response = server.post_json(
+      "/r/metadata",
+      &vec![with_metadata; MAX_METADATA_BATCH_SIZE + 1],
     );
-
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     assert_eq!(
-      server
-        .get_json::<api::SatInscription>("/r/sat/5000000000/at/-1")
-        .id,
-      Some(ids[110])
+      response.text().unwrap(),
+      "metadata batch size 101 exceeds maximum of 100",
     );
-
-    assert!(server
-      .get_json::<api::SatInscription>("/r/sat/5000000000/at/111")
-      .id
-      .is_none());
   }
 
   #[test]
-  fn children_recursive_endpoint() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn content_is_served_by_cid() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .ord_flag("--index-cids")
+      .server_flag("--ipfs")
+      .build();
+
     server.mine_blocks(1);
 
-    let parent_txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+    let inscription = inscription("text/plain", "hello");
+    let cid = inscription.cid().unwrap();
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription.to_witness())],
       ..default()
     });
 
-    let parent_inscription_id = InscriptionId {
-      txid: parent_txid,
-      index: 0,
-    };
+    server.mine_blocks(1);
+
+    let response = server.get(format!("/ipfs/{cid}"));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain");
+    assert_eq!(response.text().unwrap(), "hello");
 
     server.assert_response(
-      format!("/r/children/{parent_inscription_id}"),
+      format!("/ipfs/{}", Cid::from_content(b"goodbye")),
       StatusCode::NOT_FOUND,
-      &format!("inscription {parent_inscription_id} not found"),
+      &format!("CID {} not found", Cid::from_content(b"goodbye")),
     );
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn openapi_route_requires_flag() {
+    TestServer::new().assert_response(
+      "/api/openapi.json",
+      StatusCode::NOT_FOUND,
+      "OpenAPI description is not served by this server",
+    );
+  }
 
-    let children_json =
-      server.get_json::<api::Children>(format!("/r/children/{parent_inscription_id}"));
-    assert_eq!(children_json.ids.len(), 0);
+  #[test]
+  fn openapi_spec_lists_registered_routes() {
+    let server = TestServer::builder().server_flag("--openapi").build();
 
-    let mut builder = script::Builder::new();
-    for _ in 0..111 {
-      builder = Inscription {
-        content_type: Some("text/plain".into()),
-        body: Some("hello".into()),
-        parents: vec![parent_inscription_id.value()],
-        unrecognized_even_field: false,
-        ..default()
-      }
-      .append_reveal_script_to_builder(builder);
-    }
+    let spec = server.get_json::<serde_json::Value>("/api/openapi.json");
 
-    let witness = Witness::from_slice(&[builder.into_bytes(), Vec::new()]);
+    assert_eq!(spec["info"]["version"], env!("CARGO_PKG_VERSION"));
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(2, 0, 0, witness), (2, 1, 0, Default::default())],
-      ..default()
-    });
+    let paths = spec["paths"].as_object().unwrap();
 
-    server.mine_blocks(1);
+    assert!(!paths.is_empty());
 
-    let first_child_inscription_id = InscriptionId { txid, index: 0 };
-    let hundredth_child_inscription_id = InscriptionId { txid, index: 99 };
-    let hundred_first_child_inscription_id = InscriptionId { txid, index: 100 };
-    let hundred_eleventh_child_inscription_id = InscriptionId { txid, index: 110 };
+    let parameter = Regex::new(r"\{(\w+)\}").unwrap();
 
-    let children_json =
-      server.get_json::<api::Children>(format!("/r/children/{parent_inscription_id}"));
+    for (path, operations) in paths {
+      let url = parameter.replace_all(path, |captures: &regex::Captures| match &captures[1] {
+        "inscription_id" | "inscription_query" => inscription_id(1).to_string(),
+        "output" => outpoint(1).to_string(),
+        "height" | "index" | "page" | "query" | "sat" | "sat_number" => "0".into(),
+        name => panic!("no placeholder for OpenAPI path parameter `{name}` in `{path}`"),
+      });
 
-    assert_eq!(children_json.ids.len(), 100);
-    assert_eq!(children_json.ids[0], first_child_inscription_id);
-    assert_eq!(children_json.ids[99], hundredth_child_inscription_id);
-    assert!(children_json.more);
-    assert_eq!(children_json.page, 0);
+      for method in operations.as_object().unwrap().keys() {
+        let response = reqwest::blocking::Client::new()
+          .request(
+            method.to_uppercase().parse().unwrap(),
+            server.join_url(&url),
+          )
+          .header(header::ACCEPT, "application/json")
+          .header(header::CONTENT_TYPE, "application/json")
+          .body("[]")
+          .send()
+          .unwrap();
+
+        let status = response.status();
+
+        assert_ne!(
+          status,
+          StatusCode::METHOD_NOT_ALLOWED,
+          "OpenAPI operation `{method} {path}` is not a registered route",
+        );
 
-    let children_json =
-      server.get_json::<api::Children>(format!("/r/children/{parent_inscription_id}/1"));
+        assert!(
+          !(status == StatusCode::NOT_FOUND && response.text().unwrap().is_empty()),
+          "OpenAPI path `{path}` is not a registered route",
+        );
+      }
+    }
 
-    assert_eq!(children_json.ids.len(), 11);
-    assert_eq!(children_json.ids[0], hundred_first_child_inscription_id);
-    assert_eq!(children_json.ids[10], hundred_eleventh_child_inscription_id);
-    assert!(!children_json.more);
-    assert_eq!(children_json.page, 1);
+    let segment = Regex::new(r":(\w+)").unwrap();
+
+    for (route, _) in Server::recursive_routes() {
+      let path = segment.replace_all(route, "{$1}");
+
+      assert!(
+        paths.contains_key(path.as_ref()),
+        "recursive route `{route}` is not described in openapi.json",
+      );
+    }
+  }
+
+  #[test]
+  fn inscription_export_requires_flag() {
+    TestServer::new().assert_response(
+      "/r/inscriptions/export",
+      StatusCode::NOT_FOUND,
+      "inscription export is not served by this server",
+    );
   }
 
   #[test]
-  fn inscriptions_in_block_page() {
+  fn inscription_export_streams_all_inscriptions_as_ndjson() {
     let server = TestServer::builder()
       .chain(Chain::Regtest)
-      .index_sats()
+      .server_flag("--enable-inscription-export")
       .build();
 
-    for _ in 0..101 {
-      server.mine_blocks(1);
-    }
+    server.mine_blocks(2);
 
-    for i in 0..101 {
-      server.core.broadcast_tx(TransactionTemplate {
-        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
+    let mut ids = Vec::new();
+
+    for i in 1..3 {
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(i, 0, 0, inscription("text/plain", "hello").to_witness())],
         ..default()
       });
+
+      server.mine_blocks(1);
+
+      ids.push(InscriptionId { txid, index: 0 });
     }
 
-    server.mine_blocks(1);
+    let response = server.get("/r/inscriptions/export");
 
-    server.assert_response_regex(
-      "/inscriptions/block/102",
-      StatusCode::OK,
-      r".*(<a href=/inscription/[[:xdigit:]]{64}i0>.*</a>.*){100}.*",
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "application/x-ndjson"
     );
 
-    server.assert_response_regex(
-      "/inscriptions/block/102/1",
-      StatusCode::OK,
-      r".*<a href=/inscription/[[:xdigit:]]{64}i0>.*</a>.*",
+    let exported = response
+      .text()
+      .unwrap()
+      .lines()
+      .map(|line| serde_json::from_str::<api::InscriptionRecursive>(line).unwrap())
+      .collect::<Vec<api::InscriptionRecursive>>();
+
+    assert_eq!(
+      exported,
+      ids
+        .iter()
+        .map(|id| server.get_json::<api::InscriptionRecursive>(format!("/r/inscription/{id}")))
+        .collect::<Vec<api::InscriptionRecursive>>(),
     );
   }
 
   #[test]
-  fn inscription_query_display() {
-    assert_eq!(
-      query::Inscription::Id(inscription_id(1)).to_string(),
-      "1111111111111111111111111111111111111111111111111111111111111111i1"
+  fn thumbnail_route_requires_flag() {
+    TestServer::new().assert_response(
+      format!("/content/{}/thumbnail", inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      "thumbnails are not served by this server",
     );
-    assert_eq!(query::Inscription::Number(1).to_string(), "1")
   }
 
   #[test]
-  fn inscription_not_found() {
-    TestServer::builder()
-      .chain(Chain::Regtest)
-      .build()
-      .assert_response(
-        "/inscription/0",
-        StatusCode::NOT_FOUND,
-        "inscription 0 not found",
+  fn thumbnail_size_is_bounded() {
+    let server = TestServer::builder()
+      .server_flag("--enable-thumbnails")
+      .build();
+
+    for size in [0, MAX_THUMBNAIL_SIZE + 1] {
+      server.assert_response(
+        format!("/content/{}/thumbnail?size={size}", inscription_id(1)),
+        StatusCode::BAD_REQUEST,
+        &format!("thumbnail size must be between 1 and {MAX_THUMBNAIL_SIZE}"),
       );
+    }
   }
 
   #[test]
-  fn looking_up_inscription_by_sat_requires_sat_index() {
-    TestServer::builder()
+  fn thumbnails_are_downscaled_raster_images() {
+    let server = TestServer::builder()
       .chain(Chain::Regtest)
-      .build()
-      .assert_response(
-        "/inscription/abcd",
-        StatusCode::NOT_FOUND,
-        "sat index required",
-      );
-  }
+      .server_flag("--enable-thumbnails")
+      .build();
 
-  #[test]
-  fn delegate() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(2);
 
-    server.mine_blocks(1);
+    let mut png = Vec::new();
 
-    let delegate = Inscription {
-      content_type: Some("text/html".into()),
-      body: Some("foo".into()),
-      ..default()
-    };
+    image::DynamicImage::new_rgb8(400, 200)
+      .write_to(&mut io::Cursor::new(&mut png), image::ImageFormat::Png)
+      .unwrap();
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, delegate.to_witness())],
+      inputs: &[(1, 0, 0, inscription("image/png", png).to_witness())],
       ..default()
     });
 
-    let delegate = InscriptionId { txid, index: 0 };
-
     server.mine_blocks(1);
 
-    let inscription = Inscription {
-      delegate: Some(delegate.value()),
-      ..default()
-    };
+    let response = server.get(format!("/content/{txid}i0/thumbnail?size=100"));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "image/jpeg"
+    );
+
+    let thumbnail = image::load_from_memory(&response.bytes().unwrap()).unwrap();
+
+    assert_eq!((thumbnail.width(), thumbnail.height()), (100, 50));
 
     let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(2, 0, 0, inscription.to_witness())],
+      inputs: &[(2, 0, 0, inscription("image/svg+xml", "<svg/>").to_witness())],
       ..default()
     });
 
     server.mine_blocks(1);
 
-    let id = InscriptionId { txid, index: 0 };
-
-    server.assert_response_regex(
-      format!("/inscription/{id}"),
+    server.assert_response(
+      format!("/content/{txid}i0/thumbnail"),
       StatusCode::OK,
-      format!(
-        ".*<h1>Inscription 1</h1>.*
-        <dl>
-          <dt>id</dt>
-          <dd class=monospace>{id}</dd>
-          .*
-          <dt>delegate</dt>
-          <dd><a href=/inscription/{delegate}>{delegate}</a></dd>
-          .*
-        </dl>.*"
-      )
-      .unindent(),
+      "<svg/>",
     );
+  }
 
-    server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
+  #[test]
+  fn ipfs_route_requires_flag() {
+    let server = TestServer::new();
 
-    server.assert_response(format!("/preview/{id}"), StatusCode::OK, "foo");
+    server.assert_response(
+      format!("/ipfs/{}", Cid::from_content(b"hello")),
+      StatusCode::NOT_FOUND,
+      "IPFS content is not served by this server",
+    );
   }
 
   #[test]
-  fn proxy() {
-    let server = TestServer::builder().chain(Chain::Regtest).build();
+  fn ipfs_route_requires_cid_index() {
+    TestServer::builder()
+      .server_flag("--ipfs")
+      .build()
+      .assert_response(
+        format!("/ipfs/{}", Cid::from_content(b"hello")),
+        StatusCode::NOT_FOUND,
+        "this server has no CID index",
+      );
+  }
 
-    server.mine_blocks(1);
+  #[test]
+  fn content_type_can_be_overridden() {
+    let core = mockcore::builder()
+      .network(Chain::Regtest.network())
+      .build();
 
-    let inscription = Inscription {
-      content_type: Some("text/html".into()),
-      body: Some("foo".into()),
-      ..default()
-    };
+    core.mine_blocks(1);
 
-    let txid = server.core.broadcast_tx(TransactionTemplate {
-      inputs: &[(1, 0, 0, inscription.to_witness())],
+    let txid = core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
       ..default()
     });
 
-    server.mine_blocks(1);
-
-    let id = InscriptionId { txid, index: 0 };
+    core.mine_blocks(1);
 
-    server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
+    let inscription = InscriptionId { txid, index: 0 };
 
-    let server_with_proxy = TestServer::builder()
-      .chain(Chain::Regtest)
-      .server_option("--content-proxy", server.url.as_ref())
+    let server = TestServer::builder()
+      .core(core)
+      .server_option("--serve-content-type", &format!("{inscription}=text/plain"))
       .build();
 
-    server_with_proxy.mine_blocks(1);
-
-    server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
-    server_with_proxy.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
-  }
-
-  #[test]
-  fn block_info() {
-    let server = TestServer::new();
+    let response = server.get(format!("/content/{inscription}"));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain");
+    assert_eq!(response.text().unwrap(), "hello");
 
-    pretty_assert_eq!(
-      server.get_json::<api::BlockInfo>("/r/blockinfo/0"),
-      api::BlockInfo {
-        average_fee: 0,
-        average_fee_rate: 0,
-        bits: 486604799,
-        chainwork: [0; 32],
-        confirmations: 0,
-        difficulty: 0.0,
-        hash: "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
-          .parse()
-          .unwrap(),
-        height: 0,
-        max_fee: 0,
-        max_fee_rate: 0,
-        max_tx_size: 0,
-        median_fee: 0,
-        median_time: None,
-        merkle_root: TxMerkleNode::all_zeros(),
-        min_fee: 0,
-        min_fee_rate: 0,
-        next_block: None,
-        nonce: 0,
-        previous_block: None,
-        subsidy: 0,
-        target: "00000000ffff0000000000000000000000000000000000000000000000000000"
-          .parse()
-          .unwrap(),
-        timestamp: 0,
-        total_fee: 0,
-        total_size: 0,
-        total_weight: 0,
-        transaction_count: 0,
-        version: 1,
-      },
+    server.assert_response_regex(
+      format!("/inscription/{inscription}"),
+      StatusCode::OK,
+      ".*<dt>content type</dt>\n  <dd>text/foo</dd>.*",
     );
-
-    server.mine_blocks(1);
-
-    pretty_assert_eq!(
-      server.get_json::<api::BlockInfo>("/r/blockinfo/1"),
-      api::BlockInfo {
-        average_fee: 0,
-        average_fee_rate: 0,
-        bits: 0,
-        chainwork: [0; 32],
-        confirmations: 0,
-        difficulty: 0.0,
-        hash: "56d05060a0280d0712d113f25321158747310ece87ea9e299bde06cf385b8d85"
-          .parse()
-          .unwrap(),
-        height: 1,
-        max_fee: 0,
-        max_fee_rate: 0,
-        max_tx_size: 0,
-        median_fee: 0,
-        median_time: None,
-        merkle_root: TxMerkleNode::all_zeros(),
-        min_fee: 0,
-        min_fee_rate: 0,
-        next_block: None,
-        nonce: 0,
-        previous_block: None,
-        subsidy: 0,
-        target: BlockHash::all_zeros(),
-        timestamp: 0,
-        total_fee: 0,
-        total_size: 0,
-        total_weight: 0,
-        transaction_count: 0,
-        version: 1,
-      },
-    )
-  }
-
-  #[test]
-  fn authentication_requires_username_and_password() {
-    assert!(Arguments::try_parse_from(["ord", "--server-username", "server", "foo"]).is_err());
-    assert!(Arguments::try_parse_from(["ord", "--server-password", "server", "bar"]).is_err());
-    assert!(Arguments::try_parse_from([
-      "ord",
-      "--server-username",
-      "foo",
-      "--server-password",
-      "bar",
-      "server"
-    ])
-    .is_ok());
   }
 
   #[test]
-  fn inscriptions_can_be_hidden_with_config() {
+  fn content_type_can_be_overridden_with_config() {
     let core = mockcore::builder()
       .network(Chain::Regtest.network())
       .build();
@@ -6223,16 +10222,28 @@ next
 
     let server = TestServer::builder()
       .core(core)
-      .config(&format!("hidden: [{inscription}]"))
+      .config(&format!("serve_content_type: {{{inscription}: image/png}}"))
       .build();
 
-    server.assert_response_regex(format!("/inscription/{inscription}"), StatusCode::OK, ".*");
+    let response = server.get(format!("/content/{inscription}"));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
 
-    server.assert_response_regex(
-      format!("/content/{inscription}"),
-      StatusCode::OK,
-      PreviewUnknownHtml.to_string(),
+    assert_eq!(
+      server
+        .get_json::<api::InscriptionContentType>(format!(
+          "/r/inscription/{inscription}/content-type"
+        ))
+        .content_type,
+      Some("image/png".into()),
     );
+
+    let response = server.get(format!("/r/inscription/{inscription}/content-type"));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response
+      .headers()
+      .get(header::CACHE_CONTROL)
+      .is_some_and(|cache_control| cache_control.to_str().unwrap().contains("immutable")));
   }
 
   #[test]
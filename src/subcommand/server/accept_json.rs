@@ -16,17 +16,97 @@ where
   ) -> Result<Self, Self::Rejection> {
     let state = Arc::from_ref(state);
     let json_api_enabled = state.json_api_enabled;
-    let json_header = parts
-      .headers
-      .get("accept")
-      .map(|value| value == "application/json")
-      .unwrap_or_default();
+    let json_header = Self::quality(&parts.headers, "application/json")
+      .max(Self::quality(&parts.headers, AcceptMsgpack::CONTENT_TYPE))
+      .is_some_and(|json| {
+        Self::quality(&parts.headers, "text/html").map_or(true, |html| json >= html)
+      });
     if json_header && json_api_enabled {
       Ok(Self(true))
     } else if json_header && !json_api_enabled {
       Err((StatusCode::NOT_ACCEPTABLE, "JSON API disabled"))
+    } else if state.json_api_only {
+      Err((StatusCode::NOT_FOUND, "HTML pages disabled"))
     } else {
       Ok(Self(false))
     }
   }
 }
+
+impl AcceptJson {
+  /// Quality value of `media_type` in the `Accept` header. Parameters other
+  /// than `q` are ignored. Returns `None` if the media type is absent or has
+  /// a quality of zero.
+  pub(crate) fn quality(headers: &HeaderMap, media_type: &str) -> Option<u16> {
+    headers
+      .get_all(header::ACCEPT)
+      .iter()
+      .filter_map(|value| value.to_str().ok())
+      .flat_map(|value| value.split(','))
+      .filter_map(|range| {
+        let mut params = range.split(';');
+
+        if !params
+          .next()
+          .unwrap_or_default()
+          .trim()
+          .eq_ignore_ascii_case(media_type)
+        {
+          return None;
+        }
+
+        let quality = params
+          .filter_map(|param| param.trim().strip_prefix("q="))
+          .next()
+          .map(|q| q.trim().parse::<f32>().unwrap_or_default())
+          .unwrap_or(1.0);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some((quality.clamp(0.0, 1.0) * 1000.0).round() as u16)
+      })
+      .max()
+      .filter(|&quality| quality > 0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn quality(accept: &str, media_type: &str) -> Option<u16> {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT, accept.parse().unwrap());
+    AcceptJson::quality(&headers, media_type)
+  }
+
+  #[test]
+  fn exact_media_type() {
+    assert_eq!(quality("application/json", "application/json"), Some(1000));
+    assert_eq!(quality("text/html", "application/json"), None);
+  }
+
+  #[test]
+  fn parameters_are_ignored() {
+    assert_eq!(
+      quality("application/json; charset=utf-8", "application/json"),
+      Some(1000)
+    );
+    assert_eq!(
+      quality("Application/JSON;charset=utf-8", "application/json"),
+      Some(1000)
+    );
+  }
+
+  #[test]
+  fn quality_values() {
+    assert_eq!(
+      quality(
+        "text/html;q=0.9, application/msgpack;q=0.5, application/json",
+        "application/msgpack"
+      ),
+      Some(500)
+    );
+    assert_eq!(quality("application/json;q=0", "application/json"), None);
+    assert_eq!(quality("application/json;q=foo", "application/json"), None);
+  }
+}
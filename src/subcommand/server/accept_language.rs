@@ -0,0 +1,185 @@
+use {
+  super::*,
+  axum::{http::Request, middleware::Next},
+};
+
+/// Localized messages for explorer error pages. To support a new language, add
+/// an entry with its ISO 639-1 code. Requests which prefer English, or no
+/// supported language, receive the unlocalized error response.
+const ERROR_PAGES: &[ErrorPage] = &[
+  ErrorPage {
+    language: "de",
+    not_found: "Nicht gefunden",
+    service_unavailable: "Dienst nicht verfügbar",
+  },
+  ErrorPage {
+    language: "es",
+    not_found: "No encontrado",
+    service_unavailable: "Servicio no disponible",
+  },
+];
+
+#[derive(Debug, PartialEq)]
+struct ErrorPage {
+  language: &'static str,
+  not_found: &'static str,
+  service_unavailable: &'static str,
+}
+
+impl ErrorPage {
+  fn localizable(status: StatusCode) -> bool {
+    matches!(
+      status,
+      StatusCode::NOT_FOUND | StatusCode::SERVICE_UNAVAILABLE
+    )
+  }
+
+  fn message(&self, status: StatusCode) -> Option<&'static str> {
+    match status {
+      StatusCode::NOT_FOUND => Some(self.not_found),
+      StatusCode::SERVICE_UNAVAILABLE => Some(self.service_unavailable),
+      _ => None,
+    }
+  }
+
+  fn negotiate(accept_language: &str) -> Option<&'static Self> {
+    let mut languages = accept_language
+      .split(',')
+      .filter_map(|range| {
+        let mut parts = range.split(';');
+
+        let language = parts.next()?.trim().split('-').next()?.to_lowercase();
+
+        let quality = parts
+          .find_map(|param| param.trim().strip_prefix("q="))
+          .map(|q| q.parse::<f32>().unwrap_or(0.0))
+          .unwrap_or(1.0);
+
+        (quality > 0.0).then_some((language, quality))
+      })
+      .collect::<Vec<(String, f32)>>();
+
+    languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    for (language, _quality) in languages {
+      if language == "en" {
+        return None;
+      }
+
+      if let Some(page) = ERROR_PAGES.iter().find(|page| page.language == language) {
+        return Some(page);
+      }
+    }
+
+    None
+  }
+}
+
+/// Localize plain text 404 and 503 responses to HTML page requests according to
+/// the `Accept-Language` header. API and recursive requests, which don't accept
+/// `text/html`, are left untouched.
+pub(super) async fn localize_error_pages<B>(request: Request<B>, next: Next<B>) -> Response {
+  let accept_html = request
+    .headers()
+    .get(header::ACCEPT)
+    .and_then(|value| value.to_str().ok())
+    .map(|value| value.contains("text/html"))
+    .unwrap_or_default();
+
+  let page = request
+    .headers()
+    .get(header::ACCEPT_LANGUAGE)
+    .and_then(|value| value.to_str().ok())
+    .and_then(ErrorPage::negotiate);
+
+  let mut response = next.run(request).await;
+
+  if !ErrorPage::localizable(response.status()) {
+    return response;
+  }
+
+  let plain_text = response
+    .headers()
+    .get(header::CONTENT_TYPE)
+    .map(|value| value.as_bytes().starts_with(b"text/plain"))
+    .unwrap_or(true);
+
+  if !plain_text {
+    return response;
+  }
+
+  response.headers_mut().append(
+    header::VARY,
+    HeaderValue::from_static("Accept, Accept-Language"),
+  );
+
+  let (Some(page), true) = (page, accept_html) else {
+    return response;
+  };
+
+  let Some(message) = page.message(response.status()) else {
+    return response;
+  };
+
+  let (mut parts, body) = response.into_parts();
+
+  let Ok(detail) = hyper::body::to_bytes(body).await else {
+    return (parts.status, message).into_response();
+  };
+
+  let text = if detail.is_empty() {
+    message.to_string()
+  } else {
+    format!("{message}: {}", String::from_utf8_lossy(&detail))
+  };
+
+  parts.headers.remove(header::CONTENT_LENGTH);
+  parts.headers.insert(
+    header::CONTENT_TYPE,
+    HeaderValue::from_static("text/plain; charset=utf-8"),
+  );
+  parts.headers.insert(
+    header::CONTENT_LANGUAGE,
+    HeaderValue::from_static(page.language),
+  );
+
+  Response::from_parts(parts, body::boxed(body::Full::from(text)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn negotiate() {
+    assert_eq!(ErrorPage::negotiate(""), None);
+    assert_eq!(ErrorPage::negotiate("en-US,en;q=0.9"), None);
+    assert_eq!(ErrorPage::negotiate("fr"), None);
+    assert_eq!(ErrorPage::negotiate("de-CH"), Some(&ERROR_PAGES[0]));
+    assert_eq!(ErrorPage::negotiate("fr, es;q=0.5"), Some(&ERROR_PAGES[1]));
+    assert_eq!(
+      ErrorPage::negotiate("en;q=0.5, de;q=0.8"),
+      Some(&ERROR_PAGES[0])
+    );
+    assert_eq!(ErrorPage::negotiate("de;q=0.5, en;q=0.8"), None);
+    assert_eq!(ErrorPage::negotiate("de;q=0, es"), Some(&ERROR_PAGES[1]));
+  }
+
+  #[test]
+  fn localizable_statuses_have_messages() {
+    for status in [
+      StatusCode::OK,
+      StatusCode::BAD_REQUEST,
+      StatusCode::NOT_FOUND,
+      StatusCode::INTERNAL_SERVER_ERROR,
+      StatusCode::SERVICE_UNAVAILABLE,
+    ] {
+      for page in ERROR_PAGES {
+        assert_eq!(
+          ErrorPage::localizable(status),
+          page.message(status).is_some()
+        );
+      }
+    }
+  }
+}
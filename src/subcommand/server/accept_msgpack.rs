@@ -0,0 +1,69 @@
+use super::*;
+
+#[derive(Clone, Copy)]
+pub(crate) struct AcceptMsgpack(pub(crate) bool);
+
+#[async_trait::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for AcceptMsgpack
+where
+  S: Send + Sync,
+{
+  type Rejection = (StatusCode, &'static str);
+
+  async fn from_request_parts(
+    parts: &mut http::request::Parts,
+    _state: &S,
+  ) -> Result<Self, Self::Rejection> {
+    Ok(Self(
+      AcceptJson::quality(&parts.headers, Self::CONTENT_TYPE).is_some_and(|msgpack| {
+        AcceptJson::quality(&parts.headers, "application/json").map_or(true, |json| msgpack > json)
+      }),
+    ))
+  }
+}
+
+impl AcceptMsgpack {
+  pub(crate) const CONTENT_TYPE: &'static str = "application/msgpack";
+
+  /// Negotiated responses carry `AcceptMsgpack` as an extension, so that
+  /// `vary` can add `Vary: Accept` outside of the CORS layer, which replaces
+  /// any `Vary` header set by handlers.
+  pub(crate) fn response<T: Serialize>(&self, value: T) -> Response {
+    let mut response = self.serialize(value);
+    response.extensions_mut().insert(*self);
+    response
+  }
+
+  pub(crate) fn vary(response: &Response) -> Option<HeaderValue> {
+    response
+      .extensions()
+      .get::<Self>()
+      .map(|_| HeaderValue::from_static("accept"))
+  }
+
+  fn serialize<T: Serialize>(&self, value: T) -> Response {
+    if !self.0 {
+      return Json(value).into_response();
+    }
+
+    // Serialize structs as maps and use human readable representations, so
+    // that MessagePack responses have the same shape as JSON responses.
+    let mut buffer = Vec::new();
+
+    match value.serialize(
+      &mut rmp_serde::Serializer::new(&mut buffer)
+        .with_struct_map()
+        .with_human_readable(),
+    ) {
+      Ok(()) => (
+        [(
+          header::CONTENT_TYPE,
+          HeaderValue::from_static(Self::CONTENT_TYPE),
+        )],
+        buffer,
+      )
+        .into_response(),
+      Err(err) => ServerError::Internal(err.into()).into_response(),
+    }
+  }
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+pub(crate) struct Backoff {
+  base: Duration,
+  current: Duration,
+  max: Duration,
+}
+
+impl Backoff {
+  pub(crate) fn new(base: Duration, max: Duration) -> Self {
+    Self {
+      base,
+      current: base,
+      max: max.max(base),
+    }
+  }
+
+  pub(crate) fn next(&mut self, caught_up: bool) -> Duration {
+    if !caught_up {
+      self.current = self.base;
+      return self.base;
+    }
+
+    let interval = self.current;
+    self.current = self.current.saturating_mul(2).min(self.max);
+    interval
+  }
+
+  /// Sleep for `duration` in short slices, returning early once shutdown
+  /// has been requested, so that long backoff intervals don't delay exit.
+  pub(crate) fn sleep(duration: Duration) {
+    const SLICE: Duration = Duration::from_millis(100);
+
+    let deadline = Instant::now() + duration;
+
+    while !SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+      let remaining = deadline.saturating_duration_since(Instant::now());
+
+      if remaining.is_zero() {
+        break;
+      }
+
+      thread::sleep(remaining.min(SLICE));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn backs_off_while_caught_up() {
+    let mut backoff = Backoff::new(Duration::from_secs(5), Duration::from_secs(30));
+    assert_eq!(backoff.next(true), Duration::from_secs(5));
+    assert_eq!(backoff.next(true), Duration::from_secs(10));
+    assert_eq!(backoff.next(true), Duration::from_secs(20));
+    assert_eq!(backoff.next(true), Duration::from_secs(30));
+    assert_eq!(backoff.next(true), Duration::from_secs(30));
+  }
+
+  #[test]
+  fn resets_when_not_caught_up() {
+    let mut backoff = Backoff::new(Duration::from_secs(5), Duration::from_secs(30));
+    backoff.next(true);
+    backoff.next(true);
+    assert_eq!(backoff.next(false), Duration::from_secs(5));
+    assert_eq!(backoff.next(true), Duration::from_secs(5));
+    assert_eq!(backoff.next(true), Duration::from_secs(10));
+  }
+
+  #[test]
+  fn max_below_base_is_base() {
+    let mut backoff = Backoff::new(Duration::from_secs(5), Duration::from_secs(1));
+    assert_eq!(backoff.next(true), Duration::from_secs(5));
+    assert_eq!(backoff.next(true), Duration::from_secs(5));
+  }
+}
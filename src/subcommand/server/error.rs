@@ -2,13 +2,16 @@ use {super::*, std::fmt::Write};
 
 #[derive(Debug)]
 pub(super) enum ServerError {
+  BadGateway(String),
   BadRequest(String),
+  GatewayTimeout(String),
   Internal(Error),
   NotAcceptable {
     accept_encoding: AcceptEncoding,
     content_encoding: HeaderValue,
   },
   NotFound(String),
+  PayloadTooLarge(String),
 }
 
 pub(super) type ServerResult<T = Response> = Result<T, ServerError>;
@@ -16,7 +19,9 @@ pub(super) type ServerResult<T = Response> = Result<T, ServerError>;
 impl IntoResponse for ServerError {
   fn into_response(self) -> Response {
     match self {
+      Self::BadGateway(message) => (StatusCode::BAD_GATEWAY, message).into_response(),
       Self::BadRequest(message) => (StatusCode::BAD_REQUEST, message).into_response(),
+      Self::GatewayTimeout(message) => (StatusCode::GATEWAY_TIMEOUT, message).into_response(),
       Self::Internal(error) => {
         eprintln!("error serving request: {error}");
         (
@@ -50,6 +55,7 @@ impl IntoResponse for ServerError {
         message,
       )
         .into_response(),
+      Self::PayloadTooLarge(message) => (StatusCode::PAYLOAD_TOO_LARGE, message).into_response(),
     }
   }
 }
@@ -0,0 +1,83 @@
+use super::*;
+
+#[derive(Default, Debug)]
+pub(crate) struct IfModifiedSince(pub(crate) Option<DateTime<Utc>>);
+
+#[async_trait::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for IfModifiedSince
+where
+  S: Send + Sync,
+{
+  type Rejection = (StatusCode, &'static str);
+
+  async fn from_request_parts(
+    parts: &mut http::request::Parts,
+    _state: &S,
+  ) -> Result<Self, Self::Rejection> {
+    Ok(Self(
+      parts
+        .headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+        .map(|date| date.with_timezone(&Utc)),
+    ))
+  }
+}
+
+impl IfModifiedSince {
+  pub(crate) fn is_modified(&self, last_modified: DateTime<Utc>) -> bool {
+    self.0.map(|since| last_modified > since).unwrap_or(true)
+  }
+
+  pub(crate) fn http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    axum::{extract::FromRequestParts, http::Request},
+    http::header::IF_MODIFIED_SINCE,
+  };
+
+  async fn parse(value: &str) -> IfModifiedSince {
+    let req = Request::builder()
+      .header(IF_MODIFIED_SINCE, value)
+      .body(())
+      .unwrap();
+
+    IfModifiedSince::from_request_parts(&mut req.into_parts().0, &())
+      .await
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn http_date_round_trips() {
+    let date = timestamp(1231006505);
+
+    assert_eq!(
+      IfModifiedSince::http_date(date),
+      "Sat, 03 Jan 2009 18:15:05 GMT"
+    );
+
+    assert_eq!(parse(&IfModifiedSince::http_date(date)).await.0, Some(date));
+  }
+
+  #[tokio::test]
+  async fn malformed_dates_are_ignored() {
+    let if_modified_since = parse("yesterday").await;
+    assert_eq!(if_modified_since.0, None);
+    assert!(if_modified_since.is_modified(timestamp(0)));
+  }
+
+  #[tokio::test]
+  async fn is_modified() {
+    let if_modified_since = parse("Sat, 03 Jan 2009 18:15:05 GMT").await;
+    assert!(!if_modified_since.is_modified(timestamp(1231006505)));
+    assert!(!if_modified_since.is_modified(timestamp(1231006504)));
+    assert!(if_modified_since.is_modified(timestamp(1231006506)));
+  }
+}
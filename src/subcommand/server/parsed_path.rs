@@ -0,0 +1,124 @@
+use {
+  super::*,
+  axum::extract::{path::ErrorKind, rejection::PathRejection, FromRequestParts, RawPathParams},
+  serde::de::DeserializeOwned,
+};
+
+pub(super) trait PathParam: FromStr {
+  const NAME: &'static str;
+  const FORMAT: &'static str;
+}
+
+impl PathParam for InscriptionId {
+  const NAME: &'static str = "inscription ID";
+  const FORMAT: &'static str = "`<TXID>i<INDEX>`";
+}
+
+impl PathParam for OutPoint {
+  const NAME: &'static str = "outpoint";
+  const FORMAT: &'static str = "`<TXID>:<VOUT>`";
+}
+
+impl PathParam for Sat {
+  const NAME: &'static str = "sat";
+  const FORMAT: &'static str = "an integer, decimal, degree, percentile, or name";
+}
+
+impl PathParam for Txid {
+  const NAME: &'static str = "transaction ID";
+  const FORMAT: &'static str = "64 hexadecimal characters";
+}
+
+impl PathParam for query::Content {
+  const NAME: &'static str = "inscription ID";
+  const FORMAT: &'static str = "`<TXID>i<INDEX>` with an optional `.<EXTENSION>`";
+}
+
+impl PathParam for query::Inscription {
+  const NAME: &'static str = "inscription";
+  const FORMAT: &'static str = "an inscription ID, number, or sat name";
+}
+
+impl PathParam for query::Block {
+  const NAME: &'static str = "block";
+  const FORMAT: &'static str = "a block height or hash";
+}
+
+impl PathParam for query::Rune {
+  const NAME: &'static str = "rune";
+  const FORMAT: &'static str = "a rune name, number, or `<BLOCK>:<TX>` ID";
+}
+
+/// Path parameters whose parse errors are reported with the name and expected
+/// format of `Param`.
+pub(super) trait PathParams: DeserializeOwned {
+  type Param: PathParam;
+}
+
+impl PathParams for InscriptionId {
+  type Param = Self;
+}
+
+impl PathParams for OutPoint {
+  type Param = Self;
+}
+
+impl PathParams for Txid {
+  type Param = Self;
+}
+
+impl<T: PathParam> PathParams for DeserializeFromStr<T>
+where
+  T::Err: Display,
+{
+  type Param = T;
+}
+
+impl<T: PathParams, U: DeserializeOwned> PathParams for (T, U) {
+  type Param = T::Param;
+}
+
+pub(super) struct ParsedPath<T>(pub(super) T);
+
+#[async_trait::async_trait]
+impl<S, T> FromRequestParts<S> for ParsedPath<T>
+where
+  S: Send + Sync,
+  T: PathParams + Send,
+{
+  type Rejection = ServerError;
+
+  async fn from_request_parts(
+    parts: &mut http::request::Parts,
+    state: &S,
+  ) -> Result<Self, Self::Rejection> {
+    match Path::<T>::from_request_parts(parts, state).await {
+      Ok(Path(value)) => Ok(Self(value)),
+      Err(PathRejection::FailedToDeserializePathParams(err)) => {
+        Err(ServerError::BadRequest(match err.into_kind() {
+          ErrorKind::Message(message) => {
+            let value = RawPathParams::from_request_parts(parts, state)
+              .await
+              .ok()
+              .and_then(|params| {
+                params
+                  .iter()
+                  .map(|(_key, value)| value)
+                  .find(|value| value.parse::<T::Param>().is_err())
+                  .map(str::to_string)
+              })
+              .unwrap_or_default();
+
+            format!(
+              "invalid {} `{value}`, expected {}: {message}",
+              T::Param::NAME,
+              T::Param::FORMAT,
+            )
+          }
+          kind => kind.to_string(),
+        }))
+      }
+      Err(rejection) => Err(ServerError::Internal(anyhow!(rejection.body_text()))),
+    }
+  }
+}
@@ -17,6 +17,28 @@ impl FromStr for Block {
   }
 }
 
+pub(super) struct Content {
+  pub(super) inscription_id: InscriptionId,
+  pub(super) extension: Option<String>,
+}
+
+impl FromStr for Content {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(match s.split_once('.') {
+      Some((inscription_id, extension)) => Self {
+        inscription_id: inscription_id.parse()?,
+        extension: Some(extension.into()),
+      },
+      None => Self {
+        inscription_id: s.parse()?,
+        extension: None,
+      },
+    })
+  }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum Inscription {
   Id(InscriptionId),
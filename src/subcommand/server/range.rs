@@ -0,0 +1,117 @@
+use super::*;
+
+#[derive(Default, Debug)]
+pub(crate) struct Range(pub(crate) Option<String>);
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ByteRange {
+  Full,
+  Partial { start: u64, end: u64 },
+  Unsatisfiable,
+}
+
+#[async_trait::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for Range
+where
+  S: Send + Sync,
+{
+  type Rejection = (StatusCode, &'static str);
+
+  async fn from_request_parts(
+    parts: &mut http::request::Parts,
+    _state: &S,
+  ) -> Result<Self, Self::Rejection> {
+    Ok(Self(
+      parts
+        .headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned),
+    ))
+  }
+}
+
+impl Range {
+  /// Resolve against a body of `len` bytes. Multiple ranges and malformed
+  /// headers are ignored, in which case the full body should be served.
+  pub(crate) fn resolve(&self, len: u64) -> ByteRange {
+    let Some((start, end)) = self
+      .0
+      .as_deref()
+      .and_then(|range| range.trim().strip_prefix("bytes="))
+      .filter(|ranges| !ranges.contains(','))
+      .and_then(|range| range.split_once('-'))
+    else {
+      return ByteRange::Full;
+    };
+
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+      let Ok(suffix) = end.parse::<u64>() else {
+        return ByteRange::Full;
+      };
+
+      if suffix == 0 || len == 0 {
+        return ByteRange::Unsatisfiable;
+      }
+
+      return ByteRange::Partial {
+        start: len.saturating_sub(suffix),
+        end: len - 1,
+      };
+    }
+
+    let Ok(start) = start.parse::<u64>() else {
+      return ByteRange::Full;
+    };
+
+    let end = if end.is_empty() {
+      u64::MAX
+    } else {
+      let Ok(end) = end.parse::<u64>() else {
+        return ByteRange::Full;
+      };
+      end
+    };
+
+    if end < start {
+      return ByteRange::Full;
+    }
+
+    if start >= len {
+      return ByteRange::Unsatisfiable;
+    }
+
+    ByteRange::Partial {
+      start,
+      end: end.min(len - 1),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[track_caller]
+  fn case(header: &str, len: u64, expected: ByteRange) {
+    assert_eq!(Range(Some(header.into())).resolve(len), expected);
+  }
+
+  #[test]
+  fn resolve() {
+    assert_eq!(Range(None).resolve(10), ByteRange::Full);
+    case("bytes=0-4", 10, ByteRange::Partial { start: 0, end: 4 });
+    case("bytes=5-", 10, ByteRange::Partial { start: 5, end: 9 });
+    case("bytes=5-100", 10, ByteRange::Partial { start: 5, end: 9 });
+    case("bytes=-3", 10, ByteRange::Partial { start: 7, end: 9 });
+    case("bytes=-100", 10, ByteRange::Partial { start: 0, end: 9 });
+    case("bytes=10-", 10, ByteRange::Unsatisfiable);
+    case("bytes=-0", 10, ByteRange::Unsatisfiable);
+    case("bytes=0-1,3-4", 10, ByteRange::Full);
+    case("bytes=4-2", 10, ByteRange::Full);
+    case("items=0-4", 10, ByteRange::Full);
+    case("bytes=a-b", 10, ByteRange::Full);
+  }
+}
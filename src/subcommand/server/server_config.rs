@@ -1,17 +1,48 @@
-use {super::*, axum::http::HeaderName};
+use {super::*, crate::index::lru::Lru, axum::http::HeaderName};
 
 #[derive(Default)]
 pub(crate) struct ServerConfig {
   pub(crate) chain: Chain,
   pub(crate) content_proxy: Option<Url>,
+  pub(crate) content_proxy_max_size: usize,
+  pub(crate) content_proxy_timeout: Duration,
   pub(crate) csp_origin: Option<String>,
   pub(crate) decompress: bool,
   pub(crate) domain: Option<String>,
+  pub(crate) enable_thumbnails: bool,
+  pub(crate) find_range: bool,
   pub(crate) index_sats: bool,
+  pub(crate) inscription_export: bool,
+  pub(crate) ipfs: bool,
   pub(crate) json_api_enabled: bool,
+  pub(crate) json_api_only: bool,
+  pub(crate) max_content_response_size: usize,
+  pub(crate) max_decompressed_size: usize,
+  pub(crate) max_page_size: usize,
+  pub(crate) openapi: bool,
+  pub(crate) serve_content_type: HashMap<InscriptionId, HeaderValue>,
+  pub(crate) static_dir: Option<PathBuf>,
+  pub(crate) thumbnail_cache: Mutex<Lru<(InscriptionId, u32), Vec<u8>>>,
 }
 
 impl ServerConfig {
+  pub(super) fn page_size(
+    &self,
+    limit: Option<usize>,
+    default: usize,
+    page_index: usize,
+  ) -> ServerResult<usize> {
+    let page_size = limit.unwrap_or(default).min(self.max_page_size).max(1);
+
+    if page_index.checked_mul(page_size).is_none() {
+      return Err(ServerError::BadRequest(format!(
+        "page {page_index} is out of range"
+      )));
+    }
+
+    Ok(page_size)
+  }
+
   pub(super) fn preview_content_security_policy(
     &self,
     media: Media,
@@ -0,0 +1,61 @@
+use {
+  super::*,
+  hyper::server::accept::Accept,
+  std::{
+    os::unix::fs::FileTypeExt,
+    pin::Pin,
+    task::{self, Poll},
+  },
+  tokio::net::{UnixListener, UnixStream},
+};
+
+pub(crate) struct UnixSocket {
+  listener: UnixListener,
+  path: PathBuf,
+}
+
+impl UnixSocket {
+  pub(crate) fn bind(path: PathBuf) -> Result<Self> {
+    if fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+      fs::remove_file(&path)
+        .with_context(|| format!("failed to remove stale socket `{}`", path.display()))?;
+    }
+
+    let listener = UnixListener::bind(&path)
+      .with_context(|| format!("failed to listen on unix socket `{}`", path.display()))?;
+
+    Ok(Self { listener, path })
+  }
+
+  pub(crate) async fn serve(self, router: Router) -> io::Result<()> {
+    let path = self.path.clone();
+
+    let result = axum::Server::builder(self)
+      .serve(router.into_make_service())
+      .with_graceful_shutdown(async {
+        while !SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+          tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+      })
+      .await;
+
+    fs::remove_file(path).ok();
+
+    result.map_err(io::Error::other)
+  }
+}
+
+impl Accept for UnixSocket {
+  type Conn = UnixStream;
+  type Error = io::Error;
+
+  fn poll_accept(
+    self: Pin<&mut Self>,
+    cx: &mut task::Context,
+  ) -> Poll<Option<io::Result<Self::Conn>>> {
+    self
+      .listener
+      .poll_accept(cx)
+      .map(|result| Some(result.map(|(stream, _address)| stream)))
+  }
+}
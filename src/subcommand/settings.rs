@@ -1,5 +1,5 @@
 use super::*;
 
 pub(crate) fn run(settings: Settings) -> SubcommandResult {
-  Ok(Some(Box::new(settings)))
+  Ok(Some(Box::new(settings.redact())))
 }
@@ -2,8 +2,16 @@ use super::*;
 
 #[derive(Debug, Parser)]
 pub(crate) struct Teleburn {
-  #[arg(help = "Generate teleburn addresses for inscription <DESTINATION>.")]
-  destination: InscriptionId,
+  #[arg(
+    long,
+    help = "Output a map from inscription id to teleburn addresses, even for a single destination. Required for more than one destination."
+  )]
+  batch: bool,
+  #[arg(
+    required = true,
+    help = "Generate teleburn addresses for inscription <DESTINATIONS>."
+  )]
+  destinations: Vec<InscriptionId>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -13,8 +21,29 @@ pub struct Output {
 
 impl Teleburn {
   pub(crate) fn run(self) -> SubcommandResult {
-    Ok(Some(Box::new(Output {
-      ethereum: self.destination.into(),
-    })))
+    if !self.batch {
+      let [destination] = self.destinations.as_slice() else {
+        bail!("teleburning more than one destination requires `--batch`");
+      };
+
+      return Ok(Some(Box::new(Output {
+        ethereum: (*destination).into(),
+      })));
+    }
+
+    Ok(Some(Box::new(
+      self
+        .destinations
+        .iter()
+        .map(|destination| {
+          (
+            *destination,
+            Output {
+              ethereum: (*destination).into(),
+            },
+          )
+        })
+        .collect::<BTreeMap<InscriptionId, Output>>(),
+    )))
   }
 }
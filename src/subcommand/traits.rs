@@ -2,7 +2,9 @@ use super::*;
 
 #[derive(Debug, Parser)]
 pub(crate) struct Traits {
-  #[arg(help = "Show traits for <SAT>.")]
+  #[arg(
+    help = "Show traits for <SAT>, given as an integer, decimal, degree, name, or percentile."
+  )]
   sat: Sat,
 }
 
@@ -12,6 +14,7 @@ pub struct Output {
   pub decimal: String,
   pub degree: String,
   pub name: String,
+  pub percentile: String,
   pub height: u32,
   pub cycle: u32,
   pub epoch: u32,
@@ -27,6 +30,7 @@ impl Traits {
       decimal: self.sat.decimal().to_string(),
       degree: self.sat.degree().to_string(),
       name: self.sat.name(),
+      percentile: self.sat.percentile(),
       height: self.sat.height().0,
       cycle: self.sat.cycle(),
       epoch: self.sat.epoch().0,
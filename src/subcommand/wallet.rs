@@ -7,12 +7,13 @@ use {
 
 pub mod balance;
 mod batch_command;
+pub mod broadcast;
 pub mod cardinals;
 pub mod create;
 pub mod dump;
 pub mod inscribe;
 pub mod inscriptions;
-mod label;
+pub mod label;
 pub mod mint;
 pub mod outputs;
 pub mod receive;
@@ -21,6 +22,8 @@ pub mod resume;
 pub mod sats;
 pub mod send;
 mod shared_args;
+pub mod sign;
+pub mod split;
 pub mod transactions;
 
 #[derive(Debug, Parser)]
@@ -45,6 +48,8 @@ pub(crate) enum Subcommand {
   Balance,
   #[command(about = "Create inscriptions and runes")]
   Batch(batch_command::Batch),
+  #[command(about = "Broadcast a signed transaction or PSBT")]
+  Broadcast(broadcast::Broadcast),
   #[command(about = "List unspent cardinal outputs in wallet")]
   Cardinals,
   #[command(about = "Create new wallet")]
@@ -55,8 +60,8 @@ pub(crate) enum Subcommand {
   Inscribe(inscribe::Inscribe),
   #[command(about = "List wallet inscriptions")]
   Inscriptions,
-  #[command(about = "Export output labels")]
-  Label,
+  #[command(about = "Export output labels, or manage local labels")]
+  Label(label::Label),
   #[command(about = "Mint a rune")]
   Mint(mint::Mint),
   #[command(about = "List all unspent outputs in wallet")]
@@ -71,6 +76,10 @@ pub(crate) enum Subcommand {
   Sats(sats::Sats),
   #[command(about = "Send sat or inscription")]
   Send(send::Send),
+  #[command(about = "Sign a PSBT with wallet keys")]
+  Sign(sign::Sign),
+  #[command(about = "Split inscriptions and runes onto individual outputs")]
+  Split(split::Split),
   #[command(about = "See wallet transactions")]
   Transactions(transactions::Transactions),
 }
@@ -100,18 +109,21 @@ impl WalletCommand {
     match self.subcommand {
       Subcommand::Balance => balance::run(wallet),
       Subcommand::Batch(batch) => batch.run(wallet),
+      Subcommand::Broadcast(broadcast) => broadcast.run(wallet),
       Subcommand::Cardinals => cardinals::run(wallet),
       Subcommand::Create(_) | Subcommand::Restore(_) => unreachable!(),
       Subcommand::Dump => dump::run(wallet),
       Subcommand::Inscribe(inscribe) => inscribe.run(wallet),
       Subcommand::Inscriptions => inscriptions::run(wallet),
-      Subcommand::Label => label::run(wallet),
+      Subcommand::Label(label) => label.run(wallet),
       Subcommand::Mint(mint) => mint.run(wallet),
       Subcommand::Outputs => outputs::run(wallet),
       Subcommand::Receive(receive) => receive.run(wallet),
       Subcommand::Resume(resume) => resume.run(wallet),
       Subcommand::Sats(sats) => sats.run(wallet),
       Subcommand::Send(send) => send.run(wallet),
+      Subcommand::Sign(sign) => sign.run(wallet),
+      Subcommand::Split(split) => split.run(wallet),
       Subcommand::Transactions(transactions) => transactions.run(wallet),
     }
   }
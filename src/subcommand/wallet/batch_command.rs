@@ -4,6 +4,11 @@ use super::*;
 pub(crate) struct Batch {
   #[command(flatten)]
   shared: SharedArgs,
+  #[arg(
+    long,
+    help = "Allow `satpoint` entries in batch files to have a nonzero offset."
+  )]
+  pub(crate) allow_offset: bool,
   #[arg(
     long,
     help = "Inscribe multiple inscriptions and rune defined in YAML <BATCH_FILE>."
@@ -15,7 +20,7 @@ impl Batch {
   pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
     let utxos = wallet.utxos();
 
-    let batchfile = batch::File::load(&self.batch)?;
+    let batchfile = batch::File::load(&self.batch, self.allow_offset)?;
 
     let parent_info = wallet.get_parent_info(batchfile.parent)?;
 
@@ -23,9 +28,15 @@ impl Batch {
       &wallet,
       utxos,
       parent_info.as_ref().map(|info| info.tx_out.value),
-      self.shared.compress,
+      self.shared.compression()?,
     )?;
 
+    for (entry, inscription) in batchfile.inscriptions.iter().zip(&inscriptions) {
+      if let Some(file) = &entry.file {
+        self.shared.check_content_type(file, inscription)?;
+      }
+    }
+
     let mut locked_utxos = wallet.locked_utxos().clone();
 
     locked_utxos.extend(
@@ -40,15 +51,19 @@ impl Batch {
 
     batch::Plan {
       commit_fee_rate: self.shared.commit_fee_rate.unwrap_or(self.shared.fee_rate),
+      compute_ids: self.shared.compute_ids,
       destinations,
       dry_run: self.shared.dry_run,
       etching: batchfile.etching,
       inscriptions,
+      min_confirmations: self.shared.min_confirmations,
       mode: batchfile.mode,
       no_backup: self.shared.no_backup,
       no_limit: self.shared.no_limit,
       parent_info,
+      postage_floor: self.shared.postage_floor,
       postages,
+      psbt: self.shared.psbt,
       reinscribe: batchfile.reinscribe,
       reveal_fee_rate: self.shared.fee_rate,
       reveal_satpoints,
@@ -227,7 +242,7 @@ inscriptions:
     metadata.insert(Value::String("description".to_string()), Value::String("Lorem ipsum dolor sit amet, consectetur adipiscing elit. In tristique, massa nec condimentum venenatis, ante massa tempor velit, et accumsan ipsum ligula a massa. Nunc quis orci ante.".to_string()));
 
     assert_eq!(
-      batch::File::load(&batch_path).unwrap(),
+      batch::File::load(&batch_path, false).unwrap(),
       batch::File {
         inscriptions: vec![
           batch::Entry {
@@ -257,7 +272,7 @@ inscriptions:
     )
     .unwrap();
 
-    assert!(batch::File::load(&batch_path)
+    assert!(batch::File::load(&batch_path, false)
       .unwrap_err()
       .to_string()
       .contains("unknown field `unknown`"));
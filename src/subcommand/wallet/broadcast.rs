@@ -0,0 +1,37 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub txid: Txid,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct Broadcast {
+  #[arg(
+    help = "Broadcast <TRANSACTION>, either a hex-encoded raw transaction or a base64-encoded signed PSBT."
+  )]
+  transaction: String,
+}
+
+impl Broadcast {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let raw_transaction = match hex::decode(&self.transaction) {
+      Ok(raw_transaction) => {
+        consensus::encode::deserialize::<Transaction>(&raw_transaction)
+          .context("invalid raw transaction")?;
+        raw_transaction
+      }
+      Err(_) => wallet
+        .bitcoin_client()
+        .finalize_psbt(&self.transaction, None)?
+        .hex
+        .ok_or_else(|| anyhow!("PSBT is not fully signed"))?,
+    };
+
+    Ok(Some(Box::new(Output {
+      txid: wallet
+        .bitcoin_client()
+        .send_raw_transaction(&raw_transaction)?,
+    })))
+  }
+}
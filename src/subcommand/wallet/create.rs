@@ -26,7 +26,7 @@ impl Create {
 
     let mnemonic = Mnemonic::from_entropy(&entropy)?;
 
-    Wallet::initialize(name, settings, mnemonic.to_seed(&self.passphrase))?;
+    Wallet::initialize(name, settings, mnemonic.to_seed(&self.passphrase), None)?;
 
     Ok(Some(Box::new(Output {
       mnemonic,
@@ -59,30 +59,40 @@ impl Inscribe {
       }
     }
 
+    let inscription = Inscription::new(
+      chain,
+      self.shared.compression()?,
+      self.delegate,
+      Inscribe::parse_metadata(self.cbor_metadata, self.json_metadata)?,
+      self.metaprotocol,
+      self.parent.into_iter().collect(),
+      self.file.clone(),
+      None,
+      None,
+    )?;
+
+    if let Some(file) = &self.file {
+      self.shared.check_content_type(file, &inscription)?;
+    }
+
     batch::Plan {
       commit_fee_rate: self.shared.commit_fee_rate.unwrap_or(self.shared.fee_rate),
+      compute_ids: self.shared.compute_ids,
       destinations: vec![match self.destination.clone() {
         Some(destination) => destination.require_network(chain.network())?,
         None => wallet.get_change_address()?,
       }],
       dry_run: self.shared.dry_run,
       etching: None,
-      inscriptions: vec![Inscription::new(
-        chain,
-        self.shared.compress,
-        self.delegate,
-        Inscribe::parse_metadata(self.cbor_metadata, self.json_metadata)?,
-        self.metaprotocol,
-        self.parent.into_iter().collect(),
-        self.file,
-        None,
-        None,
-      )?],
+      inscriptions: vec![inscription],
+      min_confirmations: self.shared.min_confirmations,
       mode: batch::Mode::SeparateOutputs,
       no_backup: self.shared.no_backup,
       no_limit: self.shared.no_limit,
       parent_info: wallet.get_parent_info(self.parent)?,
+      postage_floor: self.shared.postage_floor,
       postages: vec![self.postage.unwrap_or(TARGET_POSTAGE)],
+      psbt: self.shared.psbt,
       reinscribe: self.reinscribe,
       reveal_fee_rate: self.shared.fee_rate,
       reveal_satpoints: Vec::new(),
@@ -165,6 +175,26 @@ mod tests {
     );
   }
 
+  #[test]
+  fn compute_ids_and_dry_run_flags_conflict() {
+    assert_regex_match!(
+      Arguments::try_parse_from([
+        "ord",
+        "wallet",
+        "inscribe",
+        "--compute-ids",
+        "--dry-run",
+        "--file",
+        "baz",
+        "--fee-rate",
+        "1",
+      ])
+      .unwrap_err()
+      .to_string(),
+      ".*--compute-ids.*cannot be used with.*--dry-run.*"
+    );
+  }
+
   #[test]
   fn delegate_or_file_must_be_set() {
     assert_regex_match!(
@@ -1,7 +1,71 @@
 use super::*;
 
+#[derive(Debug, Parser)]
+pub(crate) struct Label {
+  #[command(subcommand)]
+  subcommand: Option<Subcommand>,
+}
+
+#[derive(Debug, Parser)]
+enum Subcommand {
+  #[command(about = "Get label of an output or inscription")]
+  Get {
+    #[arg(help = "Get label of <REFERENCE>, an outpoint or inscription id.")]
+    reference: Reference,
+  },
+  #[command(about = "List all labels")]
+  List,
+  #[command(about = "Remove label from an output or inscription")]
+  Remove {
+    #[arg(help = "Remove label from <REFERENCE>, an outpoint or inscription id.")]
+    reference: Reference,
+  },
+  #[command(about = "Label an output or inscription")]
+  Set {
+    #[arg(help = "Label <REFERENCE>, an outpoint or inscription id.")]
+    reference: Reference,
+    #[arg(help = "Set label to <LABEL>.")]
+    label: String,
+  },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Reference {
+  Inscription(InscriptionId),
+  Output(OutPoint),
+}
+
+impl Display for Reference {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Inscription(inscription_id) => write!(f, "{inscription_id}"),
+      Self::Output(outpoint) => write!(f, "{outpoint}"),
+    }
+  }
+}
+
+impl FromStr for Reference {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Ok(inscription_id) = s.parse() {
+      Ok(Self::Inscription(inscription_id))
+    } else if let Ok(outpoint) = s.parse() {
+      Ok(Self::Output(outpoint))
+    } else {
+      bail!("invalid reference `{s}`, expected an outpoint or inscription id")
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub reference: String,
+  pub label: String,
+}
+
 #[derive(Serialize)]
-struct Label {
+struct OutputLabel {
   first_sat: SatLabel,
   inscriptions: BTreeMap<u64, BTreeSet<InscriptionId>>,
 }
@@ -20,7 +84,45 @@ struct Line {
   r#type: String,
 }
 
-pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
+impl Label {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    match self.subcommand {
+      None => export(wallet),
+      Some(Subcommand::Get { reference }) => {
+        let reference = reference.to_string();
+
+        let label = wallet
+          .labels()?
+          .remove(&reference)
+          .ok_or_else(|| anyhow!("no label for `{reference}`"))?;
+
+        Ok(Some(Box::new(Output { reference, label })))
+      }
+      Some(Subcommand::List) => Ok(Some(Box::new(
+        wallet
+          .labels()?
+          .into_iter()
+          .map(|(reference, label)| Output { reference, label })
+          .collect::<Vec<Output>>(),
+      ))),
+      Some(Subcommand::Remove { reference }) => {
+        ensure!(
+          wallet.remove_label(&reference.to_string())?,
+          "no label for `{reference}`"
+        );
+
+        Ok(None)
+      }
+      Some(Subcommand::Set { reference, label }) => {
+        wallet.set_label(&reference.to_string(), &label)?;
+
+        Ok(None)
+      }
+    }
+  }
+}
+
+fn export(wallet: Wallet) -> SubcommandResult {
   let mut lines: Vec<Line> = Vec::new();
 
   let sat_ranges = wallet.get_output_sat_ranges()?;
@@ -49,7 +151,7 @@ pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
     }
 
     lines.push(Line {
-      label: serde_json::to_string(&Label {
+      label: serde_json::to_string(&OutputLabel {
         first_sat: SatLabel {
           name: sat.name(),
           number: sat.n(),
@@ -69,3 +171,30 @@ pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
 
   Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reference_from_str() {
+    assert_eq!(
+      "1111111111111111111111111111111111111111111111111111111111111111i1"
+        .parse::<Reference>()
+        .unwrap(),
+      Reference::Inscription(inscription_id(1)),
+    );
+
+    assert_eq!(
+      "1111111111111111111111111111111111111111111111111111111111111111:1"
+        .parse::<Reference>()
+        .unwrap(),
+      Reference::Output(outpoint(1)),
+    );
+
+    assert_eq!(
+      "foo".parse::<Reference>().unwrap_err().to_string(),
+      "invalid reference `foo`, expected an outpoint or inscription id",
+    );
+  }
+}
@@ -4,14 +4,19 @@ use super::*;
 pub struct Output {
   pub output: OutPoint,
   pub amount: u64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
 }
 
 pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
+  let labels = wallet.labels()?;
+
   let mut outputs = Vec::new();
   for (output, txout) in wallet.utxos() {
     outputs.push(Output {
       output: *output,
       amount: txout.value,
+      label: labels.get(&output.to_string()).cloned(),
     });
   }
 
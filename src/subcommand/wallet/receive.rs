@@ -7,7 +7,7 @@ pub struct Output {
 
 #[derive(Debug, Parser)]
 pub(crate) struct Receive {
-  #[arg(short, long, help = "Generate <NUMBER> addresses.")]
+  #[arg(short, long, alias = "count", help = "Generate <NUMBER> addresses.")]
   number: Option<u64>,
 }
 
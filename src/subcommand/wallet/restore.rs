@@ -1,7 +1,17 @@
-use super::*;
+use {super::*, bitcoin::bip32::DerivationPath};
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub descriptors: Vec<String>,
+}
 
 #[derive(Debug, Parser)]
 pub(crate) struct Restore {
+  #[arg(
+    long,
+    help = "Derive wallet keys at <DERIVATION_PATH> instead of m/86'/0'/0', or m/86'/1'/0' on non-mainnet chains. Must be of the form m/86'/COIN_TYPE'/ACCOUNT', since wallet outputs are always taproot. Prints derived public descriptors."
+  )]
+  pub(crate) derivation_path: Option<DerivationPath>,
   #[clap(value_enum, long, help = "Restore wallet from <SOURCE> on stdin.")]
   from: Source,
   #[arg(long, help = "Use <PASSPHRASE> when deriving wallet")]
@@ -26,6 +36,10 @@ impl Restore {
       name
     );
 
+    if let Some(derivation_path) = &self.derivation_path {
+      Wallet::check_derivation_path(settings.chain(), derivation_path)?;
+    }
+
     let mut buffer = String::new();
 
     match self.from {
@@ -35,20 +49,31 @@ impl Restore {
           self.passphrase.is_none(),
           "descriptor does not take a passphrase"
         );
+        ensure!(
+          self.derivation_path.is_none(),
+          "descriptor does not take a derivation path"
+        );
         let wallet_descriptors: ListDescriptorsResult = serde_json::from_str(&buffer)?;
         Wallet::initialize_from_descriptors(name, settings, wallet_descriptors.descriptors)?;
+        Ok(None)
       }
       Source::Mnemonic => {
         io::stdin().read_line(&mut buffer)?;
         let mnemonic = Mnemonic::from_str(&buffer)?;
-        Wallet::initialize(
+        let print_descriptors = self.derivation_path.is_some();
+        let descriptors = Wallet::initialize(
           name,
           settings,
           mnemonic.to_seed(self.passphrase.unwrap_or_default()),
+          self.derivation_path,
         )?;
+
+        if print_descriptors {
+          Ok(Some(Box::new(Output { descriptors })))
+        } else {
+          Ok(None)
+        }
       }
     }
-
-    Ok(None)
   }
 }
@@ -104,7 +104,7 @@ impl Sats {
     results
   }
 
-  fn needles(tsv: &str) -> Result<Vec<(Sat, &str)>> {
+  pub(crate) fn needles(tsv: &str) -> Result<Vec<(Sat, &str)>> {
     let mut needles = tsv
       .lines()
       .enumerate()
@@ -133,13 +133,12 @@ impl Sats {
         let mut offset = 0;
         sat_ranges.into_iter().filter_map(move |(start, end)| {
           let sat = Sat(start);
-          let rarity = sat.rarity();
           let start_offset = offset;
           offset += end - start;
-          if rarity > Rarity::Common {
-            Some((outpoint, sat, start_offset, rarity))
-          } else {
+          if sat.common() {
             None
+          } else {
+            Some((outpoint, sat, start_offset, sat.rarity()))
           }
         })
       })
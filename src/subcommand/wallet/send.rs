@@ -1,11 +1,26 @@
-use {super::*, crate::outgoing::Outgoing, base64::Engine, bitcoin::psbt::Psbt};
+use {super::*, crate::outgoing::Outgoing};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Send {
   #[arg(long, help = "Don't sign or broadcast transaction")]
   pub(crate) dry_run: bool,
-  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB")]
-  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> sats/vB. [default: Bitcoin Core's `estimatesmartfee` estimate]"
+  )]
+  fee_rate: Option<FeeRate>,
+  #[arg(
+    long,
+    default_value = "1000",
+    help = "Refuse to send with a fee rate above <MAX_FEE_RATE> sats/vB"
+  )]
+  max_fee_rate: FeeRate,
+  #[arg(
+    long,
+    default_value = "0",
+    help = "Only fund transaction with cardinal outputs that have at least <MIN_CONFIRMATIONS> confirmations"
+  )]
+  pub(crate) min_confirmations: u32,
   #[arg(
     long,
     help = "Target <AMOUNT> postage with sent inscriptions. [default: 10000 sat]"
@@ -21,6 +36,7 @@ pub struct Output {
   pub psbt: String,
   pub outgoing: Outgoing,
   pub fee: u64,
+  pub fee_rate: f64,
 }
 
 impl Send {
@@ -30,17 +46,34 @@ impl Send {
       .clone()
       .require_network(wallet.chain().network())?;
 
+    let fee_rate = match self.fee_rate {
+      Some(fee_rate) => fee_rate,
+      None => Self::estimate_fee_rate(&wallet)?,
+    };
+
+    ensure!(
+      fee_rate.n() <= self.max_fee_rate.n(),
+      "fee rate of {} sats/vB exceeds maximum of {} sats/vB, raise it with `--max-fee-rate`",
+      fee_rate.n(),
+      self.max_fee_rate.n(),
+    );
+
     let unsigned_transaction = match self.outgoing {
-      Outgoing::Amount(amount) => {
-        Self::create_unsigned_send_amount_transaction(&wallet, address, amount, self.fee_rate)?
-      }
+      Outgoing::Amount(amount) => Self::create_unsigned_send_amount_transaction(
+        &wallet,
+        address,
+        amount,
+        fee_rate,
+        self.min_confirmations,
+      ),
       Outgoing::Rune { decimal, rune } => Self::create_unsigned_send_runes_transaction(
         &wallet,
         address,
         rune,
         decimal,
-        self.fee_rate,
-      )?,
+        fee_rate,
+        self.min_confirmations,
+      ),
       Outgoing::InscriptionId(id) => Self::create_unsigned_send_satpoint_transaction(
         &wallet,
         address,
@@ -50,91 +83,74 @@ impl Send {
           .ok_or_else(|| anyhow!("inscription {id} not found"))?
           .satpoint,
         self.postage,
-        self.fee_rate,
+        fee_rate,
+        self.min_confirmations,
         true,
-      )?,
+      ),
       Outgoing::SatPoint(satpoint) => Self::create_unsigned_send_satpoint_transaction(
         &wallet,
         address,
         satpoint,
         self.postage,
-        self.fee_rate,
+        fee_rate,
+        self.min_confirmations,
         false,
-      )?,
+      ),
       Outgoing::Sat(sat) => Self::create_unsigned_send_satpoint_transaction(
         &wallet,
         address,
         wallet.find_sat_in_outputs(sat)?,
         self.postage,
-        self.fee_rate,
+        fee_rate,
+        self.min_confirmations,
         true,
-      )?,
-    };
-
-    let unspent_outputs = wallet.utxos();
-
-    let (txid, psbt) = if self.dry_run {
-      let psbt = wallet
-        .bitcoin_client()
-        .wallet_process_psbt(
-          &base64::engine::general_purpose::STANDARD
-            .encode(Psbt::from_unsigned_tx(unsigned_transaction.clone())?.serialize()),
-          Some(false),
-          None,
-          None,
-        )?
-        .psbt;
-
-      (unsigned_transaction.txid(), psbt)
-    } else {
-      let psbt = wallet
-        .bitcoin_client()
-        .wallet_process_psbt(
-          &base64::engine::general_purpose::STANDARD
-            .encode(Psbt::from_unsigned_tx(unsigned_transaction.clone())?.serialize()),
-          Some(true),
-          None,
-          None,
-        )?
-        .psbt;
-
-      let signed_tx = wallet
-        .bitcoin_client()
-        .finalize_psbt(&psbt, None)?
-        .hex
-        .ok_or_else(|| anyhow!("unable to sign transaction"))?;
-
-      (
-        wallet.bitcoin_client().send_raw_transaction(&signed_tx)?,
-        psbt,
-      )
-    };
-
-    let mut fee = 0;
-    for txin in unsigned_transaction.input.iter() {
-      let Some(txout) = unspent_outputs.get(&txin.previous_output) else {
-        panic!("input {} not found in utxos", txin.previous_output);
-      };
-      fee += txout.value;
+      ),
     }
+    .map_err(|err| {
+      if self.min_confirmations > 0 {
+        err.context(format!(
+          "failed to fund transaction from outputs with at least {} confirmations",
+          self.min_confirmations
+        ))
+      } else {
+        err
+      }
+    })?;
 
-    for txout in unsigned_transaction.output.iter() {
-      fee = fee.checked_sub(txout.value).unwrap();
-    }
+    let (txid, psbt, fee) =
+      wallet.sign_and_broadcast_transaction(unsigned_transaction, self.dry_run)?;
 
     Ok(Some(Box::new(Output {
       txid,
       psbt,
       outgoing: self.outgoing,
       fee,
+      fee_rate: fee_rate.n(),
     })))
   }
 
+  fn estimate_fee_rate(wallet: &Wallet) -> Result<FeeRate> {
+    let estimate = wallet.bitcoin_client().estimate_smart_fee(6, None)?;
+
+    let Some(fee_rate) = estimate.fee_rate else {
+      bail!(
+        "failed to estimate fee rate{}, specify one with `--fee-rate`",
+        estimate
+          .errors
+          .map(|errors| format!(": {}", errors.join(", ")))
+          .unwrap_or_default()
+      );
+    };
+
+    FeeRate::try_from(fee_rate.to_sat() as f64 / 1000.0)
+  }
+
   fn create_unsigned_send_amount_transaction(
     wallet: &Wallet,
     destination: Address,
     amount: Amount,
     fee_rate: FeeRate,
+    min_confirmations: u32,
   ) -> Result<Transaction> {
     wallet.lock_non_cardinal_outputs()?;
 
@@ -148,10 +164,11 @@ impl Send {
       }],
     };
 
-    let unsigned_transaction = consensus::encode::deserialize(&fund_raw_transaction(
-      wallet.bitcoin_client(),
+    let unsigned_transaction = consensus::encode::deserialize(&Self::fund_raw_transaction(
+      wallet,
       fee_rate,
       &unfunded_transaction,
+      min_confirmations,
     )?)?;
 
     Ok(unsigned_transaction)
@@ -163,6 +180,7 @@ impl Send {
     satpoint: SatPoint,
     postage: Option<Amount>,
     fee_rate: FeeRate,
+    min_confirmations: u32,
     sending_inscription: bool,
   ) -> Result<Transaction> {
     if !sending_inscription {
@@ -188,11 +206,17 @@ impl Send {
       Target::Postage
     };
 
+    let mut utxos = wallet.exclude_unconfirmed_utxos(wallet.utxos(), min_confirmations)?;
+
+    if let Some(tx_out) = wallet.utxos().get(&satpoint.outpoint) {
+      utxos.insert(satpoint.outpoint, tx_out.clone());
+    }
+
     Ok(
       TransactionBuilder::new(
         satpoint,
         wallet.inscriptions().clone(),
-        wallet.utxos().clone(),
+        utxos,
         wallet.locked_utxos().clone().into_keys().collect(),
         runic_outputs,
         destination.clone(),
@@ -210,6 +234,7 @@ impl Send {
     spaced_rune: SpacedRune,
     decimal: Decimal,
     fee_rate: FeeRate,
+    min_confirmations: u32,
   ) -> Result<Transaction> {
     ensure!(
       wallet.has_rune_index(),
@@ -218,7 +243,6 @@ impl Send {
 
     let inscriptions = wallet.inscriptions();
     let runic_outputs = wallet.get_runic_outputs()?;
-    let bitcoin_client = wallet.bitcoin_client();
 
     wallet.lock_non_cardinal_outputs()?;
 
@@ -302,7 +326,7 @@ impl Send {
     };
 
     let unsigned_transaction =
-      fund_raw_transaction(bitcoin_client, fee_rate, &unfunded_transaction)?;
+      Self::fund_raw_transaction(wallet, fee_rate, &unfunded_transaction, min_confirmations)?;
 
     let unsigned_transaction = consensus::encode::deserialize(&unsigned_transaction)?;
 
@@ -313,4 +337,35 @@ impl Send {
 
     Ok(unsigned_transaction)
   }
+
+  fn fund_raw_transaction(
+    wallet: &Wallet,
+    fee_rate: FeeRate,
+    unfunded_transaction: &Transaction,
+    min_confirmations: u32,
+  ) -> Result<Vec<u8>> {
+    let locked = Wallet::get_locked_outpoints(wallet.bitcoin_client())?;
+
+    let unconfirmed = wallet
+      .unconfirmed_utxos(min_confirmations)?
+      .into_iter()
+      .filter(|outpoint| !locked.contains(outpoint))
+      .collect::<Vec<OutPoint>>();
+
+    if unconfirmed.is_empty() {
+      return fund_raw_transaction(wallet.bitcoin_client(), fee_rate, unfunded_transaction);
+    }
+
+    if !wallet.bitcoin_client().lock_unspent(&unconfirmed)? {
+      bail!("failed to lock UTXOs");
+    }
+
+    let funded = fund_raw_transaction(wallet.bitcoin_client(), fee_rate, unfunded_transaction);
+
+    if !wallet.bitcoin_client().unlock_unspent(&unconfirmed)? {
+      bail!("failed to unlock UTXOs");
+    }
+
+    funded
+  }
 }
@@ -2,6 +2,12 @@ use super::*;
 
 #[derive(Debug, Parser)]
 pub(super) struct SharedArgs {
+  #[arg(
+    long,
+    value_name = "CONTENT_TYPE",
+    help = "Only inscribe files with content type <CONTENT_TYPE>. May be given multiple times. Content types match with or without parameters, so `text/plain` allows `text/plain;charset=utf-8`."
+  )]
+  pub(crate) allow_content_type: Vec<String>,
   #[arg(
     long,
     help = "Use <COMMIT_FEE_RATE> sats/vbyte for commit transaction.\nDefaults to <FEE_RATE> if unset."
@@ -9,10 +15,40 @@ pub(super) struct SharedArgs {
   pub(crate) commit_fee_rate: Option<FeeRate>,
   #[arg(long, help = "Compress inscription content with brotli.")]
   pub(crate) compress: bool,
+  #[arg(
+    long,
+    requires = "compress",
+    help = "Compress with brotli quality <COMPRESS_QUALITY>, from 0 to 11. Lower is faster but compresses less. [default: 11]"
+  )]
+  pub(crate) compress_quality: Option<i32>,
+  #[arg(
+    long,
+    requires = "compress",
+    help = "Compress with a brotli window of 2^<COMPRESS_WINDOW> bytes, from 10 to 24. [default: 24]"
+  )]
+  pub(crate) compress_window: Option<i32>,
+  #[arg(
+    long,
+    conflicts_with_all = &["dry_run", "psbt"],
+    help = "Sign but don't broadcast transactions. Output the signed commit and reveal transactions and the inscription ids and locations they produce. Broadcast them with `ord wallet broadcast`."
+  )]
+  pub(crate) compute_ids: bool,
   #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
   pub(crate) fee_rate: FeeRate,
   #[arg(long, help = "Don't sign or broadcast transactions.")]
   pub(crate) dry_run: bool,
+  #[arg(
+    long,
+    conflicts_with = "dry_run",
+    help = "Don't sign or broadcast transactions. Output a commit PSBT to sign offline, and a reveal PSBT whose commit input is already signed. Broadcast them with `ord wallet broadcast`."
+  )]
+  pub(crate) psbt: bool,
+  #[arg(
+    long,
+    default_value = "0",
+    help = "Only fund transactions with wallet outputs that have at least <MIN_CONFIRMATIONS> confirmations."
+  )]
+  pub(crate) min_confirmations: u32,
   #[arg(long, alias = "nobackup", help = "Do not back up recovery key.")]
   pub(crate) no_backup: bool,
   #[arg(
@@ -21,4 +57,63 @@ pub(super) struct SharedArgs {
     help = "Do not check that transactions are equal to or below the MAX_STANDARD_TX_WEIGHT of 400,000 weight units. Transactions over this limit are currently nonstandard and will not be relayed by bitcoind in its default configuration. Do not use this flag unless you understand the implications."
   )]
   pub(crate) no_limit: bool,
+  #[arg(
+    long,
+    help = "Require each reveal output to carry at least <POSTAGE_FLOOR> postage. Postage is always required to be at or above the dust limit of the destination's script type."
+  )]
+  pub(crate) postage_floor: Option<Amount>,
+}
+
+impl SharedArgs {
+  pub(crate) fn compression(&self) -> Result<Option<Compression>> {
+    if !self.compress {
+      return Ok(None);
+    }
+
+    let default = Compression::default();
+
+    let compression = Compression {
+      quality: self.compress_quality.unwrap_or(default.quality),
+      window: self.compress_window.unwrap_or(default.window),
+    };
+
+    ensure!(
+      Compression::QUALITY.contains(&compression.quality),
+      "--compress-quality must be between {} and {}",
+      Compression::QUALITY.start(),
+      Compression::QUALITY.end(),
+    );
+
+    ensure!(
+      Compression::WINDOW.contains(&compression.window),
+      "--compress-window must be between {} and {}",
+      Compression::WINDOW.start(),
+      Compression::WINDOW.end(),
+    );
+
+    Ok(Some(compression))
+  }
+
+  pub(crate) fn check_content_type(&self, path: &Path, inscription: &Inscription) -> Result {
+    if self.allow_content_type.is_empty() {
+      return Ok(());
+    }
+
+    let content_type = inscription.content_type().unwrap_or_default();
+
+    let normalized = Media::normalize_content_type(content_type);
+
+    let essence = normalized.split(';').next().unwrap_or_default();
+
+    ensure!(
+      self.allow_content_type.iter().any(|allowed| {
+        let allowed = Media::normalize_content_type(allowed);
+        allowed == normalized || allowed == essence
+      }),
+      "file `{}` has content type `{content_type}`, which is not allowed by `--allow-content-type`",
+      path.display(),
+    );
+
+    Ok(())
+  }
 }
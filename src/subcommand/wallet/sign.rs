@@ -0,0 +1,26 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub complete: bool,
+  pub psbt: String,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct Sign {
+  #[arg(help = "Sign wallet inputs of base64-encoded <PSBT>.")]
+  psbt: String,
+}
+
+impl Sign {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let result = wallet
+      .bitcoin_client()
+      .wallet_process_psbt(&self.psbt, Some(true), None, None)?;
+
+    Ok(Some(Box::new(Output {
+      complete: result.complete,
+      psbt: result.psbt,
+    })))
+  }
+}
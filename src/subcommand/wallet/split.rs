@@ -0,0 +1,169 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Split {
+  #[arg(long, help = "Don't sign or broadcast transaction")]
+  dry_run: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Include <AMOUNT> postage with each split output. [default: 10000 sat]"
+  )]
+  postage: Option<Amount>,
+  #[arg(help = "Split inscriptions and runes in <OUTPOINT> onto individual outputs.")]
+  outpoint: OutPoint,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Output {
+  pub txid: Txid,
+  pub psbt: String,
+  pub fee: u64,
+}
+
+impl Split {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let unsigned_transaction = self.create_unsigned_split_transaction(&wallet)?;
+
+    let (txid, psbt, fee) =
+      wallet.sign_and_broadcast_transaction(unsigned_transaction, self.dry_run)?;
+
+    Ok(Some(Box::new(Output { txid, psbt, fee })))
+  }
+
+  fn create_unsigned_split_transaction(&self, wallet: &Wallet) -> Result<Transaction> {
+    ensure!(
+      wallet.utxos().contains_key(&self.outpoint),
+      "output {} not found in wallet",
+      self.outpoint
+    );
+
+    let postage = self.postage.unwrap_or(TARGET_POSTAGE).to_sat();
+
+    let mut offsets = wallet
+      .inscriptions()
+      .iter()
+      .filter(|(satpoint, _)| satpoint.outpoint == self.outpoint)
+      .flat_map(|(satpoint, ids)| ids.iter().map(|_| satpoint.offset))
+      .collect::<Vec<u64>>();
+
+    offsets.sort();
+    offsets.dedup();
+
+    let runes = wallet.get_runes_balances_for_output(&self.outpoint)?;
+
+    ensure!(
+      !offsets.is_empty() || !runes.is_empty(),
+      "output {} contains no inscriptions or runes to split",
+      self.outpoint
+    );
+
+    let mut output = Vec::new();
+    let mut cursor = 0;
+
+    for (i, offset) in offsets.iter().enumerate() {
+      let script_pubkey = wallet.get_change_address()?.script_pubkey();
+      let dust = script_pubkey.dust_value().to_sat();
+
+      if offset - cursor >= dust {
+        output.push(TxOut {
+          script_pubkey: wallet.get_change_address()?.script_pubkey(),
+          value: offset - cursor,
+        });
+        cursor = *offset;
+      }
+
+      let mut end = offset + postage;
+
+      if let Some(next) = offsets.get(i + 1) {
+        if *next < end + dust {
+          end = *next;
+        }
+      }
+
+      ensure!(
+        end - cursor >= dust,
+        "inscriptions in output {} are too close together to split",
+        self.outpoint,
+      );
+
+      output.push(TxOut {
+        script_pubkey,
+        value: end - cursor,
+      });
+
+      cursor = end;
+    }
+
+    let mut edicts = Vec::new();
+
+    for (spaced_rune, pile) in &runes {
+      let (id, _, _) = wallet
+        .get_rune(spaced_rune.rune)?
+        .with_context(|| format!("rune `{}` has not been etched", spaced_rune.rune))?;
+
+      edicts.push(Edict {
+        id,
+        amount: pile.amount,
+        output: output.len().try_into().unwrap(),
+      });
+
+      output.push(TxOut {
+        script_pubkey: wallet.get_change_address()?.script_pubkey(),
+        value: postage,
+      });
+    }
+
+    let runestone = (!edicts.is_empty()).then(|| {
+      edicts.sort_by_key(|edict| edict.id);
+
+      Runestone {
+        edicts,
+        ..default()
+      }
+    });
+
+    if let Some(runestone) = &runestone {
+      let script_pubkey = runestone.encipher();
+
+      ensure!(
+        script_pubkey.len() <= 82,
+        "runestone greater than maximum OP_RETURN size: {} > 82",
+        script_pubkey.len()
+      );
+
+      output.push(TxOut {
+        script_pubkey,
+        value: 0,
+      });
+    }
+
+    let unfunded_transaction = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: self.outpoint,
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::MAX,
+        witness: Witness::new(),
+      }],
+      output,
+    };
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let unsigned_transaction: Transaction = consensus::encode::deserialize(&fund_raw_transaction(
+      wallet.bitcoin_client(),
+      self.fee_rate,
+      &unfunded_transaction,
+    )?)?;
+
+    assert_eq!(
+      Runestone::decipher(&unsigned_transaction),
+      runestone.map(Artifact::Runestone),
+    );
+
+    Ok(unsigned_transaction)
+  }
+}
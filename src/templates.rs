@@ -25,8 +25,8 @@ pub(crate) use {
 };
 
 pub use {
-  blocks::BlocksHtml, rune::RuneHtml, runes::RunesHtml, status::StatusHtml,
-  transaction::TransactionHtml,
+  blocks::BlocksHtml, rune::RuneHtml, rune_holders::RuneHoldersHtml, runes::RunesHtml,
+  status::StatusHtml, transaction::TransactionHtml,
 };
 
 pub mod block;
@@ -47,6 +47,7 @@ mod preview;
 mod range;
 mod rare;
 pub mod rune;
+pub mod rune_holders;
 pub mod runes;
 pub mod sat;
 pub mod status;
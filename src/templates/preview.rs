@@ -1,5 +1,9 @@
 use super::*;
 
+mod integrity {
+  include!(concat!(env!("OUT_DIR"), "/integrity.rs"));
+}
+
 #[derive(Boilerplate)]
 pub(crate) struct PreviewAudioHtml {
   pub(crate) inscription_id: InscriptionId,
@@ -49,3 +53,54 @@ pub(crate) struct PreviewUnknownHtml;
 pub(crate) struct PreviewVideoHtml {
   pub(crate) inscription_id: InscriptionId,
 }
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    sha2::{Digest, Sha384},
+  };
+
+  #[test]
+  fn preview_script_integrity_matches_static_files() {
+    let id = inscription_id(1);
+
+    let pages = [
+      PreviewCodeHtml {
+        inscription_id: id,
+        language: media::Language::JavaScript,
+      }
+      .to_string(),
+      PreviewImageHtml {
+        image_rendering: ImageRendering::Auto,
+        inscription_id: id,
+      }
+      .to_string(),
+      PreviewMarkdownHtml { inscription_id: id }.to_string(),
+      PreviewPdfHtml { inscription_id: id }.to_string(),
+      PreviewTextHtml { inscription_id: id }.to_string(),
+    ];
+
+    let script = Regex::new(
+      r#"<script src=/static/(preview-[a-z]+\.js) integrity="(sha384-[A-Za-z0-9+/=]+)" crossorigin=anonymous "#,
+    )
+    .unwrap();
+
+    for page in pages {
+      let captures = script.captures(&page).unwrap();
+
+      assert_eq!(
+        &captures[2],
+        format!(
+          "sha384-{}",
+          STANDARD.encode(Sha384::digest(
+            fs::read(Path::new("static").join(&captures[1])).unwrap()
+          ))
+        ),
+        "integrity of {} does not match the file on disk",
+        &captures[1],
+      );
+    }
+  }
+}
@@ -2,6 +2,8 @@ use super::*;
 
 #[derive(Boilerplate, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RuneHtml {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub amount: Option<u128>,
   pub entry: RuneEntry,
   pub id: RuneId,
   pub mintable: bool,
@@ -22,6 +24,7 @@ mod tests {
   fn display() {
     assert_regex_match!(
       RuneHtml {
+        amount: None,
         entry: RuneEntry {
           block: 1,
           burned: 123456789123456789,
@@ -110,6 +113,7 @@ mod tests {
   fn display_no_mint() {
     assert_regex_match!(
       RuneHtml {
+        amount: None,
         entry: RuneEntry {
           block: 0,
           burned: 123456789123456789,
@@ -144,6 +148,7 @@ mod tests {
   fn display_no_turbo() {
     assert_regex_match!(
       RuneHtml {
+        amount: None,
         entry: RuneEntry {
           block: 0,
           burned: 123456789123456789,
@@ -178,6 +183,7 @@ mod tests {
   fn display_empty_mint() {
     assert_regex_match!(
       RuneHtml {
+        amount: None,
         entry: RuneEntry {
           block: 0,
           burned: 123456789123456789,
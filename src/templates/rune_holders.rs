@@ -0,0 +1,70 @@
+use super::*;
+
+#[derive(Boilerplate, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuneHoldersHtml {
+  pub spaced_rune: SpacedRune,
+  pub holder_count: u64,
+  pub holders: Vec<(OutPoint, Pile)>,
+  pub more: bool,
+  pub prev: Option<usize>,
+  pub next: Option<usize>,
+}
+
+impl PageContent for RuneHoldersHtml {
+  fn title(&self) -> String {
+    format!("Rune {} Holders", self.spaced_rune)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_regex_match!(
+      RuneHoldersHtml {
+        spaced_rune: SpacedRune {
+          rune: Rune(26),
+          spacers: 1,
+        },
+        holder_count: 3,
+        holders: vec![(
+          unbound_outpoint(),
+          Pile {
+            amount: 1000,
+            divisibility: 1,
+            symbol: Some('%'),
+          }
+        )],
+        more: true,
+        prev: Some(0),
+        next: Some(2),
+      },
+      "<h1><a href=/rune/A•A>A•A</a> Holders</h1>
+<dl>
+  <dt>holders</dt>
+  <dd>3</dd>
+</dl>
+<table>
+  <tr>
+    <th>output</th>
+    <th>balance</th>
+  </tr>
+  <tr>
+    <td class=monospace>
+      <a href=/output/0{64}:0>0{64}:0</a>
+    </td>
+    <td class=monospace>
+      100\u{A0}%
+    </td>
+  </tr>
+</table>
+<div class=center>
+  <a class=prev href=/rune/A•A/holders/0>prev</a>
+  <a class=next href=/rune/A•A/holders/2>next</a>
+</div>
+"
+    );
+  }
+}
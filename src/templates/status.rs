@@ -6,18 +6,25 @@ pub struct StatusHtml {
   pub chain: Chain,
   pub content_type_counts: Vec<(Option<Vec<u8>>, u64)>,
   pub cursed_inscriptions: u64,
+  pub first_inscription_height: u32,
   pub height: Option<u32>,
   pub initial_sync_time: Duration,
   pub inscriptions: u64,
   pub lost_sats: u64,
   pub minimum_rune_for_next_block: Rune,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub reorgs: Vec<api::Reorg>,
+  pub rpc_latency: Option<Duration>,
   pub rune_index: bool,
   pub runes: u64,
   pub sat_index: bool,
   pub started: DateTime<Utc>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub supply: Option<api::Supply>,
   pub transaction_index: bool,
   pub unrecoverably_reorged: bool,
   pub uptime: Duration,
+  pub version: String,
 }
 
 impl PageContent for StatusHtml {
@@ -14,7 +14,10 @@ use {
   indicatif::{ProgressBar, ProgressStyle},
   log::log_enabled,
   miniscript::descriptor::{DescriptorSecretKey, DescriptorXKey, Wildcard},
-  redb::{Database, DatabaseError, ReadableTable, RepairSession, StorageError, TableDefinition},
+  redb::{
+    Database, DatabaseError, ReadableTable, RepairSession, StorageError, TableDefinition,
+    TableError,
+  },
   reqwest::header,
   std::sync::Once,
   transaction_builder::TransactionBuilder,
@@ -27,6 +30,7 @@ pub mod wallet_constructor;
 
 const SCHEMA_VERSION: u64 = 1;
 
+define_table! { LABELS, &str, &str }
 define_table! { RUNE_TO_ETCHING, u128, EtchingEntryValue }
 define_table! { STATISTICS, u64, u64 }
 
@@ -63,6 +67,10 @@ pub(crate) struct Wallet {
 }
 
 impl Wallet {
+  // Sat ranges for every wallet output are fetched up front in a single
+  // `POST /outputs` request when the wallet is constructed, so this makes no
+  // further requests. A scan of the server's outpoint to sat range table would
+  // be slower, since it holds every unspent output, not just the wallet's.
   pub(crate) fn get_output_sat_ranges(&self) -> Result<Vec<(OutPoint, Vec<(u64, u64)>)>> {
     ensure!(
       self.has_sat_index,
@@ -150,6 +158,54 @@ impl Wallet {
     Ok(())
   }
 
+  pub(crate) fn get_locked_outpoints(bitcoin_client: &Client) -> Result<BTreeSet<OutPoint>> {
+    #[derive(Deserialize)]
+    pub(crate) struct JsonOutPoint {
+      txid: Txid,
+      vout: u32,
+    }
+
+    Ok(
+      bitcoin_client
+        .call::<Vec<JsonOutPoint>>("listlockunspent", &[])?
+        .into_iter()
+        .map(|outpoint| OutPoint::new(outpoint.txid, outpoint.vout))
+        .collect(),
+    )
+  }
+
+  pub(crate) fn unconfirmed_utxos(&self, min_confirmations: u32) -> Result<BTreeSet<OutPoint>> {
+    if min_confirmations == 0 {
+      return Ok(BTreeSet::new());
+    }
+
+    Ok(
+      self
+        .bitcoin_client()
+        .list_unspent(Some(0), None, None, None, None)?
+        .into_iter()
+        .filter(|utxo| utxo.confirmations < min_confirmations)
+        .map(|utxo| OutPoint::new(utxo.txid, utxo.vout))
+        .collect(),
+    )
+  }
+
+  pub(crate) fn exclude_unconfirmed_utxos(
+    &self,
+    utxos: &BTreeMap<OutPoint, TxOut>,
+    min_confirmations: u32,
+  ) -> Result<BTreeMap<OutPoint, TxOut>> {
+    let unconfirmed = self.unconfirmed_utxos(min_confirmations)?;
+
+    Ok(
+      utxos
+        .iter()
+        .filter(|(outpoint, _txout)| !unconfirmed.contains(outpoint))
+        .map(|(outpoint, txout)| (*outpoint, txout.clone()))
+        .collect(),
+    )
+  }
+
   pub(crate) fn inscriptions(&self) -> &BTreeMap<SatPoint, Vec<InscriptionId>> {
     &self.inscriptions
   }
@@ -270,6 +326,52 @@ impl Wallet {
     Ok(Some((rune_json.id, rune_json.entry, rune_json.parent)))
   }
 
+  pub(crate) fn sign_and_broadcast_transaction(
+    &self,
+    unsigned_transaction: Transaction,
+    dry_run: bool,
+  ) -> Result<(Txid, String, u64)> {
+    let psbt = self
+      .bitcoin_client()
+      .wallet_process_psbt(
+        &base64::engine::general_purpose::STANDARD
+          .encode(Psbt::from_unsigned_tx(unsigned_transaction.clone())?.serialize()),
+        Some(!dry_run),
+        None,
+        None,
+      )?
+      .psbt;
+
+    let txid = if dry_run {
+      unsigned_transaction.txid()
+    } else {
+      let signed_tx = self
+        .bitcoin_client()
+        .finalize_psbt(&psbt, None)?
+        .hex
+        .ok_or_else(|| anyhow!("unable to sign transaction"))?;
+
+      self.bitcoin_client().send_raw_transaction(&signed_tx)?
+    };
+
+    let mut fee = 0;
+    for txin in &unsigned_transaction.input {
+      fee += self
+        .utxos()
+        .get(&txin.previous_output)
+        .ok_or_else(|| anyhow!("input {} not found in utxos", txin.previous_output))?
+        .value;
+    }
+
+    for txout in &unsigned_transaction.output {
+      fee = fee
+        .checked_sub(txout.value)
+        .ok_or_else(|| anyhow!("transaction outputs exceed inputs"))?;
+    }
+
+    Ok((txid, psbt, fee))
+  }
+
   pub(crate) fn get_change_address(&self) -> Result<Address> {
     Ok(
       self
@@ -334,11 +436,13 @@ impl Wallet {
       bail!("no etching found");
     };
 
-    eprintln!(
-      "Waiting for rune {} commitment {} to mature…",
-      rune,
-      entry.commit.txid()
-    );
+    if !self.settings.quiet() {
+      eprintln!(
+        "Waiting for rune {} commitment {} to mature…",
+        rune,
+        entry.commit.txid()
+      );
+    }
 
     loop {
       if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
@@ -431,7 +535,12 @@ impl Wallet {
     Ok(())
   }
 
-  pub(crate) fn initialize(name: String, settings: &Settings, seed: [u8; 64]) -> Result {
+  pub(crate) fn initialize(
+    name: String,
+    settings: &Settings,
+    seed: [u8; 64],
+    derivation_path: Option<DerivationPath>,
+  ) -> Result<Vec<String>> {
     Self::check_version(settings.bitcoin_rpc_client(None)?)?.create_wallet(
       &name,
       None,
@@ -448,25 +557,61 @@ impl Wallet {
 
     let fingerprint = master_private_key.fingerprint(&secp);
 
-    let derivation_path = DerivationPath::master()
-      .child(ChildNumber::Hardened { index: 86 })
-      .child(ChildNumber::Hardened {
-        index: u32::from(network != Network::Bitcoin),
-      })
-      .child(ChildNumber::Hardened { index: 0 });
+    let derivation_path = derivation_path.unwrap_or_else(|| {
+      DerivationPath::master()
+        .child(ChildNumber::Hardened { index: 86 })
+        .child(ChildNumber::Hardened {
+          index: Self::coin_type(network),
+        })
+        .child(ChildNumber::Hardened { index: 0 })
+    });
 
     let derived_private_key = master_private_key.derive_priv(&secp, &derivation_path)?;
 
-    for change in [false, true] {
-      Self::derive_and_import_descriptor(
-        name.clone(),
-        settings,
-        &secp,
-        (fingerprint, derivation_path.clone()),
-        derived_private_key,
-        change,
-      )?;
-    }
+    [false, true]
+      .into_iter()
+      .map(|change| {
+        Self::derive_and_import_descriptor(
+          name.clone(),
+          settings,
+          &secp,
+          (fingerprint, derivation_path.clone()),
+          derived_private_key,
+          change,
+        )
+      })
+      .collect()
+  }
+
+  fn coin_type(network: Network) -> u32 {
+    u32::from(network != Network::Bitcoin)
+  }
+
+  pub(crate) fn check_derivation_path(chain: Chain, derivation_path: &DerivationPath) -> Result {
+    let [purpose, coin_type, account] = derivation_path.as_ref() else {
+      bail!(
+        "derivation path `{derivation_path}` must be of the form m/PURPOSE'/COIN_TYPE'/ACCOUNT'"
+      );
+    };
+
+    ensure!(
+      purpose.is_hardened() && coin_type.is_hardened() && account.is_hardened(),
+      "derivation path `{derivation_path}` must only contain hardened indices",
+    );
+
+    ensure!(
+      *purpose == ChildNumber::Hardened { index: 86 },
+      "derivation path `{derivation_path}` purpose {purpose} is not supported, only taproot wallets with purpose 86' can be restored",
+    );
+
+    let expected = ChildNumber::Hardened {
+      index: Self::coin_type(chain.network()),
+    };
+
+    ensure!(
+      *coin_type == expected,
+      "derivation path `{derivation_path}` coin type {coin_type} does not match {chain}, expected {expected}",
+    );
 
     Ok(())
   }
@@ -478,7 +623,7 @@ impl Wallet {
     origin: (Fingerprint, DerivationPath),
     derived_private_key: ExtendedPrivKey,
     change: bool,
-  ) -> Result {
+  ) -> Result<String> {
     let secret_key = DescriptorSecretKey::XPrv(DescriptorXKey {
       origin: Some(origin),
       xkey: derived_private_key,
@@ -507,7 +652,7 @@ impl Wallet {
         label: None,
       }])?;
 
-    Ok(())
+    Ok(descriptor.to_string())
   }
 
   pub(crate) fn check_version(client: Client) -> Result<Client> {
@@ -551,16 +696,19 @@ impl Wallet {
     let once = Once::new();
     let progress_bar = Mutex::new(None);
     let integration_test = settings.integration_test();
+    let quiet = settings.quiet();
 
     let repair_callback = move |progress: &mut RepairSession| {
-      once.call_once(|| {
-        println!(
-          "Wallet database file `{}` needs recovery. This can take some time.",
-          db_path.display()
-        )
-      });
+      if !quiet {
+        once.call_once(|| {
+          eprintln!(
+            "Wallet database file `{}` needs recovery. This can take some time.",
+            db_path.display()
+          )
+        });
+      }
 
-      if !(cfg!(test) || log_enabled!(log::Level::Info) || integration_test) {
+      if !(cfg!(test) || log_enabled!(log::Level::Info) || integration_test || quiet) {
         let mut guard = progress_bar.lock().unwrap();
 
         let progress_bar = guard.get_or_insert_with(|| {
@@ -614,6 +762,7 @@ impl Wallet {
 
         let tx = database.begin_write()?;
 
+        tx.open_table(LABELS)?;
         tx.open_table(RUNE_TO_ETCHING)?;
 
         tx.open_table(STATISTICS)?
@@ -686,4 +835,43 @@ impl Wallet {
         .collect::<Result<Vec<(Rune, EtchingEntry)>, StorageError>>()?,
     )
   }
+
+  pub(crate) fn set_label(&self, reference: &str, label: &str) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx.open_table(LABELS)?.insert(reference, label)?;
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  pub(crate) fn remove_label(&self, reference: &str) -> Result<bool> {
+    let wtx = self.database.begin_write()?;
+
+    let removed = wtx.open_table(LABELS)?.remove(reference)?.is_some();
+    wtx.commit()?;
+
+    Ok(removed)
+  }
+
+  pub(crate) fn labels(&self) -> Result<BTreeMap<String, String>> {
+    let rtx = self.database.begin_read()?;
+
+    // wallets created before labels were added don't have a label table
+    let table = match rtx.open_table(LABELS) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(BTreeMap::new()),
+      Err(err) => return Err(err.into()),
+    };
+
+    Ok(
+      table
+        .iter()?
+        .map(|result| {
+          result
+            .map(|(reference, label)| (reference.value().to_string(), label.value().to_string()))
+        })
+        .collect::<Result<BTreeMap<String, String>, StorageError>>()?,
+    )
+  }
 }
@@ -42,6 +42,15 @@ pub struct Output {
   pub total_fees: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ComputedIds {
+  pub commit: Txid,
+  pub commit_tx: String,
+  pub inscriptions: Vec<InscriptionInfo>,
+  pub reveal: Txid,
+  pub reveal_tx: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct InscriptionInfo {
   pub destination: Address<NetworkUnchecked>,
@@ -1180,4 +1189,51 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn postage_below_dust_limit_or_floor_is_an_error() {
+    let utxos = vec![(outpoint(1), tx_out(20_000, address()))];
+
+    let plan = |postage, postage_floor| {
+      batch::Plan {
+        inscriptions: vec![inscription("text/plain", "ord")],
+        destinations: vec![recipient()],
+        postage_floor,
+        postages: vec![Amount::from_sat(postage)],
+        mode: batch::Mode::SeparateOutputs,
+        ..default()
+      }
+      .create_batch_transactions(
+        BTreeMap::new(),
+        Chain::Signet,
+        BTreeSet::new(),
+        BTreeSet::new(),
+        utxos.clone().into_iter().collect(),
+        [change(0), change(1)],
+        change(2),
+      )
+      .map(|_| ())
+      .map_err(|err| err.to_string())
+    };
+
+    assert_eq!(
+      plan(293, None),
+      Err(format!(
+        "postage of 293sat to {} is below dust limit of 294sat",
+        recipient()
+      ))
+    );
+
+    assert_eq!(plan(294, None), Ok(()));
+
+    assert_eq!(
+      plan(1_000, Some(Amount::from_sat(1_001))),
+      Err(format!(
+        "postage of 1000sat to {} is below postage floor of 1001sat",
+        recipient()
+      ))
+    );
+
+    assert_eq!(plan(1_001, Some(Amount::from_sat(1_001))), Ok(()));
+  }
 }
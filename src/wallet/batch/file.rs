@@ -15,7 +15,7 @@ pub struct File {
 }
 
 impl File {
-  pub(crate) fn load(path: &Path) -> Result<Self> {
+  pub(crate) fn load(path: &Path, allow_offset: bool) -> Result<Self> {
     let batchfile: Self = serde_yaml::from_reader(fs::File::open(path)?)?;
 
     ensure!(
@@ -67,11 +67,12 @@ impl File {
       );
 
       ensure!(
-        batchfile
-          .inscriptions
-          .iter()
-          .all(|entry| entry.satpoint.unwrap().offset == 0),
-        "`satpoint` can only be specified for first sat of an output"
+        allow_offset
+          || batchfile
+            .inscriptions
+            .iter()
+            .all(|entry| entry.satpoint.unwrap().offset == 0),
+        "`satpoint` can only be specified for first sat of an output unless `--allow-offset` is set"
       );
     }
 
@@ -108,7 +109,7 @@ impl File {
     wallet: &Wallet,
     utxos: &BTreeMap<OutPoint, TxOut>,
     parent_value: Option<u64>,
-    compress: bool,
+    compression: Option<Compression>,
   ) -> Result<(
     Vec<Inscription>,
     Vec<(SatPoint, TxOut)>,
@@ -129,21 +130,7 @@ impl File {
         }
       }
 
-      inscriptions.push(Inscription::new(
-        wallet.chain(),
-        compress,
-        entry.delegate,
-        entry.metadata()?,
-        entry.metaprotocol.clone(),
-        self.parent.into_iter().collect(),
-        entry.file.clone(),
-        Some(pointer),
-        self
-          .etching
-          .and_then(|etch| (i == 0).then_some(etch.rune.rune)),
-      )?);
-
-      let postage = if self.mode == Mode::SatPoints {
+      let (postage, offset) = if self.mode == Mode::SatPoints {
         let satpoint = entry
           .satpoint
           .ok_or_else(|| anyhow!("no satpoint specified for entry {i}"))?;
@@ -152,17 +139,40 @@ impl File {
           .get(&satpoint.outpoint)
           .ok_or_else(|| anyhow!("{} not in wallet", satpoint))?;
 
+        ensure!(
+          satpoint.offset < txout.value,
+          "satpoint {satpoint} offset is not less than output value {}",
+          txout.value
+        );
+
         reveal_satpoints.push((satpoint, txout.clone()));
 
-        txout.value
+        (txout.value, satpoint.offset)
       } else {
-        self
-          .postage
-          .map(Amount::from_sat)
-          .unwrap_or(TARGET_POSTAGE)
-          .to_sat()
+        (
+          self
+            .postage
+            .map(Amount::from_sat)
+            .unwrap_or(TARGET_POSTAGE)
+            .to_sat(),
+          0,
+        )
       };
 
+      inscriptions.push(Inscription::new(
+        wallet.chain(),
+        compression,
+        entry.delegate,
+        entry.metadata()?,
+        entry.metaprotocol.clone(),
+        self.parent.into_iter().collect(),
+        entry.file.clone(),
+        Some(pointer + offset),
+        self
+          .etching
+          .and_then(|etch| (i == 0).then_some(etch.rune.rune)),
+      )?);
+
       pointer += postage;
 
       if self.mode == Mode::SameSat && i > 0 {
@@ -218,7 +228,9 @@ inscriptions:
     .unwrap();
 
     assert_eq!(
-      File::load(batch_file.as_path()).unwrap_err().to_string(),
+      File::load(batch_file.as_path(), false)
+        .unwrap_err()
+        .to_string(),
       "batchfile cannot set both `sat` and `satpoint`"
     );
   }
@@ -243,7 +255,7 @@ inscriptions:
     .unwrap();
 
     assert_eq!(
-      batch::File::load(batch_file.as_path())
+      batch::File::load(batch_file.as_path(), false)
         .unwrap_err()
         .to_string(),
       "specifying `satpoint` in an inscription only works in `satpoints` mode"
@@ -269,7 +281,7 @@ inscriptions:
     .unwrap();
 
     assert_eq!(
-      batch::File::load(batch_file.as_path())
+      batch::File::load(batch_file.as_path(), false)
         .unwrap_err()
         .to_string(),
       "if `satpoint` is set for any inscription, then all inscriptions need to specify a satpoint"
@@ -296,10 +308,38 @@ inscriptions:
     .unwrap();
 
     assert_eq!(
-      batch::File::load(batch_file.as_path())
+      batch::File::load(batch_file.as_path(), false)
         .unwrap_err()
         .to_string(),
-      "`satpoint` can only be specified for first sat of an output"
+      "`satpoint` can only be specified for first sat of an output unless `--allow-offset` is set"
+    );
+  }
+
+  #[test]
+  fn batchfile_satpoint_offset_allowed_with_flag() {
+    let tempdir = TempDir::new().unwrap();
+    let batch_file = tempdir.path().join("batch.yaml");
+    fs::write(
+      batch_file.clone(),
+      r#"
+mode: satpoints
+inscriptions:
+- file: inscription.txt
+  satpoint: bc4c30829a9564c0d58e6287195622b53ced54a25711d1b86be7cd3a70ef61ed:0:0
+- file: tulip.png
+  satpoint: 5fddcbdc3eb21a93e8dd1dd3f9087c3677f422b82d5ba39a6b1ec37338154af6:0:21
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      batch::File::load(batch_file.as_path(), true)
+        .unwrap()
+        .inscriptions[1]
+        .satpoint
+        .unwrap()
+        .offset,
+      21
     );
   }
 
@@ -324,7 +364,7 @@ inscriptions:
     .unwrap();
 
     assert_eq!(
-      batch::File::load(batch_file.as_path())
+      batch::File::load(batch_file.as_path(), false)
         .unwrap_err()
         .to_string(),
       "`postage` cannot be set if in `satpoints` mode"
@@ -353,7 +393,7 @@ inscriptions:
     .unwrap();
 
     assert_eq!(
-      batch::File::load(batch_file.as_path())
+      batch::File::load(batch_file.as_path(), false)
         .unwrap_err()
         .to_string(),
       "duplicate satpoint bc4c30829a9564c0d58e6287195622b53ced54a25711d1b86be7cd3a70ef61ed:0:0"
@@ -363,7 +403,7 @@ inscriptions:
   #[test]
   fn example_batchfile_deserializes_successfully() {
     assert_eq!(
-      batch::File::load(Path::new("batch.yaml")).unwrap(),
+      batch::File::load(Path::new("batch.yaml"), false).unwrap(),
       batch::File {
         mode: batch::Mode::SeparateOutputs,
         parent: Some(
@@ -462,6 +502,6 @@ inscriptions:
     )
     .unwrap();
 
-    assert!(batch::File::load(batch_file.as_path()).is_ok());
+    assert!(batch::File::load(batch_file.as_path(), false).is_ok());
   }
 }
@@ -2,15 +2,19 @@ use super::*;
 
 pub struct Plan {
   pub(crate) commit_fee_rate: FeeRate,
+  pub(crate) compute_ids: bool,
   pub(crate) destinations: Vec<Address>,
   pub(crate) dry_run: bool,
   pub(crate) etching: Option<Etching>,
   pub(crate) inscriptions: Vec<Inscription>,
+  pub(crate) min_confirmations: u32,
   pub(crate) mode: Mode,
   pub(crate) no_backup: bool,
   pub(crate) no_limit: bool,
   pub(crate) parent_info: Option<ParentInfo>,
+  pub(crate) postage_floor: Option<Amount>,
   pub(crate) postages: Vec<Amount>,
+  pub(crate) psbt: bool,
   pub(crate) reinscribe: bool,
   pub(crate) reveal_fee_rate: FeeRate,
   pub(crate) reveal_satpoints: Vec<(SatPoint, TxOut)>,
@@ -21,15 +25,19 @@ impl Default for Plan {
   fn default() -> Self {
     Self {
       commit_fee_rate: 1.0.try_into().unwrap(),
+      compute_ids: false,
       destinations: Vec::new(),
       dry_run: false,
       etching: None,
       inscriptions: Vec::new(),
+      min_confirmations: 0,
       mode: Mode::SharedOutput,
       no_backup: false,
       no_limit: false,
       parent_info: None,
+      postage_floor: None,
       postages: vec![Amount::from_sat(10_000)],
+      psbt: false,
       reinscribe: false,
       reveal_fee_rate: 1.0.try_into().unwrap(),
       reveal_satpoints: Vec::new(),
@@ -53,17 +61,38 @@ impl Plan {
       recovery_key_pair,
       total_fees,
       rune,
-    } = self.create_batch_transactions(
-      wallet.inscriptions().clone(),
-      wallet.chain(),
-      locked_utxos.clone(),
-      runic_utxos,
-      utxos.clone(),
-      [wallet.get_change_address()?, wallet.get_change_address()?],
-      wallet.get_change_address()?,
-    )?;
+    } = self
+      .create_batch_transactions(
+        wallet.inscriptions().clone(),
+        wallet.chain(),
+        locked_utxos.clone(),
+        runic_utxos,
+        wallet.exclude_unconfirmed_utxos(utxos, self.min_confirmations)?,
+        [wallet.get_change_address()?, wallet.get_change_address()?],
+        wallet.get_change_address()?,
+      )
+      .map_err(|err| {
+        if self.min_confirmations > 0 {
+          err.context(format!(
+            "failed to fund transactions from outputs with at least {} confirmations",
+            self.min_confirmations
+          ))
+        } else {
+          err
+        }
+      })?;
+
+    ensure!(
+      !self.compute_ids || rune.is_none(),
+      "`--compute-ids` cannot be used to etch runes"
+    );
+
+    if self.dry_run || self.psbt {
+      ensure!(
+        !self.psbt || rune.is_none(),
+        "`--psbt` cannot be used to etch runes"
+      );
 
-    if self.dry_run {
       let commit_psbt = wallet
         .bitcoin_client()
         .wallet_process_psbt(
@@ -75,7 +104,20 @@ impl Plan {
         )?
         .psbt;
 
-      let reveal_psbt = Psbt::from_unsigned_tx(Self::remove_witnesses(reveal_tx.clone()))?;
+      let mut reveal_psbt = Psbt::from_unsigned_tx(Self::remove_witnesses(reveal_tx.clone()))?;
+
+      if self.psbt {
+        for (input, txin) in reveal_psbt.inputs.iter_mut().zip(&reveal_tx.input) {
+          if txin.previous_output.txid == commit_tx.txid() {
+            input.witness_utxo = Some(commit_tx.output[commit_vout].clone());
+            input.final_script_witness = Some(txin.witness.clone());
+          }
+        }
+
+        if !self.no_backup {
+          Self::backup_recovery_key(wallet, recovery_key_pair)?;
+        }
+      }
 
       return Ok(Some(Box::new(self.output(
         commit_tx.txid(),
@@ -124,6 +166,30 @@ impl Plan {
       Self::backup_recovery_key(wallet, recovery_key_pair)?;
     }
 
+    if self.compute_ids {
+      let commit = consensus::encode::deserialize::<Transaction>(&signed_commit_tx)?.txid();
+      let reveal = consensus::encode::deserialize::<Transaction>(&signed_reveal_tx)?.txid();
+
+      return Ok(Some(Box::new(ComputedIds {
+        commit,
+        commit_tx: hex::encode(signed_commit_tx),
+        inscriptions: self
+          .output(
+            commit,
+            None,
+            reveal,
+            false,
+            None,
+            total_fees,
+            self.inscriptions.clone(),
+            None,
+          )
+          .inscriptions,
+        reveal,
+        reveal_tx: hex::encode(signed_reveal_tx),
+      })));
+    }
+
     let commit_txid = wallet
       .bitcoin_client()
       .send_raw_transaction(&signed_commit_tx)?;
@@ -227,7 +293,8 @@ impl Plan {
           .iter()
           .map(|amount| amount.to_sat())
           .sum(),
-        Mode::SeparateOutputs | Mode::SameSat | Mode::SatPoints => 0,
+        Mode::SatPoints => self.reveal_satpoints[i].0.offset,
+        Mode::SeparateOutputs | Mode::SameSat => 0,
       };
 
       let destination = match self.mode {
@@ -419,12 +486,31 @@ impl Plan {
     reveal_inputs.push(OutPoint::null());
 
     for (i, destination) in self.destinations.iter().enumerate() {
+      let script_pubkey = destination.script_pubkey();
+
+      let value = match self.mode {
+        Mode::SeparateOutputs | Mode::SatPoints => self.postages[i].to_sat(),
+        Mode::SharedOutput | Mode::SameSat => total_postage,
+      };
+
+      let dust_limit = script_pubkey.dust_value().to_sat();
+
+      ensure!(
+        value >= dust_limit,
+        "postage of {value}sat to {destination} is below dust limit of {dust_limit}sat"
+      );
+
+      if let Some(postage_floor) = self.postage_floor {
+        ensure!(
+          value >= postage_floor.to_sat(),
+          "postage of {value}sat to {destination} is below postage floor of {}sat",
+          postage_floor.to_sat(),
+        );
+      }
+
       reveal_outputs.push(TxOut {
-        script_pubkey: destination.script_pubkey(),
-        value: match self.mode {
-          Mode::SeparateOutputs | Mode::SatPoints => self.postages[i].to_sat(),
-          Mode::SharedOutput | Mode::SameSat => total_postage,
-        },
+        script_pubkey,
+        value,
       });
     }
 
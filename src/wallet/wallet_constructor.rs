@@ -180,24 +180,16 @@ impl WalletConstructor {
   }
 
   fn get_locked_utxos(bitcoin_client: &Client) -> Result<BTreeMap<OutPoint, TxOut>> {
-    #[derive(Deserialize)]
-    pub(crate) struct JsonOutPoint {
-      txid: Txid,
-      vout: u32,
-    }
-
-    let outpoints = bitcoin_client.call::<Vec<JsonOutPoint>>("listlockunspent", &[])?;
-
     let mut utxos = BTreeMap::new();
 
-    for outpoint in outpoints {
+    for outpoint in Wallet::get_locked_outpoints(bitcoin_client)? {
       let Some(tx_out) = bitcoin_client.get_tx_out(&outpoint.txid, outpoint.vout, Some(false))?
       else {
         continue;
       };
 
       utxos.insert(
-        OutPoint::new(outpoint.txid, outpoint.vout),
+        outpoint,
         TxOut {
           value: tx_out.value.to_sat(),
           script_pubkey: ScriptBuf::from_bytes(tx_out.script_pub_key.hex),
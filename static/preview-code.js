@@ -1,9 +1,9 @@
-import hljs from 'https://cdn.jsdelivr.net/gh/highlightjs/cdn-release@11/build/es/highlight.min.js';
+import hljs from 'https://cdn.jsdelivr.net/gh/highlightjs/cdn-release@11.9.0/build/es/highlight.min.js';
 
 const inscription = document.documentElement.dataset.inscription;
 const language = document.documentElement.dataset.language;
 
-const definition = await import(`https://cdn.jsdelivr.net/gh/highlightjs/cdn-release@11/build/es/languages/${language}.min.js`);
+const definition = await import(`https://cdn.jsdelivr.net/gh/highlightjs/cdn-release@11.9.0/build/es/languages/${language}.min.js`);
 
 hljs.registerLanguage(language, definition.default);
 
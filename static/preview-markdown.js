@@ -1,4 +1,4 @@
-import { marked } from 'https://cdn.jsdelivr.net/npm/marked@9/+esm'
+import { marked } from 'https://cdn.jsdelivr.net/npm/marked@9.1.6/+esm'
 
 const inscription = document.documentElement.dataset.inscription;
 const response = await fetch(`/content/${inscription}`);
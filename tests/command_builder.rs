@@ -263,7 +263,7 @@ impl CommandBuilder {
   }
 
   #[track_caller]
-  fn run(self) -> (TempDir, String) {
+  pub(crate) fn run(self) -> (TempDir, String) {
     self.spawn().run()
   }
 
@@ -285,4 +285,13 @@ impl CommandBuilder {
       Err(err) => panic!("Failed to deserialize JSON: {err}\n{stdout}"),
     }
   }
+
+  #[track_caller]
+  pub(crate) fn run_and_deserialize_output_with_tempdir<T: DeserializeOwned>(self) -> (TempDir, T) {
+    let (tempdir, stdout) = self.stdout_regex(".*").run();
+    match serde_json::from_str(&stdout) {
+      Ok(output) => (tempdir, output),
+      Err(err) => panic!("Failed to deserialize JSON: {err}\n{stdout}"),
+    }
+  }
 }
@@ -89,3 +89,42 @@ fn no_satoshi_index() {
     .expected_exit_code(1)
     .run_and_extract_stdout();
 }
+
+#[test]
+fn find_tsv_returns_satpoint_for_each_sat() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(1);
+
+  pretty_assert_eq!(
+    CommandBuilder::new("--index-sats find --tsv sats.tsv")
+      .write("sats.tsv", "# sats\n5000000001\tfoo\n0\n10000000000\n")
+      .core(&core)
+      .run_and_deserialize_output::<BTreeMap<String, Option<SatPoint>>>(),
+    [
+      (
+        "0".to_string(),
+        Some(SatPoint {
+          outpoint: OutPoint {
+            txid: core.tx(0, 0).into(),
+            vout: 0,
+          },
+          offset: 0,
+        })
+      ),
+      ("10000000000".to_string(), None),
+      (
+        "5000000001".to_string(),
+        Some(SatPoint {
+          outpoint: OutPoint {
+            txid: core.tx(1, 0).into(),
+            vout: 0,
+          },
+          offset: 1,
+        })
+      ),
+    ]
+    .into_iter()
+    .collect()
+  );
+}
@@ -1,4 +1,4 @@
-use super::*;
+use {super::*, std::io::Read};
 
 #[test]
 fn run_is_an_alias_for_update() {
@@ -52,6 +52,165 @@ fn re_opening_database_does_not_trigger_schema_check() {
     .run_and_extract_stdout();
 }
 
+#[test]
+fn opening_index_with_flag_it_was_not_built_with_is_an_error() {
+  for flag in [
+    "--compress-sat-ranges",
+    "--index-runes",
+    "--index-sats",
+    "--index-spent-sats",
+    "--index-transactions",
+  ] {
+    let core = mockcore::spawn();
+    core.mine_blocks(1);
+
+    let tempdir = TempDir::new().unwrap();
+
+    let index_path = tempdir.path().join("foo.redb");
+
+    CommandBuilder::new(format!("--index {} index update", index_path.display()))
+      .core(&core)
+      .run_and_extract_stdout();
+
+    CommandBuilder::new(format!(
+      "--index {} {flag} index update",
+      index_path.display()
+    ))
+    .core(&core)
+    .expected_exit_code(1)
+    .expected_stderr(format!(
+      "error: index at `{}` was built without `{flag}`, delete and rebuild the index to enable it\n",
+      index_path.display()
+    ))
+    .run_and_extract_stdout();
+  }
+}
+
+#[test]
+fn opening_index_with_different_strict_runestone_is_an_error() {
+  let core = mockcore::spawn();
+  core.mine_blocks(1);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let index_path = tempdir.path().join("foo.redb");
+
+  CommandBuilder::new(format!("--index {} index update", index_path.display()))
+    .core(&core)
+    .run_and_extract_stdout();
+
+  CommandBuilder::new(format!(
+    "--index {} --strict-runestone index update",
+    index_path.display()
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: index at `{}` was built without `--strict-runestone`, delete and rebuild the index to change it\n",
+    index_path.display()
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn opening_index_with_different_jubilee_height_is_an_error() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+  core.mine_blocks(1);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let index_path = tempdir.path().join("foo.redb");
+
+  CommandBuilder::new(format!(
+    "--regtest --index {} --jubilee-height 5 index update",
+    index_path.display()
+  ))
+  .core(&core)
+  .run_and_extract_stdout();
+
+  CommandBuilder::new(format!(
+    "--regtest --index {} --jubilee-height 6 index update",
+    index_path.display()
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: index at `{}` was built with jubilee height 5, but jubilee height is 6, delete and rebuild the index to change it\n",
+    index_path.display()
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn opening_index_without_flag_it_was_built_with_is_allowed() {
+  let core = mockcore::spawn();
+  core.mine_blocks(1);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let index_path = tempdir.path().join("foo.redb");
+
+  CommandBuilder::new(format!(
+    "--index {} --index-sats index update",
+    index_path.display()
+  ))
+  .core(&core)
+  .run_and_extract_stdout();
+
+  CommandBuilder::new(format!("--index {} index update", index_path.display()))
+    .core(&core)
+    .run_and_extract_stdout();
+}
+
+#[cfg(unix)]
+#[test]
+fn interrupted_update_leaves_index_at_committed_block() {
+  use nix::{
+    sys::signal::{self, Signal},
+    unistd::Pid,
+  };
+
+  let core = mockcore::spawn();
+  core.mine_blocks(500);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let index_path = tempdir.path().join("foo.redb");
+
+  let spawn = CommandBuilder::new(format!(
+    "--commit-interval 1 --index {} index update",
+    index_path.display()
+  ))
+  .core(&core)
+  .stderr_regex(".*")
+  .spawn();
+
+  for attempt in 0.. {
+    if index_path.is_file() {
+      break;
+    }
+
+    if attempt == 100 {
+      panic!("index was not created");
+    }
+
+    thread::sleep(Duration::from_millis(10));
+  }
+
+  signal::kill(
+    Pid::from_raw(spawn.child.id().try_into().unwrap()),
+    Signal::SIGINT,
+  )
+  .unwrap();
+
+  spawn.run_and_extract_stdout();
+
+  CommandBuilder::new(format!("--index {} index info", index_path.display()))
+    .core(&core)
+    .stdout_regex(r#".*"blocks_indexed": 501,.*"#)
+    .run_and_extract_stdout();
+}
+
 #[test]
 fn export_inscription_number_to_id_tsv() {
   let core = mockcore::spawn();
@@ -90,3 +249,108 @@ fn export_inscription_number_to_id_tsv() {
     &ord::Object::InscriptionId(inscription),
   );
 }
+
+#[test]
+fn check_passes_on_consistent_index() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  inscribe(&core, &ord);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let index_path = tempdir.path().join("foo.redb");
+
+  CommandBuilder::new(format!("--index {} index update", index_path.display()))
+    .core(&core)
+    .run_and_extract_stdout();
+
+  let output = CommandBuilder::new(format!("--index {} index check", index_path.display()))
+    .core(&core)
+    .run_and_deserialize_output::<ord::subcommand::index::check::Output>();
+
+  assert_eq!(output.inscriptions, 1);
+  assert_eq!(output.missing_inscription_entries, 0);
+  assert_eq!(output.missing_satpoints, 0);
+  assert_eq!(output.sequence_number_gaps, 0);
+  assert_eq!(output.duplicate_satpoints, None);
+
+  let output = CommandBuilder::new(format!(
+    "--index {} index check --deep",
+    index_path.display()
+  ))
+  .core(&core)
+  .run_and_deserialize_output::<ord::subcommand::index::check::Output>();
+
+  assert_eq!(output.duplicate_satpoints, Some(0));
+  assert!(output.duplicate_satpoint_examples.is_empty());
+}
+
+#[test]
+fn export_runes_csv() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let etched = etch(&core, &ord, Rune(RUNE));
+
+  let csv = CommandBuilder::new("--regtest --index-runes index export-runes --csv runes.csv")
+    .core(&core)
+    .run_and_extract_file("runes.csv");
+
+  assert_eq!(
+    csv,
+    format!(
+      "id,rune,symbol,divisibility,supply,holders\n{},AAAAAAAAAAAAA,¢,0,1000,1\n",
+      etched.id
+    ),
+  );
+
+  let holders = CommandBuilder::new(
+    "--regtest --index-runes index export-runes --csv runes.csv --holders holders.csv",
+  )
+  .core(&core)
+  .run_and_extract_file("holders.csv");
+
+  assert_eq!(
+    holders,
+    format!(
+      "id,rune,outpoint,balance\n{},AAAAAAAAAAAAA,{}:1,1000\n",
+      etched.id, etched.output.reveal
+    ),
+  );
+}
+
+#[test]
+fn export_runes_gzip() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let etched = etch(&core, &ord, Rune(RUNE));
+
+  let (tempdir, _) =
+    CommandBuilder::new("--regtest --index-runes index export-runes --gzip --csv runes.csv.gz")
+      .core(&core)
+      .run();
+
+  let mut csv = String::new();
+
+  flate2::read::GzDecoder::new(fs::File::open(tempdir.path().join("runes.csv.gz")).unwrap())
+    .read_to_string(&mut csv)
+    .unwrap();
+
+  assert_eq!(
+    csv,
+    format!(
+      "id,rune,symbol,divisibility,supply,holders\n{},AAAAAAAAAAAAA,¢,0,1000,1\n",
+      etched.id
+    ),
+  );
+}
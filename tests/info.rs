@@ -1,4 +1,7 @@
-use {super::*, ord::subcommand::index::info::TransactionsOutput};
+use {
+  super::*,
+  ord::subcommand::index::info::{TableOutput, TransactionsOutput},
+};
 
 #[test]
 fn json_with_satoshi_index() {
@@ -110,3 +113,35 @@ fn transactions() {
   assert_eq!(output[1].end, 11);
   assert_eq!(output[1].count, 10);
 }
+
+#[test]
+fn table() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(2);
+
+  let output = CommandBuilder::new("--index-sats index info --table OUTPOINT_TO_SAT_RANGES")
+    .core(&core)
+    .run_and_deserialize_output::<TableOutput>();
+
+  assert_eq!(output.name, "OUTPOINT_TO_SAT_RANGES");
+  assert_eq!(output.entries, 4);
+  assert_eq!(output.sample.len(), 4);
+  assert!(output
+    .sample
+    .iter()
+    .any(|sample| sample.value == "0-5000000000"));
+}
+
+#[test]
+fn unknown_table() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("index info --table OUTPOINT_TO_SAT_RANGE")
+    .core(&core)
+    .stderr_regex(
+      "error: unknown table `OUTPOINT_TO_SAT_RANGE`, expected one of: .*OUTPOINT_TO_SAT_RANGES.*\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
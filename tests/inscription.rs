@@ -0,0 +1,135 @@
+use {
+  super::*,
+  ord::subcommand::inscription::dump::{Field, Output},
+};
+
+#[test]
+fn dump_envelope() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(1);
+
+  let witness = envelope(&[b"ord", &[1], b"text/plain", &[255], b"baz", &[], b"bar"]);
+
+  let envelope = hex::encode(witness.tapscript().unwrap().as_bytes());
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, witness)],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let inscription = InscriptionId { txid, index: 0 };
+
+  let tempdir = TempDir::new().unwrap();
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!(
+      "inscription dump {inscription} --output-dir {}",
+      tempdir.path().display()
+    ))
+    .core(&core)
+    .run_and_deserialize_output::<Output>(),
+    Output {
+      body: Some(hex::encode("bar")),
+      content_encoding: None,
+      content_type: Some("text/plain".into()),
+      duplicate_field: false,
+      envelope,
+      fields: vec![
+        Field {
+          tag: "01".into(),
+          value: hex::encode("text/plain"),
+        },
+        Field {
+          tag: "ff".into(),
+          value: hex::encode("baz"),
+        },
+      ],
+      incomplete_field: false,
+      input: 0,
+      inscription,
+      metadata: None,
+      offset: 0,
+      pushnum: false,
+      stutter: false,
+      unrecognized_even_field: false,
+    }
+  );
+
+  assert_eq!(fs::read(tempdir.path().join("body.bin")).unwrap(), b"bar");
+  assert!(tempdir.path().join("envelope.bin").exists());
+  assert!(!tempdir.path().join("metadata.cbor").exists());
+}
+
+#[test]
+fn dump_missing_envelope() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(1);
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(
+      1,
+      0,
+      0,
+      envelope(&[b"ord", &[1], b"text/plain", &[], b"bar"]),
+    )],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!("inscription dump {txid}i1"))
+    .core(&core)
+    .expected_stderr(format!(
+      "error: transaction {txid} has no envelope with index 1\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn dump_cursed_and_unbound_envelope() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(2);
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[
+      (
+        1,
+        0,
+        0,
+        envelope(&[b"ord", &[1], b"text/plain", &[], b"foo"]),
+      ),
+      (
+        2,
+        0,
+        0,
+        envelope(&[
+          b"ord",
+          &[1],
+          b"text/plain",
+          &[1],
+          b"text/plain",
+          &[],
+          b"bar",
+        ]),
+      ),
+    ],
+    fee: 100 * COIN_VALUE,
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!("inscription dump {txid}i1"))
+    .core(&core)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.body, Some(hex::encode("bar")));
+  assert!(output.duplicate_field);
+  assert_eq!(output.input, 1);
+}
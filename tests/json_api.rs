@@ -155,10 +155,17 @@ fn get_inscription() {
       address: None,
       charms: vec![Charm::Coin, Charm::Uncommon],
       children: Vec::new(),
+      cid: Some(
+        "bafkreievebbxz2eqf2zxtj6yvkuy7rgjj3vqpntijbkind5g64v7gsyp2m"
+          .parse()
+          .unwrap()
+      ),
       content_length: Some(3),
       content_type: Some("text/plain;charset=utf-8".to_string()),
+      delegate: None,
       effective_content_type: Some("text/plain;charset=utf-8".to_string()),
       fee: 138,
+      fee_rate: Some(1.0),
       height: 2,
       id: inscription_id,
       number: 0,
@@ -166,10 +173,14 @@ fn get_inscription() {
       value: Some(10000),
       parents: Vec::new(),
       previous: None,
+      rarity: Some(Rarity::Uncommon),
+      recursive: false,
       rune: None,
       sat: Some(Sat(50 * COIN_VALUE)),
       satpoint: SatPoint::from_str(&format!("{}:{}:{}", reveal, 0, 0)).unwrap(),
+      teleburn: inscription_id.into(),
       timestamp: 2,
+      unbound: false,
     }
   )
 }
@@ -337,12 +348,19 @@ fn get_output() {
           .parse()
           .unwrap()
       ),
+      confirmed: Some(true),
+      height: Some(4),
       inscriptions: vec![
         InscriptionId { txid, index: 0 },
         InscriptionId { txid, index: 1 },
         InscriptionId { txid, index: 2 },
       ],
       indexed: true,
+      rarity_counts: Some(
+        [(Rarity::Common, 15000000000 - 3), (Rarity::Uncommon, 3)]
+          .into_iter()
+          .collect()
+      ),
       runes: Vec::new(),
       sat_ranges: Some(vec![
         (5000000000, 10000000000,),
@@ -367,6 +385,32 @@ fn json_request_fails_when_disabled() {
   assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
 }
 
+#[test]
+fn html_requests_fail_when_json_api_only() {
+  let core = mockcore::spawn();
+
+  let server = TestServer::spawn_with_server_args(&core, &[], &["--json-api-only"]);
+
+  assert_eq!(
+    server.json_request("/sat/2099999997689999").status(),
+    StatusCode::OK
+  );
+
+  assert_eq!(
+    server.request("/sat/2099999997689999").status(),
+    StatusCode::NOT_FOUND
+  );
+
+  assert_eq!(server.request("/").status(), StatusCode::NOT_FOUND);
+
+  assert_eq!(
+    server.request("/static/index.css").status(),
+    StatusCode::NOT_FOUND
+  );
+
+  assert_eq!(server.request("/r/blockheight").status(), StatusCode::OK);
+}
+
 #[test]
 fn get_block() {
   let core = mockcore::spawn();
@@ -481,7 +525,10 @@ fn get_status() {
 
   let dummy_duration = Duration::from_secs(1);
 
+  assert!(status_json.rpc_latency.is_some());
+
   status_json.initial_sync_time = dummy_duration;
+  status_json.rpc_latency = Some(dummy_duration);
   status_json.started = dummy_started;
   status_json.uptime = dummy_duration;
 
@@ -492,18 +539,28 @@ fn get_status() {
       chain: Chain::Regtest,
       content_type_counts: vec![(Some("text/plain;charset=utf-8".into()), 1)],
       cursed_inscriptions: 0,
+      first_inscription_height: 0,
       height: Some(3),
       initial_sync_time: dummy_duration,
       inscriptions: 1,
       lost_sats: 0,
       minimum_rune_for_next_block: Rune(99218849511960410),
+      reorgs: Vec::new(),
+      rpc_latency: Some(dummy_duration),
       rune_index: true,
       runes: 0,
       sat_index: true,
       started: dummy_started,
+      supply: Some(api::Supply {
+        circulating: 20_000_000_000,
+        lost: 0,
+        mined: 20_000_000_000,
+        unmined: 2099999997690000 - 20_000_000_000,
+      }),
       transaction_index: false,
       unrecoverably_reorged: false,
       uptime: dummy_duration,
+      version: env!("CARGO_PKG_VERSION").into(),
     }
   );
 }
@@ -532,6 +589,7 @@ fn get_runes() {
   pretty_assert_eq!(
     rune_json,
     api::Rune {
+      amount: None,
       entry: RuneEntry {
         block: a.id.block,
         burned: 0,
@@ -587,6 +645,7 @@ fn get_runes() {
             timestamp: 24,
             turbo: false,
           }
+          .into()
         ),
         (
           RuneId { block: 17, tx: 1 },
@@ -607,6 +666,7 @@ fn get_runes() {
             timestamp: 17,
             turbo: false,
           }
+          .into()
         ),
         (
           RuneId { block: 10, tx: 1 },
@@ -627,6 +687,7 @@ fn get_runes() {
             timestamp: 10,
             turbo: false,
           }
+          .into()
         )
       ],
       more: false,
@@ -60,6 +60,7 @@ mod epochs;
 mod find;
 mod index;
 mod info;
+mod inscription;
 mod json_api;
 mod list;
 mod parse;
@@ -68,6 +69,7 @@ mod server;
 mod settings;
 mod subsidy;
 mod supply;
+mod teleburn;
 mod traits;
 mod version;
 mod wallet;
@@ -1,4 +1,7 @@
-use {super::*, ord::subcommand::runes::Output};
+use {
+  super::*,
+  ord::subcommand::runes::{BalancesOutput, Output},
+};
 
 #[test]
 fn flag_is_required() {
@@ -141,3 +144,62 @@ fn two_runes() {
     }
   );
 }
+
+#[test]
+fn balances_flag_is_required() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  CommandBuilder::new(format!("--regtest runes balances {}", OutPoint::null()))
+    .core(&core)
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: `ord runes balances` requires index created with `--index-runes` flag\n",
+    )
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn balances_of_output() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let etch = etch(&core, &ord, Rune(RUNE));
+
+  let outpoint = OutPoint {
+    txid: etch.output.reveal,
+    vout: 1,
+  };
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!("--index-runes --regtest runes balances {outpoint}"))
+      .core(&core)
+      .run_and_deserialize_output::<BalancesOutput>(),
+    BalancesOutput {
+      runes: vec![(
+        SpacedRune::new(Rune(RUNE), 0),
+        Pile {
+          amount: 1000,
+          divisibility: 0,
+          symbol: Some('¢'),
+        },
+      )]
+      .into_iter()
+      .collect(),
+    }
+  );
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!(
+      "--index-runes --regtest runes balances {}",
+      OutPoint::null()
+    ))
+    .core(&core)
+    .run_and_deserialize_output::<BalancesOutput>(),
+    BalancesOutput {
+      runes: BTreeMap::new(),
+    }
+  );
+}
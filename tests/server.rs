@@ -318,6 +318,8 @@ fn recursive_inscription_endpoint() {
       id: inscription.id,
       number: 0,
       output: inscription.location.outpoint,
+      rarity: Some(Rarity::Uncommon),
+      rune: None,
       sat: Some(Sat(50 * COIN_VALUE)),
       satpoint: SatPoint {
         outpoint: inscription.location.outpoint,
@@ -418,6 +420,52 @@ fn missing_credentials() {
     .run_and_extract_stdout();
 }
 
+#[test]
+fn invalid_redirect_url_is_an_error() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("--faq-url foo server")
+    .core(&core)
+    .expected_exit_code(2)
+    .stderr_regex(
+      "error: invalid value 'foo' for '--faq-url <FAQ_URL>': relative URL without a base\n.*",
+    )
+    .run_and_extract_stdout();
+
+  let tempdir = TempDir::new().unwrap();
+
+  fs::write(tempdir.path().join("ord.yaml"), "faq_url: foo").unwrap();
+
+  CommandBuilder::new(format!(
+    "--config {} server",
+    tempdir.path().join("ord.yaml").display()
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .stderr_regex("error: .*relative URL without a base.*\n.*")
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn strict_server_refuses_index_with_different_first_inscription_height() {
+  let core = mockcore::spawn();
+
+  let (tempdir, _) = CommandBuilder::new("--first-inscription-height 3 index update")
+    .core(&core)
+    .integration_test(false)
+    .run();
+
+  CommandBuilder::new("--first-inscription-height 5 server --strict")
+    .core(&core)
+    .integration_test(false)
+    .temp_dir(Arc::new(tempdir))
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: index was built with first inscription height 3, but first inscription height 5 is configured\n",
+    )
+    .run_and_extract_stdout();
+}
+
 #[test]
 fn all_endpoints_in_recursive_directory_return_json() {
   let core = mockcore::spawn();
@@ -8,34 +8,67 @@ fn default() {
       r#"\{
   "bitcoin_data_dir": ".*(Bitcoin|bitcoin)",
   "bitcoin_rpc_password": null,
+  "bitcoin_rpc_retries": 6,
+  "bitcoin_rpc_timeout": "15s",
   "bitcoin_rpc_url": "127.0.0.1:8332",
   "bitcoin_rpc_username": null,
+  "bounties_url": null,
   "chain": "mainnet",
   "commit_interval": 5000,
+  "compress_sat_ranges": false,
   "config": null,
   "config_dir": null,
   "cookie_file": ".*\.cookie",
   "data_dir": ".*",
+  "faq_url": null,
   "first_inscription_height": 767430,
   "height_limit": null,
   "hidden": \[\],
+  "hidden_patterns": \[\],
   "index": ".*index\.redb",
   "index_cache_size": \d+,
+  "index_cids": false,
   "index_runes": false,
   "index_sats": false,
   "index_spent_sats": false,
   "index_transactions": false,
+  "inscriptions_on_output_cache_size": null,
+  "install_script_url": null,
   "integration_test": false,
+  "jubilee_height": 824544,
+  "max_fetch_requests": 12,
   "no_index_inscriptions": false,
+  "quiet": false,
+  "reorg_purge_webhook": null,
+  "serve_content_type": null,
   "server_password": null,
   "server_url": null,
-  "server_username": null
+  "server_username": null,
+  "strict_runestone": false,
+  "verify_sat_ranges": false
 \}
 "#,
     )
     .run_and_extract_stdout();
 }
 
+#[test]
+fn passwords_are_redacted() {
+  CommandBuilder::new(
+    "--bitcoin-rpc-username foo --bitcoin-rpc-password bar \
+     --server-username baz --server-password qux settings",
+  )
+  .integration_test(false)
+  .stdout_regex(
+    r#".*
+  "bitcoin_rpc_password": "<redacted>",
+.*
+  "server_password": "<redacted>",
+.*"#,
+  )
+  .run_and_extract_stdout();
+}
+
 #[test]
 fn config_is_loaded_from_config_option() {
   let tempdir = TempDir::new().unwrap();
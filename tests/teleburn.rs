@@ -0,0 +1,50 @@
+use {super::*, ord::subcommand::teleburn::Output};
+
+#[test]
+fn single_destination() {
+  let output = CommandBuilder::new(format!(
+    "teleburn {}i0",
+    "0000000000000000000000000000000000000000000000000000000000000000"
+  ))
+  .run_and_deserialize_output::<Output>();
+
+  assert_eq!(
+    output.ethereum.to_string(),
+    "0x6db65fD59fd356F6729140571B5BCd6bB3b83492",
+  );
+}
+
+#[test]
+fn multiple_destinations() {
+  let a = "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i0"
+    .parse::<InscriptionId>()
+    .unwrap();
+  let b = "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799i1"
+    .parse::<InscriptionId>()
+    .unwrap();
+
+  let output = CommandBuilder::new(format!("teleburn --batch {a} {b}"))
+    .run_and_deserialize_output::<BTreeMap<InscriptionId, Output>>();
+
+  assert_eq!(output.len(), 2);
+  assert_eq!(output[&a].ethereum, a.into());
+  assert_eq!(output[&b].ethereum, b.into());
+
+  let output = CommandBuilder::new(format!("teleburn --batch {a}"))
+    .run_and_deserialize_output::<BTreeMap<InscriptionId, Output>>();
+
+  assert_eq!(output.len(), 1);
+  assert_eq!(output[&a].ethereum, a.into());
+}
+
+#[test]
+fn multiple_destinations_require_batch() {
+  CommandBuilder::new(format!(
+    "teleburn {}i0 {}i1",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000000"
+  ))
+  .expected_exit_code(1)
+  .expected_stderr("error: teleburning more than one destination requires `--batch`\n")
+  .run_and_extract_stdout();
+}
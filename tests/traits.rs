@@ -9,6 +9,7 @@ fn traits_command_prints_sat_traits() {
       decimal: "0.0".into(),
       degree: "0°0′0″0‴".into(),
       name: "nvtdijuwxlp".into(),
+      percentile: "0%".into(),
       height: 0,
       cycle: 0,
       epoch: 0,
@@ -27,6 +28,7 @@ fn traits_command_for_last_sat() {
       decimal: "6929999.0".into(),
       degree: "5°209999′1007″0‴".into(),
       name: "a".into(),
+      percentile: "100%".into(),
       height: 6929999,
       cycle: 5,
       epoch: 32,
@@ -36,3 +38,15 @@ fn traits_command_for_last_sat() {
     }
   );
 }
+
+#[test]
+fn sat_is_an_alias_for_traits_and_accepts_any_representation() {
+  let expected = CommandBuilder::new("traits 0").run_and_deserialize_output::<Output>();
+
+  for representation in ["0", "0.0", "0°0′0″0‴", "nvtdijuwxlp", "0%"] {
+    assert_eq!(
+      CommandBuilder::new(format!("sat {representation}")).run_and_deserialize_output::<Output>(),
+      expected,
+    );
+  }
+}
@@ -197,6 +197,67 @@ fn batch_inscribe_respects_dry_run_flag() {
   assert_eq!(request.status(), 404);
 }
 
+#[test]
+fn batch_inscribe_compute_ids() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml --compute-ids")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      "mode: shared-output\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::wallet::batch::ComputedIds>();
+
+  assert!(core.mempool().is_empty());
+
+  assert_eq!(output.inscriptions.len(), 2);
+
+  for (index, inscription) in output.inscriptions.iter().enumerate() {
+    assert_eq!(
+      inscription.id,
+      InscriptionId {
+        txid: output.reveal,
+        index: index.try_into().unwrap(),
+      }
+    );
+    assert_eq!(inscription.location.outpoint.txid, output.reveal);
+  }
+
+  assert_eq!(output.inscriptions[0].location.offset, 0);
+  assert_eq!(output.inscriptions[1].location.offset, 10_000);
+
+  for (transaction, txid) in [
+    (&output.commit_tx, output.commit),
+    (&output.reveal_tx, output.reveal),
+  ] {
+    assert_eq!(
+      CommandBuilder::new(format!("wallet broadcast {transaction}"))
+        .core(&core)
+        .ord(&ord)
+        .run_and_deserialize_output::<ord::subcommand::wallet::broadcast::Output>()
+        .txid,
+      txid,
+    );
+  }
+
+  core.mine_blocks(1);
+
+  let response = ord.request(format!("/content/{}", output.inscriptions[0].id));
+
+  assert_eq!(response.status(), 200);
+  assert_eq!(response.text().unwrap(), "Hello World");
+}
+
 #[test]
 fn batch_in_same_output_but_different_satpoints() {
   let core = mockcore::spawn();
@@ -487,6 +548,35 @@ fn batch_in_separate_outputs_with_parent_and_non_default_postage() {
   );
 }
 
+#[test]
+fn batch_inscribe_fails_if_content_type_is_not_allowed() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet batch --batch batch.yaml --fee-rate 55 --allow-content-type text/plain --allow-content-type image/png",
+  )
+  .write("inscription.txt", "Hello World")
+  .write("tulip.png", [0; 555])
+  .write("meow.wav", [0; 2048])
+  .write(
+    "batch.yaml",
+    "mode: shared-output\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n- file: meow.wav\n",
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: file `meow.wav` has content type `audio/wav`, which is not allowed by `--allow-content-type`\n",
+  )
+  .run_and_extract_stdout();
+}
+
 #[test]
 fn batch_inscribe_fails_if_invalid_network_destination_address() {
   let core = mockcore::builder().network(Network::Regtest).build();
@@ -1411,6 +1501,85 @@ inscriptions:
   );
 }
 
+#[test]
+fn batch_inscribe_with_satpoint_offset() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let address = receive(&core, &ord);
+
+  core.mine_blocks(3);
+
+  let outpoint = OutPoint {
+    txid: CommandBuilder::new(format!(
+      "--index-sats wallet send --fee-rate 1 {address} 1btc"
+    ))
+    .core(&core)
+    .ord(&ord)
+    .stdout_regex(r".*")
+    .run_and_deserialize_output::<send::Output>()
+    .txid,
+    vout: 0,
+  };
+
+  core.mine_blocks(1);
+
+  let satpoint = SatPoint {
+    outpoint,
+    offset: 1000,
+  };
+
+  let output = serde_json::from_str::<api::Output>(
+    &ord
+      .json_request(format!("/output/{outpoint}"))
+      .text()
+      .unwrap(),
+  )
+  .unwrap();
+
+  let sat = output.sat_ranges.unwrap()[0].0 + satpoint.offset;
+
+  let batchfile =
+    format!("mode: satpoints\ninscriptions:\n- file: inscription.txt\n  satpoint: {satpoint}\n");
+
+  CommandBuilder::new("--index-sats wallet batch --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World".repeat(100))
+    .write("batch.yaml", &batchfile)
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(
+      "error: `satpoint` can only be specified for first sat of an output unless `--allow-offset` is set\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  let output =
+    CommandBuilder::new("--index-sats wallet batch --fee-rate 1 --allow-offset --batch batch.yaml")
+      .write("inscription.txt", "Hello World".repeat(100))
+      .write("batch.yaml", &batchfile)
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  let inscription = &output.inscriptions[0];
+
+  assert_eq!(inscription.location.offset, satpoint.offset);
+
+  ord.assert_response_regex(
+    format!("/inscription/{}", inscription.id),
+    format!(
+      r".*<dt>value</dt>.*<dd>{}</dd>.*<dt>sat</dt>.*<dd>.*{sat}.*</dd>.*<dt>location</dt>.*<dd class=monospace>{}</dd>.*",
+      COIN_VALUE,
+      inscription.location
+    ),
+  );
+}
+
 #[test]
 fn batch_can_etch_rune() {
   let core = mockcore::builder().network(Network::Regtest).build();
@@ -2700,6 +2869,55 @@ fn batch_inscribe_errors_if_pending_etchings() {
     .run_and_extract_stdout();
 }
 
+#[test]
+fn quiet_flag_suppresses_progress_messages() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let spawn = CommandBuilder::new(
+    "--regtest --index-runes --quiet wallet batch --fee-rate 0 --batch batch.yaml",
+  )
+  .write("inscription.jpeg", "inscription")
+  .write(
+    "batch.yaml",
+    serde_yaml::to_string(&batch::File {
+      etching: Some(batch::Etching {
+        divisibility: 0,
+        rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        supply: "1000".parse().unwrap(),
+        premine: "1000".parse().unwrap(),
+        symbol: '¢',
+        ..default()
+      }),
+      inscriptions: vec![batch::Entry {
+        file: Some("inscription.jpeg".into()),
+        ..default()
+      }],
+      ..default()
+    })
+    .unwrap(),
+  )
+  .core(&core)
+  .ord(&ord)
+  .spawn();
+
+  while core.mempool().is_empty() {
+    thread::sleep(Duration::from_millis(10));
+  }
+
+  core.mine_blocks(6);
+
+  spawn.run_and_deserialize_output::<Batch>();
+}
+
 #[test]
 fn forbid_etching_below_rune_activation_height() {
   let core = mockcore::builder().build();
@@ -0,0 +1,75 @@
+use {
+  super::*,
+  ord::subcommand::wallet::{broadcast, sign},
+};
+
+#[test]
+fn inscribe_psbts_can_be_signed_and_broadcast() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let batch = CommandBuilder::new("wallet inscribe --psbt --file foo.txt --fee-rate 1")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  assert!(!batch.reveal_broadcast);
+  assert!(core.mempool().is_empty());
+
+  let signed = CommandBuilder::new(format!("wallet sign {}", batch.commit_psbt.unwrap()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<sign::Output>();
+
+  assert_eq!(
+    CommandBuilder::new(format!("wallet broadcast {}", signed.psbt))
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<broadcast::Output>()
+      .txid,
+    batch.commit,
+  );
+
+  assert_eq!(
+    CommandBuilder::new(format!("wallet broadcast {}", batch.reveal_psbt.unwrap()))
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<broadcast::Output>()
+      .txid,
+    batch.reveal,
+  );
+
+  core.mine_blocks(1);
+
+  let response = ord.request(format!("/content/{}", batch.inscriptions[0].id));
+
+  assert_eq!(response.status(), 200);
+  assert_eq!(response.text().unwrap(), "FOO");
+}
+
+#[test]
+fn raw_transactions_can_be_broadcast() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  let transaction = core.mine_blocks(1)[0].txdata[0].clone();
+
+  assert_eq!(
+    CommandBuilder::new(format!(
+      "wallet broadcast {}",
+      bitcoin::consensus::encode::serialize_hex(&transaction)
+    ))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<broadcast::Output>()
+    .txid,
+    transaction.txid(),
+  );
+}
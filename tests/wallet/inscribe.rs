@@ -47,6 +47,42 @@ fn inscribe_works_with_huge_expensive_inscriptions() {
   .run_and_deserialize_output::<Batch>();
 }
 
+#[test]
+fn inscribe_with_allowed_content_type() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet inscribe --file foo.txt --fee-rate 1 --allow-content-type image/png --allow-content-type text/plain",
+  )
+  .write("foo.txt", "FOO")
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Batch>();
+
+  CommandBuilder::new(
+    "wallet inscribe --file foo.txt --fee-rate 1 --allow-content-type Text/Plain;Charset=UTF-8",
+  )
+  .write("foo.txt", "FOO")
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Batch>();
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --fee-rate 1 --allow-content-type image/png")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: file `foo.txt` has content type `text/plain;charset=utf-8`, which is not allowed by `--allow-content-type`\n",
+    )
+    .run_and_extract_stdout();
+}
+
 #[test]
 fn metaprotocol_appears_on_inscription_page() {
   let core = mockcore::spawn();
@@ -436,6 +472,36 @@ fn inscribe_with_dry_run_flag() {
   assert_eq!(core.mempool().len(), 2);
 }
 
+#[test]
+fn inscribe_with_min_confirmations() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new("wallet inscribe --min-confirmations 2 --file foo.txt --fee-rate 1")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: failed to fund transactions from outputs with at least 2 confirmations\nbecause: wallet contains no cardinal utxos\n",
+    )
+    .run_and_extract_stdout();
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new("wallet inscribe --min-confirmations 2 --file foo.txt --fee-rate 1")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  assert_eq!(core.mempool().len(), 2);
+}
+
 #[test]
 fn inscribe_with_dry_run_flag_fees_increase() {
   let core = mockcore::spawn();
@@ -561,6 +627,33 @@ fn inscribe_works_with_postage() {
   pretty_assert_eq!(inscriptions[0].postage, 5 * COIN_VALUE);
 }
 
+#[test]
+fn inscribe_with_postage_below_dust_limit_fails() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+  core.mine_blocks(1);
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --postage 329sat --fee-rate 1")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .stderr_regex("error: postage of 329sat to .* is below dust limit of 330sat\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  CommandBuilder::new(
+    "wallet inscribe --file foo.txt --postage 1000sat --postage-floor 2000sat --fee-rate 1",
+  )
+  .write("foo.txt", "FOO")
+  .core(&core)
+  .ord(&ord)
+  .stderr_regex("error: postage of 1000sat to .* is below postage floor of 2000sat\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
 #[test]
 fn inscribe_with_non_existent_parent_inscription() {
   let core = mockcore::spawn();
@@ -986,6 +1079,76 @@ fn inscribe_can_compress() {
   assert_eq!(response.bytes().unwrap().deref(), [0; 350_000]);
 }
 
+#[test]
+fn inscribe_can_compress_with_custom_quality_and_window() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let Batch { inscriptions, .. } = CommandBuilder::new(
+    "wallet inscribe --compress --compress-quality 1 --compress-window 16 --file foo.txt --fee-rate 1",
+  )
+  .write("foo.txt", [0; 350_000])
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output();
+
+  let inscription = inscriptions[0].id;
+
+  core.mine_blocks(1);
+
+  ord.sync_server();
+
+  let response = reqwest::blocking::Client::builder()
+    .brotli(true)
+    .build()
+    .unwrap()
+    .get(
+      ord
+        .url()
+        .join(format!("/content/{inscription}",).as_ref())
+        .unwrap(),
+    )
+    .send()
+    .unwrap();
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(response.bytes().unwrap().deref(), [0; 350_000]);
+}
+
+#[test]
+fn inscribe_compression_parameters_are_validated() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet inscribe --compress --compress-quality 12 --file foo.txt --fee-rate 1",
+  )
+  .write("foo.txt", "foo")
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr("error: --compress-quality must be between 0 and 11\n")
+  .run_and_extract_stdout();
+
+  CommandBuilder::new("wallet inscribe --compress --compress-window 9 --file foo.txt --fee-rate 1")
+    .write("foo.txt", "foo")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: --compress-window must be between 10 and 24\n")
+    .run_and_extract_stdout();
+}
+
 #[test]
 fn inscriptions_are_not_compressed_if_no_space_is_saved_by_compression() {
   let core = mockcore::spawn();
@@ -1148,6 +1311,49 @@ fn server_can_decompress_brotli() {
 
   assert_eq!(response.status(), StatusCode::OK);
   assert_eq!(response.bytes().unwrap().deref(), [0; 350_000]);
+
+  let test_server = TestServer::spawn_with_server_args(
+    &core,
+    &[],
+    &["--decompress", "--max-decompressed-size", "349999"],
+  );
+
+  test_server.sync_server();
+
+  let response = client
+    .get(
+      test_server
+        .url()
+        .join(format!("/content/{inscription}",).as_ref())
+        .unwrap(),
+    )
+    .send()
+    .unwrap();
+
+  assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+  let test_server = TestServer::spawn_with_server_args(
+    &core,
+    &[],
+    &["--decompress", "--max-content-response-size", "349999"],
+  );
+
+  test_server.sync_server();
+
+  let response = client
+    .get(
+      test_server
+        .url()
+        .join(format!("/content/{inscription}",).as_ref())
+        .unwrap(),
+    )
+    .send()
+    .unwrap();
+
+  assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+  assert_eq!(
+    response.text().unwrap(),
+    "decompressed content larger than 349999 bytes"
+  );
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use super::*;
+use {super::*, ord::subcommand::wallet::label};
 
 #[test]
 fn label() {
@@ -28,3 +28,108 @@ fn label() {
 
   assert!(output.contains(&inscription.to_string()));
 }
+
+#[test]
+fn local_labels() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, _reveal) = inscribe(&core, &ord);
+
+  let (tempdir, outputs) = CommandBuilder::new("wallet outputs")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output_with_tempdir::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  let outpoint = outputs[0].output;
+
+  let (tempdir, _) = CommandBuilder::new(format!("wallet label set {outpoint} savings"))
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run();
+
+  let (tempdir, _) = CommandBuilder::new(format!("wallet label set {inscription} favorite"))
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run();
+
+  let (tempdir, output) = CommandBuilder::new(format!("wallet label get {inscription}"))
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output_with_tempdir::<label::Output>();
+
+  assert_eq!(
+    output,
+    label::Output {
+      reference: inscription.to_string(),
+      label: "favorite".into(),
+    },
+  );
+
+  let (tempdir, output) = CommandBuilder::new("wallet label list")
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output_with_tempdir::<Vec<label::Output>>();
+
+  let mut labels = vec![
+    label::Output {
+      reference: outpoint.to_string(),
+      label: "savings".into(),
+    },
+    label::Output {
+      reference: inscription.to_string(),
+      label: "favorite".into(),
+    },
+  ];
+
+  labels.sort_by(|a, b| a.reference.cmp(&b.reference));
+
+  assert_eq!(output, labels);
+
+  let (tempdir, output) = CommandBuilder::new("wallet outputs")
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output_with_tempdir::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  assert_eq!(
+    output
+      .into_iter()
+      .find(|output| output.output == outpoint)
+      .unwrap()
+      .label,
+    Some("savings".into()),
+  );
+
+  let (tempdir, output) = CommandBuilder::new("wallet inscriptions")
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output_with_tempdir::<Vec<ord::subcommand::wallet::inscriptions::Output>>(
+    );
+
+  assert_eq!(output[0].label, Some("favorite".into()));
+
+  let (tempdir, _) = CommandBuilder::new(format!("wallet label remove {inscription}"))
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .run();
+
+  CommandBuilder::new(format!("wallet label get {inscription}"))
+    .temp_dir(Arc::new(tempdir))
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(format!("error: no label for `{inscription}`\n"))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
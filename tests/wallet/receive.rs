@@ -18,3 +18,39 @@ fn receive() {
     .unwrap()
     .is_valid_for_network(Network::Bitcoin));
 }
+
+#[test]
+fn receive_count_generates_distinct_addresses() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  let output = CommandBuilder::new("wallet receive --count 3")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<receive::Output>();
+
+  assert_eq!(output.addresses.len(), 3);
+
+  let mut addresses = output
+    .addresses
+    .into_iter()
+    .map(|address| address.assume_checked().to_string())
+    .collect::<Vec<String>>();
+
+  let next = CommandBuilder::new("wallet receive")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<receive::Output>();
+
+  addresses.push(next.addresses[0].clone().assume_checked().to_string());
+
+  assert_eq!(
+    addresses
+      .iter()
+      .collect::<std::collections::BTreeSet<_>>()
+      .len(),
+    4
+  );
+}
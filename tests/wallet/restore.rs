@@ -1,4 +1,7 @@
-use {super::*, ord::subcommand::wallet::create};
+use {
+  super::*,
+  ord::subcommand::wallet::{create, restore},
+};
 
 #[test]
 fn restore_generates_same_descriptors() {
@@ -194,3 +197,100 @@ fn passphrase_conflicts_with_descriptor() {
   .expected_stderr("error: descriptor does not take a passphrase\n")
   .run_and_extract_stdout();
 }
+
+#[test]
+fn restore_with_derivation_path() {
+  let (mnemonic, descriptors) = {
+    let core = mockcore::spawn();
+
+    let create::Output { mnemonic, .. } = CommandBuilder::new("wallet create")
+      .core(&core)
+      .run_and_deserialize_output();
+
+    (mnemonic, core.descriptors())
+  };
+
+  let core = mockcore::spawn();
+
+  let output = CommandBuilder::new([
+    "wallet",
+    "restore",
+    "--from",
+    "mnemonic",
+    "--derivation-path",
+    "m/86'/0'/1'",
+  ])
+  .stdin(mnemonic.to_string().into())
+  .core(&core)
+  .run_and_deserialize_output::<restore::Output>();
+
+  assert_eq!(output.descriptors.len(), 2);
+
+  for (descriptor, restored) in output.descriptors.iter().zip(core.descriptors()) {
+    assert_regex_match!(
+      descriptor,
+      r"tr\(\[[[:xdigit:]]{8}/86'/0'/1'\]xpub.*/[01]/\*\)#.{8}"
+    );
+    assert_regex_match!(
+      restored,
+      r"tr\(\[[[:xdigit:]]{8}/86'/0'/1'\]xprv.*/[01]/\*\)#.{8}"
+    );
+  }
+
+  assert_ne!(core.descriptors(), descriptors);
+}
+
+#[test]
+fn restore_with_invalid_derivation_path_fails() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new([
+    "wallet",
+    "restore",
+    "--from",
+    "mnemonic",
+    "--derivation-path",
+    "m/86'/1'/0'",
+  ])
+  .stdin("".into())
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: derivation path `m/86'/1'/0'` coin type 1' does not match mainnet, expected 0'\n",
+  )
+  .run_and_extract_stdout();
+
+  CommandBuilder::new([
+    "wallet",
+    "restore",
+    "--from",
+    "mnemonic",
+    "--derivation-path",
+    "m/86'/0'",
+  ])
+  .stdin("".into())
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: derivation path `m/86'/0'` must be of the form m/PURPOSE'/COIN_TYPE'/ACCOUNT'\n",
+  )
+  .run_and_extract_stdout();
+
+  CommandBuilder::new([
+    "wallet",
+    "restore",
+    "--from",
+    "mnemonic",
+    "--derivation-path",
+    "m/84'/0'/0'",
+  ])
+  .stdin("".into())
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: derivation path `m/84'/0'/0'` purpose 84' is not supported, only taproot wallets with purpose 86' can be restored\n",
+  )
+  .run_and_extract_stdout();
+
+  assert!(core.descriptors().is_empty());
+}
@@ -347,6 +347,8 @@ inscriptions:
     output_json,
     api::Output {
       address: Some(destination.clone()),
+      confirmed: Some(true),
+      height: Some(2),
       inscriptions: vec![
         InscriptionId {
           txid: reveal_txid,
@@ -362,6 +364,11 @@ inscriptions:
         },
       ],
       indexed: true,
+      rarity_counts: Some(
+        [(Rarity::Common, 29_999), (Rarity::Uncommon, 1)]
+          .into_iter()
+          .collect()
+      ),
       runes: Vec::new(),
       sat_ranges: Some(vec![(5_000_000_000, 5_000_030_000)]),
       script_pubkey: destination.payload.script_pubkey().to_asm_string(),
@@ -484,6 +491,67 @@ fn send_btc_with_fee_rate() {
   assert_eq!(tx.output[0].value, 2 * COIN_VALUE);
 }
 
+#[test]
+fn send_btc_with_min_confirmations() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --min-confirmations 2 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: failed to fund transaction from outputs with at least 2 confirmations\nbecause: not enough cardinal utxos\n",
+  )
+  .run_and_extract_stdout();
+
+  assert!(core.get_locked().is_empty());
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --min-confirmations 2 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert_eq!(core.mempool().len(), 1);
+  assert!(core.get_locked().is_empty());
+}
+
+#[test]
+fn send_btc_with_min_confirmations_keeps_inscriptions_locked() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let (_, reveal) = inscribe(&core, &ord);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --min-confirmations 2 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(core.get_locked().contains(&OutPoint {
+    txid: reveal,
+    vout: 0,
+  }));
+}
+
 #[test]
 fn send_btc_locks_inscriptions() {
   let core = mockcore::spawn();
@@ -562,7 +630,7 @@ fn wallet_send_with_fee_rate() {
 }
 
 #[test]
-fn user_must_provide_fee_rate_to_send() {
+fn fee_rate_is_estimated_if_not_provided() {
   let core = mockcore::spawn();
 
   let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
@@ -573,17 +641,43 @@ fn user_must_provide_fee_rate_to_send() {
 
   let (inscription, _) = inscribe(&core, &ord);
 
-  CommandBuilder::new(format!(
+  let output = CommandBuilder::new(format!(
     "wallet send bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}"
   ))
   .core(&core)
   .ord(&ord)
-  .expected_exit_code(2)
-  .stderr_regex(
-    ".*error: the following required arguments were not provided:
-.*--fee-rate <FEE_RATE>.*",
+  .run_and_deserialize_output::<Send>();
+
+  assert_eq!(output.fee_rate, 2.0);
+}
+
+#[test]
+fn fee_rate_above_maximum_is_an_error() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 10000 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: fee rate of 10000 sats/vB exceeds maximum of 1000 sats/vB, raise it with `--max-fee-rate`\n",
   )
   .run_and_extract_stdout();
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 10000 --max-fee-rate 10000 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
 }
 
 #[test]
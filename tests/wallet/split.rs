@@ -0,0 +1,192 @@
+use {super::*, ord::subcommand::wallet::split};
+
+#[test]
+fn inscriptions_are_split_onto_separate_outputs() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let reveal = CommandBuilder::new("wallet batch --fee-rate 0 --batch batch.yaml")
+    .write("inscription.txt", "INSCRIPTION")
+    .write(
+      "batch.yaml",
+      "\
+mode: shared-output
+
+inscriptions:
+- file: inscription.txt
+- file: inscription.txt
+- file: inscription.txt
+",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>()
+    .reveal;
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!("wallet split --fee-rate 0 {reveal}:0"))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<split::Output>();
+
+  core.mine_blocks(1);
+
+  let inscriptions = CommandBuilder::new("wallet inscriptions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::inscriptions::Output>>();
+
+  pretty_assert_eq!(
+    inscriptions
+      .iter()
+      .map(|output| (output.inscription, output.location, output.postage))
+      .collect::<Vec<(InscriptionId, SatPoint, u64)>>(),
+    (0..3)
+      .map(|i| (
+        InscriptionId {
+          txid: reveal,
+          index: i,
+        },
+        SatPoint {
+          outpoint: OutPoint {
+            txid: output.txid,
+            vout: i,
+          },
+          offset: 0,
+        },
+        10_000,
+      ))
+      .collect::<Vec<(InscriptionId, SatPoint, u64)>>(),
+  );
+}
+
+#[test]
+fn runes_are_split_onto_separate_outputs() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  let outpoint = *balances.runes[&SpacedRune::new(Rune(RUNE), 0)]
+    .keys()
+    .next()
+    .unwrap();
+
+  let output = CommandBuilder::new(format!(
+    "--regtest --index-runes wallet split --fee-rate 0 {outpoint}"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<split::Output>();
+
+  core.mine_blocks(1);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  pretty_assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)],
+    [(
+      OutPoint {
+        txid: output.txid,
+        vout: 0,
+      },
+      Pile {
+        amount: 1000,
+        divisibility: 0,
+        symbol: Some('¢'),
+      },
+    )]
+    .into_iter()
+    .collect()
+  );
+}
+
+#[test]
+fn splitting_output_without_inscriptions_or_runes_is_an_error() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let txid = core.mine_blocks(1)[0].txdata[0].txid();
+
+  CommandBuilder::new(format!(
+    "--regtest --index-runes wallet split --fee-rate 1 {txid}:0"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: output {txid}:0 contains no inscriptions or runes to split\n"
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn inscriptions_on_the_same_sat_are_split_together() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let reveal = CommandBuilder::new("wallet batch --fee-rate 0 --batch batch.yaml")
+    .write("inscription.txt", "INSCRIPTION")
+    .write(
+      "batch.yaml",
+      "mode: same-sat\ninscriptions:\n- file: inscription.txt\n- file: inscription.txt\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>()
+    .reveal;
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!("wallet split --fee-rate 0 {reveal}:0"))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<split::Output>();
+
+  core.mine_blocks(1);
+
+  let inscriptions = CommandBuilder::new("wallet inscriptions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::inscriptions::Output>>();
+
+  assert_eq!(inscriptions.len(), 2);
+
+  for inscription in inscriptions {
+    assert_eq!(
+      inscription.location,
+      SatPoint {
+        outpoint: OutPoint {
+          txid: output.txid,
+          vout: 0,
+        },
+        offset: 0,
+      }
+    );
+  }
+}